@@ -0,0 +1,30 @@
+use freedom_api::prelude::*;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::from_env()?;
+
+    let account_id = client
+        .new_account()
+        .name("ABC Space")
+        .storage_key("ABCSpace")
+        .add_realtime_cidr("Operations", "1.2.3.4/32")
+        .add_api_cidr("Office", "10.0.0.0/8")
+        .send()
+        .await?;
+
+    println!("Created account {account_id}");
+
+    let user = client
+        .new_user()
+        .account_id(account_id)
+        .first_name("Han")
+        .last_name("Solo")
+        .email("flyingsolo@gmail.com")
+        .send()
+        .await?;
+
+    println!("{:#?}", user);
+
+    Ok(())
+}
@@ -1,15 +1,10 @@
 use freedom_api::prelude::*;
-use futures::StreamExt;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let client = Client::from_env()?;
-    let accounts = client
-        .get_accounts()
-        .filter_map(|result| async move { result.ok() })
-        .map(|container| container.into_inner())
-        .collect::<Vec<_>>()
-        .await;
+    // Items which fail to deserialize are skipped
+    let (accounts, _errors) = client.get_accounts().collect_inner_lossy().await;
 
     println!("{:#?}", accounts);
 
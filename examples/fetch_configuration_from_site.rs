@@ -0,0 +1,19 @@
+use freedom_api::prelude::*;
+use freedom_config::Config;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let atlas_config = Config::from_env()?;
+    let atlas_client = Client::from_config(atlas_config);
+
+    let configurations = atlas_client
+        .get_site_by_id(14)
+        .await?
+        .into_inner()
+        .get_configurations(&atlas_client)
+        .await?;
+
+    println!("{:#?}", configurations);
+
+    Ok(())
+}
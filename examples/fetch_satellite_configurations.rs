@@ -1,16 +1,15 @@
 use freedom_api::prelude::*;
 use freedom_config::Config;
-use futures::StreamExt;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let config = Config::from_env()?;
     let client = Client::from_config(config);
-    let satellite_configurations = client
-        .get_satellite_configurations_by_account_name("ATLAS")
-        .filter_map(|result| async move { result.ok() })
-        .map(|container| container.into_inner())
-        .collect::<Vec<_>>()
+    let account = client.get_current_account().await?;
+    // Items which fail to deserialize are skipped
+    let (satellite_configurations, _errors) = client
+        .get_satellite_configurations_by_account_name(&account.name)
+        .collect_inner_lossy()
         .await;
 
     println!("{:#?}", satellite_configurations);
@@ -0,0 +1,201 @@
+use bytes::Bytes;
+use freedom_config::Config;
+use futures_util::StreamExt;
+use reqwest::{header::HeaderMap, Response, StatusCode};
+use tokio::sync::OnceCell;
+use url::Url;
+
+use crate::{
+    api::{
+        info::ServerInfo, pagination::PageOptions, provenance::Provenance, Api, Container,
+        PaginatedStream, Value,
+    },
+    backoff::Backoff,
+    caching_client::CachingClient,
+    client::Inner,
+    error::Error,
+    Client,
+};
+
+/// A client which is either a [`Client`] or a [`CachingClient`], selected at runtime.
+///
+/// The [`Api`] trait is not object safe, since its methods are generic and each client defines
+/// its own [`Container`](Api::Container), so `dyn Api` cannot be used to choose between clients
+/// at runtime. Wrapping either client in this enum allows for the choice to be made from
+/// configuration, without making the surrounding code generic over the client type.
+///
+/// # Trade-offs
+///
+/// Every item is returned in an `Inner<T>`, regardless of the wrapped client. For the caching
+/// client this means each item is cloned out of its `Arc`, so large items lose the cheap sharing
+/// that [`CachingClient`] offers on its own. Each call also matches on the variant, although this
+/// cost is negligible next to the latency of the request. Code which only ever uses one client
+/// should use it directly.
+///
+/// # Example
+///
+/// ```
+/// # use freedom_api::prelude::*;
+/// let config = Config::builder()
+///     .environment(Test)
+///     .key("foo")
+///     .secret("bar")
+///     .build()
+///     .unwrap();
+/// let client = Client::from_config(config);
+///
+/// let use_cache = true;
+/// let client: AnyClient = if use_cache {
+///     CachingClient::new(client, 10_000).into()
+/// } else {
+///     client.into()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub enum AnyClient {
+    /// A client which sends every request to freedom
+    Client(Client),
+    /// A client which caches responses
+    Caching(CachingClient),
+}
+
+impl From<Client> for AnyClient {
+    fn from(client: Client) -> Self {
+        Self::Client(client)
+    }
+}
+
+impl From<CachingClient> for AnyClient {
+    fn from(client: CachingClient) -> Self {
+        Self::Caching(client)
+    }
+}
+
+impl Api for AnyClient {
+    type Container<T: Value> = Inner<T>;
+
+    async fn get(&self, url: Url) -> Result<(Bytes, StatusCode), Error> {
+        match self {
+            Self::Client(client) => client.get(url).await,
+            Self::Caching(client) => client.get(url).await,
+        }
+    }
+
+    async fn get_response(&self, url: Url, headers: HeaderMap) -> Result<Response, Error> {
+        match self {
+            Self::Client(client) => client.get_response(url, headers).await,
+            Self::Caching(client) => client.get_response(url, headers).await,
+        }
+    }
+
+    async fn get_traced(&self, url: Url) -> Result<(Bytes, StatusCode, Provenance), Error> {
+        match self {
+            Self::Client(client) => client.get_traced(url).await,
+            Self::Caching(client) => client.get_traced(url).await,
+        }
+    }
+
+    fn get_paginated_with_options<T>(
+        &self,
+        head_url: Url,
+        options: PageOptions,
+    ) -> PaginatedStream<'_, Self::Container<T>>
+    where
+        T: 'static + Value,
+    {
+        match self {
+            Self::Client(client) => client.get_paginated_with_options(head_url, options),
+            Self::Caching(client) => Box::pin(
+                client
+                    .get_paginated_with_options::<T>(head_url, options)
+                    .map(|item| item.map(|value| Inner(value.into_inner()))),
+            ),
+        }
+    }
+
+    async fn delete(&self, url: Url) -> Result<Response, Error> {
+        match self {
+            Self::Client(client) => client.delete(url).await,
+            Self::Caching(client) => client.delete(url).await,
+        }
+    }
+
+    async fn post<S>(&self, url: Url, msg: S) -> Result<Response, Error>
+    where
+        S: serde::Serialize + Send + Sync,
+    {
+        match self {
+            Self::Client(client) => client.post(url, msg).await,
+            Self::Caching(client) => client.post(url, msg).await,
+        }
+    }
+
+    async fn put<S>(&self, url: Url, msg: S) -> Result<Response, Error>
+    where
+        S: serde::Serialize + Send + Sync,
+    {
+        match self {
+            Self::Client(client) => client.put(url, msg).await,
+            Self::Caching(client) => client.put(url, msg).await,
+        }
+    }
+
+    async fn patch<S>(&self, url: Url, msg: S) -> Result<Response, Error>
+    where
+        S: serde::Serialize + Send + Sync,
+    {
+        match self {
+            Self::Client(client) => client.patch(url, msg).await,
+            Self::Caching(client) => client.patch(url, msg).await,
+        }
+    }
+
+    fn config(&self) -> &Config {
+        match self {
+            Self::Client(client) => client.config(),
+            Self::Caching(client) => client.config(),
+        }
+    }
+
+    fn lenient_links_enabled(&self) -> bool {
+        match self {
+            Self::Client(client) => client.lenient_links_enabled(),
+            Self::Caching(client) => client.lenient_links_enabled(),
+        }
+    }
+
+    fn log_bodies_enabled(&self) -> bool {
+        match self {
+            Self::Client(client) => client.log_bodies_enabled(),
+            Self::Caching(client) => client.log_bodies_enabled(),
+        }
+    }
+
+    fn backoff(&self) -> Box<dyn Backoff> {
+        match self {
+            Self::Client(client) => client.backoff(),
+            Self::Caching(client) => client.backoff(),
+        }
+    }
+
+    fn retries_requests(&self) -> bool {
+        match self {
+            Self::Client(client) => client.retries_requests(),
+            Self::Caching(client) => client.retries_requests(),
+        }
+    }
+
+    fn server_info_memo(&self) -> Option<&OnceCell<ServerInfo>> {
+        match self {
+            Self::Client(client) => client.server_info_memo(),
+            Self::Caching(client) => client.server_info_memo(),
+        }
+    }
+
+    fn config_mut(&mut self) -> &mut Config {
+        match self {
+            Self::Client(client) => client.config_mut(),
+            Self::Caching(client) => client.config_mut(),
+        }
+    }
+}
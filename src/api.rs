@@ -5,33 +5,74 @@
 //!
 //! The API trait
 #![allow(clippy::type_complexity)]
-use std::{future::Future, ops::Deref, pin::Pin};
+use std::{
+    future::Future,
+    ops::{Deref, Range},
+    path::Path,
+    pin::Pin,
+    time::Duration,
+};
 
 use async_stream::stream;
 use bytes::Bytes;
 use freedom_config::Config;
 use freedom_models::{
     account::Account,
+    azel::AzEl,
     band::Band,
-    pagination::Paginated,
     satellite::Satellite,
     satellite_configuration::SatelliteConfiguration,
-    site::Site,
+    site::{Site, SiteConfiguration},
     task::{Task, TaskRequest, TaskStatusType, TaskType},
     user::User,
-    utils::Embedded,
 };
-use reqwest::{Response, StatusCode};
+use reqwest::{header::HeaderMap, Method, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde_json::Value as JsonValue;
-use time::{format_description::well_known::Iso8601, OffsetDateTime};
+use time::OffsetDateTime;
 use url::Url;
 
 use futures_core::Stream;
+use futures_util::StreamExt;
+use tokio::sync::OnceCell;
+use tracing::Instrument;
+
+use crate::{
+    backoff::Backoff,
+    error::Error,
+    ids::{
+        AccountId, BandId, SatelliteConfigurationId, SatelliteId, SiteConfigurationId, SiteId,
+        TaskId, TaskRequestId, UserId,
+    },
+};
 
-use crate::error::Error;
+use self::{
+    orbit::OrbitInfo,
+    pagination::{EmbeddedPage, PageOptions, PaginationBehavior},
+    provenance::{Provenance, Traced},
+    resource::{NamedResource, Resource},
+    time_range::TimeRange,
+    visibility::Visibility,
+};
 
+pub mod bulk;
+pub(crate) mod current;
+pub(crate) mod delete;
+pub(crate) mod download;
+pub mod fps;
+pub(crate) mod info;
+pub mod orbit;
+pub(crate) mod pagination;
+pub(crate) mod pairing;
 pub(crate) mod post;
+pub(crate) mod provenance;
+pub(crate) mod put;
+pub(crate) mod resource;
+pub(crate) mod time_range;
+pub(crate) mod token;
+pub(crate) mod usage;
+pub(crate) mod visibility;
+pub(crate) mod watch;
 
 /// A super trait containing all the requirements for Freedom API Values
 pub trait Value: std::fmt::Debug + DeserializeOwned + Clone + Send + Sync {}
@@ -72,6 +113,9 @@ pub trait Container<T>: Deref<Target = T> + Value {
 /// Each item in the stream is a result, since one or more items may fail to be serialized
 pub type PaginatedStream<'a, T> = Pin<Box<dyn Stream<Item = Result<T, Error>> + 'a + Send>>;
 
+/// A stream of the chunks of a file, as they are received from freedom
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Bytes, Error>> + Send>>;
+
 /// The primary trait for interfacing with the Freedom API
 pub trait Api: Send + Sync {
     /// The [`Api`] supports implementors with different so-called "container" types.
@@ -90,21 +134,152 @@ pub trait Api: Send + Sync {
         T: Value,
     {
         async move {
-            let (body, status) = self.get(url).await?;
+            let fetch = async {
+                let (body, status) = self.get(url.clone()).await?;
+
+                error_on_non_success(&status, &body)?;
+
+                deserialize_body(&body, self.lenient_links_enabled())
+            };
+
+            fetch.await.map_err(|error| error.at(Method::GET, &url))
+        }
+    }
+
+    /// Like [`get_json_map`](Self::get_json_map), however the deserialized value is accompanied by
+    /// the [`Provenance`] of the request: the source URL, the time it was fetched, and whether it
+    /// was served from a cache.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let url = client.path_to_url("satellites/42")?;
+    /// let satellite = client.get_json_map_traced::<Satellite>(url).await?;
+    /// println!(
+    ///     "{} fetched from {} at {}",
+    ///     satellite.name,
+    ///     satellite.provenance().url,
+    ///     satellite.provenance().fetched_at
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn get_json_map_traced<T>(
+        &self,
+        url: Url,
+    ) -> impl Future<Output = Result<Traced<T>, Error>> + Send
+    where
+        T: Value,
+    {
+        async move {
+            let fetch = async {
+                let (body, status, provenance) = self.get_traced(url.clone()).await?;
+
+                error_on_non_success(&status, &body)?;
 
-            error_on_non_success(&status)?;
+                let value = deserialize_body(&body, self.lenient_links_enabled())?;
+                Ok::<_, Error>(Traced::new(value, provenance))
+            };
 
-            let utf8_str = String::from_utf8_lossy(&body);
-            serde_json::from_str(&utf8_str).map_err(From::from)
+            fetch.await.map_err(|error| error.at(Method::GET, &url))
         }
     }
 
+    /// Whether links which cannot be parsed as a URL should be dropped prior to deserialization.
+    ///
+    /// When enabled, a malformed link is logged and removed from the object rather than failing
+    /// the deserialization of the entire object. Disabled by default.
+    fn lenient_links_enabled(&self) -> bool {
+        false
+    }
+
+    /// Whether the bodies of requests and responses are logged, which may expose personal
+    /// information and tokens. Disabled by default.
+    fn log_bodies_enabled(&self) -> bool {
+        false
+    }
+
+    /// A fresh instance of the strategy used when polling or resuming after a failure
+    fn backoff(&self) -> Box<dyn Backoff> {
+        crate::backoff::default_strategy()
+    }
+
+    /// Whether the client already retries requests which failed for transient reasons, in which
+    /// case they are not retried again on top. Disabled by default.
+    fn retries_requests(&self) -> bool {
+        false
+    }
+
+    /// Where the [`ServerInfo`](info::ServerInfo) of the server is remembered, so that it is only
+    /// fetched once. Clients without a memo fetch it on every call.
+    fn server_info_memo(&self) -> Option<&OnceCell<info::ServerInfo>> {
+        None
+    }
+
+    /// Fetch the version and capabilities reported by the server, which allows features to be
+    /// detected before they are used
+    ///
+    /// Servers which do not report any information produce [`ServerInfo::unknown`](info::ServerInfo::unknown)
+    /// rather than an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let info = client.get_server_info().await?;
+    /// if info.version_at_least((3, 12, 0)) == Some(true) {
+    ///     // Use the newer finders ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn get_server_info(&self) -> impl Future<Output = Result<info::ServerInfo, Error>> + Send {
+        info::server_info(self)
+    }
+
     /// Creates a get request at the provided absolute URI for the client's environment, using basic
     /// authentication.
     ///
     /// Returns the raw binary body, and the status code.
     fn get(&self, url: Url) -> impl Future<Output = Result<(Bytes, StatusCode), Error>> + Send;
 
+    /// Creates a get request at the provided absolute URI for the client's environment, using basic
+    /// authentication and the provided headers, returning the response before its body is read.
+    ///
+    /// Responses are never cached, which makes this suitable for large downloads.
+    fn get_response(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+    ) -> impl Future<Output = Result<Response, Error>> + Send;
+
+    /// Like [`get`](Self::get), additionally returning the [`Provenance`] of the response.
+    ///
+    /// Clients which cache responses should override this to report cache hits, along with the
+    /// time of the original fetch.
+    fn get_traced(
+        &self,
+        url: Url,
+    ) -> impl Future<Output = Result<(Bytes, StatusCode, Provenance), Error>> + Send {
+        async move {
+            let (body, status) = self.get(url.clone()).await?;
+            let provenance = Provenance {
+                url,
+                fetched_at: OffsetDateTime::now_utc(),
+                from_cache: false,
+            };
+
+            Ok((body, status, provenance))
+        }
+    }
+
     /// Creates a stream of items from a paginated endpoint.
     ///
     /// The stream is produced as a collection of `Result<T>`. This is so that if any one item fails
@@ -123,45 +298,207 @@ pub trait Api: Send + Sync {
     where
         T: 'static + Value,
     {
-        let base = self.config().environment().freedom_entrypoint();
-        let mut current_url = head_url; // Not necessary but makes control flow more obvious
-        Box::pin(stream! {
-            loop {
-                // Get the results for the current page.
-                let pag = self.get_json_map::<Paginated<JsonValue>>(current_url).await?;
-                for item in pag.items {
-                    let i = serde_json::from_value::<Self::Container<T>>(item).map_err(From::from);
-                    yield i;
-                }
-                if let Some(link) = pag.links.get("next") {
-                    // Update the URL to the next page.
-                    current_url = match link.has_host() {
-                        true => link.to_owned(),
-                        false => {
-                            base.clone()
-                                .join(link.as_str())
-                                .map_err(|e| crate::error::Error::pag_item(e.to_string()))?
-                        }
-                    };
-                } else {
-                    break;
-                }
-            }
-        })
+        self.get_paginated_with_options(head_url, PageOptions::default())
+    }
+
+    /// Creates a stream of items from a paginated endpoint, which handles a page that cannot be
+    /// fetched according to the provided behavior
+    ///
+    /// Long scans can retry a failed page rather than losing every page which follows it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::{error::Error, prelude::*};
+    /// # use futures::StreamExt;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let url = client.path_to_url("requests")?;
+    /// let mut requests = client.get_paginated_with::<freedom_models::task::TaskRequest>(
+    ///     url,
+    ///     PaginationBehavior::RetryPage { attempts: 3 },
+    /// );
+    /// while let Some(request) = requests.next().await {
+    ///     match request {
+    ///         Ok(request) => println!("{}", request.target_date),
+    ///         Err(Error::PageFetch { url, .. }) => eprintln!("Gave up on {url}"),
+    ///         Err(error) => eprintln!("Skipping a request: {error}"),
+    ///     }
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn get_paginated_with<T>(
+        &self,
+        head_url: Url,
+        behavior: PaginationBehavior,
+    ) -> PaginatedStream<'_, Self::Container<T>>
+    where
+        T: 'static + Value,
+    {
+        self.get_paginated_with_options(head_url, PageOptions::new().on_page_error(behavior))
+    }
+
+    /// Creates a stream of items from a paginated endpoint, using the provided page size, starting
+    /// page, and sort order.
+    ///
+    /// The size and sort options are preserved when following the link to the next page, if the
+    /// server omits them from the link.
+    ///
+    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
+    /// and return type
+    fn get_paginated_with_options<T>(
+        &self,
+        head_url: Url,
+        options: PageOptions,
+    ) -> PaginatedStream<'_, Self::Container<T>>
+    where
+        T: 'static + Value,
+    {
+        paginate(self, head_url, options)
+    }
+
+    /// Fetch the size and totals of the paginated collection at the URL, such as to pre-allocate
+    /// or report progress while streaming it
+    ///
+    /// Only the page at the URL is fetched, so limiting the page size with [`PageOptions`] keeps
+    /// the request small.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let mut url = client.path_to_url("satellites")?;
+    /// url.query_pairs_mut().append_pair("size", "1");
+    /// let meta = client.get_page_meta(url).await?;
+    /// println!("{} satellites", meta.total_elements);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn get_page_meta(
+        &self,
+        url: Url,
+    ) -> impl Future<Output = Result<pagination::PageMeta, Error>> + Send {
+        pagination::page_meta(self, url)
+    }
+
+    /// Count the [`TaskRequest`] objects visible to the client, without fetching them
+    fn count_requests(&self) -> impl Future<Output = Result<u64, Error>> + Send {
+        pagination::count(self, "requests/search/findAll")
+    }
+
+    /// Count the [`Satellite`] objects visible to the client, without fetching them
+    fn count_satellites(&self) -> impl Future<Output = Result<u64, Error>> + Send {
+        pagination::count(self, "satellites")
+    }
+
+    /// Count the [`Site`] objects visible to the client, without fetching them
+    fn count_sites(&self) -> impl Future<Output = Result<u64, Error>> + Send {
+        pagination::count(self, "sites")
+    }
+
+    /// Count the [`Band`] objects visible to the client, without fetching them
+    fn count_satellite_bands(&self) -> impl Future<Output = Result<u64, Error>> + Send {
+        pagination::count(self, "satellite_bands")
+    }
+
+    /// Count the [`SatelliteConfiguration`] objects visible to the client, without fetching them
+    fn count_satellite_configurations(&self) -> impl Future<Output = Result<u64, Error>> + Send {
+        pagination::count(self, "satellite_configurations")
     }
 
     fn config(&self) -> &Config;
 
     fn config_mut(&mut self) -> &mut Config;
 
+    /// Fetch the URL from the given path, relative to the entrypoint of the client's environment
+    ///
+    /// Fails with [`Error::UrlConstruction`] when the URL cannot be constructed, or when it would
+    /// fall outside of the entrypoint. This is the case for absolute URLs, paths starting with
+    /// `/`, and entrypoints without a trailing slash.
+    fn path_to_url(&self, path: impl AsRef<str>) -> Result<Url, Error> {
+        let entrypoint = self.config().environment().freedom_entrypoint();
+        join_entrypoint(&entrypoint, path.as_ref())
+    }
+
     /// Fetch the URL from the given path
     ///
     /// # Panics
     ///
     /// Panics in the event the URL cannot be constructed from the provided path
-    fn path_to_url(&self, path: impl AsRef<str>) -> Url {
-        let url = self.config().environment().freedom_entrypoint();
-        url.join(path.as_ref()).expect("Invalid URL construction")
+    #[deprecated(note = "use `path_to_url`, which returns an error rather than panicking")]
+    fn path_to_url_unchecked(&self, path: impl AsRef<str>) -> Url {
+        self.path_to_url(path).expect("Invalid URL construction")
+    }
+
+    /// Produces a single [`Resource`] matching the provided ID.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let satellite = client.get_by_id::<Satellite>(42).await?;
+    /// println!("{}", satellite.name);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn get_by_id<T: Resource>(
+        &self,
+        id: impl Into<T::Id> + Send,
+    ) -> impl Future<Output = Result<Self::Container<T>, Error>> + Send {
+        let id: T::Id = id.into();
+
+        async move {
+            let uri = self.path_to_url(format!("{}/{id}", T::COLLECTION))?;
+            self.get_json_map(uri).await
+        }
+    }
+
+    /// Produces a paginated stream of every [`Resource`] of the collection.
+    ///
+    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
+    /// and return type
+    fn list<T: Resource>(&self) -> PaginatedStream<'_, Self::Container<T>> {
+        self.list_with_options(PageOptions::default())
+    }
+
+    /// Produces a paginated stream of every [`Resource`] of the collection, using the provided
+    /// page options.
+    ///
+    /// See [`get_paginated_with_options`](Self::get_paginated_with_options) documentation for more
+    /// details about the process and return type
+    fn list_with_options<T: Resource>(
+        &self,
+        options: PageOptions,
+    ) -> PaginatedStream<'_, Self::Container<T>> {
+        let uri = match self.path_to_url(T::LIST) {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+        self.get_paginated_with_options(uri, options)
+    }
+
+    /// Produces a single [`NamedResource`] matching the provided name.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    fn find_by_name<T: NamedResource>(
+        &self,
+        name: impl AsRef<str> + Send,
+    ) -> impl Future<Output = Result<Self::Container<T>, Error>> + Send {
+        async move {
+            let mut uri = self.path_to_url(T::FIND_BY_NAME)?;
+            uri.query_pairs_mut().append_pair("name", name.as_ref());
+            self.get_json_map(uri).await
+        }
     }
 
     fn delete(&self, url: Url) -> impl Future<Output = Result<Response, Error>> + Send;
@@ -179,10 +516,41 @@ pub trait Api: Send + Sync {
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// # });
     /// ```
-    fn delete_band_details(&self, id: i32) -> impl Future<Output = Result<Response, Error>> + Send {
+    fn delete_band_details(
+        &self,
+        id: impl Into<BandId> + Send,
+    ) -> impl Future<Output = Result<Response, Error>> + Send {
+        let id: BandId = id.into();
+
+        async move {
+            let uri = self.path_to_url(format!("satellite_bands/{id}"))?;
+            delete_checked(self, uri).await
+        }
+    }
+
+    /// Request to delete the band details object matching the provided `id`, refusing with
+    /// [`Error::InUse`] when any upcoming task request targets the band
+    ///
+    /// See [`get_band_usage`](Self::get_band_usage) for how the task requests are found.
+    fn delete_band_details_if_unused(
+        &self,
+        id: impl Into<BandId> + Send,
+    ) -> impl Future<Output = Result<Response, Error>> + Send
+    where
+        Self: Sized,
+    {
+        let id: BandId = id.into();
+
         async move {
-            let uri = self.path_to_url(format!("satellite_bands/{id}"));
-            self.delete(uri).await
+            let usage = self.get_band_usage(id).await?;
+            if usage.has_upcoming() {
+                return Err(Error::InUse {
+                    entity: format!("band {id}"),
+                    upcoming: usage.upcoming,
+                });
+            }
+
+            self.delete_band_details(id).await
         }
     }
 
@@ -201,11 +569,37 @@ pub trait Api: Send + Sync {
     /// ```
     fn delete_satellite_configuration(
         &self,
-        id: i32,
+        id: impl Into<SatelliteConfigurationId> + Send,
     ) -> impl Future<Output = Result<Response, Error>> + Send {
+        let id: SatelliteConfigurationId = id.into();
+
+        async move {
+            let uri = self.path_to_url(format!("satellite_configurations/{id}"))?;
+            delete_checked(self, uri).await
+        }
+    }
+
+    /// Request to delete the satellite configuration matching the provided `id`, refusing with
+    /// [`Error::InUse`] when any upcoming task request references the configuration
+    fn delete_satellite_configuration_if_unused(
+        &self,
+        id: impl Into<SatelliteConfigurationId> + Send,
+    ) -> impl Future<Output = Result<Response, Error>> + Send
+    where
+        Self: Sized,
+    {
+        let id: SatelliteConfigurationId = id.into();
+
         async move {
-            let uri = self.path_to_url(format!("satellite_configurations/{id}"));
-            self.delete(uri).await
+            let usage = self.get_configuration_usage(id).await?;
+            if usage.has_upcoming() {
+                return Err(Error::InUse {
+                    entity: format!("satellite configuration {id}"),
+                    upcoming: usage.upcoming,
+                });
+            }
+
+            self.delete_satellite_configuration(id).await
         }
     }
 
@@ -222,10 +616,31 @@ pub trait Api: Send + Sync {
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// # });
     /// ```
-    fn delete_satellite(&self, id: i32) -> impl Future<Output = Result<Response, Error>> + Send {
+    fn delete_satellite(
+        &self,
+        id: impl Into<SatelliteId> + Send,
+    ) -> impl Future<Output = Result<Response, Error>> + Send {
+        let id: SatelliteId = id.into();
+
+        async move {
+            let uri = self.path_to_url(format!("satellites/{id}"))?;
+            delete_checked(self, uri).await
+        }
+    }
+
+    /// Produces a single [`ExistingOverride`](post::overrides::ExistingOverride) matching the
+    /// provided ID.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    fn get_override_by_id(
+        &self,
+        override_id: i32,
+    ) -> impl Future<Output = Result<Self::Container<post::overrides::ExistingOverride>, Error>> + Send
+    {
         async move {
-            let uri = self.path_to_url(format!("satellites/{id}"));
-            self.delete(uri).await
+            let uri = self.path_to_url(format!("overrides/{override_id}"))?;
+
+            self.get_json_map(uri).await
         }
     }
 
@@ -244,8 +659,8 @@ pub trait Api: Send + Sync {
     /// ```
     fn delete_override(&self, id: i32) -> impl Future<Output = Result<Response, Error>> + Send {
         async move {
-            let uri = self.path_to_url(format!("overrides/{id}"));
-            self.delete(uri).await
+            let uri = self.path_to_url(format!("overrides/{id}"))?;
+            delete_checked(self, uri).await
         }
     }
 
@@ -262,10 +677,15 @@ pub trait Api: Send + Sync {
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// # });
     /// ```
-    fn delete_user(&self, id: i32) -> impl Future<Output = Result<Response, Error>> + Send {
+    fn delete_user(
+        &self,
+        id: impl Into<UserId> + Send,
+    ) -> impl Future<Output = Result<Response, Error>> + Send {
+        let id: UserId = id.into();
+
         async move {
-            let uri = self.path_to_url(format!("users/{id}"));
-            self.delete(uri).await
+            let uri = self.path_to_url(format!("users/{id}"))?;
+            delete_checked(self, uri).await
         }
     }
 
@@ -282,38 +702,23 @@ pub trait Api: Send + Sync {
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// # });
     /// ```
-    fn delete_task_request(&self, id: i32) -> impl Future<Output = Result<Response, Error>> + Send {
-        async move {
-            let uri = self.path_to_url(format!("requests/{id}"));
-            self.delete(uri).await
-        }
-    }
-
-    /// Lower level method, not intended for direct use
-    fn post_deserialize<S, T>(
+    fn delete_task_request(
         &self,
-        url: Url,
-        msg: S,
-    ) -> impl Future<Output = Result<T, Error>> + Send
-    where
-        S: serde::Serialize + Send + Sync,
-        T: Value,
-    {
-        async move {
-            let resp = self.post(url, msg).await?;
+        id: impl Into<TaskRequestId> + Send,
+    ) -> impl Future<Output = Result<Response, Error>> + Send {
+        let id: TaskRequestId = id.into();
 
-            resp.json::<T>().await.map_err(From::from)
+        async move {
+            let uri = self.path_to_url(format!("requests/{id}"))?;
+            delete_checked(self, uri).await
         }
     }
 
-    /// Lower level method, not intended for direct use
-    fn post<S>(&self, url: Url, msg: S) -> impl Future<Output = Result<Response, Error>> + Send
-    where
-        S: serde::Serialize + Send + Sync;
-
-    /// Produces a single [`Account`](freedom_models::account::Account) matching the provided ID.
+    /// Request to cancel the task request matching the provided `id`
     ///
-    /// See [`get`](Self::get) documentation for more details about the process and return type
+    /// Unlike [`delete_task_request`](Self::delete_task_request), the request is kept, so its
+    /// history is preserved while the slot it occupied is freed. A request which can no longer be
+    /// cancelled is rejected by the server with an [`Error::Api`].
     ///
     /// # Example
     ///
@@ -322,25 +727,42 @@ pub trait Api: Send + Sync {
     /// # tokio_test::block_on(async {
     /// let client = Client::from_env()?;
     ///
-    /// let account = client.get_account_by_name("ATLAS").await?;
-    /// println!("{}", account.name);
+    /// client.cancel_task_request(42).await?;
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// # });
     /// ```
-    fn get_account_by_name(
+    fn cancel_task_request(
         &self,
-        account_name: &str,
-    ) -> impl Future<Output = Result<Self::Container<Account>, Error>> + Send {
-        async move {
-            let mut uri = self.path_to_url("accounts/search/findOneByName");
-            uri.set_query(Some(&format!("name={account_name}")));
-            self.get_json_map(uri).await
-        }
+        id: impl Into<TaskRequestId> + Send,
+    ) -> impl Future<Output = Result<Response, Error>> + Send {
+        let id: TaskRequestId = id.into();
+
+        patch_status(self, id, TaskStatusType::Cancelled)
     }
 
-    /// Produces a single [`Account`](freedom_models::account::Account) matching the provided ID.
+    /// Request to move the task request matching the provided `id` into the provided status
     ///
-    /// See [`get`](Self::get) documentation for more details about the process and return type
+    /// The server decides which transitions are allowed, and rejects the others with an
+    /// [`Error::Api`] describing why.
+    fn set_task_request_status(
+        &self,
+        id: impl Into<TaskRequestId> + Send,
+        status: TaskStatusType,
+    ) -> impl Future<Output = Result<Response, Error>> + Send {
+        let id: TaskRequestId = id.into();
+
+        patch_status(self, id, status)
+    }
+
+    /// Delete every task request matching the provided filter, within the limits of the provided
+    /// safeguards.
+    ///
+    /// All matching task request IDs are gathered before anything is deleted, and the deletion is
+    /// aborted with [`Error::BulkDeleteRefused`] if more requests match than the safeguards allow,
+    /// or with [`Error::BulkDeleteInProduction`] if the client targets the production environment
+    /// without explicitly allowing it. Use
+    /// [`DeleteSafeguards::dry_run`](delete::DeleteSafeguards::dry_run) to preview the matching IDs
+    /// without deleting them.
     ///
     /// # Example
     ///
@@ -349,59 +771,352 @@ pub trait Api: Send + Sync {
     /// # tokio_test::block_on(async {
     /// let client = Client::from_env()?;
     ///
-    /// let data = client.get_file_by_task_id_and_name(42, "data.bin").await?;
+    /// let filter = RequestFilter::new(|request| request.get_id().is_ok_and(|id| id > 1000))
+    ///     .satellite_name("FooBar 6");
+    /// let report = client
+    ///     .delete_requests_matching(filter, DeleteSafeguards::new(25))
+    ///     .await?;
+    ///
+    /// for (id, error) in report.failed() {
+    ///     println!("Failed to delete {id}: {error}");
+    /// }
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// # });
     /// ```
-    fn get_file_by_task_id_and_name(
+    fn delete_requests_matching(
         &self,
-        task_id: i32,
-        file_name: &str,
-    ) -> impl Future<Output = Result<Bytes, Error>> + Send {
-        async move {
-            let path = format!("downloads/{}/{}", task_id, file_name);
-            let uri = self.path_to_url(path);
+        filter: delete::RequestFilter,
+        safeguards: delete::DeleteSafeguards,
+    ) -> impl Future<Output = Result<delete::DeleteReport, Error>> + Send
+    where
+        Self: Sized,
+    {
+        delete::delete_requests_matching(self, filter, safeguards)
+    }
+
+    /// Lower level method, not intended for direct use
+    fn post_deserialize<S, T>(
+        &self,
+        url: Url,
+        msg: S,
+    ) -> impl Future<Output = Result<T, Error>> + Send
+    where
+        S: serde::Serialize + Send + Sync,
+        T: Value,
+    {
+        async move {
+            let resp = self.post_checked(url.clone(), msg).await?;
+
+            resp.json::<T>()
+                .await
+                .map_err(|error| Error::from(error).at(Method::POST, &url))
+        }
+    }
+
+    /// Lower level method, not intended for direct use
+    ///
+    /// Like [`post`](Self::post), however an unsuccessful response is converted into an
+    /// [`Error::Api`], carrying the response body.
+    fn post_checked<S>(
+        &self,
+        url: Url,
+        msg: S,
+    ) -> impl Future<Output = Result<Response, Error>> + Send
+    where
+        S: serde::Serialize + Send + Sync,
+    {
+        async move {
+            let resp = self
+                .post(url.clone(), msg)
+                .await
+                .map_err(|error| error.at(Method::POST, &url))?;
+
+            error_on_non_success_response(resp)
+                .await
+                .map_err(|error| error.at(Method::POST, &url))
+        }
+    }
+
+    /// Lower level method, not intended for direct use
+    fn post<S>(&self, url: Url, msg: S) -> impl Future<Output = Result<Response, Error>> + Send
+    where
+        S: serde::Serialize + Send + Sync;
+
+    /// Lower level method, not intended for direct use
+    fn put_deserialize<S, T>(
+        &self,
+        url: Url,
+        msg: S,
+    ) -> impl Future<Output = Result<T, Error>> + Send
+    where
+        S: serde::Serialize + Send + Sync,
+        T: Value,
+    {
+        async move {
+            let send = async {
+                let resp = self.put(url.clone(), msg).await?;
+                let resp = error_on_non_success_response(resp).await?;
+
+                resp.json::<T>().await.map_err(Error::from)
+            };
+
+            send.await.map_err(|error| error.at(Method::PUT, &url))
+        }
+    }
+
+    /// Lower level method, not intended for direct use
+    fn put<S>(&self, url: Url, msg: S) -> impl Future<Output = Result<Response, Error>> + Send
+    where
+        S: serde::Serialize + Send + Sync;
+
+    /// Lower level method, not intended for direct use
+    fn patch_deserialize<S, T>(
+        &self,
+        url: Url,
+        msg: S,
+    ) -> impl Future<Output = Result<T, Error>> + Send
+    where
+        S: serde::Serialize + Send + Sync,
+        T: Value,
+    {
+        async move {
+            let send = async {
+                let resp = self.patch(url.clone(), msg).await?;
+                let resp = error_on_non_success_response(resp).await?;
+
+                resp.json::<T>().await.map_err(Error::from)
+            };
+
+            send.await.map_err(|error| error.at(Method::PATCH, &url))
+        }
+    }
+
+    /// Lower level method, not intended for direct use
+    fn patch<S>(&self, url: Url, msg: S) -> impl Future<Output = Result<Response, Error>> + Send
+    where
+        S: serde::Serialize + Send + Sync;
+
+    /// Produces a single [`Account`](freedom_models::account::Account) matching the provided ID.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let account = client.get_account_by_name("ATLAS").await?;
+    /// println!("{}", account.name);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn get_account_by_name(
+        &self,
+        account_name: &str,
+    ) -> impl Future<Output = Result<Self::Container<Account>, Error>> + Send {
+        self.find_by_name(account_name)
+    }
+
+    /// Produces a single [`Account`](freedom_models::account::Account) matching the provided ID.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let data = client.get_file_by_task_id_and_name(42, "data.bin").await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn get_file_by_task_id_and_name(
+        &self,
+        task_id: impl Into<TaskId> + Send,
+        file_name: &str,
+    ) -> impl Future<Output = Result<Bytes, Error>> + Send {
+        let task_id: TaskId = task_id.into();
+
+        async move {
+            let uri = download::file_url(self, task_id, file_name)?;
 
             let (data, status) = self.get(uri).await?;
-            error_on_non_success(&status)?;
+            error_on_non_success(&status, &data)?;
 
             Ok(data)
         }
     }
 
+    /// Produces a stream of the chunks of the file with the provided name, for the task matching
+    /// the provided ID, without buffering the whole file in memory.
+    ///
+    /// An unsuccessful response is returned as an error before the stream is produced.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # use futures::StreamExt;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let mut chunks = client.get_file_streamed(42, "data.bin").await?;
+    /// let mut received = 0;
+    /// while let Some(chunk) = chunks.next().await {
+    ///     received += chunk?.len();
+    ///     println!("Received {received} bytes");
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn get_file_streamed(
+        &self,
+        task_id: impl Into<TaskId> + Send,
+        file_name: &str,
+    ) -> impl Future<Output = Result<ByteStream, Error>> + Send
+    where
+        Self: Sized,
+    {
+        let task_id: TaskId = task_id.into();
+
+        download::file_streamed(self, task_id, file_name)
+    }
+
+    /// Write the file with the provided name, for the task matching the provided ID, to the path,
+    /// returning the number of bytes written.
+    ///
+    /// The file is written as it is received, so memory usage remains bounded regardless of its
+    /// size. Nothing is written when the response is unsuccessful.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let bytes = client
+    ///     .download_file_to(42, "data.bin", "recording.bin".as_ref())
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn download_file_to(
+        &self,
+        task_id: impl Into<TaskId> + Send,
+        file_name: &str,
+        path: &Path,
+    ) -> impl Future<Output = Result<u64, Error>> + Send
+    where
+        Self: Sized,
+    {
+        let task_id: TaskId = task_id.into();
+
+        download::download_to(self, task_id, file_name, path)
+    }
+
+    /// Produces a stream of the bytes within the range of the file with the provided name, for
+    /// the task matching the provided ID.
+    ///
+    /// See [`get_file_streamed`](Self::get_file_streamed) for more details.
+    fn get_file_range(
+        &self,
+        task_id: impl Into<TaskId> + Send,
+        file_name: &str,
+        range: Range<u64>,
+    ) -> impl Future<Output = Result<ByteStream, Error>> + Send
+    where
+        Self: Sized,
+    {
+        let task_id: TaskId = task_id.into();
+
+        download::file_range(self, task_id, file_name, range)
+    }
+
+    /// Like [`download_file_to`](Self::download_file_to), however an existing partial file at the
+    /// path is continued rather than replaced, returning the size of the complete file.
+    ///
+    /// Only the missing bytes are requested from the server. Should the server ignore the request
+    /// for a range, the file is instead written from the start.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// // Safe to call again should the connection drop part of the way through
+    /// let size = client
+    ///     .download_file_resumable(42, "data.bin", "recording.bin".as_ref())
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn download_file_resumable(
+        &self,
+        task_id: impl Into<TaskId> + Send,
+        file_name: &str,
+        path: &Path,
+    ) -> impl Future<Output = Result<u64, Error>> + Send
+    where
+        Self: Sized,
+    {
+        let task_id: TaskId = task_id.into();
+
+        download::download_resumable(self, task_id, file_name, path)
+    }
+
     /// Produces a single [`Account`](freedom_models::account::Account) matching the provided ID.
     ///
     /// See [`get`](Self::get) documentation for more details about the process and return type
     fn get_account_by_id(
         &self,
-        account_id: i32,
+        account_id: impl Into<AccountId> + Send,
     ) -> impl Future<Output = Result<Self::Container<Account>, Error>> + Send {
-        async move {
-            let uri = self.path_to_url(format!("accounts/{account_id}"));
-            self.get_json_map(uri).await
-        }
+        self.get_by_id(account_id)
     }
 
     /// Produces a paginated stream of [`Account`](freedom_models::account::Account) objects.
     ///
     /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
     /// and return type
-    fn get_accounts(
+    fn get_accounts(&self) -> PaginatedStream<'_, Self::Container<Account>> {
+        self.list()
+    }
+
+    /// Produces a paginated stream of [`Account`](freedom_models::account::Account) objects, using the provided page options.
+    ///
+    /// See [`get_paginated_with_options`](Self::get_paginated_with_options) documentation for more
+    /// details about the process and return type
+    fn get_accounts_with_options(
         &self,
-    ) -> Pin<Box<dyn Stream<Item = Result<Self::Container<Account>, Error>> + '_>> {
-        let uri = self.path_to_url("accounts");
-        self.get_paginated(uri)
+        options: PageOptions,
+    ) -> PaginatedStream<'_, Self::Container<Account>> {
+        self.list_with_options(options)
     }
 
     /// Produces a paginated stream of [`Band`] objects.
     ///
     /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
     /// and return type
-    fn get_satellite_bands(
+    fn get_satellite_bands(&self) -> PaginatedStream<'_, Self::Container<Band>> {
+        self.list()
+    }
+
+    /// Produces a paginated stream of [`Band`] objects, using the provided page options.
+    ///
+    /// See [`get_paginated_with_options`](Self::get_paginated_with_options) documentation for more
+    /// details about the process and return type
+    fn get_satellite_bands_with_options(
         &self,
-    ) -> Pin<Box<dyn Stream<Item = Result<Self::Container<Band>, Error>> + '_>> {
-        let uri = self.path_to_url("satellite_bands");
-        self.get_paginated(uri)
+        options: PageOptions,
+    ) -> PaginatedStream<'_, Self::Container<Band>> {
+        self.list_with_options(options)
     }
 
     /// Produces a single [`Band`] matching the provided ID.
@@ -409,12 +1124,9 @@ pub trait Api: Send + Sync {
     /// See [`get`](Self::get) documentation for more details about the process and return type
     fn get_satellite_band_by_id(
         &self,
-        satellite_band_id: i32,
+        satellite_band_id: impl Into<BandId> + Send,
     ) -> impl Future<Output = Result<Self::Container<Band>, Error>> + Send {
-        async move {
-            let uri = self.path_to_url(format!("satellite_bands/{satellite_band_id}"));
-            self.get_json_map(uri).await
-        }
+        self.get_by_id(satellite_band_id)
     }
 
     /// Produces a single [`Band`] matching the provided name.
@@ -424,11 +1136,7 @@ pub trait Api: Send + Sync {
         &self,
         satellite_band_name: &str,
     ) -> impl Future<Output = Result<Self::Container<Band>, Error>> + Send {
-        async move {
-            let mut uri = self.path_to_url("satellite_bands/search/findOneByName");
-            uri.set_query(Some(&format!("name={satellite_band_name}")));
-            self.get_json_map(uri).await
-        }
+        self.find_by_name(satellite_band_name)
     }
 
     /// Produces a paginated stream of [`Band`] objects matching the provided account name.
@@ -438,9 +1146,13 @@ pub trait Api: Send + Sync {
     fn get_satellite_bands_by_account_name(
         &self,
         account_name: &str,
-    ) -> Pin<Box<dyn Stream<Item = Result<Self::Container<Band>, Error>> + '_>> {
-        let mut uri = self.path_to_url("satellite_bands/search/findAllByAccountName");
-        uri.set_query(Some(&format!("accountName={account_name}")));
+    ) -> PaginatedStream<'_, Self::Container<Band>> {
+        let mut uri = match self.path_to_url("satellite_bands/search/findAllByAccountName") {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+        uri.query_pairs_mut()
+            .append_pair("accountName", account_name);
 
         self.get_paginated(uri)
     }
@@ -453,61 +1165,171 @@ pub trait Api: Send + Sync {
     fn get_satellite_configurations_by_account_name(
         &self,
         account_name: &str,
-    ) -> Pin<Box<dyn Stream<Item = Result<Self::Container<SatelliteConfiguration>, Error>> + '_>>
-    {
-        let mut uri = self.path_to_url("satellite_configurations/search/findAllByAccountName");
-        uri.set_query(Some(&format!("accountName={account_name}")));
+    ) -> PaginatedStream<'_, Self::Container<SatelliteConfiguration>> {
+        let mut uri = match self.path_to_url("satellite_configurations/search/findAllByAccountName")
+        {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+        uri.query_pairs_mut()
+            .append_pair("accountName", account_name);
 
         self.get_paginated(uri)
     }
 
+    /// Choose the receive band, and the transmit band if any, of the satellite configuration
+    /// matching the provided ID, for use with [`band_pair`](post::TaskRequestBuilder::band_pair)
+    ///
+    /// See [`BandPair::select`](pairing::BandPair::select) for how the bands are chosen.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # use time::OffsetDateTime;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let pair = client.suggest_band_pair(812).await?;
+    /// client
+    ///     .new_task_request()
+    ///     .test_task("my_test_file.bin")
+    ///     .target_time_utc(OffsetDateTime::now_utc() + time::Duration::hours(2))
+    ///     .task_duration(120)
+    ///     .satellite_id(1016)
+    ///     .site_id(27)
+    ///     .site_configuration_id(47)
+    ///     .band_pair(pair)
+    ///     .send()
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn suggest_band_pair(
+        &self,
+        satellite_configuration_id: impl Into<SatelliteConfigurationId> + Send,
+    ) -> impl Future<Output = Result<pairing::BandPair, Error>> + Send {
+        let satellite_configuration_id: SatelliteConfigurationId =
+            satellite_configuration_id.into();
+
+        pairing::suggest_band_pair(self, satellite_configuration_id)
+    }
+
+    /// Summarize the task requests which target the band matching the provided `id`
+    ///
+    /// The task requests are found with
+    /// [`get_requests_by_band_id`](Self::get_requests_by_band_id).
+    fn get_band_usage(
+        &self,
+        id: impl Into<BandId> + Send,
+    ) -> impl Future<Output = Result<usage::UsageReport, Error>> + Send
+    where
+        Self: Sized,
+    {
+        let id: BandId = id.into();
+
+        usage::band_usage(self, id)
+    }
+
+    /// Summarize the task requests which reference the satellite configuration matching the
+    /// provided `id`
+    fn get_configuration_usage(
+        &self,
+        id: impl Into<SatelliteConfigurationId> + Send,
+    ) -> impl Future<Output = Result<usage::UsageReport, Error>> + Send
+    where
+        Self: Sized,
+    {
+        let id: SatelliteConfigurationId = id.into();
+
+        usage::configuration_usage(self, id)
+    }
+
     /// Produces a paginated stream of [`SatelliteConfiguration`] objects.
     ///
     /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
     /// and return type
     fn get_satellite_configurations(
         &self,
-    ) -> Pin<Box<dyn Stream<Item = Result<Self::Container<SatelliteConfiguration>, Error>> + '_>>
-    {
-        let uri = self.path_to_url("satellite_configurations");
+    ) -> PaginatedStream<'_, Self::Container<SatelliteConfiguration>> {
+        self.list()
+    }
 
-        self.get_paginated(uri)
+    /// Produces a paginated stream of [`SatelliteConfiguration`] objects, using the provided page options.
+    ///
+    /// See [`get_paginated_with_options`](Self::get_paginated_with_options) documentation for more
+    /// details about the process and return type
+    fn get_satellite_configurations_with_options(
+        &self,
+        options: PageOptions,
+    ) -> PaginatedStream<'_, Self::Container<SatelliteConfiguration>> {
+        self.list_with_options(options)
     }
 
-    /// Produces a single satellite configuration matching the provided satellite configuration ID
+    /// Produces a single [`SatelliteConfiguration`] matching the provided ID.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
     fn get_satellite_configuration_by_id(
         &self,
-        satellite_configuration_id: i32,
+        satellite_configuration_id: impl Into<SatelliteConfigurationId> + Send,
     ) -> impl Future<Output = Result<Self::Container<SatelliteConfiguration>, Error>> + Send {
-        async move {
-            let uri = self.path_to_url(format!(
-                "satellite_configurations/{satellite_configuration_id}"
-            ));
+        let satellite_configuration_id: SatelliteConfigurationId =
+            satellite_configuration_id.into();
 
-            self.get_json_map(uri).await
-        }
+        self.get_by_id(satellite_configuration_id)
     }
 
-    /// Produces a single satellite configuration matching the provided satellite configuration name
+    /// Produces a single [`SatelliteConfiguration`] matching the provided name.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
     fn get_satellite_configuration_by_name(
         &self,
         satellite_configuration_name: &str,
     ) -> impl Future<Output = Result<Self::Container<SatelliteConfiguration>, Error>> + Send {
-        async move {
-            let mut uri = self.path_to_url("satellite_configurations/search/findOneByName");
-            uri.set_query(Some(&format!("name={satellite_configuration_name}")));
-
-            self.get_json_map(uri).await
-        }
+        self.find_by_name(satellite_configuration_name)
     }
 
     /// Produces a paginated stream of [`Site`] objects.
     ///
     /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
     /// and return type
-    fn get_sites(&self) -> Pin<Box<dyn Stream<Item = Result<Self::Container<Site>, Error>> + '_>> {
-        let uri = self.path_to_url("sites");
-        self.get_paginated(uri)
+    fn get_sites(&self) -> PaginatedStream<'_, Self::Container<Site>> {
+        self.list()
+    }
+
+    /// Produces a paginated stream of [`Site`] objects, using the provided page options.
+    ///
+    /// See [`get_paginated_with_options`](Self::get_paginated_with_options) documentation for more
+    /// details about the process and return type
+    fn get_sites_with_options(
+        &self,
+        options: PageOptions,
+    ) -> PaginatedStream<'_, Self::Container<Site>> {
+        self.list_with_options(options)
+    }
+
+    /// Produces a paginated stream of [`Site`] objects visible to the account matching the
+    /// provided name.
+    ///
+    /// Items are [`Error::PermissionDenied`] when the account is not visible to the caller.
+    ///
+    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
+    /// and return type
+    fn get_sites_by_account_name(
+        &self,
+        account_name: &str,
+    ) -> PaginatedStream<'_, Self::Container<Site>> {
+        let mut uri = match self.path_to_url("sites/search/findAllByAccountName") {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+        uri.query_pairs_mut()
+            .append_pair("accountName", account_name);
+
+        Box::pin(
+            self.get_paginated(uri)
+                .map(|item| item.map_err(Error::permission_denied)),
+        )
     }
 
     /// Produces a single [`Site`] object matching the provided ID.
@@ -515,12 +1337,53 @@ pub trait Api: Send + Sync {
     /// See [`get`](Self::get) documentation for more details about the process and return type
     fn get_site_by_id(
         &self,
-        id: i32,
+        id: impl Into<SiteId> + Send,
     ) -> impl Future<Output = Result<Self::Container<Site>, Error>> + Send {
-        async move {
-            let uri = self.path_to_url(format!("sites/{id}"));
-            self.get_json_map(uri).await
-        }
+        self.get_by_id(id)
+    }
+
+    /// The host and port of the FPS data plane serving the site configuration
+    ///
+    /// The host is the [FPS host](freedom_config::Env::fps_host) of the environment. The port is
+    /// the site's `base_fps_port`, offset by the position of the configuration among the site's
+    /// configurations, see [`fps_port`](fps::fps_port).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let request = client.get_request_by_id(42).await?;
+    /// let site = request.get_site(&client).await?;
+    /// let configuration = request.get_config(&client).await?;
+    /// let (host, port) = client.fps_endpoint_for(&site, &configuration).await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn fps_endpoint_for(
+        &self,
+        site: &Site,
+        configuration: &SiteConfiguration,
+    ) -> impl Future<Output = Result<(String, u16), Error>> + Send {
+        fps::fps_endpoint_for(self, site, configuration)
+    }
+
+    /// The host and port of the FPS data plane serving the site configuration matching the
+    /// provided ID, at the site matching the provided ID
+    ///
+    /// See [`fps_endpoint_for`](Self::fps_endpoint_for) for how the endpoint is determined.
+    fn fps_endpoint_by_ids(
+        &self,
+        site_id: impl Into<SiteId> + Send,
+        configuration_id: impl Into<SiteConfigurationId> + Send,
+    ) -> impl Future<Output = Result<(String, u16), Error>> + Send {
+        let configuration_id: SiteConfigurationId = configuration_id.into();
+
+        let site_id: SiteId = site_id.into();
+
+        fps::fps_endpoint_by_ids(self, site_id, configuration_id)
     }
 
     /// Produces a single [`Site`] object matching the provided name.
@@ -530,12 +1393,41 @@ pub trait Api: Send + Sync {
         &self,
         name: impl AsRef<str> + Send,
     ) -> impl Future<Output = Result<Self::Container<Site>, Error>> + Send {
+        self.find_by_name(name)
+    }
+
+    /// Produces a paginated stream of [`SiteConfiguration`] objects.
+    ///
+    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
+    /// and return type
+    fn get_site_configurations(&self) -> PaginatedStream<'_, Self::Container<SiteConfiguration>> {
+        self.list()
+    }
+
+    /// Produces a single [`SiteConfiguration`] object matching the provided ID.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    fn get_site_configuration_by_id(
+        &self,
+        id: impl Into<SiteConfigurationId> + Send,
+    ) -> impl Future<Output = Result<Self::Container<SiteConfiguration>, Error>> + Send {
+        self.get_by_id(id)
+    }
+
+    /// Produces a vector of the [`SiteConfiguration`] objects of the site matching the provided
+    /// ID, in the order they are listed by the server.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    fn get_site_configurations_by_site_id(
+        &self,
+        site_id: impl Into<SiteId> + Send,
+    ) -> impl Future<Output = Result<Self::Container<Vec<SiteConfiguration>>, Error>> + Send {
+        let site_id: SiteId = site_id.into();
+
         async move {
-            let mut uri = self.path_to_url("sites/search/findOneByName");
-            let query = format!("name={}", name.as_ref());
-            uri.set_query(Some(&query));
+            let uri = self.path_to_url(format!("sites/{site_id}/configurations"))?;
 
-            self.get_json_map(uri).await
+            pagination::collect_embedded(self, uri).await
         }
     }
 
@@ -544,10 +1436,28 @@ pub trait Api: Send + Sync {
     /// See [`get`](Self::get) documentation for more details about the process and return type
     fn get_request_by_id(
         &self,
-        task_request_id: i32,
+        task_request_id: impl Into<TaskRequestId> + Send,
+    ) -> impl Future<Output = Result<Self::Container<TaskRequest>, Error>> + Send {
+        self.get_by_id(task_request_id)
+    }
+
+    /// Produces the [`TaskRequest`] from which the task matching the provided ID was created.
+    ///
+    /// Unlike [`get_request_by_id`](Self::get_request_by_id), which looks up a request by its own
+    /// ID, this looks up a request by the ID of its task, which is useful when only the task is at
+    /// hand.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    fn get_request_by_task_id(
+        &self,
+        task_id: impl Into<TaskId> + Send,
     ) -> impl Future<Output = Result<Self::Container<TaskRequest>, Error>> + Send {
+        let task_id: TaskId = task_id.into();
+
         async move {
-            let uri = self.path_to_url(format!("requests/{task_request_id}"));
+            let task = self.path_to_url(format!("tasks/{task_id}"))?;
+            let mut uri = self.path_to_url("requests/search/findByTask")?;
+            uri.query_pairs_mut().append_pair("task", task.as_str());
 
             self.get_json_map(uri).await
         }
@@ -558,10 +1468,51 @@ pub trait Api: Send + Sync {
     /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
     /// and return type
     fn get_requests(&self) -> PaginatedStream<'_, Self::Container<TaskRequest>> {
-        {
-            let uri = self.path_to_url("requests/search/findAll");
-            self.get_paginated(uri)
-        }
+        self.list()
+    }
+
+    /// Produces a paginated stream of [`TaskRequest`] objects, using the provided page options.
+    ///
+    /// See [`get_paginated_with_options`](Self::get_paginated_with_options) documentation for more
+    /// details about the process and return type
+    fn get_requests_with_options(
+        &self,
+        options: PageOptions,
+    ) -> PaginatedStream<'_, Self::Container<TaskRequest>> {
+        self.list_with_options(options)
+    }
+
+    /// Produces a stream of the [`TaskRequest`] objects created after the stream is first polled.
+    ///
+    /// The task requests are polled at the provided interval, and each new task request is yielded
+    /// exactly once. Should a poll fail, the error is yielded and polling continues after a delay
+    /// determined by the client's [`backoff`](Self::backoff) strategy, which is never shorter than
+    /// the poll interval. Polling stops when the stream is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # use futures::StreamExt;
+    /// # use std::time::Duration;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let mut requests = client.watch_requests(Duration::from_secs(30));
+    /// while let Some(request) = requests.next().await {
+    ///     println!("New task request: {:?}", request?.get_id());
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn watch_requests(
+        &self,
+        poll_interval: Duration,
+    ) -> PaginatedStream<'_, Self::Container<TaskRequest>>
+    where
+        Self: Sized,
+    {
+        watch::watch_requests(self, poll_interval)
     }
 
     /// Produces a vector of [`TaskRequest`] items, representing all the task requests matching the
@@ -572,13 +1523,22 @@ pub trait Api: Send + Sync {
         end: OffsetDateTime,
     ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send {
         async move {
-            let mut uri = self.path_to_url("requests/search/findAllByTargetDateBetween");
+            let range = TimeRange::new(start, end)?;
+
+            self.get_requests_by_target_date_between_in(range).await
+        }
+    }
+
+    /// Like [`Self::get_requests_by_target_date_between`],
+    /// searching within the provided [`TimeRange`]
+    fn get_requests_by_target_date_between_in(
+        &self,
+        range: TimeRange,
+    ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send {
+        async move {
+            let mut uri = self.path_to_url("requests/search/findAllByTargetDateBetween")?;
 
-            uri.set_query(Some(&format!(
-                "start={}&end={}",
-                start.format(&Iso8601::DEFAULT).unwrap(),
-                end.format(&Iso8601::DEFAULT).unwrap(),
-            )));
+            uri.query_pairs_mut().extend_pairs(range.query_pairs());
 
             self.get_json_map(uri).await
         }
@@ -599,14 +1559,33 @@ pub trait Api: Send + Sync {
     where
         T: AsRef<str> + Send,
     {
-        let mut uri = self.path_to_url("requests/search/findAllByAccountAndTargetDateBetween");
+        let range = match TimeRange::new(start, end) {
+            Ok(range) => range,
+            Err(error) => return error.once_err(),
+        };
 
-        uri.set_query(Some(&format!(
-            "account={}&start={}&end={}",
-            account_uri.as_ref(),
-            start.format(&Iso8601::DEFAULT).unwrap(),
-            end.format(&Iso8601::DEFAULT).unwrap(),
-        )));
+        self.get_requests_by_account_and_target_date_between_in(account_uri, range)
+    }
+
+    /// Like [`Self::get_requests_by_account_and_target_date_between`],
+    /// searching within the provided [`TimeRange`]
+    fn get_requests_by_account_and_target_date_between_in<T>(
+        &self,
+        account_uri: T,
+        range: TimeRange,
+    ) -> PaginatedStream<'_, Self::Container<TaskRequest>>
+    where
+        T: AsRef<str> + Send,
+    {
+        let mut uri = match self.path_to_url("requests/search/findAllByAccountAndTargetDateBetween")
+        {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+
+        uri.query_pairs_mut()
+            .append_pair("account", account_uri.as_ref())
+            .extend_pairs(range.query_pairs());
 
         self.get_paginated(uri)
     }
@@ -619,7 +1598,10 @@ pub trait Api: Send + Sync {
     fn get_requests_by_account_and_upcoming_today(
         &self,
     ) -> PaginatedStream<'_, Self::Container<TaskRequest>> {
-        let uri = self.path_to_url("requests/search/findByAccountUpcomingToday");
+        let uri = match self.path_to_url("requests/search/findByAccountUpcomingToday") {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
 
         self.get_paginated(uri)
     }
@@ -640,12 +1622,14 @@ pub trait Api: Send + Sync {
     where
         T: AsRef<str> + Send,
     {
-        let mut uri = self.path_to_url("requests/search/findAllByConfigurationOrderByCreatedAsc");
+        let mut uri =
+            match self.path_to_url("requests/search/findAllByConfigurationOrderByCreatedAsc") {
+                Ok(uri) => uri,
+                Err(error) => return error.once_err(),
+            };
 
-        uri.set_query(Some(&format!(
-            "configuration={}",
-            configuration_uri.as_ref()
-        )));
+        uri.query_pairs_mut()
+            .append_pair("configuration", configuration_uri.as_ref());
 
         self.get_paginated::<TaskRequest>(uri)
     }
@@ -662,6 +1646,31 @@ pub trait Api: Send + Sync {
         start: OffsetDateTime,
         end: OffsetDateTime,
     ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send
+    where
+        T: AsRef<str> + Send,
+        I: IntoIterator<Item = S> + Send,
+        S: AsRef<str> + Send,
+    {
+        async move {
+            let range = TimeRange::new(start, end)?;
+
+            self.get_requests_by_configuration_and_satellite_names_and_target_date_between_in(
+                configuration_uri,
+                satellites,
+                range,
+            )
+            .await
+        }
+    }
+
+    /// Like [`Self::get_requests_by_configuration_and_satellite_names_and_target_date_between`],
+    /// searching within the provided [`TimeRange`]
+    fn get_requests_by_configuration_and_satellite_names_and_target_date_between_in<T, I, S>(
+        &self,
+        configuration_uri: T,
+        satellites: I,
+        range: TimeRange,
+    ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send
     where
         T: AsRef<str> + Send,
         I: IntoIterator<Item = S> + Send,
@@ -671,20 +1680,14 @@ pub trait Api: Send + Sync {
             let satellites_string = crate::utils::list_to_string(satellites);
             let mut uri = self.path_to_url(
                 "requests/search/findAllByConfigurationAndSatelliteNamesAndTargetDateBetween",
-            );
+            )?;
 
-            uri.set_query(Some(&format!(
-                "configuration={}&satelliteNames={}&start={}&end={}",
-                configuration_uri.as_ref(),
-                satellites_string,
-                start.format(&Iso8601::DEFAULT)?,
-                end.format(&Iso8601::DEFAULT)?,
-            )));
-
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<TaskRequest>>>>(uri)
-                .await?
-                .items)
+            uri.query_pairs_mut()
+                .append_pair("configuration", configuration_uri.as_ref())
+                .append_pair("satelliteNames", &satellites_string)
+                .extend_pairs(range.query_pairs());
+
+            pagination::collect_embedded(self, uri).await
         }
     }
 
@@ -700,23 +1703,35 @@ pub trait Api: Send + Sync {
         start: OffsetDateTime,
         end: OffsetDateTime,
     ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send
+    where
+        T: AsRef<str> + Send,
+    {
+        async move {
+            let range = TimeRange::new(start, end)?;
+
+            self.get_requests_by_configuration_and_target_date_between_in(configuration_uri, range)
+                .await
+        }
+    }
+
+    /// Like [`Self::get_requests_by_configuration_and_target_date_between`],
+    /// searching within the provided [`TimeRange`]
+    fn get_requests_by_configuration_and_target_date_between_in<T>(
+        &self,
+        configuration_uri: T,
+        range: TimeRange,
+    ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send
     where
         T: AsRef<str> + Send,
     {
         async move {
             let mut uri =
-                self.path_to_url("requests/search/findAllByConfigurationAndTargetDateBetween");
-            uri.set_query(Some(&format!(
-                "configuration={}&start={}&end={}",
-                configuration_uri.as_ref(),
-                start.format(&Iso8601::DEFAULT)?,
-                end.format(&Iso8601::DEFAULT)?,
-            )));
-
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<TaskRequest>>>>(uri)
-                .await?
-                .items)
+                self.path_to_url("requests/search/findAllByConfigurationAndTargetDateBetween")?;
+            uri.query_pairs_mut()
+                .append_pair("configuration", configuration_uri.as_ref())
+                .extend_pairs(range.query_pairs());
+
+            pagination::collect_embedded(self, uri).await
         }
     }
 
@@ -724,6 +1739,11 @@ pub trait Api: Send + Sync {
     /// representing all the task requests whose ID matches one of the IDs provided as part of
     /// `ids`.
     ///
+    /// Duplicate IDs are only queried once. The IDs are queried
+    /// [`IDS_PER_REQUEST`](bulk::IDS_PER_REQUEST) at a time, so that long lists of IDs do not
+    /// exceed the URL length limit of the server, see
+    /// [`get_requests_by_ids_with`](Self::get_requests_by_ids_with).
+    ///
     /// See [`get`](Self::get) documentation for more details about the process and return type
     fn get_requests_by_ids<I, S>(
         &self,
@@ -733,17 +1753,26 @@ pub trait Api: Send + Sync {
         I: IntoIterator<Item = S> + Send,
         S: AsRef<str> + Send,
     {
-        async move {
-            let ids_string = crate::utils::list_to_string(ids);
-            let mut uri = self.path_to_url("requests/search/findAllByIds");
+        self.get_requests_by_ids_with(ids, bulk::IDS_PER_REQUEST)
+    }
 
-            uri.set_query(Some(&format!("ids={}", ids_string)));
+    /// Like [`get_requests_by_ids`](Self::get_requests_by_ids), placing at most `ids_per_request`
+    /// IDs in the query of each request.
+    ///
+    /// The chunks of IDs are queried concurrently, and the task requests are produced in the order
+    /// of the chunks.
+    fn get_requests_by_ids_with<I, S>(
+        &self,
+        ids: I,
+        ids_per_request: usize,
+    ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send
+    where
+        I: IntoIterator<Item = S> + Send,
+        S: AsRef<str> + Send,
+    {
+        let ids = ids.into_iter().map(|id| id.as_ref().to_owned()).collect();
 
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<TaskRequest>>>>(uri)
-                .await?
-                .items)
-        }
+        bulk::requests_by_ids(self, ids, ids_per_request)
     }
 
     /// Produces a paginated stream of [`TaskRequest`] objects which are public, and which overlap
@@ -756,13 +1785,26 @@ pub trait Api: Send + Sync {
         start: OffsetDateTime,
         end: OffsetDateTime,
     ) -> PaginatedStream<'_, Self::Container<TaskRequest>> {
-        let mut uri = self.path_to_url("requests/search/findAllByOverlappingPublic");
+        let range = match TimeRange::new(start, end) {
+            Ok(range) => range,
+            Err(error) => return error.once_err(),
+        };
 
-        uri.set_query(Some(&format!(
-            "start={}&end={}",
-            start.format(&Iso8601::DEFAULT).unwrap(),
-            end.format(&Iso8601::DEFAULT).unwrap(),
-        )));
+        self.get_requests_by_overlapping_public_in(range)
+    }
+
+    /// Like [`Self::get_requests_by_overlapping_public`],
+    /// searching within the provided [`TimeRange`]
+    fn get_requests_by_overlapping_public_in(
+        &self,
+        range: TimeRange,
+    ) -> PaginatedStream<'_, Self::Container<TaskRequest>> {
+        let mut uri = match self.path_to_url("requests/search/findAllByOverlappingPublic") {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+
+        uri.query_pairs_mut().extend_pairs(range.query_pairs());
 
         self.get_paginated(uri)
     }
@@ -779,9 +1821,13 @@ pub trait Api: Send + Sync {
     where
         T: AsRef<str> + Send,
     {
-        let mut uri = self.path_to_url("requests/search/findBySatelliteName");
+        let mut uri = match self.path_to_url("requests/search/findBySatelliteName") {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
 
-        uri.set_query(Some(&format!("name={}", satellite_name.as_ref())));
+        uri.query_pairs_mut()
+            .append_pair("name", satellite_name.as_ref());
 
         self.get_paginated(uri)
     }
@@ -797,27 +1843,109 @@ pub trait Api: Send + Sync {
         start: OffsetDateTime,
         end: OffsetDateTime,
     ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send
+    where
+        T: AsRef<str> + Send,
+    {
+        async move {
+            let range = TimeRange::new(start, end)?;
+
+            self.get_requests_by_satellite_name_and_target_date_between_in(satellite_name, range)
+                .await
+        }
+    }
+
+    /// Like [`Self::get_requests_by_satellite_name_and_target_date_between`],
+    /// searching within the provided [`TimeRange`]
+    fn get_requests_by_satellite_name_and_target_date_between_in<T>(
+        &self,
+        satellite_name: T,
+        range: TimeRange,
+    ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send
     where
         T: AsRef<str> + Send,
     {
         async move {
             let mut uri =
-                self.path_to_url("requests/search/findAllBySatelliteNameAndTargetDateBetween");
-
-            uri.set_query(Some(&format!(
-                "name={}&start={}&end={}",
-                satellite_name.as_ref(),
-                start.format(&Iso8601::DEFAULT)?,
-                end.format(&Iso8601::DEFAULT)?
-            )));
-
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<TaskRequest>>>>(uri)
-                .await?
-                .items)
+                self.path_to_url("requests/search/findAllBySatelliteNameAndTargetDateBetween")?;
+
+            uri.query_pairs_mut()
+                .append_pair("name", satellite_name.as_ref())
+                .extend_pairs(range.query_pairs());
+
+            pagination::collect_embedded(self, uri).await
         }
     }
 
+    /// Produces a paginated stream of [`TaskRequest`] objects at the site matching the provided
+    /// ID, whose target time overlaps the provided time range.
+    ///
+    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
+    /// and return type
+    fn get_requests_by_site_id(
+        &self,
+        site_id: impl Into<SiteId> + Send,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> PaginatedStream<'_, Self::Container<TaskRequest>> {
+        let range = match TimeRange::new(start, end) {
+            Ok(range) => range,
+            Err(error) => return error.once_err(),
+        };
+
+        self.get_requests_by_site_id_in(site_id, range)
+    }
+
+    /// Like [`Self::get_requests_by_site_id`], searching within the provided [`TimeRange`]
+    fn get_requests_by_site_id_in(
+        &self,
+        site_id: impl Into<SiteId> + Send,
+        range: TimeRange,
+    ) -> PaginatedStream<'_, Self::Container<TaskRequest>> {
+        let site_id: SiteId = site_id.into();
+
+        let site = match self.path_to_url(format!("sites/{site_id}")) {
+            Ok(site) => site,
+            Err(error) => return error.once_err(),
+        };
+
+        let mut uri = match self.path_to_url("requests/search/findAllBySiteAndTargetDateBetween") {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+
+        uri.query_pairs_mut()
+            .append_pair("site", site.as_str())
+            .extend_pairs(range.query_pairs());
+
+        self.get_paginated(uri)
+    }
+
+    /// Produces a paginated stream of [`TaskRequest`] objects targeting the band matching the
+    /// provided ID.
+    ///
+    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
+    /// and return type
+    fn get_requests_by_band_id(
+        &self,
+        band_id: impl Into<BandId> + Send,
+    ) -> PaginatedStream<'_, Self::Container<TaskRequest>> {
+        let band_id: BandId = band_id.into();
+
+        let band = match self.path_to_url(format!("satellite_bands/{band_id}")) {
+            Ok(band) => band,
+            Err(error) => return error.once_err(),
+        };
+
+        let mut uri = match self.path_to_url("requests/search/findAllByTargetBand") {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+
+        uri.query_pairs_mut().append_pair("band", band.as_str());
+
+        self.get_paginated(uri)
+    }
+
     /// Produces a paginated stream of [`TaskRequest`] objects whose status matches the provided
     /// status.
     ///
@@ -832,9 +1960,9 @@ pub trait Api: Send + Sync {
         Error: From<<T as TryInto<TaskStatusType>>::Error>,
     {
         let status: TaskStatusType = status.try_into()?;
-        let mut uri = self.path_to_url("requests/search/findByStatus");
+        let mut uri = self.path_to_url("requests/search/findByStatus")?;
 
-        uri.set_query(Some(&format!("status={}", status.as_ref())));
+        uri.query_pairs_mut().append_pair("status", status.as_ref());
 
         Ok(self.get_paginated(uri))
     }
@@ -855,16 +1983,41 @@ pub trait Api: Send + Sync {
         T: AsRef<str> + Send,
         U: AsRef<str> + Send,
     {
-        let mut uri =
-            self.path_to_url("requests/search/findAllByStatusAndAccountAndTargetDateBetween");
-
-        uri.set_query(Some(&format!(
-            "status={}&satelliteNames={}&start={}&end={}",
-            status.as_ref(),
-            account_uri.as_ref(),
-            start.format(&Iso8601::DEFAULT).unwrap(),
-            end.format(&Iso8601::DEFAULT).unwrap()
-        )));
+        let range = match TimeRange::new(start, end) {
+            Ok(range) => range,
+            Err(error) => return error.once_err(),
+        };
+
+        self.get_requests_by_status_and_account_and_target_date_between_in(
+            status,
+            account_uri,
+            range,
+        )
+    }
+
+    /// Like [`Self::get_requests_by_status_and_account_and_target_date_between`],
+    /// searching within the provided [`TimeRange`]
+    fn get_requests_by_status_and_account_and_target_date_between_in<T, U>(
+        &self,
+        status: T,
+        account_uri: U,
+        range: TimeRange,
+    ) -> PaginatedStream<'_, Self::Container<TaskRequest>>
+    where
+        T: AsRef<str> + Send,
+        U: AsRef<str> + Send,
+    {
+        let mut uri = match self
+            .path_to_url("requests/search/findAllByStatusAndAccountAndTargetDateBetween")
+        {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+
+        uri.query_pairs_mut()
+            .append_pair("status", status.as_ref())
+            .append_pair("account", account_uri.as_ref())
+            .extend_pairs(range.query_pairs());
 
         self.get_paginated(uri)
     }
@@ -879,95 +2032,275 @@ pub trait Api: Send + Sync {
         start: OffsetDateTime,
         end: OffsetDateTime,
     ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send
+    where
+        T: TryInto<TaskType> + Send,
+        Error: From<<T as TryInto<TaskType>>::Error>,
+    {
+        async move {
+            let range = TimeRange::new(start, end)?;
+
+            self.get_requests_by_type_and_target_date_between_in(typ, range)
+                .await
+        }
+    }
+
+    /// Like [`Self::get_requests_by_type_and_target_date_between`],
+    /// searching within the provided [`TimeRange`]
+    fn get_requests_by_type_and_target_date_between_in<T>(
+        &self,
+        typ: T,
+        range: TimeRange,
+    ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send
     where
         T: TryInto<TaskType> + Send,
         Error: From<<T as TryInto<TaskType>>::Error>,
     {
         async move {
             let typ: TaskType = typ.try_into()?;
-            let mut uri = self.path_to_url("requests/search/findAllByTypeAndTargetDateBetween");
-
-            uri.set_query(Some(&format!(
-                "type={}&start={}&end={}",
-                typ.as_ref(),
-                start.format(&Iso8601::DEFAULT)?,
-                end.format(&Iso8601::DEFAULT)?
-            )));
-
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<TaskRequest>>>>(uri)
-                .await?
-                .items)
+            let mut uri = self.path_to_url("requests/search/findAllByTypeAndTargetDateBetween")?;
+
+            uri.query_pairs_mut()
+                .append_pair("type", typ.as_ref())
+                .extend_pairs(range.query_pairs());
+
+            pagination::collect_embedded(self, uri).await
         }
     }
 
     /// Produces a vector of [`TaskRequest`] items, representing the list of tasks which have
     /// already occurred today.
     ///
-    /// See [`get`](Self::get) documentation for more details about the process and return type
-    fn get_requests_passed_today(
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    fn get_requests_passed_today(
+        &self,
+    ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send {
+        async move {
+            let uri = self.path_to_url("requests/search/findAllPassedToday")?;
+
+            pagination::collect_embedded(self, uri).await
+        }
+    }
+
+    /// Produces a vector of [`TaskRequest`] items, representing the list of tasks which will occur
+    /// later today.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    fn get_requests_upcoming_today(
+        &self,
+    ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send {
+        async move {
+            let uri = self.path_to_url("requests/search/findAllUpcomingToday")?;
+
+            pagination::collect_embedded(self, uri).await
+        }
+    }
+
+    /// Produces a paginated stream of [`Satellite`] objects.
+    ///
+    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
+    /// and return type
+    fn get_satellites(&self) -> PaginatedStream<'_, Self::Container<Satellite>> {
+        self.list()
+    }
+
+    /// Produces a paginated stream of [`Satellite`] objects, using the provided page options.
+    ///
+    /// See [`get_paginated_with_options`](Self::get_paginated_with_options) documentation for more
+    /// details about the process and return type
+    fn get_satellites_with_options(
+        &self,
+        options: PageOptions,
+    ) -> PaginatedStream<'_, Self::Container<Satellite>> {
+        self.list_with_options(options)
+    }
+
+    /// Produces single satellite object matching the provided satellite ID
+    fn get_satellite_by_id(
+        &self,
+        satellite_id: impl Into<SatelliteId> + Send,
+    ) -> impl Future<Output = Result<Self::Container<Satellite>, Error>> + Send {
+        self.get_by_id(satellite_id)
+    }
+
+    /// Produces single satellite object matching the provided satellite name
+    fn get_satellite_by_name(
+        &self,
+        satellite_name: &str,
+    ) -> impl Future<Output = Result<Self::Container<Satellite>, Error>> + Send {
+        self.find_by_name(satellite_name)
+    }
+
+    /// Produces the orbit of the satellite matching the provided ID
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    fn get_satellite_orbit_info(
+        &self,
+        satellite_id: impl Into<SatelliteId> + Send,
+    ) -> impl Future<Output = Result<Self::Container<OrbitInfo>, Error>> + Send {
+        let satellite_id: SatelliteId = satellite_id.into();
+
+        async move {
+            let uri = self.path_to_url(format!("satellites/{}/orbitInfo", satellite_id))?;
+
+            self.get_json_map(uri).await
+        }
+    }
+
+    /// Replace the TLE of the satellite matching the provided ID, for satellites whose orbit is not
+    /// pulled from a public catalog
+    ///
+    /// The lines are first checked with [`check_tle`](orbit::check_tle), failing with an
+    /// [`Error::MalformedTle`] before any request is made. An unsuccessful response is converted
+    /// into an [`Error::Api`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// client
+    ///     .set_satellite_tle(
+    ///         710,
+    ///         "1 25544U 98067A   24290.51782528  .00016717  00000-0  10270-3 0  9005",
+    ///         "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.50377579 77070",
+    ///     )
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn set_satellite_tle(
+        &self,
+        satellite_id: impl Into<SatelliteId> + Send,
+        line1: &str,
+        line2: &str,
+    ) -> impl Future<Output = Result<Response, Error>> + Send {
+        let satellite_id: SatelliteId = satellite_id.into();
+
+        orbit::set_satellite_tle(self, satellite_id, line1, line2)
+    }
+
+    /// Produces a vector of [`Visibility`] windows of the satellite matching the provided ID, which
+    /// overlap with the provided time range
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # use time::{Duration, OffsetDateTime};
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let start = OffsetDateTime::now_utc();
+    /// let visibilities = client
+    ///     .get_visibilities_by_satellite_id(710, start, start + Duration::days(1))
+    ///     .await?;
+    /// for visibility in visibilities.iter() {
+    ///     println!("{} from {} until {}", visibility.site, visibility.start, visibility.end);
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn get_visibilities_by_satellite_id(
         &self,
-    ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send {
+        satellite_id: impl Into<SatelliteId> + Send,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> impl Future<Output = Result<Self::Container<Vec<Visibility>>, Error>> + Send {
         async move {
-            let uri = self.path_to_url("requests/search/findAllPassedToday");
+            let range = TimeRange::new(start, end)?;
 
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<TaskRequest>>>>(uri)
-                .await?
-                .items)
+            self.get_visibilities_by_satellite_id_in(satellite_id, range)
+                .await
         }
     }
 
-    /// Produces a vector of [`TaskRequest`] items, representing the list of tasks which will occur
-    /// later today.
-    ///
-    /// See [`get`](Self::get) documentation for more details about the process and return type
-    fn get_requests_upcoming_today(
+    /// Like [`Self::get_visibilities_by_satellite_id`], searching within the provided [`TimeRange`]
+    fn get_visibilities_by_satellite_id_in(
         &self,
-    ) -> impl Future<Output = Result<Self::Container<Vec<TaskRequest>>, Error>> + Send {
+        satellite_id: impl Into<SatelliteId> + Send,
+        range: TimeRange,
+    ) -> impl Future<Output = Result<Self::Container<Vec<Visibility>>, Error>> + Send {
+        let satellite_id: SatelliteId = satellite_id.into();
+
         async move {
-            let uri = self.path_to_url("requests/search/findAllUpcomingToday");
+            let mut uri = self.path_to_url(format!("satellites/{}/visibilities", satellite_id))?;
 
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<TaskRequest>>>>(uri)
-                .await?
-                .items)
+            uri.query_pairs_mut().extend_pairs(range.query_pairs());
+
+            self.get_json_map(uri).await
         }
     }
 
-    /// Produces a paginated stream of [`Satellite`] objects.
+    /// Produces single satellite object matching the provided NORAD catalog ID
     ///
-    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
-    /// and return type
-    fn get_satellites(&self) -> PaginatedStream<'_, Self::Container<Satellite>> {
-        let uri = self.path_to_url("satellites");
-
-        self.get_paginated(uri)
-    }
-
-    /// Produces single satellite object matching the provided satellite ID
-    fn get_satellite_by_id(
+    /// An ID which does not match any satellite produces an [`Error::NotFound`], whether the server
+    /// responds with a `404 Not Found` or with an empty body.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let satellite = client.get_satellite_by_norad_id(25544).await?;
+    /// println!("{}", satellite.name);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn get_satellite_by_norad_id(
         &self,
-        satellite_id: i32,
+        norad_id: u32,
     ) -> impl Future<Output = Result<Self::Container<Satellite>, Error>> + Send {
         async move {
-            let uri = self.path_to_url(format!("satellites/{}", satellite_id));
+            let mut uri = self.path_to_url("satellites/search/findOneByNoradCatId")?;
+            uri.query_pairs_mut()
+                .append_pair("noradCatId", &norad_id.to_string());
 
-            self.get_json_map(uri).await
+            let not_found =
+                || Error::NotFound(format!("no satellite has the NORAD catalog ID {norad_id}"));
+
+            let (body, status) = self.get(uri).await?;
+            if status == StatusCode::NOT_FOUND {
+                return Err(not_found());
+            }
+
+            error_on_non_success(&status, &body)?;
+
+            if body.iter().all(u8::is_ascii_whitespace) {
+                return Err(not_found());
+            }
+
+            deserialize_body(&body, self.lenient_links_enabled())
         }
     }
 
-    /// Produces single satellite object matching the provided satellite name
-    fn get_satellite_by_name(
+    /// Produces a paginated stream of [`Satellite`] objects belonging to the account matching the
+    /// provided name.
+    ///
+    /// Items are [`Error::PermissionDenied`] when the account is not visible to the caller.
+    ///
+    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
+    /// and return type
+    fn get_satellites_by_account_name(
         &self,
-        satellite_name: &str,
-    ) -> impl Future<Output = Result<Self::Container<Satellite>, Error>> + Send {
-        async move {
-            let mut uri = self.path_to_url("satellites/findOneByName");
-            uri.set_query(Some(&format!("name={satellite_name}")));
+        account_name: &str,
+    ) -> PaginatedStream<'_, Self::Container<Satellite>> {
+        let mut uri = match self.path_to_url("satellites/search/findAllByAccountName") {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+        uri.query_pairs_mut()
+            .append_pair("accountName", account_name);
 
-            self.get_json_map(uri).await
-        }
+        Box::pin(
+            self.get_paginated(uri)
+                .map(|item| item.map_err(Error::permission_denied)),
+        )
     }
 
     /// Produces a single [`Task`] matching the provided ID.
@@ -975,10 +2308,25 @@ pub trait Api: Send + Sync {
     /// See [`get`](Self::get) documentation for more details about the process and return type
     fn get_task_by_id(
         &self,
-        task_id: i32,
+        task_id: impl Into<TaskId> + Send,
     ) -> impl Future<Output = Result<Self::Container<Task>, Error>> + Send {
+        self.get_by_id(task_id)
+    }
+
+    /// Produces the [`AzEl`] pointing of the antenna during the task matching the provided ID.
+    ///
+    /// This is the counterpart of [`TaskExt::get_azel`](crate::extensions::TaskExt::get_azel) for
+    /// when only the ID of the task is known, and saves fetching the task itself.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    fn get_azel_by_task_id(
+        &self,
+        task_id: impl Into<TaskId> + Send,
+    ) -> impl Future<Output = Result<Self::Container<AzEl>, Error>> + Send {
+        let task_id: TaskId = task_id.into();
+
         async move {
-            let uri = self.path_to_url(format!("tasks/{}", task_id));
+            let uri = self.path_to_url(format!("tasks/{}/azel", task_id))?;
 
             self.get_json_map(uri).await
         }
@@ -998,19 +2346,31 @@ pub trait Api: Send + Sync {
         T: AsRef<str> + Send,
     {
         async move {
-            let mut uri = self.path_to_url("tasks/search/findByAccountAndPassOverlapping");
-
-            uri.set_query(Some(&format!(
-                "account={}&start={}&end={}",
-                account_uri.as_ref(),
-                start.format(&Iso8601::DEFAULT)?,
-                end.format(&Iso8601::DEFAULT)?
-            )));
-
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<Task>>>>(uri)
-                .await?
-                .items)
+            let range = TimeRange::new(start, end)?;
+
+            self.get_tasks_by_account_and_pass_overlapping_in(account_uri, range)
+                .await
+        }
+    }
+
+    /// Like [`Self::get_tasks_by_account_and_pass_overlapping`],
+    /// searching within the provided [`TimeRange`]
+    fn get_tasks_by_account_and_pass_overlapping_in<T>(
+        &self,
+        account_uri: T,
+        range: TimeRange,
+    ) -> impl Future<Output = Result<Self::Container<Vec<Task>>, Error>> + Send
+    where
+        T: AsRef<str> + Send,
+    {
+        async move {
+            let mut uri = self.path_to_url("tasks/search/findByAccountAndPassOverlapping")?;
+
+            uri.query_pairs_mut()
+                .append_pair("account", account_uri.as_ref())
+                .extend_pairs(range.query_pairs());
+
+            pagination::collect_embedded(self, uri).await
         }
     }
 
@@ -1026,6 +2386,33 @@ pub trait Api: Send + Sync {
         start: OffsetDateTime,
         end: OffsetDateTime,
     ) -> impl Future<Output = Result<Self::Container<Vec<Task>>, Error>> + Send
+    where
+        T: AsRef<str> + Send,
+        U: AsRef<str> + Send,
+        V: AsRef<str> + Send,
+    {
+        async move {
+            let range = TimeRange::new(start, end)?;
+
+            self.get_tasks_by_account_and_satellite_and_band_and_pass_overlapping_in(
+                account_uri,
+                satellite_config_uri,
+                band,
+                range,
+            )
+            .await
+        }
+    }
+
+    /// Like [`Self::get_tasks_by_account_and_satellite_and_band_and_pass_overlapping`],
+    /// searching within the provided [`TimeRange`]
+    fn get_tasks_by_account_and_satellite_and_band_and_pass_overlapping_in<T, U, V>(
+        &self,
+        account_uri: T,
+        satellite_config_uri: U,
+        band: V,
+        range: TimeRange,
+    ) -> impl Future<Output = Result<Self::Container<Vec<Task>>, Error>> + Send
     where
         T: AsRef<str> + Send,
         U: AsRef<str> + Send,
@@ -1034,21 +2421,15 @@ pub trait Api: Send + Sync {
         async move {
             let mut uri = self.path_to_url(
                 "tasks/search/findByAccountAndSiteConfigurationAndBandAndPassOverlapping",
-            );
+            )?;
 
-            uri.set_query(Some(&format!(
-                "account={}&satellite={}&band={}&start={}&end={}",
-                account_uri.as_ref(),
-                satellite_config_uri.as_ref(),
-                band.as_ref(),
-                start.format(&Iso8601::DEFAULT)?,
-                end.format(&Iso8601::DEFAULT)?,
-            )));
-
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<Task>>>>(uri)
-                .await?
-                .items)
+            uri.query_pairs_mut()
+                .append_pair("account", account_uri.as_ref())
+                .append_pair("satellite", satellite_config_uri.as_ref())
+                .append_pair("band", band.as_ref())
+                .extend_pairs(range.query_pairs());
+
+            pagination::collect_embedded(self, uri).await
         }
     }
 
@@ -1064,6 +2445,33 @@ pub trait Api: Send + Sync {
         start: OffsetDateTime,
         end: OffsetDateTime,
     ) -> impl Future<Output = Result<Self::Container<Vec<Task>>, Error>> + Send
+    where
+        T: AsRef<str> + Send,
+        U: AsRef<str> + Send,
+        V: AsRef<str> + Send,
+    {
+        async move {
+            let range = TimeRange::new(start, end)?;
+
+            self.get_tasks_by_account_and_site_configuration_and_band_and_pass_overlapping_in(
+                account_uri,
+                site_config_uri,
+                band,
+                range,
+            )
+            .await
+        }
+    }
+
+    /// Like [`Self::get_tasks_by_account_and_site_configuration_and_band_and_pass_overlapping`],
+    /// searching within the provided [`TimeRange`]
+    fn get_tasks_by_account_and_site_configuration_and_band_and_pass_overlapping_in<T, U, V>(
+        &self,
+        account_uri: T,
+        site_config_uri: U,
+        band: V,
+        range: TimeRange,
+    ) -> impl Future<Output = Result<Self::Container<Vec<Task>>, Error>> + Send
     where
         T: AsRef<str> + Send,
         U: AsRef<str> + Send,
@@ -1072,128 +2480,306 @@ pub trait Api: Send + Sync {
         async move {
             let mut uri = self.path_to_url(
                 "tasks/search/findByAccountAndSiteConfigurationAndBandAndPassOverlapping",
-            );
+            )?;
+
+            uri.query_pairs_mut()
+                .append_pair("account", account_uri.as_ref())
+                .append_pair("siteConfig", site_config_uri.as_ref())
+                .append_pair("band", band.as_ref())
+                .extend_pairs(range.query_pairs());
+
+            pagination::collect_embedded(self, uri).await
+        }
+    }
+
+    /// Produces a vector of [`Task`] items, representing all the tasks contained within the
+    /// provided time frame.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    ///
+    /// # Note
+    ///
+    /// This differs from [`Self::get_tasks_by_pass_overlapping`] in that it only produces tasks
+    /// which are wholly contained within the window.
+    fn get_tasks_by_pass_window(
+        &self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> impl Future<Output = Result<Self::Container<Vec<Task>>, Error>> + Send {
+        async move {
+            let range = TimeRange::new(start, end)?;
+
+            self.get_tasks_by_pass_window_in(range).await
+        }
+    }
+
+    /// Like [`Self::get_tasks_by_pass_window`], searching within the provided [`TimeRange`]
+    fn get_tasks_by_pass_window_in(
+        &self,
+        range: TimeRange,
+    ) -> impl Future<Output = Result<Self::Container<Vec<Task>>, Error>> + Send {
+        async move {
+            let mut uri = self.path_to_url("tasks/search/findByStartBetweenOrderByStartAsc")?;
+
+            uri.query_pairs_mut().extend_pairs(range.query_pairs());
+
+            pagination::collect_embedded(self, uri).await
+        }
+    }
+
+    /// Produces a paginated stream of [`Task`] items, representing all the tasks which overlap the
+    /// provided time frame.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    ///
+    /// # Note
+    ///
+    /// This differs from [`Self::get_tasks_by_pass_window`] in that it also includes tasks which
+    /// only partially fall within the provided time frame.
+    fn get_tasks_by_pass_overlapping(
+        &self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> PaginatedStream<'_, Self::Container<Task>> {
+        let range = match TimeRange::new(start, end) {
+            Ok(range) => range,
+            Err(error) => return error.once_err(),
+        };
+
+        self.get_tasks_by_pass_overlapping_in(range)
+    }
+
+    /// Like [`Self::get_tasks_by_pass_overlapping`], searching within the provided [`TimeRange`]
+    fn get_tasks_by_pass_overlapping_in(
+        &self,
+        range: TimeRange,
+    ) -> PaginatedStream<'_, Self::Container<Task>> {
+        let mut uri = match self.path_to_url("tasks/search/findByOverlapping") {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+
+        uri.query_pairs_mut().extend_pairs(range.query_pairs());
+
+        self.get_paginated(uri)
+    }
+
+    /// Produces a paginated stream of [`Task`] items, representing all the tasks at the site
+    /// matching the provided ID.
+    ///
+    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
+    /// and return type
+    fn get_tasks_by_site_id(
+        &self,
+        site_id: impl Into<SiteId> + Send,
+    ) -> PaginatedStream<'_, Self::Container<Task>> {
+        let site_id: SiteId = site_id.into();
+
+        let site = match self.path_to_url(format!("sites/{site_id}")) {
+            Ok(site) => site,
+            Err(error) => return error.once_err(),
+        };
+
+        let mut uri = match self.path_to_url("tasks/search/findBySite") {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+
+        uri.query_pairs_mut().append_pair("site", site.as_str());
+
+        self.get_paginated(uri)
+    }
+
+    /// Produces a paginated stream of [`Task`] items, representing all the tasks of the satellite
+    /// matching the provided name.
+    ///
+    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
+    /// and return type
+    fn get_tasks_by_satellite_name(
+        &self,
+        satellite_name: &str,
+    ) -> PaginatedStream<'_, Self::Container<Task>> {
+        let mut uri = match self.path_to_url("tasks/search/findBySatelliteName") {
+            Ok(uri) => uri,
+            Err(error) => return error.once_err(),
+        };
+
+        uri.query_pairs_mut()
+            .append_pair("satelliteName", satellite_name);
+
+        self.get_paginated(uri)
+    }
+
+    /// Produces a vector of [`Task`] items, representing the list of tasks which have already
+    /// occurred today.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    fn get_tasks_passed_today(
+        &self,
+    ) -> impl Future<Output = Result<Self::Container<Vec<Task>>, Error>> + Send {
+        async move {
+            let uri = self.path_to_url("tasks/search/findAllPassedToday")?;
+
+            pagination::collect_embedded(self, uri).await
+        }
+    }
+
+    /// Produces a vector of [`Task`] items, representing the list of tasks which will occur later
+    /// today.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    fn get_tasks_upcoming_today(
+        &self,
+    ) -> impl Future<Output = Result<Self::Container<Vec<Task>>, Error>> + Send {
+        async move {
+            let uri = self.path_to_url("tasks/search/findAllUpcomingToday")?;
+
+            pagination::collect_embedded(self, uri).await
+        }
+    }
+
+    /// Produces a paginated stream of [`User`] objects.
+    ///
+    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
+    /// and return type
+    fn get_users(&self) -> PaginatedStream<'_, Self::Container<User>> {
+        self.list()
+    }
 
-            uri.set_query(Some(&format!(
-                "account={}&siteConfig={}&band={}&start={}&end={}",
-                account_uri.as_ref(),
-                site_config_uri.as_ref(),
-                band.as_ref(),
-                start.format(&Iso8601::DEFAULT)?,
-                end.format(&Iso8601::DEFAULT)?
-            )));
-
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<Task>>>>(uri)
-                .await?
-                .items)
+    /// Produces the [`User`] authenticated by the key of the client.
+    ///
+    /// See [`get`](Self::get) documentation for more details about the process and return type
+    fn get_current_user(
+        &self,
+    ) -> impl Future<Output = Result<Self::Container<User>, Error>> + Send {
+        async move {
+            let uri = self.path_to_url("users/current")?;
+
+            self.get_json_map(uri).await
         }
     }
 
-    /// Produces a vector of [`Task`] items, representing all the tasks contained within the
-    /// provided time frame.
+    /// Produces the [`Account`] of the user authenticated by the key of the client, by following
+    /// the `account` link of the [current user](Self::get_current_user).
+    fn get_current_account(&self) -> impl Future<Output = Result<Account, Error>> + Send
+    where
+        Self: Sized,
+    {
+        current::current_account(self)
+    }
+
+    /// The URI of the [current account](Self::get_current_account), as accepted by the
+    /// `account_uri` parameters of the searches, such as
+    /// [`get_requests_by_account_and_target_date_between`](Self::get_requests_by_account_and_target_date_between)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # use time::{Duration, OffsetDateTime};
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let account = client.account_uri().await?;
+    /// let start = OffsetDateTime::now_utc();
+    /// let end = start + Duration::days(1);
+    /// let requests = client
+    ///     .get_requests_by_account_and_target_date_between(account, start, end)
+    ///     .try_collect_inner()
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn account_uri(&self) -> impl Future<Output = Result<String, Error>> + Send
+    where
+        Self: Sized,
+    {
+        current::account_uri(self)
+    }
+
+    /// Produces a single [`User`] object matching the provided email address.
     ///
     /// See [`get`](Self::get) documentation for more details about the process and return type
     ///
-    /// # Note
+    /// # Example
     ///
-    /// This differs from [`Self::get_tasks_by_pass_overlapping`] in that it only produces tasks
-    /// which are wholly contained within the window.
-    fn get_tasks_by_pass_window(
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let user = client.get_user_by_email("flyingsolo@gmail.com").await?;
+    /// println!("{}", user.get_id()?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn get_user_by_email(
         &self,
-        start: OffsetDateTime,
-        end: OffsetDateTime,
-    ) -> impl Future<Output = Result<Self::Container<Vec<Task>>, Error>> + Send {
+        email: &str,
+    ) -> impl Future<Output = Result<Self::Container<User>, Error>> + Send {
         async move {
-            let mut uri = self.path_to_url("tasks/search/findByStartBetweenOrderByStartAsc");
-
-            uri.set_query(Some(&format!(
-                "start={}&end={}",
-                start.format(&Iso8601::DEFAULT)?,
-                end.format(&Iso8601::DEFAULT)?
-            )));
-
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<Task>>>>(uri)
-                .await?
-                .items)
+            let mut uri = self.path_to_url("users/search/findOneByEmail")?;
+            uri.query_pairs_mut().append_pair("email", email);
+            self.get_json_map(uri).await
         }
     }
 
-    /// Produces a paginated stream of [`Task`] items, representing all the tasks which overlap the
-    /// provided time frame.
-    ///
-    /// See [`get`](Self::get) documentation for more details about the process and return type
+    /// Produces a paginated stream of [`User`] objects belonging to the account matching the
+    /// provided name.
     ///
-    /// # Note
+    /// Items are [`Error::PermissionDenied`] when the account is not visible to the caller.
     ///
-    /// This differs from [`Self::get_tasks_by_pass_window`] in that it also includes tasks which
-    /// only partially fall within the provided time frame.
-    fn get_tasks_by_pass_overlapping(
+    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
+    /// and return type
+    fn get_users_by_account_name(
         &self,
-        start: OffsetDateTime,
-        end: OffsetDateTime,
-    ) -> PaginatedStream<'_, Self::Container<Task>> {
-        let start = match start.format(&Iso8601::DEFAULT).map_err(Error::from) {
-            Ok(start) => start,
-            Err(error) => return error.once_err(),
-        };
-
-        let end = match end.format(&Iso8601::DEFAULT).map_err(Error::from) {
-            Ok(end) => end,
+        account_name: &str,
+    ) -> PaginatedStream<'_, Self::Container<User>> {
+        let mut uri = match self.path_to_url("users/search/findAllByAccountName") {
+            Ok(uri) => uri,
             Err(error) => return error.once_err(),
         };
+        uri.query_pairs_mut()
+            .append_pair("accountName", account_name);
 
-        let mut uri = self.path_to_url("tasks/search/findByOverlapping");
-
-        uri.set_query(Some(&format!("start={}&end={}", start, end)));
-
-        self.get_paginated(uri)
+        Box::pin(
+            self.get_paginated(uri)
+                .map(|item| item.map_err(Error::permission_denied)),
+        )
     }
 
-    /// Produces a vector of [`Task`] items, representing the list of tasks which have already
-    /// occurred today.
+    /// Produces a paginated stream of [`User`] objects, using the provided page options.
     ///
-    /// See [`get`](Self::get) documentation for more details about the process and return type
-    fn get_tasks_passed_today(
+    /// See [`get_paginated_with_options`](Self::get_paginated_with_options) documentation for more
+    /// details about the process and return type
+    fn get_users_with_options(
         &self,
-    ) -> impl Future<Output = Result<Self::Container<Vec<Task>>, Error>> + Send {
-        async move {
-            let uri = self.path_to_url("tasks/search/findAllPassedToday");
-
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<Task>>>>(uri)
-                .await?
-                .items)
-        }
+        options: PageOptions,
+    ) -> PaginatedStream<'_, Self::Container<User>> {
+        self.list_with_options(options)
     }
 
-    /// Produces a vector of [`Task`] items, representing the list of tasks which will occur later
-    /// today.
+    /// Fetch the names of the roles which may be assigned to users
     ///
-    /// See [`get`](Self::get) documentation for more details about the process and return type
-    fn get_tasks_upcoming_today(
-        &self,
-    ) -> impl Future<Output = Result<Self::Container<Vec<Task>>, Error>> + Send {
+    /// When the server does not list its roles, or does not allow the user to list them, the roles
+    /// known to this crate are returned instead.
+    fn get_available_roles(&self) -> impl Future<Output = Result<Vec<String>, Error>> + Send {
         async move {
-            let uri = self.path_to_url("tasks/search/findAllUpcomingToday");
+            let uri = self.path_to_url("users/roles")?;
+            let (body, status) = self.get(uri).await?;
+            if matches!(
+                status,
+                StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED | StatusCode::FORBIDDEN
+            ) {
+                let known = post::user::KNOWN_ROLES.iter().map(|role| role.to_string());
+                return Ok(known.collect());
+            }
 
-            Ok(self
-                .get_json_map::<Embedded<Self::Container<Vec<Task>>>>(uri)
-                .await?
-                .items)
+            error_on_non_success(&status, &body)?;
+            deserialize_body(&body, false)
         }
     }
 
-    /// Produces a paginated stream of [`User`] objects.
-    ///
-    /// See [`get_paginated`](Self::get_paginated) documentation for more details about the process
-    /// and return type
-    fn get_users(&self) -> Pin<Box<dyn Stream<Item = Result<Self::Container<User>, Error>> + '_>> {
-        let uri = self.path_to_url("users");
-        self.get_paginated(uri)
-    }
-
     /// Create a new satellite band object
     ///
     /// # Example
@@ -1304,6 +2890,91 @@ pub trait Api: Send + Sync {
         post::overrides::new(self)
     }
 
+    /// Create a new site
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// client
+    ///     .new_site()
+    ///     .name("LOAG")
+    ///     .location(Location {
+    ///         latitude: 34.05,
+    ///         longitude: -118.24,
+    ///         elevation: 71.0,
+    ///     })
+    ///     .base_fps_port(20100)
+    ///     .description("Los Angeles")
+    ///     .send()
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn new_site(&self) -> post::site::SiteBuilder<'_, Self, post::site::NoName>
+    where
+        Self: Sized,
+    {
+        post::site::new(self)
+    }
+
+    /// Create a new configuration of a site
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// client
+    ///     .new_site_configuration()
+    ///     .site_id(14)
+    ///     .name("S-Band")
+    ///     .property("antenna", "3.7m")
+    ///     .send()
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn new_site_configuration(
+        &self,
+    ) -> post::site_config::SiteConfigurationBuilder<'_, Self, post::site_config::NoSite>
+    where
+        Self: Sized,
+    {
+        post::site_config::new(self)
+    }
+
+    /// Create a new account
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let account_id = client
+    ///     .new_account()
+    ///     .name("ABC Space")
+    ///     .storage_key("ABCSpace")
+    ///     .add_realtime_cidr("Operations", "1.2.3.4/32")
+    ///     .send()
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn new_account(&self) -> post::account::AccountBuilder<'_, Self, post::account::NoName>
+    where
+        Self: Sized,
+    {
+        post::account::new(self)
+    }
+
     /// Create a new user
     ///
     /// # Example
@@ -1331,6 +3002,72 @@ pub trait Api: Send + Sync {
         post::user::new(self)
     }
 
+    /// Update the satellite band details matching the provided `id`
+    ///
+    /// Only the fields which are set on the builder are sent, as a `PATCH`, so the other fields of
+    /// the band are left unchanged. To start from every field of an existing band, see
+    /// [`BandDetailsUpdateBuilder::from_existing`](crate::prelude::BandDetailsUpdateBuilder::from_existing).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// client
+    ///     .update_band_details(42)
+    ///     .frequency(8100.0)
+    ///     .send()
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn update_band_details(
+        &self,
+        id: impl Into<BandId> + Send,
+    ) -> put::band::BandDetailsUpdateBuilder<'_, Self>
+    where
+        Self: Sized,
+    {
+        let id: BandId = id.into();
+
+        put::band::new(self, id)
+    }
+
+    /// Update the satellite matching the provided `id`
+    ///
+    /// Only the fields which are set on the builder are sent, as a `PATCH`, so the other fields of
+    /// the satellite are left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// client
+    ///     .update_satellite(42)
+    ///     .name("My Renamed Satellite")
+    ///     .satellite_configuration_id(7)
+    ///     .send()
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn update_satellite(
+        &self,
+        id: impl Into<SatelliteId> + Send,
+    ) -> put::satellite::SatelliteUpdateBuilder<'_, Self>
+    where
+        Self: Sized,
+    {
+        let id: SatelliteId = id.into();
+
+        put::satellite::new(self, id)
+    }
+
     /// Create a new task request
     ///
     /// # Example
@@ -1342,7 +3079,7 @@ pub trait Api: Send + Sync {
     /// # tokio_test::block_on(async {
     /// let client = Client::from_env()?;
     ///
-    /// client
+    /// let created = client
     ///     .new_task_request()
     ///     .test_task("my_test_file.bin")
     ///     .target_time_utc(OffsetDateTime::now_utc() + Duration::from_secs(15 * 60))
@@ -1353,6 +3090,8 @@ pub trait Api: Send + Sync {
     ///     .band_ids([2017, 2019])
     ///     .send()
     ///     .await?;
+    ///
+    /// println!("Created task request {}", created.id);
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// # });
     /// ```
@@ -1370,38 +3109,49 @@ pub trait Api: Send + Sync {
     /// ```no_run
     /// # use freedom_api::prelude::*;
     /// # tokio_test::block_on(async {
-    /// const BAND_ID: u32 = 42;
-    /// const SITE_CONFIG_ID: u32 = 201;
+    /// let band = BandId::from(42);
+    /// let site_configuration = SiteConfigurationId::from(201);
     ///
     /// let client = Client::from_env()?;
     ///
-    /// let token = client.new_token_by_site_configuration_id(BAND_ID, SITE_CONFIG_ID).await?;
+    /// let token = client
+    ///     .new_token_by_site_configuration_id(band, site_configuration)
+    ///     .await?;
     /// // Submit token to FPS ...
     /// println!("{:?}", token);
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// # });
     /// ```
+    ///
+    /// The band and site configuration IDs cannot be swapped:
+    ///
+    /// ```compile_fail
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let band = BandId::from(42);
+    /// let site_configuration = SiteConfigurationId::from(201);
+    ///
+    /// let client = Client::from_env()?;
+    ///
+    /// let token = client
+    ///     .new_token_by_site_configuration_id(site_configuration, band)
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
     fn new_token_by_site_configuration_id(
         &self,
-        band_id: u32,
-        site_configuration_id: u32,
+        band_id: impl Into<BandId> + Send,
+        site_configuration_id: impl Into<SiteConfigurationId> + Send,
     ) -> impl Future<Output = Result<String, Error>> + Send {
+        let band_id: BandId = band_id.into();
+        let site_configuration_id: SiteConfigurationId = site_configuration_id.into();
+
         async move {
-            let url = self.path_to_url("fps");
-            let payload = serde_json::json!({
-                "band": format!("/api/satellite_bands/{}", band_id),
-                "configuration": format!("/api/configurations/{}", site_configuration_id),
-            });
-
-            let value: JsonValue = self.post_deserialize(url, &payload).await?;
-
-            value
-                .get("token")
-                .ok_or(Error::Response(String::from("Missing token field")))?
-                .as_str()
-                .ok_or(Error::Response(String::from("Invalid type for token")))
-                .map(|s| s.to_owned())
-                .map_err(From::from)
+            let target = token::FpsTarget::SiteConfiguration(site_configuration_id);
+            let token = self.request_fps_token(band_id, target).await?;
+
+            Ok(token.token.0)
         }
     }
 
@@ -1412,12 +3162,12 @@ pub trait Api: Send + Sync {
     /// ```no_run
     /// # use freedom_api::prelude::*;
     /// # tokio_test::block_on(async {
-    /// const BAND_ID: u32 = 42;
-    /// const SATELLITE_ID: u32 = 101;
+    /// let band = BandId::from(42);
+    /// let satellite = SatelliteId::from(101);
     ///
     /// let client = Client::from_env()?;
     ///
-    /// let token = client.new_token_by_satellite_id(BAND_ID, SATELLITE_ID).await?;
+    /// let token = client.new_token_by_satellite_id(band, satellite).await?;
     /// // Submit token to FPS ...
     /// println!("{:?}", token);
     /// # Ok::<_, Box<dyn std::error::Error>>(())
@@ -1425,33 +3175,326 @@ pub trait Api: Send + Sync {
     /// ```
     fn new_token_by_satellite_id(
         &self,
-        band_id: u32,
-        satellite_id: u32,
+        band_id: impl Into<BandId> + Send,
+        satellite_id: impl Into<SatelliteId> + Send,
     ) -> impl Future<Output = Result<String, Error>> + Send {
+        let band_id: BandId = band_id.into();
+        let satellite_id: SatelliteId = satellite_id.into();
+
         async move {
-            let url = self.path_to_url("fps");
-            let payload = serde_json::json!({
-                "band": format!("/api/satellite_bands/{}", band_id),
-                "satellite": format!("/api/satellites/{}", satellite_id),
-            });
-
-            let value: JsonValue = self.post_deserialize(url, &payload).await?;
-
-            value
-                .get("token")
-                .ok_or(Error::Response(String::from("Missing token field")))?
-                .as_str()
-                .ok_or(Error::Response(String::from("Invalid type for token")))
-                .map(|s| s.to_owned())
-                .map_err(From::from)
+            let target = token::FpsTarget::Satellite(satellite_id);
+            let token = self.request_fps_token(band_id, target).await?;
+
+            Ok(token.token.0)
+        }
+    }
+
+    /// Fetch an FPS token for the provided band ID and satellite or site configuration, along
+    /// with its expiry and the FPS host to connect to
+    ///
+    /// To reuse tokens until they expire, see [`TokenProvider`](token::TokenProvider).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let target = FpsTarget::Satellite(SatelliteId::from(101));
+    /// let token = client.request_fps_token(42, target).await?;
+    /// println!("Connecting to {} until {:?}", token.host, token.expires_at);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn request_fps_token(
+        &self,
+        band_id: impl Into<BandId> + Send,
+        target: token::FpsTarget,
+    ) -> impl Future<Output = Result<token::FpsToken, Error>> + Send {
+        token::request_token(self, band_id.into(), target)
+    }
+}
+
+/// Walk every page starting from the head URL, yielding each item
+pub(crate) fn paginate<T, C>(
+    client: &C,
+    mut head_url: Url,
+    options: PageOptions,
+) -> PaginatedStream<'_, C::Container<T>>
+where
+    C: Api + ?Sized,
+    T: 'static + Value,
+{
+    let base = client.config().environment().freedom_entrypoint();
+    options.apply(&mut head_url);
+    // Every page is requested within the span, to correlate the follow-ups with the first page
+    let span = crate::telemetry::pagination_span(&head_url);
+    let mut current_url = head_url; // Not necessary but makes control flow more obvious
+    Box::pin(stream! {
+        loop {
+            // Get the results for the current page.
+            let pag = match options.behavior() {
+                PaginationBehavior::Stop => {
+                    client
+                        .get_json_map::<EmbeddedPage>(current_url.clone())
+                        .instrument(span.clone())
+                        .await?
+                }
+                PaginationBehavior::RetryPage { attempts } => {
+                    let page =
+                        fetch_page(client, current_url.clone(), attempts).instrument(span.clone());
+                    match page.await {
+                        Ok(pag) => pag,
+                        Err(error) => {
+                            yield Err(error);
+                            break;
+                        }
+                    }
+                }
+            };
+            let next = pag.next(&base);
+            for item in pag.into_items() {
+                yield crate::json::item_from_value::<C::Container<T>>(&item)
+                    .map_err(|error| error.at(Method::GET, &current_url));
+            }
+            if let Some(next) = next? {
+                // Update the URL to the next page.
+                current_url = next;
+                options.fill_missing(&mut current_url);
+            } else {
+                break;
+            }
         }
+    })
+}
+
+/// Fetch a page of a paginated stream, making at most `attempts` attempts
+async fn fetch_page<C>(client: &C, url: Url, attempts: u32) -> Result<EmbeddedPage, Error>
+where
+    C: Api + ?Sized,
+{
+    let mut backoff = client.backoff();
+    let mut failures = 0;
+    loop {
+        let error = match client.get_json_map(url.clone()).await {
+            Ok(page) => return Ok(page),
+            Err(error) => error,
+        };
+        failures += 1;
+
+        // Only transient failures may succeed when retried, which the client may have already
+        // retried itself
+        let retryable = error.is_retryable() && !client.retries_requests();
+        let delay = match backoff.next_delay(failures - 1) {
+            Some(delay) if retryable && failures < attempts => delay,
+            _ => {
+                return Err(Error::PageFetch {
+                    url: crate::telemetry::redacted(&url).to_string(),
+                    attempts: failures,
+                    error: Box::new(error),
+                })
+            }
+        };
+
+        tracing::warn!(
+            url = %crate::telemetry::redacted(&url),
+            %error,
+            ?delay,
+            "Failed to fetch page, retrying"
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+fn deserialize_body<T>(body: &[u8], lenient_links: bool) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    if lenient_links {
+        let mut value: JsonValue = crate::json::from_slice(body)?;
+        crate::utils::sanitize_links(&mut value);
+
+        return crate::json::from_value(&value);
     }
+
+    crate::json::from_slice(body)
 }
 
-fn error_on_non_success(status: &StatusCode) -> Result<(), Error> {
+fn error_on_non_success(status: &StatusCode, body: &[u8]) -> Result<(), Error> {
     if !status.is_success() {
-        return Err(Error::Response(status.to_string()));
+        return Err(Error::from_response(*status, body));
     }
 
     Ok(())
 }
+
+/// Delete the resource, converting an unsuccessful response into an [`Error::Api`] carrying the
+/// request
+pub(crate) async fn delete_checked<C>(client: &C, url: Url) -> Result<Response, Error>
+where
+    C: Api + ?Sized,
+{
+    let delete = async {
+        let resp = client.delete(url.clone()).await?;
+
+        error_on_non_success_response(resp).await
+    };
+
+    delete.await.map_err(|error| error.at(Method::DELETE, &url))
+}
+
+/// Passes through successful responses, consuming the body of unsuccessful responses to produce an
+/// [`Error::Api`]
+pub(crate) async fn error_on_non_success_response(resp: Response) -> Result<Response, Error> {
+    let status = resp.status();
+    if status.is_success() {
+        return Ok(resp);
+    }
+
+    let url = resp.url().clone();
+    let headers = resp.headers().clone();
+    let body = resp.bytes().await?;
+    Err(Error::from_response_headers(status, &headers, &body).in_request(None, &url))
+}
+
+#[derive(Debug, serde::Serialize)]
+struct StatusUpdate {
+    status: TaskStatusType,
+}
+
+/// Transition the task request into the status, converting a refused transition into an
+/// [`Error::Api`]
+async fn patch_status<C>(
+    client: &C,
+    id: TaskRequestId,
+    status: TaskStatusType,
+) -> Result<Response, Error>
+where
+    C: Api + ?Sized,
+{
+    let uri = client.path_to_url(format!("requests/{id}"))?;
+
+    patch_status_at(client, uri, status).await
+}
+
+/// Transition the task request at the URL into the status, as for [`patch_status`]
+pub(crate) async fn patch_status_at<C>(
+    client: &C,
+    url: Url,
+    status: TaskStatusType,
+) -> Result<Response, Error>
+where
+    C: Api + ?Sized,
+{
+    let patch = async {
+        let resp = client.patch(url.clone(), StatusUpdate { status }).await?;
+
+        error_on_non_success_response(resp).await
+    };
+
+    patch.await.map_err(|error| error.at(Method::PATCH, &url))
+}
+
+/// Join the path onto the entrypoint, ensuring the resulting URL remains beneath the entrypoint
+fn join_entrypoint(entrypoint: &Url, path: &str) -> Result<Url, Error> {
+    if !entrypoint.path().ends_with('/') {
+        return Err(Error::UrlConstruction(format!(
+            "the entrypoint {entrypoint} must end with a trailing slash"
+        )));
+    }
+
+    let url = entrypoint
+        .join(path)
+        .map_err(|error| Error::UrlConstruction(format!("{path:?}: {error}")))?;
+    if !url.as_str().starts_with(entrypoint.as_str()) {
+        return Err(Error::UrlConstruction(format!(
+            "{path:?} is outside of the entrypoint {entrypoint}"
+        )));
+    }
+
+    Ok(url)
+}
+
+/// The URL of the resource at the path, as linked by the builders when given an ID
+///
+/// Since the path of a resource ID always joins, this can only fail due to the entrypoint, in
+/// which case an empty URL is returned. The same failure is then reported when the builder is
+/// sent.
+pub(crate) fn resource_url<C: Api>(client: &C, path: String) -> String {
+    client
+        .path_to_url(path)
+        .map(String::from)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn join(path: &str) -> Result<String, Error> {
+        let entrypoint = Url::parse("https://test-api.atlasground.com/api/").unwrap();
+
+        join_entrypoint(&entrypoint, path).map(String::from)
+    }
+
+    #[test]
+    fn paths_join_beneath_entrypoint() {
+        assert_eq!(
+            join("satellites/42").unwrap(),
+            "https://test-api.atlasground.com/api/satellites/42"
+        );
+        assert_eq!(
+            join("satellite_bands/search/findOneByName?name=Foo Bar").unwrap(),
+            "https://test-api.atlasground.com/api/satellite_bands/search/findOneByName?name=Foo%20Bar"
+        );
+        assert_eq!(
+            join("sites/Fläche").unwrap(),
+            "https://test-api.atlasground.com/api/sites/Fl%C3%A4che"
+        );
+    }
+
+    #[test]
+    fn paths_outside_entrypoint_are_errors() {
+        for path in [
+            "https://example.com/api/satellites",
+            "//example.com/api/satellites",
+            "/satellites",
+            "../satellites",
+        ] {
+            assert!(
+                matches!(join(path), Err(Error::UrlConstruction(_))),
+                "{path:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn entrypoint_requires_trailing_slash() {
+        let entrypoint = Url::parse("https://test-api.atlasground.com/api").unwrap();
+
+        assert!(matches!(
+            join_entrypoint(&entrypoint, "satellites"),
+            Err(Error::UrlConstruction(_))
+        ));
+    }
+
+    #[test]
+    fn query_values_are_percent_encoded() {
+        let entrypoint = Url::parse("https://test-api.atlasground.com/api/").unwrap();
+        let mut uri = join_entrypoint(&entrypoint, "sites/search/findOneByName").unwrap();
+        uri.query_pairs_mut()
+            .append_pair("name", "ABC Space & Co")
+            .append_pair("account", "a+b=c");
+
+        assert_eq!(uri.query(), Some("name=ABC+Space+%26+Co&account=a%2Bb%3Dc"));
+        let pairs: Vec<(String, String)> = uri.query_pairs().into_owned().collect();
+        assert_eq!(
+            pairs,
+            [
+                (String::from("name"), String::from("ABC Space & Co")),
+                (String::from("account"), String::from("a+b=c")),
+            ]
+        );
+    }
+}
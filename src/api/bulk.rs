@@ -0,0 +1,59 @@
+//! Querying task requests by many IDs at once
+use std::collections::HashSet;
+
+use freedom_models::task::TaskRequest;
+use futures_util::{stream, StreamExt, TryStreamExt};
+use serde_json::Value as JsonValue;
+
+use crate::{
+    api::{pagination, Api},
+    error::Error,
+};
+
+/// The number of IDs placed in the query of a single request by
+/// [`get_requests_by_ids`](Api::get_requests_by_ids)
+pub const IDS_PER_REQUEST: usize = 100;
+
+/// The maximum number of chunks of IDs queried at once
+const CONCURRENCY: usize = 4;
+
+/// Fetch the task requests matching the IDs, a chunk of IDs at a time
+///
+/// A single query holding thousands of IDs exceeds the URL length limits of servers and proxies,
+/// so the IDs are split into chunks of `ids_per_request`, which are queried concurrently. The
+/// results are concatenated in the order of the chunks.
+pub(crate) async fn requests_by_ids<C>(
+    client: &C,
+    ids: Vec<String>,
+    ids_per_request: usize,
+) -> Result<C::Container<Vec<TaskRequest>>, Error>
+where
+    C: Api + ?Sized,
+{
+    let mut seen = HashSet::new();
+    let ids: Vec<_> = ids
+        .into_iter()
+        .filter(|id| seen.insert(id.clone()))
+        .collect();
+
+    // The chunks are owned, as a future borrowing from `ids` is not `Send` for every lifetime
+    let chunks: Vec<Vec<String>> = ids
+        .chunks(ids_per_request.max(1))
+        .map(<[String]>::to_vec)
+        .collect();
+
+    let chunks: Vec<Vec<JsonValue>> = stream::iter(chunks)
+        .map(|chunk| async move {
+            let mut uri = client.path_to_url("requests/search/findAllByIds")?;
+            uri.query_pairs_mut()
+                .append_pair("ids", &crate::utils::list_to_string(&chunk));
+
+            pagination::embedded_items(client, uri).await
+        })
+        .buffered(CONCURRENCY)
+        .try_collect()
+        .await?;
+
+    let items = chunks.into_iter().flatten().collect();
+    crate::json::from_value(&JsonValue::Array(items))
+}
@@ -0,0 +1,28 @@
+use freedom_models::account::Account;
+
+use crate::{
+    api::Api,
+    error::Error,
+    extensions::{HateoasExt, UserExt},
+};
+
+/// The account of the user authenticated by the key of the client
+pub(crate) async fn current_account<C>(client: &C) -> Result<Account, Error>
+where
+    C: Api,
+{
+    let user = client.get_current_user().await?;
+
+    user.get_account(client).await
+}
+
+/// The `self` link of the account of the authenticated user
+pub(crate) async fn account_uri<C>(client: &C) -> Result<String, Error>
+where
+    C: Api,
+{
+    let account = current_account(client).await?;
+    let url = account.link("self").ok_or(Error::MissingUri("self"))?;
+
+    Ok(url.to_string())
+}
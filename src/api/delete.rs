@@ -0,0 +1,178 @@
+use freedom_config::{Env, Prod};
+use freedom_models::task::TaskRequest;
+use futures_util::{StreamExt, TryStreamExt};
+
+use crate::{api::Api, error::Error, extensions::TaskRequestExt, ids::TaskRequestId};
+
+/// Selects the task requests to be removed by
+/// [`delete_requests_matching`](crate::Api::delete_requests_matching).
+///
+/// Every task request visible to the client is checked against the predicate, unless the search is
+/// narrowed on the server with [`Self::satellite_name`].
+pub struct RequestFilter {
+    satellite_name: Option<String>,
+    predicate: Box<dyn Fn(&TaskRequest) -> bool + Send + Sync>,
+}
+
+impl RequestFilter {
+    /// Match all task requests for which the predicate returns `true`
+    pub fn new<F>(predicate: F) -> Self
+    where
+        F: Fn(&TaskRequest) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            satellite_name: None,
+            predicate: Box::new(predicate),
+        }
+    }
+
+    /// Only search the task requests of the satellite with the provided name
+    pub fn satellite_name(mut self, name: impl Into<String>) -> Self {
+        self.satellite_name = Some(name.into());
+        self
+    }
+}
+
+impl std::fmt::Debug for RequestFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestFilter")
+            .field("satellite_name", &self.satellite_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The limits which must hold for a bulk deletion to proceed.
+///
+/// By default deletion is refused when the client is configured for the production environment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteSafeguards {
+    max_count: usize,
+    allow_prod: bool,
+    concurrency: usize,
+    dry_run: bool,
+}
+
+impl DeleteSafeguards {
+    /// Abort the deletion, before deleting anything, if more than `max_count` requests match
+    pub fn new(max_count: usize) -> Self {
+        Self {
+            max_count,
+            allow_prod: false,
+            concurrency: 4,
+            dry_run: false,
+        }
+    }
+
+    /// Explicitly allow deletion when the client targets the production environment
+    pub fn allow_prod(mut self, allow_prod: bool) -> Self {
+        self.allow_prod = allow_prod;
+        self
+    }
+
+    /// The maximum number of delete calls in flight at once. Defaults to 4.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Only report the IDs of the matching task requests, without deleting any of them.
+    ///
+    /// The other safeguards are still checked, so a dry run fails exactly when the deletion would.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+/// The outcome of a bulk deletion
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeleteReport {
+    /// The IDs of every task request which matched the filter
+    pub matched: Vec<TaskRequestId>,
+    /// The result of the delete call for each matched ID, ordered by ID. Empty for a
+    /// [dry run](DeleteSafeguards::dry_run).
+    pub outcomes: Vec<(TaskRequestId, Result<(), Error>)>,
+}
+
+impl DeleteReport {
+    /// The IDs of the task requests which were successfully deleted
+    pub fn deleted(&self) -> impl Iterator<Item = TaskRequestId> + '_ {
+        self.outcomes
+            .iter()
+            .filter(|(_, outcome)| outcome.is_ok())
+            .map(|(id, _)| *id)
+    }
+
+    /// The IDs of the task requests which could not be deleted, along with the reason
+    pub fn failed(&self) -> impl Iterator<Item = (TaskRequestId, &Error)> + '_ {
+        self.outcomes
+            .iter()
+            .filter_map(|(id, outcome)| outcome.as_ref().err().map(|error| (*id, error)))
+    }
+}
+
+pub(crate) async fn delete_requests_matching<C>(
+    client: &C,
+    filter: RequestFilter,
+    safeguards: DeleteSafeguards,
+) -> Result<DeleteReport, Error>
+where
+    C: Api,
+{
+    if !safeguards.allow_prod && is_prod(client) {
+        return Err(Error::BulkDeleteInProduction);
+    }
+
+    let requests = match &filter.satellite_name {
+        Some(name) => client.get_requests_by_satellite_name(name),
+        None => client.get_requests(),
+    };
+    let requests: Vec<_> = requests.try_collect().await?;
+
+    let mut matched = requests
+        .iter()
+        .filter(|request| (filter.predicate)(request))
+        .map(|request| request.get_id())
+        .collect::<Result<Vec<_>, _>>()?;
+    matched.sort_unstable();
+    matched.dedup();
+
+    if matched.len() > safeguards.max_count {
+        return Err(Error::BulkDeleteRefused {
+            matched: matched.len(),
+            max: safeguards.max_count,
+        });
+    }
+
+    if safeguards.dry_run {
+        tracing::info!(ids = ?matched, "Dry run, not deleting task requests");
+        return Ok(DeleteReport {
+            matched,
+            outcomes: Vec::new(),
+        });
+    }
+
+    tracing::info!(ids = ?matched, "Deleting task requests");
+
+    let mut outcomes: Vec<_> = futures_util::stream::iter(matched.iter().copied())
+        .map(|id| async move {
+            let outcome = client.delete_task_request(id).await.map(|_| ());
+
+            (id, outcome)
+        })
+        .buffer_unordered(safeguards.concurrency)
+        .collect()
+        .await;
+    outcomes.sort_unstable_by_key(|(id, _)| *id);
+
+    Ok(DeleteReport { matched, outcomes })
+}
+
+/// Whether the client targets production, including through another entrypoint which kept the FPS
+/// host of production
+fn is_prod<C: Api>(client: &C) -> bool {
+    let environment = client.config().environment();
+
+    environment.freedom_entrypoint() == Prod.freedom_entrypoint()
+        || environment.fps_host() == Prod.fps_host()
+}
@@ -0,0 +1,255 @@
+use std::{io::ErrorKind, ops::Range, path::Path};
+
+use async_stream::stream;
+use futures_util::StreamExt;
+use reqwest::{
+    header::{HeaderMap, HeaderValue, CONTENT_RANGE, RANGE},
+    Response, StatusCode,
+};
+use tokio::{
+    fs::{File, OpenOptions},
+    io::AsyncWriteExt,
+};
+use url::Url;
+
+use crate::{
+    api::{error_on_non_success_response, Api, ByteStream},
+    error::Error,
+    ids::TaskId,
+};
+
+/// Request the file of the task, failing before any of the body is read when the response is
+/// unsuccessful
+pub(crate) async fn file_streamed<C>(
+    client: &C,
+    task_id: TaskId,
+    file_name: &str,
+) -> Result<ByteStream, Error>
+where
+    C: Api,
+{
+    let resp = request_file(client, task_id, file_name, HeaderMap::new()).await?;
+    let resp = error_on_non_success_response(resp).await?;
+
+    Ok(chunks(resp))
+}
+
+/// Request the bytes of the file of the task within the range
+///
+/// Servers which ignore the `Range` header respond with the whole file, in which case the bytes
+/// outside of the range are discarded as they arrive.
+pub(crate) async fn file_range<C>(
+    client: &C,
+    task_id: TaskId,
+    file_name: &str,
+    range: Range<u64>,
+) -> Result<ByteStream, Error>
+where
+    C: Api,
+{
+    if range.is_empty() {
+        return Err(Error::Validation(vec![format!(
+            "the range {range:?} of {file_name} is empty"
+        )]));
+    }
+
+    let resp = request_file(
+        client,
+        task_id,
+        file_name,
+        range_header(range.start, Some(range.end)),
+    )
+    .await?;
+    let resp = error_on_non_success_response(resp).await?;
+    if resp.status() == StatusCode::PARTIAL_CONTENT {
+        return Ok(chunks(resp));
+    }
+
+    tracing::debug!(file_name, ?range, "Server ignored the range of the request");
+    let mut body = chunks(resp);
+    Ok(Box::pin(stream! {
+        let mut offset = 0;
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk?;
+            let chunk_start = offset;
+            offset += chunk.len() as u64;
+
+            let len = chunk.len();
+            let from = (range.start.saturating_sub(chunk_start) as usize).min(len);
+            let to = (range.end.saturating_sub(chunk_start) as usize).min(len);
+            if from < to {
+                yield Ok(chunk.slice(from..to));
+            }
+            if offset >= range.end {
+                break;
+            }
+        }
+    }))
+}
+
+/// Write each chunk of the file to the path as it arrives, returning the number of bytes written
+///
+/// The file is only created once the server has responded successfully.
+pub(crate) async fn download_to<C>(
+    client: &C,
+    task_id: TaskId,
+    file_name: &str,
+    path: &Path,
+) -> Result<u64, Error>
+where
+    C: Api,
+{
+    let mut chunks = file_streamed(client, task_id, file_name).await?;
+    let mut file = File::create(path).await?;
+
+    write_chunks(&mut chunks, &mut file).await
+}
+
+/// Continue the download of the file from the end of the partial file at the path, returning the
+/// size of the complete file
+pub(crate) async fn download_resumable<C>(
+    client: &C,
+    task_id: TaskId,
+    file_name: &str,
+    path: &Path,
+) -> Result<u64, Error>
+where
+    C: Api,
+{
+    let existing = match tokio::fs::metadata(path).await {
+        Ok(metadata) => metadata.len(),
+        Err(error) if error.kind() == ErrorKind::NotFound => 0,
+        Err(error) => return Err(error.into()),
+    };
+    if existing == 0 {
+        return download_to(client, task_id, file_name, path).await;
+    }
+
+    let resp = request_file(client, task_id, file_name, range_header(existing, None)).await?;
+    match resp.status() {
+        StatusCode::PARTIAL_CONTENT if range_start(&resp) == Some(existing) => {
+            let mut file = OpenOptions::new().append(true).open(path).await?;
+            let written = write_chunks(&mut chunks(resp), &mut file).await?;
+
+            Ok(existing + written)
+        }
+        StatusCode::PARTIAL_CONTENT => {
+            tracing::warn!(
+                file_name,
+                existing,
+                content_range = ?resp.headers().get(CONTENT_RANGE),
+                "Server sent another range than requested, restarting download"
+            );
+            download_to(client, task_id, file_name, path).await
+        }
+        // The partial file already holds every byte, unless the file has since changed size
+        StatusCode::RANGE_NOT_SATISFIABLE
+            if complete_length(&resp).map_or(true, |length| length == existing) =>
+        {
+            Ok(existing)
+        }
+        StatusCode::RANGE_NOT_SATISFIABLE => {
+            tracing::warn!(
+                file_name,
+                existing,
+                "Partial file does not match, restarting download"
+            );
+            download_to(client, task_id, file_name, path).await
+        }
+        _ => {
+            // The server ignored the range, and is sending the file from the start
+            let resp = error_on_non_success_response(resp).await?;
+            let mut file = File::create(path).await?;
+
+            write_chunks(&mut chunks(resp), &mut file).await
+        }
+    }
+}
+
+async fn request_file<C>(
+    client: &C,
+    task_id: TaskId,
+    file_name: &str,
+    headers: HeaderMap,
+) -> Result<Response, Error>
+where
+    C: Api,
+{
+    let url = file_url(client, task_id, file_name)?;
+
+    client.get_response(url, headers).await
+}
+
+/// The URL of the file of the task, where the name is a single, percent-encoded segment
+pub(crate) fn file_url<C>(client: &C, task_id: TaskId, file_name: &str) -> Result<Url, Error>
+where
+    C: Api + ?Sized,
+{
+    let mut url = client.path_to_url(format!("downloads/{task_id}"))?;
+    url.path_segments_mut()
+        .map_err(|()| Error::UrlConstruction(String::from("the entrypoint cannot have a path")))?
+        .push(file_name);
+
+    Ok(url)
+}
+
+/// The `Range` header for the bytes from the start, up to but excluding the end if provided
+fn range_header(start: u64, end: Option<u64>) -> HeaderMap {
+    let range = match end {
+        Some(end) => format!("bytes={start}-{}", end - 1),
+        None => format!("bytes={start}-"),
+    };
+
+    let mut headers = HeaderMap::new();
+    // The value is only ever ASCII digits and punctuation
+    if let Ok(range) = HeaderValue::from_str(&range) {
+        headers.insert(RANGE, range);
+    }
+
+    headers
+}
+
+/// The first byte of a 206 response, as reported by its `Content-Range`
+fn range_start(resp: &Response) -> Option<u64> {
+    let range = resp.headers().get(CONTENT_RANGE)?.to_str().ok()?;
+    let (start, _) = range.strip_prefix("bytes ")?.split_once('-')?;
+
+    start.trim().parse().ok()
+}
+
+/// The complete length of the file, as reported by the `Content-Range` of a 416 response
+fn complete_length(resp: &Response) -> Option<u64> {
+    let range = resp.headers().get(CONTENT_RANGE)?.to_str().ok()?;
+
+    range.strip_prefix("bytes */")?.parse().ok()
+}
+
+fn chunks(resp: Response) -> ByteStream {
+    Box::pin(resp.bytes_stream().map(|chunk| chunk.map_err(Error::from)))
+}
+
+async fn write_chunks(chunks: &mut ByteStream, file: &mut File) -> Result<u64, Error> {
+    let mut written = 0;
+    while let Some(chunk) = chunks.next().await {
+        let chunk = chunk?;
+        file.write_all(&chunk).await?;
+        written += chunk.len() as u64;
+    }
+    file.flush().await?;
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_headers() {
+        let headers = range_header(100, Some(200));
+        assert_eq!(headers[RANGE], "bytes=100-199");
+
+        let headers = range_header(100, None);
+        assert_eq!(headers[RANGE], "bytes=100-");
+    }
+}
@@ -0,0 +1,158 @@
+//! The data-plane (FPS) ports of site configurations
+use std::collections::HashMap;
+
+use freedom_models::site::{Site, SiteConfiguration};
+use serde::Deserialize;
+use url::Url;
+
+use crate::{
+    api::{pagination, Api},
+    error::Error,
+    extensions::{SiteConfigurationExt, SiteExt},
+    ids::{SiteConfigurationId, SiteId},
+};
+
+/// A configuration as listed by its site, of which only the link to itself is read
+#[derive(Debug, Clone, Deserialize)]
+struct ListedConfiguration {
+    #[serde(rename = "_links")]
+    links: HashMap<String, Link>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Link {
+    href: Url,
+}
+
+impl ListedConfiguration {
+    fn id(&self) -> Option<SiteConfigurationId> {
+        self.links
+            .get("self")?
+            .href
+            .path_segments()?
+            .next_back()?
+            .parse()
+            .ok()
+    }
+}
+
+/// The data-plane port of the configuration at the zero-based `ordinal` of a site, whose first
+/// configuration is served on `base_fps_port`
+///
+/// Fails with [`Error::Validation`] when the base port is not a valid port, or when the port of
+/// the configuration would exceed the largest port.
+pub fn fps_port<P>(base_fps_port: P, ordinal: usize) -> Result<u16, Error>
+where
+    P: TryInto<u16>,
+{
+    let base = base_fps_port
+        .try_into()
+        .ok()
+        .filter(|base| *base != 0)
+        .ok_or_else(|| Error::Validation(vec![String::from("the base FPS port is not a port")]))?;
+
+    u16::try_from(ordinal)
+        .ok()
+        .and_then(|ordinal| base.checked_add(ordinal))
+        .ok_or_else(|| {
+            Error::Validation(vec![format!(
+                "the FPS port of configuration {ordinal} exceeds the largest port, starting from \
+                 {base}"
+            )])
+        })
+}
+
+/// The zero-based position of the configuration among the configurations of the site, in the
+/// order they are listed by the server
+async fn ordinal<C>(
+    client: &C,
+    site: &Site,
+    configuration_id: SiteConfigurationId,
+) -> Result<usize, Error>
+where
+    C: Api + ?Sized,
+{
+    let url = site
+        .links
+        .get("configurations")
+        .ok_or(Error::MissingUri("configurations"))?
+        .clone();
+    let configurations =
+        pagination::collect_embedded::<C, ListedConfiguration>(client, url).await?;
+
+    configurations
+        .iter()
+        .position(|configuration| configuration.id() == Some(configuration_id))
+        .ok_or_else(|| {
+            let site = site
+                .get_id()
+                .map_or_else(|_| site.name.clone(), |id| id.to_string());
+            Error::Validation(vec![format!(
+                "site configuration {configuration_id} does not belong to site {site}"
+            )])
+        })
+}
+
+pub(crate) async fn fps_endpoint_for<C>(
+    client: &C,
+    site: &Site,
+    configuration: &SiteConfiguration,
+) -> Result<(String, u16), Error>
+where
+    C: Api + ?Sized,
+{
+    let ordinal = ordinal(client, site, configuration.get_id()?).await?;
+    let port = fps_port(site.base_fps_port, ordinal)?;
+    let host = client.config().environment().fps_host().to_owned();
+
+    Ok((host, port))
+}
+
+pub(crate) async fn fps_endpoint_by_ids<C>(
+    client: &C,
+    site_id: SiteId,
+    configuration_id: SiteConfigurationId,
+) -> Result<(String, u16), Error>
+where
+    C: Api + ?Sized,
+{
+    let url = client.path_to_url(format!("sites/{site_id}"))?;
+    let site: Site = client.get_json_map(url).await?;
+    let ordinal = ordinal(client, &site, configuration_id).await?;
+    let port = fps_port(site.base_fps_port, ordinal)?;
+    let host = client.config().environment().fps_host().to_owned();
+
+    Ok((host, port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_configuration_uses_base_port() {
+        assert_eq!(fps_port(20100, 0).unwrap(), 20100);
+        assert_eq!(fps_port(20100, 3).unwrap(), 20103);
+    }
+
+    #[test]
+    fn largest_port_is_allowed() {
+        assert_eq!(fps_port(65530, 5).unwrap(), u16::MAX);
+    }
+
+    #[test]
+    fn overflowing_port_is_rejected() {
+        assert!(matches!(fps_port(65530, 6), Err(Error::Validation(_))));
+        assert!(matches!(
+            fps_port(20100, usize::MAX),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_base_port_is_rejected() {
+        assert!(matches!(fps_port(0, 0), Err(Error::Validation(_))));
+        assert!(matches!(fps_port(-1, 0), Err(Error::Validation(_))));
+        assert!(matches!(fps_port(70_000, 0), Err(Error::Validation(_))));
+    }
+}
@@ -0,0 +1,164 @@
+use serde_json::Value as JsonValue;
+use time::OffsetDateTime;
+
+use crate::{api::Api, error::Error, utils::parse_time};
+
+/// The version and capabilities reported by the Freedom server
+///
+/// Every field is optional, since servers report as much or as little as they are configured to.
+/// Servers which do not report any information at all are described by [`ServerInfo::unknown`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServerInfo {
+    /// The version of the server, such as `"3.12.1"`
+    pub version: Option<String>,
+    /// When the running build of the server was produced
+    pub build_time: Option<OffsetDateTime>,
+    /// The names of the optional modules enabled on the server
+    pub modules: Vec<String>,
+    known: bool,
+}
+
+impl ServerInfo {
+    /// Information for a server which does not report any
+    pub fn unknown() -> Self {
+        Self::default()
+    }
+
+    /// Whether the server reported any information
+    pub fn is_known(&self) -> bool {
+        self.known
+    }
+
+    /// Whether the module is enabled on the server, or `None` when the server did not report its
+    /// modules
+    pub fn has_module(&self, module: &str) -> Option<bool> {
+        match self.known && !self.modules.is_empty() {
+            true => Some(self.modules.iter().any(|name| name == module)),
+            false => None,
+        }
+    }
+
+    /// Whether the version of the server is at least the provided `(major, minor, patch)`
+    /// version, or `None` when the version is unknown or not numeric
+    ///
+    /// Missing components of the reported version are treated as zero, and any pre-release or
+    /// build suffix is ignored.
+    pub fn version_at_least(&self, minimum: (u64, u64, u64)) -> Option<bool> {
+        let version = self.version.as_deref()?;
+        let core = version.trim_start_matches('v').split(['-', '+']).next()?;
+        let mut parts = core.split('.').map(str::parse::<u64>);
+        let mut next = || parts.next().unwrap_or(Ok(0)).ok();
+        let version = (next()?, next()?, next()?);
+
+        Some(version >= minimum)
+    }
+
+    /// Read the information from the payload of an actuator style info endpoint
+    ///
+    /// The version and build time are read from the `build` or `app` objects, or the top level,
+    /// while modules are read from a list of names, or an object of flags.
+    fn from_payload(payload: &JsonValue) -> Self {
+        let field = |key: &str| {
+            ["/build", "/app", ""]
+                .into_iter()
+                .find_map(|parent| payload.pointer(&format!("{parent}/{key}")))
+        };
+        let version = field("version").and_then(|version| match version {
+            JsonValue::String(version) => Some(version.clone()),
+            JsonValue::Number(version) => Some(version.to_string()),
+            _ => None,
+        });
+        let build_time = field("time")
+            .or_else(|| field("timestamp"))
+            .and_then(JsonValue::as_str)
+            .and_then(parse_time);
+        let modules = match payload.get("modules").or_else(|| payload.get("features")) {
+            Some(JsonValue::Array(modules)) => modules
+                .iter()
+                .filter_map(JsonValue::as_str)
+                .map(str::to_owned)
+                .collect(),
+            Some(JsonValue::Object(modules)) => modules
+                .iter()
+                .filter(|(_, enabled)| enabled.as_bool().unwrap_or(false))
+                .map(|(name, _)| name.clone())
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Self {
+            version,
+            build_time,
+            modules,
+            known: true,
+        }
+    }
+}
+
+pub(crate) async fn server_info<C>(client: &C) -> Result<ServerInfo, Error>
+where
+    C: Api + ?Sized,
+{
+    match client.server_info_memo() {
+        Some(memo) => memo.get_or_try_init(|| fetch(client)).await.cloned(),
+        None => fetch(client).await,
+    }
+}
+
+/// Servers without an info endpoint respond with a 404, which is reported as
+/// [`ServerInfo::unknown`] rather than an error
+async fn fetch<C>(client: &C) -> Result<ServerInfo, Error>
+where
+    C: Api + ?Sized,
+{
+    let url = client
+        .config()
+        .environment()
+        .freedom_entrypoint()
+        .join("/actuator/info")?;
+
+    match client.get_json_map::<JsonValue>(url).await {
+        Ok(payload) => Ok(ServerInfo::from_payload(&payload)),
+        Err(error) if error.status() == Some(reqwest::StatusCode::NOT_FOUND) => {
+            Ok(ServerInfo::unknown())
+        }
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn versions_compare_numerically() {
+        let info = |version: &str| ServerInfo {
+            version: Some(version.to_owned()),
+            known: true,
+            ..ServerInfo::default()
+        };
+
+        assert_eq!(info("3.12.1").version_at_least((3, 9, 0)), Some(true));
+        assert_eq!(info("v3.12").version_at_least((3, 12, 1)), Some(false));
+        assert_eq!(info("4.0.0-rc.1").version_at_least((4, 0, 0)), Some(true));
+        assert_eq!(info("nightly").version_at_least((1, 0, 0)), None);
+        assert_eq!(ServerInfo::unknown().version_at_least((1, 0, 0)), None);
+    }
+
+    #[test]
+    fn modules_read_from_flags() {
+        let info = ServerInfo::from_payload(&json!({
+            "app": { "version": "3.12.1", "timestamp": "2024-10-01T12:00:00Z" },
+            "features": { "bundles": true, "visibilities": false }
+        }));
+
+        assert_eq!(info.version.as_deref(), Some("3.12.1"));
+        assert_eq!(info.build_time, Some(datetime!(2024-10-01 12:00 UTC)));
+        assert_eq!(info.has_module("bundles"), Some(true));
+        assert_eq!(info.has_module("visibilities"), Some(false));
+        assert_eq!(ServerInfo::unknown().has_module("bundles"), None);
+    }
+}
@@ -0,0 +1,112 @@
+//! Orbital elements of satellites, and checks of two-line element sets
+use freedom_models::satellite::TwoLineElement;
+use reqwest::Response;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{error_on_non_success_response, Api},
+    error::Error,
+    ids::SatelliteId,
+};
+
+/// The number of characters in each line of a two-line element set
+pub const TLE_LINE_LENGTH: usize = 69;
+
+/// The orbit of a satellite, as tracked by Freedom
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrbitInfo {
+    /// The most recent two-line element set of the satellite, if one is known
+    #[serde(default)]
+    pub tle: Option<TwoLineElement>,
+}
+
+#[derive(Debug, Serialize)]
+struct TleUpdate<'a> {
+    tle: TleLines<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct TleLines<'a> {
+    line1: &'a str,
+    line2: &'a str,
+}
+
+/// Check that the lines have the shape of a two-line element set: each line is
+/// [`TLE_LINE_LENGTH`] characters long, and starts with its line number followed by a space
+///
+/// Fails with [`Error::MalformedTle`], listing every violation.
+pub fn check_tle(line1: &str, line2: &str) -> Result<(), Error> {
+    let violations: Vec<_> = [("1", line1), ("2", line2)]
+        .into_iter()
+        .flat_map(|(number, line)| {
+            let length = line.chars().count();
+            let length = (length != TLE_LINE_LENGTH).then(|| {
+                format!("line {number} has {length} characters rather than {TLE_LINE_LENGTH}")
+            });
+            let prefix = (!line.starts_with(&format!("{number} ")))
+                .then(|| format!("line {number} does not start with \"{number} \""));
+
+            length.into_iter().chain(prefix)
+        })
+        .collect();
+
+    match violations.is_empty() {
+        true => Ok(()),
+        false => Err(Error::MalformedTle(violations)),
+    }
+}
+
+pub(crate) async fn set_satellite_tle<C>(
+    client: &C,
+    satellite_id: SatelliteId,
+    line1: &str,
+    line2: &str,
+) -> Result<Response, Error>
+where
+    C: Api + ?Sized,
+{
+    check_tle(line1, line2)?;
+
+    let url = client.path_to_url(format!("satellites/{satellite_id}"))?;
+    let update = TleUpdate {
+        tle: TleLines { line1, line2 },
+    };
+    let resp = client.patch(url, update).await?;
+
+    error_on_non_success_response(resp).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINE1: &str = "1 25544U 98067A   24290.51782528  .00016717  00000-0  10270-3 0  9005";
+    const LINE2: &str = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.50377579 77070";
+
+    #[test]
+    fn well_formed_tle_is_accepted() {
+        assert!(check_tle(LINE1, LINE2).is_ok());
+    }
+
+    #[test]
+    fn short_line_is_rejected() {
+        let error = check_tle(&LINE1[..68], LINE2).unwrap_err();
+
+        assert_eq!(
+            error,
+            Error::MalformedTle(vec![String::from(
+                "line 1 has 68 characters rather than 69"
+            )])
+        );
+    }
+
+    #[test]
+    fn swapped_lines_are_rejected() {
+        let Err(Error::MalformedTle(violations)) = check_tle(LINE2, LINE1) else {
+            panic!("expected a malformed TLE");
+        };
+
+        assert_eq!(violations.len(), 2);
+    }
+}
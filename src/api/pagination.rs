@@ -0,0 +1,394 @@
+use serde::Deserialize;
+use serde_json::{Map, Value as JsonValue};
+use url::Url;
+
+use crate::{
+    api::{Api, Value},
+    error::Error,
+};
+
+/// The maximum number of pages followed by [`collect_embedded`]
+pub(crate) const MAX_EMBEDDED_PAGES: usize = 100;
+
+/// The direction in which a paginated query is sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "asc",
+            SortOrder::Descending => "desc",
+        }
+    }
+}
+
+/// What a paginated stream does when a page cannot be fetched
+///
+/// Item-level failures, such as an item which cannot be deserialized, are always yielded as an
+/// error for that item, and the stream continues with the next item.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PaginationBehavior {
+    /// Yield the error of the failed request, then end the stream
+    #[default]
+    Stop,
+    /// Retry the page up to `attempts` times in total, waiting between attempts according to the
+    /// client's [`backoff`](Api::backoff) strategy. Should every attempt fail, an
+    /// [`Error::PageFetch`] is yielded, then the stream ends, since the link to the following page
+    /// is unknown.
+    ///
+    /// Only [retryable](Error::is_retryable) failures are retried, and only when the client does
+    /// not already [retry requests](Api::retries_requests) itself.
+    RetryPage { attempts: u32 },
+}
+
+/// Page size, starting page, and sort order for paginated queries
+///
+/// Any option which is not set is left to the server's default.
+///
+/// # Example
+///
+/// ```
+/// # use freedom_api::prelude::*;
+/// let options = PageOptions::new()
+///     .size(500)
+///     .sort("created", SortOrder::Descending);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageOptions {
+    size: Option<u32>,
+    page: Option<u32>,
+    sort: Vec<(String, SortOrder)>,
+    behavior: PaginationBehavior,
+}
+
+impl PageOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of items requested per page
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// The zero-indexed page at which to start
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sort by the provided field. May be called multiple times, with earlier fields taking
+    /// precedence.
+    pub fn sort(mut self, field: impl Into<String>, order: SortOrder) -> Self {
+        self.sort.push((field.into(), order));
+        self
+    }
+
+    /// How the stream handles a page which cannot be fetched, see [`PaginationBehavior`]
+    pub fn on_page_error(mut self, behavior: PaginationBehavior) -> Self {
+        self.behavior = behavior;
+        self
+    }
+
+    pub(crate) fn behavior(&self) -> PaginationBehavior {
+        self.behavior
+    }
+
+    /// Append every option to the URL of the first page
+    pub(crate) fn apply(&self, url: &mut Url) {
+        if let Some(page) = self.page {
+            url.query_pairs_mut().append_pair("page", &page.to_string());
+        }
+
+        self.fill_missing(url);
+    }
+
+    /// Append the size and sort options to the URL of a subsequent page, when the server omitted
+    /// them from the link.
+    ///
+    /// The page is never appended, since the link always points to the next page.
+    pub(crate) fn fill_missing(&self, url: &mut Url) {
+        if let Some(size) = self.size {
+            if !has_key(url, "size") {
+                url.query_pairs_mut().append_pair("size", &size.to_string());
+            }
+        }
+
+        if !self.sort.is_empty() && !has_key(url, "sort") {
+            let mut pairs = url.query_pairs_mut();
+            for (field, order) in &self.sort {
+                pairs.append_pair("sort", &format!("{field},{}", order.as_str()));
+            }
+        }
+    }
+}
+
+/// The size and totals of a paginated collection, as reported alongside each page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageMeta {
+    /// The number of items requested per page
+    pub size: u64,
+    /// The number of items in the entire collection
+    pub total_elements: u64,
+    /// The number of pages in the entire collection, at the current page size
+    pub total_pages: u64,
+    /// The zero-indexed number of the current page
+    pub number: u64,
+}
+
+/// The envelope of a paginated endpoint, ignoring its items
+#[derive(Debug, Clone, Deserialize)]
+struct PageEnvelope {
+    page: Option<PageMeta>,
+}
+
+/// Fetch the page metadata of the URL
+pub(crate) async fn page_meta<C>(client: &C, url: Url) -> Result<PageMeta, Error>
+where
+    C: Api + ?Sized,
+{
+    let envelope = client.get_json_map::<PageEnvelope>(url.clone()).await?;
+
+    envelope
+        .page
+        .ok_or_else(|| Error::Deserialization(format!("{url} did not report page metadata")))
+}
+
+/// Count the items of the collection at the path, fetching only a single item
+pub(crate) async fn count<C>(client: &C, path: &str) -> Result<u64, Error>
+where
+    C: Api + ?Sized,
+{
+    let mut url = client.path_to_url(path)?;
+    PageOptions::new().size(1).page(0).apply(&mut url);
+
+    Ok(page_meta(client, url).await?.total_elements)
+}
+
+/// A page of an endpoint which embeds its items, along with the links of the envelope
+///
+/// The links are left as JSON, since the server may link the following page with a relative URL.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct EmbeddedPage {
+    #[serde(rename = "_embedded", default)]
+    embedded: Map<String, JsonValue>,
+    #[serde(rename = "_links", default)]
+    links: Map<String, JsonValue>,
+}
+
+impl EmbeddedPage {
+    pub(crate) fn next(&self, base: &Url) -> Result<Option<Url>, Error> {
+        self.links
+            .get("next")
+            .and_then(|link| link.get("href").or(Some(link)))
+            .and_then(JsonValue::as_str)
+            .map(|href| join_link(base, href))
+            .transpose()
+    }
+
+    pub(crate) fn into_items(self) -> impl Iterator<Item = JsonValue> {
+        self.embedded
+            .into_iter()
+            .flat_map(|(_, items)| match items {
+                JsonValue::Array(items) => items,
+                _ => Vec::new(),
+            })
+    }
+}
+
+/// Resolve a link to another page against the entrypoint
+///
+/// Absolute links are used as-is. A link which is an absolute path is resolved beneath the
+/// entrypoint, whether or not it repeats the path of the entrypoint, such as `/api/requests` and
+/// `/requests` for the entrypoint `https://api.example.com/api/`. Any other link is joined relative
+/// to the entrypoint.
+pub(crate) fn join_link(entrypoint: &Url, href: &str) -> Result<Url, Error> {
+    if let Ok(url) = Url::parse(href) {
+        return Ok(url);
+    }
+
+    let relative = match href.strip_prefix(entrypoint.path()) {
+        Some(relative) => relative,
+        None => href.trim_start_matches('/'),
+    };
+
+    entrypoint
+        .join(relative)
+        .map_err(|error| Error::pag_item(error.to_string()))
+}
+
+/// Collect the embedded items of every page, following the `next` link of each envelope
+///
+/// Endpoints which return a single list are still paginated by the server once the list exceeds
+/// its default page size, so only reading the first page silently truncates the list. At most
+/// [`MAX_EMBEDDED_PAGES`] pages are followed, failing with [`Error::TooManyPages`] rather than
+/// returning a truncated list when there are more.
+pub(crate) async fn collect_embedded<C, T>(
+    client: &C,
+    url: Url,
+) -> Result<C::Container<Vec<T>>, Error>
+where
+    C: Api + ?Sized,
+    T: Value,
+{
+    let items = embedded_items(client, url).await?;
+
+    crate::json::from_value(&JsonValue::Array(items))
+}
+
+/// Like [`collect_embedded`], however the items are left as JSON, so that the items of several
+/// queries can be combined before deserializing
+pub(crate) async fn embedded_items<C>(client: &C, mut url: Url) -> Result<Vec<JsonValue>, Error>
+where
+    C: Api + ?Sized,
+{
+    let base = client.config().environment().freedom_entrypoint();
+    let mut items = Vec::new();
+    for page in 1.. {
+        let envelope = client.get_json_map::<EmbeddedPage>(url).await?;
+        let next = envelope.next(&base)?;
+        items.extend(envelope.into_items());
+
+        match next {
+            Some(next) if page < MAX_EMBEDDED_PAGES => url = next,
+            Some(next) => {
+                return Err(Error::TooManyPages {
+                    url: crate::telemetry::redacted(&next).to_string(),
+                    pages: page,
+                })
+            }
+            None => break,
+        }
+    }
+
+    Ok(items)
+}
+
+fn has_key(url: &Url, key: &str) -> bool {
+    url.query_pairs().any(|(k, _)| k == key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url() -> Url {
+        Url::parse("http://localhost:8080/api/requests/search/findAll").unwrap()
+    }
+
+    #[test]
+    fn default_options_leave_url_untouched() {
+        let mut url = url();
+        PageOptions::default().apply(&mut url);
+
+        assert_eq!(url.query(), None);
+    }
+
+    #[test]
+    fn apply_all_options() {
+        let mut url = url();
+        PageOptions::new()
+            .size(500)
+            .page(2)
+            .sort("created", SortOrder::Descending)
+            .sort("name", SortOrder::Ascending)
+            .apply(&mut url);
+
+        assert_eq!(
+            url.query(),
+            Some("page=2&size=500&sort=created%2Cdesc&sort=name%2Casc")
+        );
+    }
+
+    #[test]
+    fn embedded_page_next_link_is_joined() {
+        let base = Url::parse("http://localhost:8080/api/").unwrap();
+        let page: EmbeddedPage = serde_json::from_value(serde_json::json!({
+            "_embedded": { "requests": [{ "id": 1 }, { "id": 2 }] },
+            "_links": { "next": { "href": "requests/search/findAllPassedToday?page=1" } }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            page.next(&base).unwrap().unwrap().as_str(),
+            "http://localhost:8080/api/requests/search/findAllPassedToday?page=1"
+        );
+        assert_eq!(page.into_items().count(), 2);
+
+        let page: EmbeddedPage = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert_eq!(page.next(&base).unwrap(), None);
+        assert_eq!(page.into_items().count(), 0);
+    }
+
+    #[test]
+    fn absolute_link_is_used_as_is() {
+        let base = Url::parse("http://localhost:8080/api/").unwrap();
+
+        assert_eq!(
+            join_link(&base, "https://other.example.com/api/requests?page=1")
+                .unwrap()
+                .as_str(),
+            "https://other.example.com/api/requests?page=1"
+        );
+    }
+
+    #[test]
+    fn absolute_path_link_is_joined_beneath_entrypoint() {
+        let base = Url::parse("http://localhost:8080/api/").unwrap();
+
+        for href in ["/api/requests?page=1", "/requests?page=1"] {
+            assert_eq!(
+                join_link(&base, href).unwrap().as_str(),
+                "http://localhost:8080/api/requests?page=1",
+                "{href}"
+            );
+        }
+    }
+
+    #[test]
+    fn relative_link_is_joined_to_entrypoint() {
+        let base = Url::parse("http://localhost:8080/api/").unwrap();
+
+        assert_eq!(
+            join_link(&base, "requests/search/findAll?page=1")
+                .unwrap()
+                .as_str(),
+            "http://localhost:8080/api/requests/search/findAll?page=1"
+        );
+    }
+
+    #[test]
+    fn page_meta_from_paginated_fixture() {
+        let fixture = std::fs::read_to_string("resources/requests_find_all_page_1.json").unwrap();
+        let envelope: PageEnvelope = serde_json::from_str(&fixture).unwrap();
+
+        assert_eq!(
+            envelope.page,
+            Some(PageMeta {
+                size: 500,
+                total_elements: 3,
+                total_pages: 2,
+                number: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn fill_missing_keeps_existing_params() {
+        let mut url = url();
+        url.set_query(Some("page=1&size=20"));
+        PageOptions::new()
+            .size(500)
+            .page(0)
+            .sort("created", SortOrder::Descending)
+            .fill_missing(&mut url);
+
+        assert_eq!(url.query(), Some("page=1&size=20&sort=created%2Cdesc"));
+    }
+}
@@ -0,0 +1,74 @@
+use freedom_models::band::{Band, BandType};
+
+use crate::{
+    api::{pagination, Api},
+    error::Error,
+    extensions::BandExt,
+    ids::SatelliteConfigurationId,
+};
+
+/// The receive band and optional transmit band used by a pass
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandPair {
+    pub rx: Band,
+    pub tx: Option<Band>,
+}
+
+impl BandPair {
+    /// Choose a pair from the bands of a satellite configuration
+    ///
+    /// The configuration must have exactly one receive band, since it determines the data
+    /// collected by the pass, so multiple receive bands fail with [`Error::AmbiguousBands`] rather
+    /// than guessing. When the configuration has transmit bands, the transmit band with the
+    /// lowest frequency is paired with it.
+    pub fn select(bands: impl IntoIterator<Item = Band>) -> Result<Self, Error> {
+        let mut bands: Vec<_> = bands.into_iter().collect();
+        bands.sort_by(|a, b| a.frequency_mghz.total_cmp(&b.frequency_mghz));
+
+        let (mut rx, tx): (Vec<_>, Vec<_>) = bands
+            .into_iter()
+            .filter(|band| band.typ.is_some())
+            .partition(|band| matches!(band.typ, Some(BandType::Receive)));
+
+        match rx.len() {
+            0 => Err(Error::Validation(vec![String::from(
+                "the configuration has no receive band",
+            )])),
+            1 => Ok(Self {
+                rx: rx.remove(0),
+                tx: tx.into_iter().next(),
+            }),
+            _ => Err(Error::AmbiguousBands(
+                rx.iter().filter_map(|band| band.get_id().ok()).collect(),
+            )),
+        }
+    }
+
+    /// The URLs of the bands, as targeted by a task request
+    pub(crate) fn urls(&self) -> Vec<String> {
+        std::iter::once(&self.rx)
+            .chain(&self.tx)
+            .map(|band| {
+                band.links
+                    .get("self")
+                    .map(ToString::to_string)
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+}
+
+pub(crate) async fn suggest_band_pair<C>(
+    client: &C,
+    satellite_configuration_id: SatelliteConfigurationId,
+) -> Result<BandPair, Error>
+where
+    C: Api + ?Sized,
+{
+    let url = client.path_to_url(format!(
+        "satellite_configurations/{satellite_configuration_id}/bandDetails"
+    ))?;
+    let bands = pagination::collect_embedded::<C, Band>(client, url).await?;
+
+    BandPair::select(bands.iter().cloned())
+}
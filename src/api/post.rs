@@ -1,11 +1,21 @@
+pub mod account;
 pub mod band;
 pub mod overrides;
 pub mod request;
 pub mod sat_config;
 pub mod satellite;
+pub mod site;
+pub mod site_config;
 pub mod user;
 
 pub use self::{
-    band::BandDetailsBuilder, overrides::OverrideBuilder, request::TaskRequestBuilder,
-    sat_config::SatelliteConfigurationBuilder, satellite::SatelliteBuilder, user::UserBuilder,
+    account::AccountBuilder,
+    band::{BandDetailsBuilder, BandRule, ValidationRules},
+    overrides::{ExistingOverride, OverrideBuilder},
+    request::{CreatedTaskRequest, TaskRequestBuilder},
+    sat_config::SatelliteConfigurationBuilder,
+    satellite::SatelliteBuilder,
+    site::SiteBuilder,
+    site_config::SiteConfigurationBuilder,
+    user::UserBuilder,
 };
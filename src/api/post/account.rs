@@ -0,0 +1,194 @@
+use std::net::IpAddr;
+
+use reqwest::{header::LOCATION, Response};
+use serde::Serialize;
+use url::Url;
+
+use crate::{api::Api, error::Error};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Account {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    storage_key: Option<String>,
+    access_realtime_cidr: Vec<CidrEntry>,
+    access_api_cidr: Vec<CidrEntry>,
+    post_process_done_by_account: bool,
+}
+
+/// A named range of addresses allowed to access the account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CidrEntry {
+    pub name: String,
+    pub cidr: String,
+}
+
+pub struct AccountBuilder<'a, C, S> {
+    client: &'a C,
+    state: S,
+}
+
+pub fn new<C>(client: &C) -> AccountBuilder<'_, C, NoName> {
+    AccountBuilder {
+        client,
+        state: NoName,
+    }
+}
+
+pub struct NoName;
+
+impl<'a, C> AccountBuilder<'a, C, NoName> {
+    pub fn name(self, name: impl Into<String>) -> AccountBuilder<'a, C, Account> {
+        let state = Account {
+            name: name.into(),
+            storage_key: None,
+            access_realtime_cidr: Vec::new(),
+            access_api_cidr: Vec::new(),
+            post_process_done_by_account: false,
+        };
+
+        AccountBuilder {
+            client: self.client,
+            state,
+        }
+    }
+}
+
+impl<'a, C> AccountBuilder<'a, C, Account> {
+    /// The key under which the account's data is stored. Left to the server when not set.
+    pub fn storage_key(mut self, storage_key: impl Into<String>) -> Self {
+        self.state.storage_key = Some(storage_key.into());
+
+        self
+    }
+
+    /// Allow the range of addresses, such as `"1.2.3.4/32"`, to access realtime data
+    pub fn add_realtime_cidr(mut self, name: impl Into<String>, cidr: impl Into<String>) -> Self {
+        self.state.access_realtime_cidr.push(CidrEntry {
+            name: name.into(),
+            cidr: cidr.into(),
+        });
+
+        self
+    }
+
+    /// Allow the range of addresses, such as `"1.2.3.4/32"`, to access the API
+    pub fn add_api_cidr(mut self, name: impl Into<String>, cidr: impl Into<String>) -> Self {
+        self.state.access_api_cidr.push(CidrEntry {
+            name: name.into(),
+            cidr: cidr.into(),
+        });
+
+        self
+    }
+
+    /// Whether the account performs the post-processing of its data itself
+    pub fn post_process_done_by_account(mut self, done_by_account: bool) -> Self {
+        self.state.post_process_done_by_account = done_by_account;
+
+        self
+    }
+}
+
+impl<'a, C> AccountBuilder<'a, C, Account>
+where
+    C: Api,
+{
+    /// Submit the request, producing the ID of the created account
+    ///
+    /// Any malformed CIDR entries are first rejected with an [`Error::Validation`], and an
+    /// unsuccessful response is converted into an [`Error::Api`].
+    pub async fn send(self) -> Result<i32, Error> {
+        let violations = self.state.cidr_violations();
+        if !violations.is_empty() {
+            return Err(Error::Validation(violations));
+        }
+
+        let client = self.client;
+
+        let url = client.path_to_url("accounts")?;
+        let resp = client.post_checked(url, self.state).await?;
+
+        created_id(resp).await
+    }
+
+    /// Submit the request, returning the response regardless of its status
+    pub async fn send_raw(self) -> Result<Response, Error> {
+        let client = self.client;
+
+        let url = client.path_to_url("accounts")?;
+        client.post(url, self.state).await
+    }
+}
+
+impl Account {
+    fn cidr_violations(&self) -> Vec<String> {
+        self.access_realtime_cidr
+            .iter()
+            .chain(&self.access_api_cidr)
+            .filter(|entry| !is_cidr(&entry.cidr))
+            .map(|entry| format!("{:?} of {:?} is not a CIDR", entry.cidr, entry.name))
+            .collect()
+    }
+}
+
+/// Whether the text is an address followed by a prefix length which fits the address
+fn is_cidr(text: &str) -> bool {
+    let Some((address, prefix)) = text.split_once('/') else {
+        return false;
+    };
+    let (Ok(address), Ok(prefix)) = (address.parse::<IpAddr>(), prefix.parse::<u8>()) else {
+        return false;
+    };
+
+    match address {
+        IpAddr::V4(_) => prefix <= 32,
+        IpAddr::V6(_) => prefix <= 128,
+    }
+}
+
+/// Read the ID of the created account from either the `Location` header, or the self link of the
+/// response body
+async fn created_id(resp: Response) -> Result<i32, Error> {
+    let header = resp
+        .headers()
+        .get(LOCATION)
+        .map(|location| {
+            let location = location
+                .to_str()
+                .map_err(|error| Error::InvalidUri(error.to_string()))?;
+            resp.url().join(location).map_err(Error::from)
+        })
+        .transpose()?;
+
+    let location = match header {
+        Some(location) => location,
+        None => {
+            let body: serde_json::Value = resp.json().await?;
+            body["_links"]["self"]["href"]
+                .as_str()
+                .ok_or(Error::MissingUri("Location"))?
+                .parse::<Url>()?
+        }
+    };
+
+    crate::utils::id_from_url(&location)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cidr_entries_are_validated() {
+        assert!(is_cidr("1.2.3.4/32"));
+        assert!(is_cidr("10.0.0.0/8"));
+        assert!(is_cidr("2001:db8::/32"));
+
+        assert!(!is_cidr("1.2.3.4"));
+        assert!(!is_cidr("1.2.3.4/33"));
+        assert!(!is_cidr("1.2.3/24"));
+        assert!(!is_cidr("2001:db8::/129"));
+    }
+}
@@ -5,7 +5,15 @@ use freedom_models::{
 use reqwest::Response;
 use serde::Serialize;
 
-use crate::{api::Api, error::Error};
+use crate::{
+    api::Api,
+    error::Error,
+    utils::{check_finite, parse_finite},
+};
+
+pub use self::validation::{BandRule, ValidationRules};
+
+mod validation;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -23,9 +31,21 @@ pub struct BandDetails {
     manual_transmit_control: bool,
 }
 
+impl BandDetails {
+    fn check_finite(&self) -> Result<(), Error> {
+        check_finite([
+            ("frequency", Some(self.frequency_mghz)),
+            ("bandwidth", Some(self.default_band_width_mghz)),
+            ("EIRP", self.eirp),
+            ("gain", self.gain),
+        ])
+    }
+}
+
 pub struct BandDetailsBuilder<'a, C, S> {
     pub(crate) client: &'a C,
     state: S,
+    rules: Option<ValidationRules>,
 }
 
 pub struct NoName;
@@ -34,6 +54,7 @@ pub fn new<C>(client: &C) -> BandDetailsBuilder<'_, C, NoName> {
     BandDetailsBuilder {
         client,
         state: NoName,
+        rules: None,
     }
 }
 
@@ -42,6 +63,7 @@ impl<'a, C> BandDetailsBuilder<'a, C, NoName> {
         BandDetailsBuilder {
             client: self.client,
             state: NoBandType { name: name.into() },
+            rules: self.rules,
         }
     }
 }
@@ -58,6 +80,7 @@ impl<'a, C> BandDetailsBuilder<'a, C, NoBandType> {
                 name: self.state.name,
                 band_type,
             },
+            rules: self.rules,
         }
     }
 }
@@ -76,8 +99,23 @@ impl<'a, C> BandDetailsBuilder<'a, C, NoFrequency> {
                 band_type: self.state.band_type,
                 frequency_mghz: frequency.into(),
             },
+            rules: self.rules,
         }
     }
+
+    /// Parse the frequency, in MHz, from a string such as `"8096.5"`
+    ///
+    /// Fails with [`Error::Validation`] when the string is not a finite number. Only a period is
+    /// accepted as the decimal separator, so locale-specific forms such as `"8.096,5"` are
+    /// rejected rather than misinterpreted.
+    pub fn try_frequency_str(
+        self,
+        frequency: &str,
+    ) -> Result<BandDetailsBuilder<'a, C, NoBandWidth>, Error> {
+        let frequency = parse_finite("frequency", frequency)?;
+
+        Ok(self.frequency(frequency))
+    }
 }
 
 pub struct NoBandWidth {
@@ -99,6 +137,7 @@ impl<'a, C> BandDetailsBuilder<'a, C, NoBandWidth> {
                 frequency_mghz: self.state.frequency_mghz,
                 default_band_width_mghz: bandwidth_mghz.into(),
             },
+            rules: self.rules,
         }
     }
 }
@@ -133,6 +172,7 @@ impl<'a, C> BandDetailsBuilder<'a, C, NoIoConfig> {
         BandDetailsBuilder {
             client: self.client,
             state,
+            rules: self.rules,
         }
     }
 }
@@ -162,16 +202,50 @@ impl<'a, C> BandDetailsBuilder<'a, C, BandDetails> {
         self.state.manual_transmit_control = control;
         self
     }
+
+    /// Check the band against the provided rules when it is sent, failing with
+    /// [`Error::Validation`] listing every violation rather than creating the band.
+    pub fn validate(mut self, rules: ValidationRules) -> Self {
+        self.rules = Some(rules);
+        self
+    }
 }
 
 impl<'a, C> BandDetailsBuilder<'a, C, BandDetails>
 where
     C: Api,
 {
+    /// Submit the request, converting an unsuccessful response into an [`Error::Api`]
+    ///
+    /// When rules were provided with [`Self::validate`], the band is first checked against them.
+    /// Bands with NaN or infinite numbers are always rejected with [`Error::Validation`].
     pub async fn send(self) -> Result<Response, Error> {
+        if let Some(rules) = &self.rules {
+            let violations = rules.violations(&self.state);
+            if !violations.is_empty() {
+                return Err(Error::Validation(violations));
+            }
+        }
+
+        self.send_unchecked().await
+    }
+
+    /// Submit the request without validating the band, converting an unsuccessful response into
+    /// an [`Error::Api`]
+    pub async fn send_unchecked(self) -> Result<Response, Error> {
+        self.state.check_finite()?;
+        let client = self.client;
+
+        let url = client.path_to_url("satellite_bands")?;
+        client.post_checked(url, self.state).await
+    }
+
+    /// Submit the request, returning the response regardless of its status
+    pub async fn send_raw(self) -> Result<Response, Error> {
+        self.state.check_finite()?;
         let client = self.client;
 
-        let url = client.path_to_url("satellite_bands");
+        let url = client.path_to_url("satellite_bands")?;
         client.post(url, self.state).await
     }
 }
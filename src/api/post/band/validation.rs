@@ -0,0 +1,248 @@
+use freedom_models::{
+    band::{BandType, IoHardware},
+    task::Polarization,
+};
+
+use super::BandDetails;
+
+/// The rules checked by [`BandDetailsBuilder::validate`](super::BandDetailsBuilder::validate)
+/// before a band is created
+///
+/// The default rules check that the frequency lies within 100–50,000 MHz, that the bandwidth is
+/// positive, and that the band does not match any of the crate's known invalid combinations of
+/// band type, polarization, and IO hardware. Additional combinations may be forbidden with
+/// [`Self::forbid`].
+///
+/// # Example
+///
+/// ```
+/// # use freedom_api::prelude::*;
+/// let rules = ValidationRules::default()
+///     .frequency_range(2000.0, 12_000.0)
+///     .forbid(
+///         BandRule::new("Our modems are transmit only")
+///             .band_type(BandType::Receive)
+///             .io_hardware(IoHardware::Modem),
+///     );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationRules {
+    min_frequency_mghz: f64,
+    max_frequency_mghz: f64,
+    forbidden: Vec<BandRule>,
+}
+
+impl Default for ValidationRules {
+    fn default() -> Self {
+        Self {
+            min_frequency_mghz: 100.0,
+            max_frequency_mghz: 50_000.0,
+            forbidden: vec![BandRule::new("transmit bands must specify a polarization")
+                .band_type(BandType::Transmit)
+                .without_polarization()],
+        }
+    }
+}
+
+impl ValidationRules {
+    /// Rules which only check the frequency and bandwidth, without any forbidden combinations
+    pub fn ranges_only() -> Self {
+        Self {
+            forbidden: Vec::new(),
+            ..Self::default()
+        }
+    }
+
+    /// The inclusive range of valid frequencies, in MHz
+    pub fn frequency_range(mut self, min_mghz: f64, max_mghz: f64) -> Self {
+        self.min_frequency_mghz = min_mghz;
+        self.max_frequency_mghz = max_mghz;
+        self
+    }
+
+    /// Reject any band matching the provided rule
+    pub fn forbid(mut self, rule: BandRule) -> Self {
+        self.forbidden.push(rule);
+        self
+    }
+
+    /// Every violation of the rules by the band
+    pub(crate) fn violations(&self, band: &BandDetails) -> Vec<String> {
+        let mut violations = Vec::new();
+
+        let frequency = band.frequency_mghz;
+        if !(self.min_frequency_mghz..=self.max_frequency_mghz).contains(&frequency) {
+            violations.push(format!(
+                "frequency of {frequency} MHz is outside of {}–{} MHz",
+                self.min_frequency_mghz, self.max_frequency_mghz
+            ));
+        }
+
+        let bandwidth = band.default_band_width_mghz;
+        if bandwidth.is_nan() || bandwidth <= 0.0 {
+            violations.push(format!("bandwidth of {bandwidth} MHz must be positive"));
+        }
+
+        violations.extend(
+            self.forbidden
+                .iter()
+                .filter(|rule| rule.matches(band))
+                .map(|rule| rule.reason.clone()),
+        );
+
+        violations
+    }
+}
+
+/// A combination of band type, polarization, and IO hardware which is forbidden
+///
+/// Each property which is not set on the rule matches any value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandRule {
+    band_type: Matcher<BandType>,
+    polarization: Matcher<Polarization>,
+    io_hardware: Matcher<IoHardware>,
+    reason: String,
+}
+
+/// Matches a property of the band
+#[derive(Debug, Clone, PartialEq)]
+enum Matcher<T> {
+    Any,
+    Unset,
+    Is(T),
+}
+
+impl<T: PartialEq> Matcher<T> {
+    fn matches(&self, value: Option<&T>) -> bool {
+        match (self, value) {
+            (Self::Any, _) => true,
+            (Self::Unset, value) => value.is_none(),
+            (Self::Is(expected), value) => value == Some(expected),
+        }
+    }
+}
+
+impl BandRule {
+    /// A rule matching every band, rejected with the provided reason
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            band_type: Matcher::Any,
+            polarization: Matcher::Any,
+            io_hardware: Matcher::Any,
+            reason: reason.into(),
+        }
+    }
+
+    /// Only match bands of the provided type
+    pub fn band_type(mut self, band_type: BandType) -> Self {
+        self.band_type = Matcher::Is(band_type);
+        self
+    }
+
+    /// Only match bands with the provided polarization
+    pub fn polarization(mut self, polarization: Polarization) -> Self {
+        self.polarization = Matcher::Is(polarization);
+        self
+    }
+
+    /// Only match bands without a polarization
+    pub fn without_polarization(mut self) -> Self {
+        self.polarization = Matcher::Unset;
+        self
+    }
+
+    /// Only match bands using the provided IO hardware
+    pub fn io_hardware(mut self, io_hardware: IoHardware) -> Self {
+        self.io_hardware = Matcher::Is(io_hardware);
+        self
+    }
+
+    fn matches(&self, band: &BandDetails) -> bool {
+        self.band_type.matches(Some(&band.typ))
+            && self.polarization.matches(band.polarization.as_ref())
+            && self
+                .io_hardware
+                .matches(band.io_configuration.io_hardware.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use freedom_models::band::IoConfiguration;
+
+    use super::*;
+
+    fn band(typ: BandType, polarization: Option<Polarization>) -> BandDetails {
+        BandDetails {
+            name: String::from("Band"),
+            typ,
+            frequency_mghz: 8096.0,
+            default_band_width_mghz: 1.45,
+            modulation: None,
+            eirp: None,
+            gain: None,
+            io_configuration: IoConfiguration {
+                start_hex_pattern: None,
+                end_hex_pattern: None,
+                strip_pattern: false,
+                io_hardware: Some(IoHardware::Modem),
+            },
+            polarization,
+            manual_transmit_control: false,
+        }
+    }
+
+    #[test]
+    fn valid_band_has_no_violations() {
+        let band = band(BandType::Receive, None);
+
+        assert!(ValidationRules::default().violations(&band).is_empty());
+    }
+
+    #[test]
+    fn ranges_are_checked() {
+        let mut band = band(BandType::Receive, None);
+        band.frequency_mghz = 60_000.0;
+        band.default_band_width_mghz = 0.0;
+
+        let violations = ValidationRules::default().violations(&band);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].contains("60000 MHz"));
+        assert!(violations[1].contains("bandwidth"));
+    }
+
+    #[test]
+    fn forbidden_combination_is_reported() {
+        let rules = ValidationRules::ranges_only().forbid(
+            BandRule::new("modems cannot receive")
+                .band_type(BandType::Receive)
+                .io_hardware(IoHardware::Modem),
+        );
+
+        assert_eq!(
+            rules.violations(&band(BandType::Receive, None)),
+            vec![String::from("modems cannot receive")]
+        );
+    }
+
+    #[test]
+    fn matchers_compare_values() {
+        assert!(Matcher::<BandType>::Any.matches(None));
+        assert!(Matcher::<BandType>::Unset.matches(None));
+        assert!(!Matcher::Unset.matches(Some(&BandType::Receive)));
+        assert!(Matcher::Is(BandType::Receive).matches(Some(&BandType::Receive)));
+        assert!(!Matcher::Is(BandType::Transmit).matches(Some(&BandType::Receive)));
+        assert!(!Matcher::Is(BandType::Transmit).matches(None));
+    }
+
+    #[test]
+    fn transmit_band_without_polarization_is_rejected() {
+        let violations = ValidationRules::default().violations(&band(BandType::Transmit, None));
+
+        assert_eq!(
+            violations,
+            vec![String::from("transmit bands must specify a polarization")]
+        );
+    }
+}
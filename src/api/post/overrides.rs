@@ -1,9 +1,15 @@
 use std::collections::HashMap;
 
 use reqwest::Response;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use url::Url;
 
-use crate::{api::Api, error::Error};
+use crate::{
+    api::{resource_url, Api},
+    error::Error,
+    ids::{SatelliteConfigurationId, SatelliteId},
+};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,6 +20,43 @@ pub struct Override {
     properties: HashMap<String, String>,
 }
 
+/// An override as returned by the API
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ExistingOverride {
+    pub name: String,
+    #[serde(default)]
+    properties: HashMap<String, JsonValue>,
+    #[serde(rename = "_links", default)]
+    links: HashMap<String, Link>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Link {
+    href: Url,
+}
+
+impl ExistingOverride {
+    /// The properties of the override, with any non-string values in their JSON representation
+    pub fn properties(&self) -> HashMap<String, String> {
+        self.properties
+            .iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    JsonValue::String(value) => value.clone(),
+                    value => value.to_string(),
+                };
+
+                (key.clone(), value)
+            })
+            .collect()
+    }
+
+    /// The link of the provided relation, such as `satellite` or `configuration`
+    pub fn link(&self, rel: &str) -> Option<&Url> {
+        self.links.get(rel).map(|link| &link.href)
+    }
+}
+
 pub struct OverrideBuilder<'a, C, S> {
     pub(crate) client: &'a C,
     state: S,
@@ -45,11 +88,8 @@ impl<'a, C> OverrideBuilder<'a, C, NoSatellite>
 where
     C: Api,
 {
-    pub fn satellite_id(self, id: impl Into<i32>) -> OverrideBuilder<'a, C, NoConfig> {
-        let satellite = self
-            .client
-            .path_to_url(format!("satellites/{}", id.into()))
-            .to_string();
+    pub fn satellite_id(self, id: impl Into<SatelliteId>) -> OverrideBuilder<'a, C, NoConfig> {
+        let satellite = resource_url(self.client, format!("satellites/{}", id.into()));
 
         self.satellite_url(satellite)
     }
@@ -78,14 +118,14 @@ where
 {
     pub fn satellite_configuration_id(
         self,
-        id: impl Into<i32>,
+        id: impl Into<SatelliteConfigurationId>,
     ) -> OverrideBuilder<'a, C, Override> {
-        let satellite = self
-            .client
-            .path_to_url(format!("satellites/{}", id.into()))
-            .to_string();
+        let configuration = resource_url(
+            self.client,
+            format!("satellite_configurations/{}", id.into()),
+        );
 
-        self.satellite_configuration_url(satellite)
+        self.satellite_configuration_url(configuration)
     }
 }
 
@@ -108,21 +148,175 @@ impl<'a, C> OverrideBuilder<'a, C, NoConfig> {
     }
 }
 
+impl<'a, C> OverrideBuilder<'a, C, Override>
+where
+    C: Api,
+{
+    /// Begin a new override from the name, satellite, configuration, and properties of the
+    /// existing override matching the provided `id`
+    ///
+    /// The satellite and configuration links of the existing override are followed, so that the
+    /// new override references the same resources.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// OverrideBuilder::from_existing(&client, 42)
+    ///     .await?
+    ///     .name("downconverter.gain override, attempt 2")
+    ///     .set_property("site.hardware.modem.ttc.rx.demodulator.bitrate", 4096)
+    ///     .send()
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub async fn from_existing(client: &'a C, id: i32) -> Result<Self, Error> {
+        let existing = client.get_override_by_id(id).await?;
+        let self_link = |rel: &'static str| {
+            let link = existing.link(rel).cloned().ok_or(Error::MissingUri(rel))?;
+
+            Ok::<_, Error>(async move {
+                let linked: JsonValue = client.get_json_map(link).await?;
+                linked
+                    .pointer("/_links/self/href")
+                    .and_then(JsonValue::as_str)
+                    .map(str::to_owned)
+                    .ok_or(Error::MissingUri("self"))
+            })
+        };
+        let (satellite, configuration) =
+            futures_util::future::try_join(self_link("satellite")?, self_link("configuration")?)
+                .await?;
+
+        let state = Override {
+            name: existing.name.clone(),
+            satellite,
+            configuration,
+            properties: existing.properties(),
+        };
+
+        Ok(OverrideBuilder { client, state })
+    }
+}
+
 impl<'a, C> OverrideBuilder<'a, C, Override> {
     pub fn add_property(mut self, key: impl Into<String>, value: impl ToString) -> Self {
         self.state.properties.insert(key.into(), value.to_string());
         self
     }
+
+    /// Set the property, replacing any existing value
+    pub fn set_property(self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.add_property(key, value)
+    }
+
+    /// Remove the property, if present
+    pub fn remove_property(mut self, key: &str) -> Self {
+        self.state.properties.remove(key);
+        self
+    }
+
+    /// Replace the name of the override
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.state.name = name.into();
+        self
+    }
+}
+
+impl Override {
+    fn check_properties(&self) -> Result<(), Error> {
+        match self.properties.is_empty() {
+            true => Err(Error::Validation(vec![String::from(
+                "an override requires at least one property",
+            )])),
+            false => Ok(()),
+        }
+    }
 }
 
 impl<'a, C> OverrideBuilder<'a, C, Override>
 where
     C: Api,
 {
+    /// Submit the request, converting an unsuccessful response into an [`Error::Api`]
+    ///
+    /// Overrides without any properties are rejected with [`Error::Validation`].
     pub async fn send(self) -> Result<Response, Error> {
+        self.state.check_properties()?;
+        let client = self.client;
+
+        let url = client.path_to_url("overrides")?;
+        client.post_checked(url, self.state).await
+    }
+
+    /// Submit the request, returning the response regardless of its status
+    pub async fn send_raw(self) -> Result<Response, Error> {
+        self.state.check_properties()?;
         let client = self.client;
 
-        let url = client.path_to_url("overrides");
+        let url = client.path_to_url("overrides")?;
         client.post(url, self.state).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use freedom_config::{Config, Test};
+
+    use crate::Client;
+
+    use super::*;
+
+    #[test]
+    fn satellite_configuration_id_links_configuration() {
+        let config = Config::builder()
+            .environment(Test)
+            .key("foo")
+            .secret("bar")
+            .build()
+            .unwrap();
+        let client = Client::from_config(config);
+
+        let builder = new(&client)
+            .name("override")
+            .satellite_id(1)
+            .satellite_configuration_id(2);
+
+        assert!(builder.state.satellite.ends_with("/satellites/1"));
+        assert!(builder
+            .state
+            .configuration
+            .ends_with("/satellite_configurations/2"));
+    }
+
+    #[test]
+    fn properties_can_be_replaced_and_removed() {
+        let config = Config::builder()
+            .environment(Test)
+            .key("foo")
+            .secret("bar")
+            .build()
+            .unwrap();
+        let client = Client::from_config(config);
+
+        let builder = new(&client)
+            .name("override")
+            .satellite_url("satellite")
+            .satellite_configuration_url("configuration")
+            .add_property("gain", 1)
+            .set_property("gain", 2)
+            .remove_property("missing")
+            .remove_property("bitrate");
+        assert_eq!(builder.state.properties["gain"], "2");
+
+        let builder = builder.remove_property("gain");
+        assert!(matches!(
+            builder.state.check_properties(),
+            Err(Error::Validation(_))
+        ));
+    }
+}
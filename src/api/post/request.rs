@@ -1,9 +1,14 @@
 use freedom_models::task::TaskType;
-use reqwest::Response;
+use reqwest::{header::LOCATION, Response};
 use serde::Serialize;
 use time::OffsetDateTime;
+use url::Url;
 
-use crate::{api::Api, error::Error};
+use crate::{
+    api::{pairing::BandPair, resource_url, Api},
+    error::Error,
+    ids::{BandId, SatelliteId, SiteConfigurationId, SiteId, TaskRequestId},
+};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -185,11 +190,8 @@ impl<'a, C, T> TaskRequestBuilder<'a, C, NoSatellite<T>>
 where
     C: Api,
 {
-    pub fn satellite_id(self, id: impl Into<i32>) -> TaskRequestBuilder<'a, C, NoSite<T>> {
-        let satellite = self
-            .client
-            .path_to_url(format!("satellites/{}", id.into()))
-            .to_string();
+    pub fn satellite_id(self, id: impl Into<SatelliteId>) -> TaskRequestBuilder<'a, C, NoSite<T>> {
+        let satellite = resource_url(self.client, format!("satellites/{}", id.into()));
 
         self.satellite_url(satellite)
     }
@@ -220,11 +222,8 @@ impl<'a, C, T> TaskRequestBuilder<'a, C, NoSite<T>>
 where
     C: Api,
 {
-    pub fn site_id(self, id: impl Into<i32>) -> TaskRequestBuilder<'a, C, NoConfig<T>> {
-        let site = self
-            .client
-            .path_to_url(format!("sites/{}", id.into()))
-            .to_string();
+    pub fn site_id(self, id: impl Into<SiteId>) -> TaskRequestBuilder<'a, C, NoConfig<T>> {
+        let site = resource_url(self.client, format!("sites/{}", id.into()));
 
         self.site_url(site)
     }
@@ -257,11 +256,11 @@ impl<'a, C, T> TaskRequestBuilder<'a, C, NoConfig<T>>
 where
     C: Api,
 {
-    pub fn site_configuration_id(self, id: impl Into<i32>) -> TaskRequestBuilder<'a, C, NoBand<T>> {
-        let configuration = self
-            .client
-            .path_to_url(format!("configurations/{}", id.into()))
-            .to_string();
+    pub fn site_configuration_id(
+        self,
+        id: impl Into<SiteConfigurationId>,
+    ) -> TaskRequestBuilder<'a, C, NoBand<T>> {
+        let configuration = resource_url(self.client, format!("configurations/{}", id.into()));
 
         self.site_configuration_url(configuration)
     }
@@ -301,21 +300,24 @@ where
 {
     pub fn band_ids(
         self,
-        ids: impl IntoIterator<Item = i32>,
+        ids: impl IntoIterator<Item = impl Into<BandId>>,
     ) -> TaskRequestBuilder<'a, C, TaskRequest>
     where
         C: Api,
     {
         let client = self.client;
-        let bands = ids.into_iter().map(|id| {
-            client
-                .path_to_url(format!("satellite_bands/{}", id))
-                .to_string()
-        });
+        let bands = ids
+            .into_iter()
+            .map(|id| resource_url(client, format!("satellite_bands/{}", id.into())));
 
         self.band_urls(bands)
     }
 
+    /// Target the bands of the pair, see [`Api::suggest_band_pair`]
+    pub fn band_pair(self, pair: BandPair) -> TaskRequestBuilder<'a, C, TaskRequest> {
+        self.band_urls(pair.urls())
+    }
+
     pub fn band_urls(
         mut self,
         urls: impl IntoIterator<Item = String>,
@@ -366,18 +368,77 @@ where
     C: Api,
 {
     pub fn override_id(self, id: impl Into<i32>) -> Self {
-        let override_url = self
-            .client
-            .path_to_url(format!("overrides/{}", id.into()))
-            .to_string();
+        let override_url = resource_url(self.client, format!("overrides/{}", id.into()));
 
         self.override_url(override_url)
     }
 
-    pub async fn send(self) -> Result<Response, Error> {
+    /// Submit the request, producing the ID and location of the created task request
+    ///
+    /// An unsuccessful response is converted into an [`Error::Api`].
+    pub async fn send(self) -> Result<CreatedTaskRequest, Error> {
+        let client = self.client;
+
+        let url = client.path_to_url("requests")?;
+        let resp = client.post_checked(url, self.state).await?;
+
+        CreatedTaskRequest::from_response(resp).await
+    }
+
+    /// Submit the request, returning the response regardless of its status
+    pub async fn send_raw(self) -> Result<Response, Error> {
         let client = self.client;
 
-        let url = client.path_to_url("requests");
+        let url = client.path_to_url("requests")?;
         client.post(url, self.state).await
     }
 }
+
+/// The task request created by [`TaskRequestBuilder::send`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatedTaskRequest {
+    /// The ID of the created task request
+    pub id: TaskRequestId,
+    /// The URL of the created task request
+    pub location: Url,
+    /// The created task request, when the server included it in the response
+    pub body: Option<freedom_models::task::TaskRequest>,
+}
+
+impl CreatedTaskRequest {
+    /// Locate the created task request from either the `Location` header, or the self link of the
+    /// response body, whichever is present.
+    async fn from_response(resp: Response) -> Result<Self, Error> {
+        let header = resp
+            .headers()
+            .get(LOCATION)
+            .map(|location| {
+                let location = location
+                    .to_str()
+                    .map_err(|error| Error::InvalidUri(error.to_string()))?;
+                resp.url().join(location).map_err(Error::from)
+            })
+            .transpose()?;
+
+        let body = resp.bytes().await?;
+        let body = match body.iter().all(u8::is_ascii_whitespace) {
+            true => None,
+            false => match crate::json::from_slice::<freedom_models::task::TaskRequest>(&body) {
+                Ok(request) => Some(request),
+                // The location is all that is required, so an unexpected body is not fatal
+                Err(error) if header.is_some() => {
+                    tracing::warn!(%error, "Failed to deserialize the created task request");
+                    None
+                }
+                Err(error) => return Err(error),
+            },
+        };
+
+        let location = header
+            .or_else(|| body.as_ref()?.links.get("self").cloned())
+            .ok_or(Error::MissingUri("Location"))?;
+        let id = crate::utils::id_from_url(&location)?;
+
+        Ok(Self { id, location, body })
+    }
+}
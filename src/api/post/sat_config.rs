@@ -1,7 +1,11 @@
 use reqwest::Response;
 use serde::Serialize;
 
-use crate::{api::Api, error::Error};
+use crate::{
+    api::{resource_url, Api},
+    error::Error,
+    ids::BandId,
+};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -66,14 +70,12 @@ where
 {
     pub fn band_ids(
         self,
-        ids: impl IntoIterator<Item = i32>,
+        ids: impl IntoIterator<Item = impl Into<BandId>>,
     ) -> SatelliteConfigurationBuilder<'a, C, SatelliteConfiguration> {
         let client = self.client;
-        let bands = ids.into_iter().map(|id| {
-            client
-                .path_to_url(format!("satellite_bands/{}", id))
-                .to_string()
-        });
+        let bands = ids
+            .into_iter()
+            .map(|id| resource_url(client, format!("satellite_bands/{}", id.into())));
 
         self.band_urls(bands)
     }
@@ -95,10 +97,19 @@ impl<'a, C> SatelliteConfigurationBuilder<'a, C, SatelliteConfiguration>
 where
     C: Api,
 {
+    /// Submit the request, converting an unsuccessful response into an [`Error::Api`]
     pub async fn send(self) -> Result<Response, Error> {
         let client = self.client;
 
-        let url = client.path_to_url("satellite_configurations");
+        let url = client.path_to_url("satellite_configurations")?;
+        client.post_checked(url, self.state).await
+    }
+
+    /// Submit the request, returning the response regardless of its status
+    pub async fn send_raw(self) -> Result<Response, Error> {
+        let client = self.client;
+
+        let url = client.path_to_url("satellite_configurations")?;
         client.post(url, self.state).await
     }
 }
@@ -1,7 +1,11 @@
 use reqwest::Response;
 use serde::Serialize;
 
-use crate::{api::Api, error::Error};
+use crate::{
+    api::{resource_url, Api},
+    error::Error,
+    ids::SatelliteConfigurationId,
+};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -60,12 +64,12 @@ where
 {
     pub fn satellite_configuration_id(
         self,
-        id: impl Into<i32>,
+        id: impl Into<SatelliteConfigurationId>,
     ) -> SatelliteBuilder<'a, C, NoNorad> {
-        let configuration = self
-            .client
-            .path_to_url(format!("satellite_configurations/{}", id.into()))
-            .to_string();
+        let configuration = resource_url(
+            self.client,
+            format!("satellite_configurations/{}", id.into()),
+        );
 
         self.satellite_configuration_url(configuration)
     }
@@ -103,10 +107,19 @@ impl<'a, C> SatelliteBuilder<'a, C, Satellite>
 where
     C: Api,
 {
+    /// Submit the request, converting an unsuccessful response into an [`Error::Api`]
     pub async fn send(self) -> Result<Response, Error> {
         let client = self.client;
 
-        let url = client.path_to_url("satellites");
+        let url = client.path_to_url("satellites")?;
+        client.post_checked(url, self.state).await
+    }
+
+    /// Submit the request, returning the response regardless of its status
+    pub async fn send_raw(self) -> Result<Response, Error> {
+        let client = self.client;
+
+        let url = client.path_to_url("satellites")?;
         client.post(url, self.state).await
     }
 }
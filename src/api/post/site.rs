@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use freedom_models::azel::Location;
+use reqwest::Response;
+use serde::Serialize;
+
+use crate::{api::Api, error::Error, utils::check_finite};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Site {
+    name: String,
+    description: Option<String>,
+    location: Location,
+    base_fps_port: u16,
+    properties: HashMap<String, String>,
+}
+
+pub fn new<C>(client: &C) -> SiteBuilder<'_, C, NoName> {
+    SiteBuilder {
+        client,
+        state: NoName,
+    }
+}
+
+pub struct SiteBuilder<'a, C, S> {
+    pub(crate) client: &'a C,
+    state: S,
+}
+
+pub struct NoName;
+
+impl<'a, C> SiteBuilder<'a, C, NoName> {
+    pub fn name(self, name: impl Into<String>) -> SiteBuilder<'a, C, NoLocation> {
+        SiteBuilder {
+            client: self.client,
+            state: NoLocation { name: name.into() },
+        }
+    }
+}
+
+pub struct NoLocation {
+    name: String,
+}
+
+impl<'a, C> SiteBuilder<'a, C, NoLocation> {
+    pub fn location(self, location: Location) -> SiteBuilder<'a, C, NoPort> {
+        SiteBuilder {
+            client: self.client,
+            state: NoPort {
+                name: self.state.name,
+                location,
+            },
+        }
+    }
+}
+
+pub struct NoPort {
+    name: String,
+    location: Location,
+}
+
+impl<'a, C> SiteBuilder<'a, C, NoPort> {
+    /// The FPS port of the site's first configuration, see [`fps_port`](crate::fps::fps_port)
+    pub fn base_fps_port(self, port: u16) -> SiteBuilder<'a, C, Site> {
+        let state = Site {
+            name: self.state.name,
+            description: None,
+            location: self.state.location,
+            base_fps_port: port,
+            properties: HashMap::new(),
+        };
+
+        SiteBuilder {
+            client: self.client,
+            state,
+        }
+    }
+}
+
+impl<'a, C> SiteBuilder<'a, C, Site> {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.state.description = Some(description.into());
+        self
+    }
+
+    pub fn property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.state.properties.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn properties<I, K, V>(mut self, properties: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        for (key, value) in properties {
+            self = self.property(key, value);
+        }
+        self
+    }
+}
+
+impl Site {
+    fn check(&self) -> Result<(), Error> {
+        let latitude = self.location.latitude;
+        let longitude = self.location.longitude;
+        let elevation = self.location.elevation;
+
+        check_finite([
+            ("latitude", Some(latitude)),
+            ("longitude", Some(longitude)),
+            ("elevation", Some(elevation)),
+        ])?;
+
+        let mut violations = Vec::new();
+        if !(-90.0..=90.0).contains(&latitude) {
+            violations.push(format!("latitude of {latitude} is not within ±90 degrees"));
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            violations.push(format!(
+                "longitude of {longitude} is not within ±180 degrees"
+            ));
+        }
+        if self.base_fps_port == 0 {
+            violations.push(String::from("base FPS port of 0 is not a port"));
+        }
+
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err(Error::Validation(violations)),
+        }
+    }
+}
+
+impl<'a, C> SiteBuilder<'a, C, Site>
+where
+    C: Api,
+{
+    /// Submit the request, converting an unsuccessful response into an [`Error::Api`]
+    ///
+    /// A location which is not finite or not on the globe, or a base FPS port of zero, is first
+    /// rejected with an [`Error::Validation`].
+    pub async fn send(self) -> Result<Response, Error> {
+        self.state.check()?;
+        let client = self.client;
+
+        let url = client.path_to_url("sites")?;
+        client.post_checked(url, self.state).await
+    }
+
+    /// Submit the request, returning the response regardless of its status
+    pub async fn send_raw(self) -> Result<Response, Error> {
+        let client = self.client;
+
+        let url = client.path_to_url("sites")?;
+        client.post(url, self.state).await
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use reqwest::Response;
+use serde::Serialize;
+
+use crate::{
+    api::{resource_url, Api},
+    error::Error,
+    ids::SiteId,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SiteConfiguration {
+    site: String,
+    name: String,
+    properties: HashMap<String, String>,
+}
+
+pub fn new<C>(client: &C) -> SiteConfigurationBuilder<'_, C, NoSite> {
+    SiteConfigurationBuilder {
+        client,
+        state: NoSite,
+    }
+}
+
+pub struct SiteConfigurationBuilder<'a, C, S> {
+    pub(crate) client: &'a C,
+    state: S,
+}
+
+pub struct NoSite;
+
+impl<'a, C> SiteConfigurationBuilder<'a, C, NoSite> {
+    pub fn site_url(self, url: impl Into<String>) -> SiteConfigurationBuilder<'a, C, NoName> {
+        SiteConfigurationBuilder {
+            client: self.client,
+            state: NoName { site: url.into() },
+        }
+    }
+}
+
+impl<'a, C> SiteConfigurationBuilder<'a, C, NoSite>
+where
+    C: Api,
+{
+    pub fn site_id(self, id: impl Into<SiteId>) -> SiteConfigurationBuilder<'a, C, NoName> {
+        let site = resource_url(self.client, format!("sites/{}", id.into()));
+
+        self.site_url(site)
+    }
+}
+
+pub struct NoName {
+    site: String,
+}
+
+impl<'a, C> SiteConfigurationBuilder<'a, C, NoName> {
+    pub fn name(
+        self,
+        name: impl Into<String>,
+    ) -> SiteConfigurationBuilder<'a, C, SiteConfiguration> {
+        let state = SiteConfiguration {
+            site: self.state.site,
+            name: name.into(),
+            properties: HashMap::new(),
+        };
+
+        SiteConfigurationBuilder {
+            client: self.client,
+            state,
+        }
+    }
+}
+
+impl<'a, C> SiteConfigurationBuilder<'a, C, SiteConfiguration> {
+    /// Describe the hardware of the configuration, such as its antenna or modem
+    pub fn property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.state.properties.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn properties<I, K, V>(mut self, properties: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        for (key, value) in properties {
+            self = self.property(key, value);
+        }
+        self
+    }
+}
+
+impl<'a, C> SiteConfigurationBuilder<'a, C, SiteConfiguration>
+where
+    C: Api,
+{
+    /// Submit the request, converting an unsuccessful response into an [`Error::Api`]
+    pub async fn send(self) -> Result<Response, Error> {
+        let client = self.client;
+
+        let url = client.path_to_url("configurations")?;
+        client.post_checked(url, self.state).await
+    }
+
+    /// Submit the request, returning the response regardless of its status
+    pub async fn send_raw(self) -> Result<Response, Error> {
+        let client = self.client;
+
+        let url = client.path_to_url("configurations")?;
+        client.post(url, self.state).await
+    }
+}
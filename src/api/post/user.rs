@@ -1,20 +1,30 @@
 use reqwest::Response;
 use serde::Serialize;
 
-use crate::{api::Api, error::Error};
+use crate::{api::Api, error::Error, ids::AccountId};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct User {
     #[serde(skip_serializing)]
-    account_id: i32,
+    account_id: AccountId,
     first_name: String,
     last_name: String,
     email: String,
     machine_service: bool,
     roles: Vec<String>,
+    #[serde(skip)]
+    known_roles: Option<Vec<String>>,
 }
 
+/// The roles assumed to be available when the server does not list them
+pub(crate) const KNOWN_ROLES: &[&str] = &[
+    "ROLE_USER",
+    "ROLE_ADMIN",
+    "ROLE_ACCOUNT_ADMIN",
+    "ROLE_OPERATOR",
+];
+
 pub struct UserBuilder<'a, C, S> {
     client: &'a C,
     state: S,
@@ -30,7 +40,7 @@ pub fn new<C>(client: &C) -> UserBuilder<'_, C, NoAccount> {
 pub struct NoAccount;
 
 impl<'a, C> UserBuilder<'a, C, NoAccount> {
-    pub fn account_id(self, account_id: impl Into<i32>) -> UserBuilder<'a, C, NoFirstName> {
+    pub fn account_id(self, account_id: impl Into<AccountId>) -> UserBuilder<'a, C, NoFirstName> {
         UserBuilder {
             client: self.client,
             state: NoFirstName {
@@ -41,7 +51,7 @@ impl<'a, C> UserBuilder<'a, C, NoAccount> {
 }
 
 pub struct NoFirstName {
-    account_id: i32,
+    account_id: AccountId,
 }
 
 impl<'a, C> UserBuilder<'a, C, NoFirstName> {
@@ -57,7 +67,7 @@ impl<'a, C> UserBuilder<'a, C, NoFirstName> {
 }
 
 pub struct NoLastName {
-    account_id: i32,
+    account_id: AccountId,
     first_name: String,
 }
 
@@ -75,7 +85,7 @@ impl<'a, C> UserBuilder<'a, C, NoLastName> {
 }
 
 pub struct NoEmail {
-    account_id: i32,
+    account_id: AccountId,
     first_name: String,
     last_name: String,
 }
@@ -89,6 +99,7 @@ impl<'a, C> UserBuilder<'a, C, NoEmail> {
             email: email.into(),
             machine_service: false,
             roles: Vec::new(),
+            known_roles: None,
         };
 
         UserBuilder {
@@ -116,16 +127,139 @@ impl<'a, C> UserBuilder<'a, C, User> {
 
         self
     }
+
+    /// Reject the user when sent if any of its roles are not among the provided roles
+    ///
+    /// The roles available may be fetched with [`Api::get_available_roles`].
+    pub fn validate_roles<I, T>(mut self, known_roles: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.state.known_roles = Some(known_roles.into_iter().map(Into::into).collect());
+
+        self
+    }
 }
 
 impl<'a, C> UserBuilder<'a, C, User>
 where
     C: Api,
 {
+    /// Submit the request, converting an unsuccessful response into an [`Error::Api`]
+    ///
+    /// When [`validate_roles`](Self::validate_roles) was called, any unknown roles are first
+    /// rejected with an [`Error::Validation`].
     pub async fn send(self) -> Result<Response, Error> {
+        if let Some(known_roles) = &self.state.known_roles {
+            let violations = role_violations(&self.state.roles, known_roles);
+            if !violations.is_empty() {
+                return Err(Error::Validation(violations));
+            }
+        }
+
+        let client = self.client;
+
+        let url = client.path_to_url(format!("accounts/{}/newuser", self.state.account_id))?;
+        client.post_checked(url, self.state).await
+    }
+
+    /// Validate the roles against those available from the server, then submit the request
+    pub async fn send_validated(self) -> Result<Response, Error> {
+        let known_roles = self.client.get_available_roles().await?;
+
+        self.validate_roles(known_roles).send().await
+    }
+
+    /// Submit the request, returning the response regardless of its status
+    pub async fn send_raw(self) -> Result<Response, Error> {
         let client = self.client;
 
-        let url = client.path_to_url(format!("accounts/{}/newuser", self.state.account_id));
+        let url = client.path_to_url(format!("accounts/{}/newuser", self.state.account_id))?;
         client.post(url, self.state).await
     }
 }
+
+/// Describe each role which is not known, suggesting a known role which is close to it
+fn role_violations(roles: &[String], known_roles: &[String]) -> Vec<String> {
+    roles
+        .iter()
+        .filter(|role| !known_roles.contains(role))
+        .map(
+            |role| match known_roles.iter().find(|known| is_close(role, known)) {
+                Some(suggestion) => format!("unknown role {role:?}, did you mean {suggestion:?}?"),
+                None => format!("unknown role {role:?}"),
+            },
+        )
+        .collect()
+}
+
+/// Whether the strings differ by case, or by at most a single insertion, deletion, or substitution
+fn is_close(a: &str, b: &str) -> bool {
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+    let (short, long) = match a.len() <= b.len() {
+        true => (&a, &b),
+        false => (&b, &a),
+    };
+
+    if long.len() - short.len() > 1 {
+        return false;
+    }
+
+    let common = short.iter().zip(long).take_while(|(a, b)| a == b).count();
+    if short.len() == long.len() {
+        short
+            .iter()
+            .skip(common + 1)
+            .eq(long.iter().skip(common + 1))
+    } else {
+        short[common..] == long[common + 1..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn known() -> Vec<String> {
+        KNOWN_ROLES.iter().map(|role| role.to_string()).collect()
+    }
+
+    #[test]
+    fn exact_roles_are_valid() {
+        let roles = vec![String::from("ROLE_USER"), String::from("ROLE_ADMIN")];
+
+        assert!(role_violations(&roles, &known()).is_empty());
+    }
+
+    #[test]
+    fn case_mismatched_role_suggests_known_role() {
+        let roles = vec![String::from("role_user")];
+
+        assert_eq!(
+            role_violations(&roles, &known()),
+            [r#"unknown role "role_user", did you mean "ROLE_USER"?"#]
+        );
+    }
+
+    #[test]
+    fn misspelled_roles_suggest_known_role() {
+        let roles = vec![
+            String::from("ROLE_USR"),
+            String::from("ROLE_ADMIM"),
+            String::from("ROLE_OPERATORS"),
+            String::from("SUPERUSER"),
+        ];
+
+        assert_eq!(
+            role_violations(&roles, &known()),
+            [
+                r#"unknown role "ROLE_USR", did you mean "ROLE_USER"?"#,
+                r#"unknown role "ROLE_ADMIM", did you mean "ROLE_ADMIN"?"#,
+                r#"unknown role "ROLE_OPERATORS", did you mean "ROLE_OPERATOR"?"#,
+                r#"unknown role "SUPERUSER""#,
+            ]
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use std::ops::Deref;
+
+use serde::{Serialize, Serializer};
+use time::OffsetDateTime;
+use url::Url;
+
+/// Describes where and when a value was fetched
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Provenance {
+    /// The URL from which the value was fetched
+    pub url: Url,
+    /// The time at which the response was received from the server
+    ///
+    /// For values served from a cache, this is the time of the original fetch.
+    #[serde(with = "time::serde::rfc3339")]
+    pub fetched_at: OffsetDateTime,
+    /// Whether the value was served from a cache rather than from the server
+    pub from_cache: bool,
+}
+
+/// A value along with the [`Provenance`] of the request which produced it
+///
+/// The provenance is purely informational. It is ignored when comparing two traced values, and
+/// when serializing, in which case only the wrapped value is written. The provenance may be
+/// serialized explicitly via [`Traced::provenance`].
+#[derive(Debug, Clone)]
+pub struct Traced<T> {
+    value: T,
+    provenance: Provenance,
+}
+
+impl<T> Traced<T> {
+    pub(crate) fn new(value: T, provenance: Provenance) -> Self {
+        Self { value, provenance }
+    }
+
+    /// Where and when the value was fetched
+    pub fn provenance(&self) -> &Provenance {
+        &self.provenance
+    }
+
+    /// Discard the provenance, returning the wrapped value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Split into the wrapped value and its provenance
+    pub fn into_parts(self) -> (T, Provenance) {
+        (self.value, self.provenance)
+    }
+}
+
+impl<T> Deref for Traced<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T: PartialEq> PartialEq for Traced<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for Traced<T> {}
+
+impl<T: Serialize> Serialize for Traced<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn provenance(from_cache: bool) -> Provenance {
+        Provenance {
+            url: Url::parse("http://localhost:8080/api/satellites/1").unwrap(),
+            fetched_at: datetime!(2024-10-01 12:00 UTC),
+            from_cache,
+        }
+    }
+
+    #[test]
+    fn provenance_does_not_affect_eq() {
+        let fresh = Traced::new(String::from("foo"), provenance(false));
+        let cached = Traced::new(String::from("foo"), provenance(true));
+
+        assert_eq!(fresh, cached);
+        assert_ne!(fresh.provenance(), cached.provenance());
+    }
+
+    #[test]
+    fn only_value_is_serialized() {
+        let traced = Traced::new(String::from("foo"), provenance(true));
+
+        assert_eq!(serde_json::to_string(&traced).unwrap(), "\"foo\"");
+        assert_eq!(
+            serde_json::to_value(traced.provenance()).unwrap(),
+            serde_json::json!({
+                "url": "http://localhost:8080/api/satellites/1",
+                "fetchedAt": "2024-10-01T12:00:00Z",
+                "fromCache": true,
+            })
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod band;
+pub mod satellite;
+
+pub use self::{band::BandDetailsUpdateBuilder, satellite::SatelliteUpdateBuilder};
@@ -0,0 +1,188 @@
+use freedom_models::{
+    band::{Band, BandType, IoConfiguration},
+    task::Polarization,
+};
+use reqwest::Response;
+use serde::Serialize;
+
+use crate::{
+    api::{error_on_non_success_response, Api},
+    error::Error,
+    extensions::BandExt,
+    ids::BandId,
+    utils::{check_finite, parse_finite},
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BandDetailsUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(rename(serialize = "type"), skip_serializing_if = "Option::is_none")]
+    typ: Option<BandType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frequency_mghz: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_band_width_mghz: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    modulation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eirp: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gain: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    io_configuration: Option<IoConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    polarization: Option<Polarization>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    manual_transmit_control: Option<bool>,
+}
+
+impl BandDetailsUpdate {
+    fn check_finite(&self) -> Result<(), Error> {
+        check_finite([
+            ("frequency", self.frequency_mghz),
+            ("bandwidth", self.default_band_width_mghz),
+            ("EIRP", self.eirp),
+            ("gain", self.gain),
+        ])
+    }
+}
+
+pub fn new<C>(client: &C, id: BandId) -> BandDetailsUpdateBuilder<'_, C> {
+    BandDetailsUpdateBuilder {
+        client,
+        id,
+        state: BandDetailsUpdate::default(),
+    }
+}
+
+pub struct BandDetailsUpdateBuilder<'a, C> {
+    pub(crate) client: &'a C,
+    id: BandId,
+    state: BandDetailsUpdate,
+}
+
+impl<'a, C> BandDetailsUpdateBuilder<'a, C> {
+    /// Seed the update with every field of an existing band, so that only the fields which are
+    /// subsequently set on the builder are changed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let band = client.get_satellite_band_by_id(42).await?;
+    /// BandDetailsUpdateBuilder::from_existing(&client, &band)?
+    ///     .frequency(8100.0)
+    ///     .send()
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub fn from_existing(client: &'a C, band: &Band) -> Result<Self, Error> {
+        let state = BandDetailsUpdate {
+            name: Some(band.name.clone()),
+            typ: band.typ.to_owned(),
+            frequency_mghz: Some(band.frequency_mghz),
+            default_band_width_mghz: Some(band.default_band_width_mghz),
+            modulation: band.modulation.clone(),
+            eirp: band.eirp,
+            gain: band.gain,
+            io_configuration: Some(band.io_configuration.clone()),
+            polarization: band.polarization.to_owned(),
+            manual_transmit_control: band.manual_transmit_control,
+        };
+
+        Ok(Self {
+            client,
+            id: band.get_id()?,
+            state,
+        })
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.state.name = Some(name.into());
+        self
+    }
+
+    pub fn band_type(mut self, band_type: BandType) -> Self {
+        self.state.typ = Some(band_type);
+        self
+    }
+
+    pub fn frequency(mut self, frequency: impl Into<f64>) -> Self {
+        self.state.frequency_mghz = Some(frequency.into());
+        self
+    }
+
+    /// Parse the frequency, in MHz, from a string such as `"8096.5"`
+    ///
+    /// Fails with [`Error::Validation`] when the string is not a finite number, rather than
+    /// misinterpreting locale-specific forms such as `"8.096,5"`.
+    pub fn try_frequency_str(self, frequency: &str) -> Result<Self, Error> {
+        let frequency = parse_finite("frequency", frequency)?;
+
+        Ok(self.frequency(frequency))
+    }
+
+    pub fn default_band_width(mut self, bandwidth_mghz: impl Into<f64>) -> Self {
+        self.state.default_band_width_mghz = Some(bandwidth_mghz.into());
+        self
+    }
+
+    pub fn modulation(mut self, modulation: impl Into<String>) -> Self {
+        self.state.modulation = Some(modulation.into());
+        self
+    }
+
+    pub fn effective_isotropic_radiative_power(mut self, eirp: impl Into<f64>) -> Self {
+        self.state.eirp = Some(eirp.into());
+        self
+    }
+
+    pub fn gain(mut self, gain: impl Into<f64>) -> Self {
+        self.state.gain = Some(gain.into());
+        self
+    }
+
+    pub fn io_configuration(mut self, io_configuration: IoConfiguration) -> Self {
+        self.state.io_configuration = Some(io_configuration);
+        self
+    }
+
+    pub fn polarization(mut self, polarization: Polarization) -> Self {
+        self.state.polarization = Some(polarization);
+        self
+    }
+
+    pub fn manual_transmit_control(mut self, control: bool) -> Self {
+        self.state.manual_transmit_control = Some(control);
+        self
+    }
+}
+
+impl<'a, C> BandDetailsUpdateBuilder<'a, C>
+where
+    C: Api,
+{
+    /// Submit the update as a `PATCH`, converting an unsuccessful response into an [`Error::Api`]
+    ///
+    /// Fields which are not set are left unchanged by the server. Updates with NaN or infinite numbers are rejected with [`Error::Validation`].
+    pub async fn send(self) -> Result<Response, Error> {
+        let resp = self.send_raw().await?;
+
+        error_on_non_success_response(resp).await
+    }
+
+    /// Submit the update, returning the response regardless of its status
+    pub async fn send_raw(self) -> Result<Response, Error> {
+        self.state.check_finite()?;
+        let client = self.client;
+
+        let url = client.path_to_url(format!("satellite_bands/{}", self.id))?;
+        client.patch(url, self.state).await
+    }
+}
@@ -0,0 +1,88 @@
+use reqwest::Response;
+use serde::Serialize;
+
+use crate::{
+    api::{error_on_non_success_response, resource_url, Api},
+    error::Error,
+    ids::{SatelliteConfigurationId, SatelliteId},
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SatelliteUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    norad_cat_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    configuration: Option<String>,
+}
+
+pub fn new<C>(client: &C, id: SatelliteId) -> SatelliteUpdateBuilder<'_, C> {
+    SatelliteUpdateBuilder {
+        client,
+        id,
+        state: SatelliteUpdate::default(),
+    }
+}
+
+pub struct SatelliteUpdateBuilder<'a, C> {
+    pub(crate) client: &'a C,
+    id: SatelliteId,
+    state: SatelliteUpdate,
+}
+
+impl<'a, C> SatelliteUpdateBuilder<'a, C> {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.state.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.state.description = Some(description.into());
+        self
+    }
+
+    pub fn norad_id(mut self, norad_id: u32) -> Self {
+        self.state.norad_cat_id = Some(norad_id);
+        self
+    }
+
+    pub fn satellite_configuration_url(mut self, url: impl Into<String>) -> Self {
+        self.state.configuration = Some(url.into());
+        self
+    }
+}
+
+impl<'a, C> SatelliteUpdateBuilder<'a, C>
+where
+    C: Api,
+{
+    pub fn satellite_configuration_id(self, id: impl Into<SatelliteConfigurationId>) -> Self {
+        let configuration = resource_url(
+            self.client,
+            format!("satellite_configurations/{}", id.into()),
+        );
+
+        self.satellite_configuration_url(configuration)
+    }
+
+    /// Submit the update as a `PATCH`, converting an unsuccessful response into an [`Error::Api`]
+    ///
+    /// Fields which are not set are left unchanged by the server.
+    pub async fn send(self) -> Result<Response, Error> {
+        let resp = self.send_raw().await?;
+
+        error_on_non_success_response(resp).await
+    }
+
+    /// Submit the update, returning the response regardless of its status
+    pub async fn send_raw(self) -> Result<Response, Error> {
+        let client = self.client;
+
+        let url = client.path_to_url(format!("satellites/{}", self.id))?;
+        client.patch(url, self.state).await
+    }
+}
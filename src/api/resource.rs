@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+use freedom_models::{
+    account::Account,
+    band::Band,
+    satellite::Satellite,
+    satellite_configuration::SatelliteConfiguration,
+    site::{Site, SiteConfiguration},
+    task::{Task, TaskRequest},
+    user::User,
+};
+
+use crate::{
+    api::Value,
+    ids::{
+        AccountId, BandId, SatelliteConfigurationId, SatelliteId, SiteConfigurationId, SiteId,
+        TaskId, TaskRequestId, UserId,
+    },
+};
+
+/// A model which the API serves from a collection, such that it can be fetched by its ID with
+/// [`Api::get_by_id`](crate::Api::get_by_id) and listed with [`Api::list`](crate::Api::list)
+pub trait Resource: Value + 'static {
+    /// The type of the ID of the resource
+    type Id: Copy + Display + From<i32> + Into<i32> + Send + Sync + 'static;
+
+    /// The path of the collection beneath the API, such as `satellites`, under which each
+    /// resource is found by its ID
+    const COLLECTION: &'static str;
+
+    /// The path listing every resource of the collection
+    const LIST: &'static str = Self::COLLECTION;
+}
+
+/// A [`Resource`] which can be found by its name with
+/// [`Api::find_by_name`](crate::Api::find_by_name)
+pub trait NamedResource: Resource {
+    /// The path of the search endpoint, which takes the name as its `name` parameter
+    const FIND_BY_NAME: &'static str;
+}
+
+impl Resource for Account {
+    type Id = AccountId;
+    const COLLECTION: &'static str = "accounts";
+}
+
+impl NamedResource for Account {
+    const FIND_BY_NAME: &'static str = "accounts/search/findOneByName";
+}
+
+impl Resource for Band {
+    type Id = BandId;
+    const COLLECTION: &'static str = "satellite_bands";
+}
+
+impl NamedResource for Band {
+    const FIND_BY_NAME: &'static str = "satellite_bands/search/findOneByName";
+}
+
+impl Resource for Satellite {
+    type Id = SatelliteId;
+    const COLLECTION: &'static str = "satellites";
+}
+
+impl NamedResource for Satellite {
+    const FIND_BY_NAME: &'static str = "satellites/findOneByName";
+}
+
+impl Resource for SatelliteConfiguration {
+    type Id = SatelliteConfigurationId;
+    const COLLECTION: &'static str = "satellite_configurations";
+}
+
+impl NamedResource for SatelliteConfiguration {
+    const FIND_BY_NAME: &'static str = "satellite_configurations/search/findOneByName";
+}
+
+impl Resource for Site {
+    type Id = SiteId;
+    const COLLECTION: &'static str = "sites";
+}
+
+impl NamedResource for Site {
+    const FIND_BY_NAME: &'static str = "sites/search/findOneByName";
+}
+
+impl Resource for SiteConfiguration {
+    type Id = SiteConfigurationId;
+    const COLLECTION: &'static str = "configurations";
+}
+
+impl Resource for Task {
+    type Id = TaskId;
+    const COLLECTION: &'static str = "tasks";
+}
+
+impl Resource for TaskRequest {
+    type Id = TaskRequestId;
+    const COLLECTION: &'static str = "requests";
+    const LIST: &'static str = "requests/search/findAll";
+}
+
+impl Resource for User {
+    type Id = UserId;
+    const COLLECTION: &'static str = "users";
+}
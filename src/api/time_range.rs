@@ -0,0 +1,147 @@
+use time::{format_description::well_known::Iso8601, Duration, OffsetDateTime, Time};
+
+use crate::error::Error;
+
+/// A range of time, starting strictly before it ends, as queried by the [`Api`](crate::Api)
+/// methods searching between two times
+///
+/// Each of those methods takes a `start` and an `end`, which are validated as by
+/// [`TimeRange::new`] when the request is made, and has an `_in` variant taking a [`TimeRange`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use freedom_api::prelude::*;
+/// # tokio_test::block_on(async {
+/// let client = Client::from_env()?;
+///
+/// let requests = client
+///     .get_requests_by_target_date_between_in(TimeRange::next_hours(6)?)
+///     .await?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// # });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimeRange {
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    start_param: String,
+    end_param: String,
+}
+
+impl TimeRange {
+    /// Creates the range from `start` until `end`
+    ///
+    /// Fails with [`Error::TimeFormatError`] when either time cannot be formatted as ISO 8601,
+    /// and with [`Error::Validation`] unless `start` is before `end`.
+    pub fn new(start: OffsetDateTime, end: OffsetDateTime) -> Result<Self, Error> {
+        let start_param = start.format(&Iso8601::DEFAULT)?;
+        let end_param = end.format(&Iso8601::DEFAULT)?;
+        if start >= end {
+            return Err(Error::Validation(vec![format!(
+                "the start of the range {start} is not before its end {end}"
+            )]));
+        }
+
+        Ok(Self {
+            start,
+            end,
+            start_param,
+            end_param,
+        })
+    }
+
+    /// The range from now until `hours` hours from now
+    pub fn next_hours(hours: u32) -> Result<Self, Error> {
+        let start = OffsetDateTime::now_utc();
+
+        Self::new(start, start + Duration::hours(hours.into()))
+    }
+
+    /// The range from midnight UTC today until midnight UTC tomorrow
+    pub fn today_utc() -> Result<Self, Error> {
+        let start = OffsetDateTime::now_utc().replace_time(Time::MIDNIGHT);
+
+        Self::new(start, start + Duration::days(1))
+    }
+
+    /// The start of the range
+    pub fn start(&self) -> OffsetDateTime {
+        self.start
+    }
+
+    /// The end of the range
+    pub fn end(&self) -> OffsetDateTime {
+        self.end
+    }
+
+    /// The `start` and `end` query parameters of the range
+    pub(crate) fn query_pairs(&self) -> [(&'static str, &str); 2] {
+        [("start", &self.start_param), ("end", &self.end_param)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{macros::datetime, UtcOffset};
+
+    use super::*;
+
+    #[test]
+    fn start_must_precede_end() {
+        let start = datetime!(2024-10-01 00:00 UTC);
+        let end = datetime!(2024-10-02 00:00 UTC);
+
+        assert!(TimeRange::new(start, end).is_ok());
+        assert!(matches!(
+            TimeRange::new(end, start),
+            Err(Error::Validation(_))
+        ));
+        assert!(matches!(
+            TimeRange::new(start, start),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn unformattable_times_are_format_errors() {
+        let time =
+            datetime!(2024-10-01 00:00 UTC).to_offset(UtcOffset::from_hms(0, 0, 30).unwrap());
+        let valid = datetime!(2024-10-02 00:00 UTC);
+
+        for (start, end) in [(time, valid), (valid, time)] {
+            assert!(matches!(
+                TimeRange::new(start, end),
+                Err(Error::TimeFormatError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn query_is_iso_8601() {
+        let range = TimeRange::new(
+            datetime!(2024-10-01 00:00 UTC),
+            datetime!(2024-10-01 06:30 -04:00),
+        )
+        .unwrap();
+
+        assert_eq!(
+            range.query_pairs(),
+            [
+                ("start", "2024-10-01T00:00:00.000000000Z"),
+                ("end", "2024-10-01T06:30:00.000000000-04:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn helpers_are_valid_ranges() {
+        let range = TimeRange::next_hours(6).unwrap();
+        assert_eq!(range.end() - range.start(), Duration::hours(6));
+        assert!(TimeRange::next_hours(0).is_err());
+
+        let today = TimeRange::today_utc().unwrap();
+        assert_eq!(today.start().time(), Time::MIDNIGHT);
+        assert_eq!(today.end() - today.start(), Duration::days(1));
+    }
+}
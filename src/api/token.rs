@@ -0,0 +1,274 @@
+use std::{collections::HashMap, fmt, time::Duration};
+
+use freedom_config::Secret;
+use serde_json::Value as JsonValue;
+use time::OffsetDateTime;
+use tokio::sync::Mutex;
+
+use crate::{
+    api::Api,
+    error::Error,
+    ids::{BandId, SatelliteId, SiteConfigurationId},
+    utils::parse_time,
+};
+
+/// The entity an FPS token is requested for, alongside the band
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FpsTarget {
+    Satellite(SatelliteId),
+    SiteConfiguration(SiteConfigurationId),
+}
+
+/// A token authorizing a connection to the Freedom Packet Service (FPS)
+#[derive(Clone)]
+pub struct FpsToken {
+    pub token: Secret<String>,
+    /// When the token stops being accepted, if reported by the server
+    pub expires_at: Option<OffsetDateTime>,
+    pub band_id: BandId,
+    pub target: FpsTarget,
+    /// The FPS host to connect to, from the response or otherwise the configured environment
+    pub host: String,
+    /// The FPS port to connect to, when reported by the server
+    pub port: Option<u16>,
+}
+
+impl fmt::Debug for FpsToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FpsToken")
+            .field("token", &"REDACTED")
+            .field("expires_at", &self.expires_at)
+            .field("band_id", &self.band_id)
+            .field("target", &self.target)
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .finish()
+    }
+}
+
+impl FpsToken {
+    /// Whether the expiry of the token has passed. Tokens without an expiry never expire.
+    pub fn is_expired(&self) -> bool {
+        self.expires_within(Duration::ZERO)
+    }
+
+    /// Whether the token will have expired after the duration
+    pub fn expires_within(&self, duration: Duration) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= OffsetDateTime::now_utc() + duration)
+    }
+
+    /// Read the token from the body of the response
+    ///
+    /// The expiry is read from either `expiration` or `expiresAt`, as a timestamp or seconds since
+    /// the Unix epoch.
+    fn from_response(
+        value: &JsonValue,
+        band_id: BandId,
+        target: FpsTarget,
+        default_host: &str,
+    ) -> Result<Self, Error> {
+        let token = match value.get("token") {
+            Some(JsonValue::String(token)) => token.to_owned(),
+            Some(_) => return Err(Error::Response(String::from("Invalid type for token"))),
+            None => return Err(Error::Response(String::from("Missing token field"))),
+        };
+        let expires_at = ["expiration", "expiresAt"]
+            .into_iter()
+            .filter_map(|key| value.get(key))
+            .find_map(|expiry| match expiry {
+                JsonValue::String(expiry) => parse_time(expiry),
+                JsonValue::Number(expiry) => expiry
+                    .as_i64()
+                    .and_then(|seconds| OffsetDateTime::from_unix_timestamp(seconds).ok()),
+                _ => None,
+            });
+        let host = value
+            .get("host")
+            .and_then(JsonValue::as_str)
+            .unwrap_or(default_host)
+            .to_owned();
+        let port = value
+            .get("port")
+            .and_then(JsonValue::as_u64)
+            .and_then(|port| u16::try_from(port).ok());
+
+        Ok(Self {
+            token: Secret(token),
+            expires_at,
+            band_id,
+            target,
+            host,
+            port,
+        })
+    }
+}
+
+/// Rejected requests are reported with the error returned by the server, so that a missing token
+/// is only reported for successful responses which lack one.
+pub(crate) async fn request_token<C>(
+    client: &C,
+    band_id: BandId,
+    target: FpsTarget,
+) -> Result<FpsToken, Error>
+where
+    C: Api + ?Sized,
+{
+    let url = client.path_to_url("fps")?;
+    let mut payload = serde_json::json!({
+        "band": format!("/api/satellite_bands/{}", band_id),
+    });
+    match target {
+        FpsTarget::Satellite(id) => {
+            payload["satellite"] = format!("/api/satellites/{}", id).into();
+        }
+        FpsTarget::SiteConfiguration(id) => {
+            payload["configuration"] = format!("/api/configurations/{}", id).into();
+        }
+    }
+
+    tracing::debug!(%payload, "Requesting FPS token");
+    let value: JsonValue = match client.post_deserialize(url, &payload).await {
+        Ok(value) => value,
+        Err(error) => {
+            tracing::warn!(%payload, %error, "Failed to request FPS token");
+            return Err(error);
+        }
+    };
+
+    let default_host = client.config().environment().fps_host();
+    match FpsToken::from_response(&value, band_id, target, default_host) {
+        Ok(token) => Ok(token),
+        Err(error) => {
+            match client.log_bodies_enabled() {
+                true => {
+                    tracing::warn!(%payload, response = %value, %error, "Invalid FPS token response")
+                }
+                false => tracing::warn!(%payload, %error, "Invalid FPS token response"),
+            }
+            Err(error)
+        }
+    }
+}
+
+/// Caches an FPS token for each band and target, requesting a new token once the cached one is
+/// about to expire
+///
+/// # Example
+///
+/// ```no_run
+/// # use freedom_api::prelude::*;
+/// # tokio_test::block_on(async {
+/// let provider = TokenProvider::new(Client::from_env()?);
+///
+/// let token = provider
+///     .token(42, FpsTarget::Satellite(SatelliteId::from(101)))
+///     .await?;
+/// println!("Connecting to {}:{:?}", token.host, token.port);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct TokenProvider<C> {
+    client: C,
+    margin: Duration,
+    tokens: Mutex<HashMap<(BandId, FpsTarget), FpsToken>>,
+}
+
+impl<C: Api> TokenProvider<C> {
+    /// A provider which requests new tokens 30 seconds before the cached ones expire
+    pub fn new(client: C) -> Self {
+        Self {
+            client,
+            margin: Duration::from_secs(30),
+            tokens: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// How long before the expiry of a cached token a new token is requested
+    pub fn refresh_margin(mut self, margin: Duration) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// The cached token for the band and target, or a new token when none is cached or the cached
+    /// token is about to expire
+    pub async fn token(
+        &self,
+        band_id: impl Into<BandId>,
+        target: FpsTarget,
+    ) -> Result<FpsToken, Error> {
+        let band_id: BandId = band_id.into();
+        // Holding the lock while requesting avoids requesting the same token concurrently
+        let mut tokens = self.tokens.lock().await;
+        if let Some(token) = tokens.get(&(band_id, target)) {
+            if !token.expires_within(self.margin) {
+                return Ok(token.clone());
+            }
+        }
+
+        let token = self.client.request_fps_token(band_id, target).await?;
+        tokens.insert((band_id, target), token.clone());
+
+        Ok(token)
+    }
+
+    /// Forget every cached token
+    pub async fn clear(&self) {
+        self.tokens.lock().await.clear();
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn expiry_parses_from_timestamp_or_epoch() {
+        let expected = datetime!(2024-10-01 12:00 UTC);
+
+        let token = FpsToken::from_response(
+            &json!({ "token": "abc", "expiration": "2024-10-01T12:00:00Z", "port": 20100 }),
+            BandId::from(1),
+            FpsTarget::Satellite(SatelliteId::from(2)),
+            "fps.example.com",
+        )
+        .unwrap();
+        assert_eq!(token.expires_at, Some(expected));
+        assert_eq!(token.host, "fps.example.com");
+        assert_eq!(token.port, Some(20100));
+        assert!(token.is_expired());
+
+        let token = FpsToken::from_response(
+            &json!({ "token": "abc", "expiresAt": expected.unix_timestamp(), "host": "fps" }),
+            BandId::from(1),
+            FpsTarget::Satellite(SatelliteId::from(2)),
+            "fps.example.com",
+        )
+        .unwrap();
+        assert_eq!(token.expires_at, Some(expected));
+        assert_eq!(token.host, "fps");
+    }
+
+    #[test]
+    fn tokens_without_expiry_never_expire() {
+        let token = FpsToken::from_response(
+            &json!({ "token": "abc", "expiration": "soon" }),
+            BandId::from(1),
+            FpsTarget::SiteConfiguration(SiteConfigurationId::from(2)),
+            "fps.example.com",
+        )
+        .unwrap();
+
+        assert_eq!(token.expires_at, None);
+        assert!(!token.is_expired());
+        assert!(!format!("{token:?}").contains("abc"));
+    }
+}
@@ -0,0 +1,128 @@
+use freedom_models::task::TaskRequest;
+use futures_util::TryStreamExt;
+use time::OffsetDateTime;
+
+use crate::{
+    api::{Api, PaginatedStream},
+    error::Error,
+    extensions::TaskRequestExt,
+    ids::{BandId, SatelliteConfigurationId, TaskRequestId},
+};
+
+/// The number of upcoming task requests listed in a [`UsageReport`]
+const NEXT_UPCOMING: usize = 5;
+
+/// Summarizes the task requests which reference an entity, such as a band or configuration
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UsageReport {
+    /// The number of referencing task requests whose target date has passed
+    pub past: usize,
+    /// The number of referencing task requests whose target date has yet to pass
+    pub upcoming: usize,
+    /// The soonest of the upcoming task requests, ordered by target date
+    pub next_upcoming: Vec<UpcomingRequest>,
+}
+
+impl UsageReport {
+    /// Whether any task request which has yet to occur references the entity
+    pub fn has_upcoming(&self) -> bool {
+        self.upcoming > 0
+    }
+
+    fn record(&mut self, request: &TaskRequest, now: OffsetDateTime) {
+        let target_date = request.target_date;
+        if target_date < now {
+            self.past += 1;
+            return;
+        }
+
+        self.upcoming += 1;
+        if let Ok(id) = request.get_id() {
+            self.next_upcoming.push(UpcomingRequest { id, target_date });
+            self.next_upcoming
+                .sort_by_key(|upcoming| upcoming.target_date);
+            self.next_upcoming.truncate(NEXT_UPCOMING);
+        }
+    }
+}
+
+/// A task request which has yet to occur
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpcomingRequest {
+    pub id: TaskRequestId,
+    pub target_date: OffsetDateTime,
+}
+
+pub(crate) async fn configuration_usage<C>(
+    client: &C,
+    id: SatelliteConfigurationId,
+) -> Result<UsageReport, Error>
+where
+    C: Api,
+{
+    let configuration = client.path_to_url(format!("satellite_configurations/{id}"))?;
+
+    summarize(client.get_requests_by_configuration(configuration.as_str())).await
+}
+
+pub(crate) async fn band_usage<C>(client: &C, id: BandId) -> Result<UsageReport, Error>
+where
+    C: Api,
+{
+    summarize(client.get_requests_by_band_id(id)).await
+}
+
+/// Record every task request found by the search
+async fn summarize<T>(requests: PaginatedStream<'_, T>) -> Result<UsageReport, Error>
+where
+    T: std::ops::Deref<Target = TaskRequest>,
+{
+    let now = OffsetDateTime::now_utc();
+
+    requests
+        .try_fold(UsageReport::default(), |mut report, request| async move {
+            report.record(&request, now);
+            Ok(report)
+        })
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{macros::datetime, Duration};
+
+    use super::*;
+
+    fn request(id: i32, target_date: OffsetDateTime) -> TaskRequest {
+        let mut request: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string("resources/requests_created_104.json").unwrap(),
+        )
+        .unwrap();
+        request["targetDate"] = serde_json::Value::String(
+            target_date
+                .format(&time::format_description::well_known::Rfc3339)
+                .unwrap(),
+        );
+        request["_links"]["self"]["href"] =
+            format!("http://localhost:8080/api/requests/{id}").into();
+
+        serde_json::from_value(request).unwrap()
+    }
+
+    #[test]
+    fn report_lists_soonest_upcoming_requests() {
+        let now = datetime!(2024-10-01 00:00 UTC);
+        let mut report = UsageReport::default();
+
+        report.record(&request(1, now - Duration::days(1)), now);
+        for id in 2..10 {
+            report.record(&request(id, now + Duration::days(20 - i64::from(id))), now);
+        }
+
+        assert_eq!(report.past, 1);
+        assert_eq!(report.upcoming, 8);
+        assert!(report.has_upcoming());
+        let ids: Vec<_> = report.next_upcoming.iter().map(|next| next.id).collect();
+        assert_eq!(ids, [9, 8, 7, 6, 5]);
+    }
+}
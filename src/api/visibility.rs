@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// A window during which a satellite is visible from a site, and so a pass may be scheduled
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Visibility {
+    /// The name of the site from which the satellite is visible
+    pub site: String,
+    /// The time at which the satellite rises above the horizon of the site
+    #[serde(with = "time::serde::rfc3339")]
+    pub start: OffsetDateTime,
+    /// The time at which the satellite sets below the horizon of the site
+    #[serde(with = "time::serde::rfc3339")]
+    pub end: OffsetDateTime,
+    /// The highest elevation of the satellite during the window, in degrees
+    pub max_elevation: f64,
+}
@@ -0,0 +1,241 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::{Hash, Hasher},
+    time::Duration,
+};
+
+use async_stream::stream;
+use freedom_models::task::TaskRequest;
+use futures_util::StreamExt;
+use time::OffsetDateTime;
+
+use crate::{
+    api::{
+        pagination::{PageOptions, SortOrder},
+        Api, PaginatedStream,
+    },
+    error::Error,
+    extensions::TaskRequestExt,
+    ids::TaskRequestId,
+};
+
+/// Identifies a task request among those sharing a creation time
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Key {
+    /// The ID from the self link of the task request
+    Id(TaskRequestId),
+    /// A fingerprint of the contents, for task requests without a usable self link
+    Content(u64),
+}
+
+impl Key {
+    fn of(request: &TaskRequest) -> Self {
+        match request.get_id() {
+            Ok(id) => Self::Id(id),
+            Err(_) => {
+                let mut hasher = DefaultHasher::new();
+                serde_json::to_vec(request)
+                    .unwrap_or_default()
+                    .hash(&mut hasher);
+                Self::Content(hasher.finish())
+            }
+        }
+    }
+}
+
+/// Tracks the newest task request observed so far.
+///
+/// Task requests are ordered by their creation time, however several requests may share the same
+/// creation time. To avoid yielding those twice, or missing those which arrive late, the key of
+/// every request created at exactly the checkpoint is remembered.
+#[derive(Debug, Default)]
+struct Checkpoint {
+    created: Option<OffsetDateTime>,
+    seen: HashSet<Key>,
+}
+
+impl Checkpoint {
+    /// Whether the task request is older than, or has already been observed at, the checkpoint
+    fn is_stale(&self, created: OffsetDateTime, key: &Key) -> bool {
+        match self.created {
+            Some(checkpoint) if created < checkpoint => true,
+            Some(checkpoint) if created == checkpoint => self.seen.contains(key),
+            _ => false,
+        }
+    }
+
+    /// Advance the checkpoint, which never moves backwards
+    fn observe(&mut self, created: OffsetDateTime, key: Key) {
+        match self.created {
+            Some(checkpoint) if created < checkpoint => return,
+            Some(checkpoint) if created == checkpoint => {}
+            _ => {
+                self.created = Some(created);
+                self.seen.clear();
+            }
+        }
+
+        self.seen.insert(key);
+    }
+}
+
+pub(crate) fn watch_requests<C>(
+    client: &C,
+    poll_interval: Duration,
+) -> PaginatedStream<'_, C::Container<TaskRequest>>
+where
+    C: Api,
+{
+    Box::pin(stream! {
+        let mut checkpoint = Checkpoint::default();
+        let mut seeded = false;
+        let mut backoff = client.backoff();
+        let mut failures = 0;
+
+        loop {
+            let result = if seeded {
+                poll(client, &checkpoint).await
+            } else {
+                seed(client).await.map(|newest| {
+                    checkpoint = newest;
+                    seeded = true;
+                    Vec::new()
+                })
+            };
+
+            match result {
+                Ok(fresh) => {
+                    failures = 0;
+                    for request in fresh.into_iter().rev() {
+                        checkpoint.observe(request.created, Key::of(&request));
+                        yield Ok(request);
+                    }
+                    tokio::time::sleep(poll_interval).await;
+                }
+                Err(error) => {
+                    // Never poll more frequently than requested, nor give up on polling
+                    let delay = backoff
+                        .next_delay(failures)
+                        .map_or(poll_interval, |delay| delay.max(poll_interval));
+                    failures += 1;
+
+                    tracing::warn!(%error, ?delay, "Failed to poll for new task requests");
+                    yield Err(error);
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    })
+}
+
+fn newest_first<C>(client: &C) -> PaginatedStream<'_, C::Container<TaskRequest>>
+where
+    C: Api,
+{
+    let options = PageOptions::new().sort("created", SortOrder::Descending);
+
+    client.get_requests_with_options(options)
+}
+
+/// Place the checkpoint at the newest task requests without yielding any of them, so that watching
+/// starts from now rather than replaying the history
+///
+/// Only the task requests sharing the newest creation time are fetched.
+async fn seed<C>(client: &C) -> Result<Checkpoint, Error>
+where
+    C: Api,
+{
+    let mut requests = newest_first(client);
+
+    let mut checkpoint = Checkpoint::default();
+    while let Some(request) = requests.next().await {
+        let request = request?;
+
+        if checkpoint
+            .created
+            .is_some_and(|created| request.created < created)
+        {
+            break;
+        }
+
+        checkpoint.observe(request.created, Key::of(&request));
+    }
+
+    Ok(checkpoint)
+}
+
+/// Fetch every task request which is newer than the checkpoint, newest first
+async fn poll<C>(
+    client: &C,
+    checkpoint: &Checkpoint,
+) -> Result<Vec<C::Container<TaskRequest>>, Error>
+where
+    C: Api,
+{
+    let mut requests = newest_first(client);
+
+    let mut fresh = Vec::new();
+    while let Some(request) = requests.next().await {
+        let request = request?;
+
+        if checkpoint
+            .created
+            .is_some_and(|created| request.created < created)
+        {
+            break;
+        }
+
+        if !checkpoint.is_stale(request.created, &Key::of(&request)) {
+            fresh.push(request);
+        }
+    }
+
+    Ok(fresh)
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn key(id: i32) -> Key {
+        Key::Id(id.into())
+    }
+
+    #[test]
+    fn checkpoint_is_monotonic() {
+        let mut checkpoint = Checkpoint::default();
+        checkpoint.observe(datetime!(2024-10-01 12:00 UTC), key(1));
+        checkpoint.observe(datetime!(2024-10-01 11:00 UTC), key(2));
+
+        assert_eq!(checkpoint.created, Some(datetime!(2024-10-01 12:00 UTC)));
+        assert!(checkpoint.is_stale(datetime!(2024-10-01 11:00 UTC), &key(3)));
+    }
+
+    #[test]
+    fn checkpoint_tracks_equal_timestamps() {
+        let mut checkpoint = Checkpoint::default();
+        let created = datetime!(2024-10-01 12:00 UTC);
+        checkpoint.observe(created, key(1));
+
+        assert!(checkpoint.is_stale(created, &key(1)));
+        assert!(!checkpoint.is_stale(created, &key(2)));
+
+        checkpoint.observe(created, key(2));
+        assert!(checkpoint.is_stale(created, &key(2)));
+
+        checkpoint.observe(datetime!(2024-10-01 13:00 UTC), key(3));
+        assert!(!checkpoint.is_stale(datetime!(2024-10-01 13:00 UTC), &key(1)));
+    }
+
+    #[test]
+    fn requests_without_links_at_the_checkpoint_are_not_dropped() {
+        let mut checkpoint = Checkpoint::default();
+        let created = datetime!(2024-10-01 12:00 UTC);
+        checkpoint.observe(created, Key::Content(1));
+
+        assert!(checkpoint.is_stale(created, &Key::Content(1)));
+        assert!(!checkpoint.is_stale(created, &Key::Content(2)));
+    }
+}
@@ -0,0 +1,166 @@
+//! Authentication of the requests sent by the client
+use std::{fmt, future::Future, pin::Pin, sync::Arc};
+
+use freedom_config::{Config, Secret};
+use reqwest::{
+    header::{HeaderValue, AUTHORIZATION},
+    RequestBuilder,
+};
+use tokio::sync::Mutex;
+
+use crate::error::Error;
+
+type RefreshFuture = Pin<Box<dyn Future<Output = Result<String, Error>> + Send>>;
+
+/// How the [`Client`](crate::Client) authenticates each request
+///
+/// By default, the key and secret of the config are sent with basic authentication. Deployments
+/// which front Freedom with a gateway may require a bearer token or another `Authorization`
+/// header instead.
+///
+/// # Example
+///
+/// ```
+/// # use freedom_api::prelude::*;
+/// let config = Config::builder()
+///     .environment(Test)
+///     .key("foo")
+///     .secret("bar")
+///     .build()
+///     .unwrap();
+/// let client = Client::from_config(config).with_auth(AuthMode::bearer("eyJhbGciOi..."));
+/// ```
+#[derive(Clone)]
+pub enum AuthMode {
+    /// Basic authentication with the key and secret
+    Basic { key: String, secret: Secret<String> },
+    /// A bearer token which does not expire
+    Bearer { token: Secret<String> },
+    /// The value of the `Authorization` header, sent as is
+    Custom(HeaderValue),
+    /// A bearer token which is fetched with a callback, see [`AuthMode::refreshing`]
+    Refreshing(RefreshingToken),
+}
+
+/// A bearer token which is fetched when first needed, and fetched again once rejected
+#[derive(Clone)]
+pub struct RefreshingToken {
+    refresh: Arc<dyn Fn() -> RefreshFuture + Send + Sync>,
+    current: Arc<Mutex<Option<String>>>,
+}
+
+impl AuthMode {
+    /// Basic authentication with the key and secret of the config
+    pub fn from_config(config: &Config) -> Self {
+        Self::Basic {
+            key: config.key().to_owned(),
+            secret: Secret(config.expose_secret().to_owned()),
+        }
+    }
+
+    /// A bearer token which does not expire
+    pub fn bearer(token: impl Into<String>) -> Self {
+        Self::Bearer {
+            token: Secret(token.into()),
+        }
+    }
+
+    /// A bearer token produced by the callback, such as a JWT which expires
+    ///
+    /// The callback is invoked for the first request, and again whenever the server rejects the
+    /// token with a `401 Unauthorized`, in which case the request is sent once more with the new
+    /// token. Failures of the callback fail the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// async fn fetch_jwt() -> Result<String, freedom_api::error::Error> {
+    ///     Ok(String::from("eyJhbGciOi..."))
+    /// }
+    ///
+    /// let auth = AuthMode::refreshing(fetch_jwt);
+    /// ```
+    pub fn refreshing<F, Fut>(refresh: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, Error>> + Send + 'static,
+    {
+        Self::Refreshing(RefreshingToken {
+            refresh: Arc::new(move || Box::pin(refresh())),
+            current: Arc::default(),
+        })
+    }
+
+    /// Authenticate the request, returning the refreshing token which was sent, if any
+    pub(crate) async fn apply(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<(RequestBuilder, Option<String>), Error> {
+        let authenticated = match self {
+            Self::Basic { key, secret } => (request.basic_auth(key, Some(&secret.0)), None),
+            Self::Bearer { token } => (request.bearer_auth(&token.0), None),
+            Self::Custom(value) => (request.header(AUTHORIZATION, value.clone()), None),
+            Self::Refreshing(token) => {
+                let token = token.get().await?;
+                (request.bearer_auth(&token), Some(token))
+            }
+        };
+
+        Ok(authenticated)
+    }
+
+    /// Discard the token once rejected by the server, returning whether a new token can be
+    /// fetched
+    ///
+    /// The token is only discarded while it is still the cached one, so a token which another
+    /// request has already refreshed is kept.
+    pub(crate) async fn invalidate(&self, rejected: Option<&str>) -> bool {
+        match (self, rejected) {
+            (Self::Refreshing(token), Some(rejected)) => {
+                token.invalidate(rejected).await;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl RefreshingToken {
+    async fn get(&self) -> Result<String, Error> {
+        let mut current = self.current.lock().await;
+        if let Some(token) = current.as_ref() {
+            return Ok(token.clone());
+        }
+
+        let token = (self.refresh)().await?;
+        *current = Some(token.clone());
+
+        Ok(token)
+    }
+
+    async fn invalidate(&self, rejected: &str) {
+        let mut current = self.current.lock().await;
+        if current.as_deref() == Some(rejected) {
+            *current = None;
+        }
+    }
+}
+
+impl fmt::Debug for AuthMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Basic { key, .. } => f
+                .debug_struct("Basic")
+                .field("key", key)
+                .field("secret", &"REDACTED")
+                .finish(),
+            Self::Bearer { .. } => f
+                .debug_struct("Bearer")
+                .field("token", &"REDACTED")
+                .finish(),
+            Self::Custom(_) => f.debug_tuple("Custom").field(&"REDACTED").finish(),
+            Self::Refreshing(_) => f.debug_tuple("Refreshing").field(&"REDACTED").finish(),
+        }
+    }
+}
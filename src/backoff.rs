@@ -0,0 +1,355 @@
+//! Backoff strategies shared by retries and polling
+use std::{
+    collections::hash_map::RandomState,
+    fmt::Debug,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// Determines the delay between successive attempts of an operation
+///
+/// Strategies are cloned for every operation which backs off, so any state kept between attempts
+/// is never shared between operations.
+///
+/// # Example
+///
+/// ```
+/// # use freedom_api::prelude::*;
+/// # use std::time::Duration;
+/// let mut backoff = Exponential::new(Duration::from_millis(100), Duration::from_secs(10))
+///     .with_jitter(Jitter::Full)
+///     .max_attempts(5);
+///
+/// assert!(backoff.next_delay(0).unwrap() <= Duration::from_millis(100));
+/// assert!(backoff.next_delay(5).is_none());
+/// ```
+pub trait Backoff: BackoffClone + Debug + Send + Sync {
+    /// The delay before the next attempt, given the number of attempts which have already failed,
+    /// or `None` when no further attempts should be made
+    fn next_delay(&mut self, attempt: u32) -> Option<Duration>;
+}
+
+/// Allows boxed strategies to be cloned, implemented for every strategy which is [`Clone`]
+#[doc(hidden)]
+pub trait BackoffClone {
+    fn clone_box(&self) -> Box<dyn Backoff>;
+}
+
+impl<T: Backoff + Clone + 'static> BackoffClone for T {
+    fn clone_box(&self) -> Box<dyn Backoff> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Backoff> {
+    fn clone(&self) -> Self {
+        (**self).clone_box()
+    }
+}
+
+/// Decorators available on every [`Backoff`]
+pub trait BackoffExt: Backoff + Sized {
+    /// Randomize each delay of the strategy
+    fn with_jitter(self, jitter: Jitter) -> Jittered<Self> {
+        Jittered::new(self, jitter)
+    }
+
+    /// Give up once the number of failed attempts reaches the maximum
+    fn max_attempts(self, max_attempts: u32) -> Limited<Self> {
+        Limited {
+            inner: self,
+            max_attempts,
+        }
+    }
+}
+
+impl<T: Backoff> BackoffExt for T {}
+
+/// The delay doubles with each attempt, starting from the base delay, up to the maximum delay
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exponential {
+    base: Duration,
+    max: Duration,
+    factor: u32,
+}
+
+impl Exponential {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self {
+            base,
+            max,
+            factor: 2,
+        }
+    }
+
+    /// The multiplier applied to the delay with each attempt. Defaults to 2.
+    pub fn factor(mut self, factor: u32) -> Self {
+        self.factor = factor;
+        self
+    }
+}
+
+impl Backoff for Exponential {
+    fn next_delay(&mut self, attempt: u32) -> Option<Duration> {
+        let factor = self.factor.saturating_pow(attempt);
+
+        Some(self.base.saturating_mul(factor).min(self.max))
+    }
+}
+
+/// The delay follows the Fibonacci sequence in multiples of the base delay, up to the maximum
+/// delay
+///
+/// This grows more gently than [`Exponential`], which suits polling for a state which is expected
+/// to arrive soon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fibonacci {
+    base: Duration,
+    max: Duration,
+}
+
+impl Fibonacci {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self { base, max }
+    }
+}
+
+impl Backoff for Fibonacci {
+    fn next_delay(&mut self, attempt: u32) -> Option<Duration> {
+        let (mut current, mut next) = (1_u32, 1_u32);
+        for _ in 0..attempt {
+            (current, next) = (next, current.saturating_add(next));
+        }
+
+        Some(self.base.saturating_mul(current).min(self.max))
+    }
+}
+
+/// The same delay before every attempt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constant {
+    delay: Duration,
+}
+
+impl Constant {
+    pub fn new(delay: Duration) -> Self {
+        Self { delay }
+    }
+}
+
+impl Backoff for Constant {
+    fn next_delay(&mut self, _attempt: u32) -> Option<Duration> {
+        Some(self.delay)
+    }
+}
+
+/// How the delays of a strategy are randomized, to avoid many clients retrying in lockstep
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jitter {
+    /// Anywhere between zero and the delay
+    Full,
+    /// Half of the delay is fixed, while the other half is random
+    Equal,
+}
+
+/// Randomizes the delays of the wrapped strategy
+///
+/// Unless [seeded](Self::seeded), each clone of the strategy draws from a fresh random seed.
+#[derive(Debug)]
+pub struct Jittered<B> {
+    inner: B,
+    jitter: Jitter,
+    seeded: bool,
+    rng: SplitMix64,
+}
+
+impl<B: Backoff> Jittered<B> {
+    pub fn new(inner: B, jitter: Jitter) -> Self {
+        Self {
+            inner,
+            jitter,
+            seeded: false,
+            rng: SplitMix64::random(),
+        }
+    }
+
+    /// Draw the jitter from a deterministic sequence, shared by every clone of the strategy
+    pub fn seeded(inner: B, jitter: Jitter, seed: u64) -> Self {
+        Self {
+            inner,
+            jitter,
+            seeded: true,
+            rng: SplitMix64(seed),
+        }
+    }
+}
+
+impl<B: Clone> Clone for Jittered<B> {
+    fn clone(&self) -> Self {
+        let rng = match self.seeded {
+            true => self.rng.clone(),
+            false => SplitMix64::random(),
+        };
+
+        Self {
+            inner: self.inner.clone(),
+            jitter: self.jitter,
+            seeded: self.seeded,
+            rng,
+        }
+    }
+}
+
+impl<B: Backoff + Clone + 'static> Backoff for Jittered<B> {
+    fn next_delay(&mut self, attempt: u32) -> Option<Duration> {
+        let delay = self.inner.next_delay(attempt)?;
+        let fraction = self.rng.next_fraction();
+
+        Some(match self.jitter {
+            Jitter::Full => delay.mul_f64(fraction),
+            Jitter::Equal => {
+                let half = delay / 2;
+                half + half.mul_f64(fraction)
+            }
+        })
+    }
+}
+
+/// Gives up once the number of failed attempts reaches the maximum
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Limited<B> {
+    inner: B,
+    max_attempts: u32,
+}
+
+impl<B: Backoff + Clone + 'static> Backoff for Limited<B> {
+    fn next_delay(&mut self, attempt: u32) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        self.inner.next_delay(attempt)
+    }
+}
+
+/// The strategy used by clients which have not been configured with another
+pub(crate) fn default_strategy() -> Box<dyn Backoff> {
+    Box::new(
+        Exponential::new(Duration::from_secs(1), Duration::from_secs(60))
+            .with_jitter(Jitter::Equal),
+    )
+}
+
+/// A small, seedable pseudo-random number generator
+#[derive(Debug, Clone)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// Seeded from the randomly seeded std hasher
+    fn random() -> Self {
+        Self(RandomState::new().build_hasher().finish())
+    }
+
+    /// A value in the range `[0, 1)`
+    fn next_fraction(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        (z >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(backoff: &mut impl Backoff, attempts: u32) -> Vec<Option<u128>> {
+        (0..attempts)
+            .map(|attempt| backoff.next_delay(attempt).map(|delay| delay.as_millis()))
+            .collect()
+    }
+
+    #[test]
+    fn exponential_sequence() {
+        let mut backoff = Exponential::new(Duration::from_millis(100), Duration::from_millis(1000));
+
+        assert_eq!(
+            millis(&mut backoff, 6),
+            [100, 200, 400, 800, 1000, 1000].map(Some)
+        );
+        assert_eq!(
+            backoff.next_delay(u32::MAX),
+            Some(Duration::from_millis(1000))
+        );
+
+        let mut backoff =
+            Exponential::new(Duration::from_millis(10), Duration::from_secs(10)).factor(3);
+        assert_eq!(millis(&mut backoff, 4), [10, 30, 90, 270].map(Some));
+    }
+
+    #[test]
+    fn fibonacci_sequence() {
+        let mut backoff = Fibonacci::new(Duration::from_millis(100), Duration::from_millis(1000));
+
+        assert_eq!(
+            millis(&mut backoff, 8),
+            [100, 100, 200, 300, 500, 800, 1000, 1000].map(Some)
+        );
+        assert_eq!(
+            backoff.next_delay(u32::MAX),
+            Some(Duration::from_millis(1000))
+        );
+    }
+
+    #[test]
+    fn constant_sequence() {
+        let mut backoff = Constant::new(Duration::from_millis(250));
+
+        assert_eq!(millis(&mut backoff, 3), [250, 250, 250].map(Some));
+    }
+
+    #[test]
+    fn limited_gives_up() {
+        let mut backoff = Constant::new(Duration::from_millis(250)).max_attempts(2);
+
+        assert_eq!(millis(&mut backoff, 4), [Some(250), Some(250), None, None]);
+    }
+
+    #[test]
+    fn jitter_within_bounds() {
+        let constant = Constant::new(Duration::from_millis(1000));
+        let mut full = Jittered::seeded(constant.clone(), Jitter::Full, 42);
+        let mut equal = Jittered::seeded(constant, Jitter::Equal, 42);
+
+        for attempt in 0..1000 {
+            let delay = full.next_delay(attempt).unwrap();
+            assert!(delay < Duration::from_millis(1000), "{delay:?}");
+
+            let delay = equal.next_delay(attempt).unwrap();
+            assert!(delay >= Duration::from_millis(500), "{delay:?}");
+            assert!(delay < Duration::from_millis(1000), "{delay:?}");
+        }
+    }
+
+    #[test]
+    fn seeded_jitter_is_deterministic() {
+        let exponential = Exponential::new(Duration::from_millis(100), Duration::from_secs(10));
+        let mut first = Jittered::seeded(exponential, Jitter::Full, 7);
+        let mut second = first.clone();
+
+        assert_eq!(millis(&mut first, 10), millis(&mut second, 10));
+    }
+
+    #[test]
+    fn boxed_strategies_clone() {
+        let backoff: Box<dyn Backoff> =
+            Box::new(Constant::new(Duration::from_millis(5)).max_attempts(1));
+        let mut cloned = backoff.clone();
+
+        assert_eq!(cloned.next_delay(0), Some(Duration::from_millis(5)));
+        assert_eq!(cloned.next_delay(1), None);
+    }
+}
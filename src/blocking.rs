@@ -0,0 +1,367 @@
+//! # Blocking client
+//!
+//! A synchronous wrapper of the asynchronous [`Client`](crate::Client), for tools which would
+//! otherwise only pull in an executor to make a handful of queries. Each call is driven to
+//! completion on a runtime owned by the client.
+//!
+//! The blocking client must not be used from within an asynchronous context, such as a task
+//! spawned on a tokio runtime, in which case it panics. Use the asynchronous client there instead.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use freedom_api::{blocking, prelude::*};
+//!
+//! let client = blocking::Client::from_env()?;
+//!
+//! let configuration = client.get_satellite_configuration_by_id(812)?;
+//! println!("{}", configuration.name);
+//!
+//! for satellite in client.get_satellites() {
+//!     println!("{}", satellite?.name);
+//! }
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use std::{future::Future, sync::Arc};
+
+use freedom_config::Config;
+use freedom_models::{
+    account::Account,
+    band::Band,
+    satellite::Satellite,
+    satellite_configuration::SatelliteConfiguration,
+    site::{Site, SiteConfiguration},
+    task::{Task, TaskRequest},
+    user::User,
+};
+use futures_util::StreamExt;
+use tokio::runtime::Runtime;
+
+use crate::{
+    api::{
+        resource::{NamedResource, Resource},
+        Api, Container, PaginatedStream, Value,
+    },
+    client::Inner,
+    error::Error,
+    ids::{
+        AccountId, BandId, SatelliteConfigurationId, SatelliteId, SiteConfigurationId, SiteId,
+        TaskId, TaskRequestId, UserId,
+    },
+};
+
+/// A blocking `Client` for interfacing with the ATLAS freedom API
+///
+/// Mirrors the most used queries of the asynchronous [`Client`](crate::Client), returning the
+/// models themselves rather than a [`Container`]. Cloning the client is cheap, and the clones share
+/// the runtime and HTTP connections.
+#[derive(Clone, Debug)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<Runtime>,
+}
+
+impl From<crate::Client> for Client {
+    /// # Panics
+    ///
+    /// Panics when the runtime cannot be initialized
+    fn from(inner: crate::Client) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to initialize the runtime of the blocking client");
+
+        Self {
+            inner,
+            runtime: Arc::new(runtime),
+        }
+    }
+}
+
+impl Client {
+    /// Construct a blocking API client from the provided Freedom config
+    ///
+    /// # Panics
+    ///
+    /// Panics when the runtime cannot be initialized
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::{blocking, prelude::*};
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = blocking::Client::from_config(config);
+    ///
+    /// assert_eq!(client.config().key(), "foo");
+    /// ```
+    pub fn from_config(config: Config) -> Self {
+        Self::from(crate::Client::from_config(config))
+    }
+
+    /// A convenience method for constructing a blocking client from environment variables, see
+    /// [`Client::from_env`](crate::Client::from_env)
+    pub fn from_env() -> Result<Self, freedom_config::Error> {
+        crate::Client::from_env().map(Self::from)
+    }
+
+    /// The asynchronous client wrapped by the blocking client
+    pub fn as_async(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// The config of the client
+    pub fn config(&self) -> &Config {
+        self.inner.config()
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    fn fetch<T, F>(&self, future: F) -> Result<T, Error>
+    where
+        T: Value,
+        F: Future<Output = Result<Inner<T>, Error>>,
+    {
+        self.block_on(future).map(Container::into_inner)
+    }
+
+    /// Produces a single [`Resource`] matching the provided ID, see [`Api::get_by_id`]
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::{blocking, prelude::*};
+    /// let client = blocking::Client::from_env()?;
+    ///
+    /// let satellite = client.get_by_id::<Satellite>(42)?;
+    /// println!("{}", satellite.name);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get_by_id<T: Resource>(&self, id: impl Into<T::Id> + Send) -> Result<T, Error> {
+        self.fetch(self.inner.get_by_id(id))
+    }
+
+    /// Produces a single [`NamedResource`] matching the provided name, see [`Api::find_by_name`]
+    pub fn find_by_name<T: NamedResource>(&self, name: impl AsRef<str> + Send) -> Result<T, Error> {
+        self.fetch(self.inner.find_by_name(name))
+    }
+
+    /// Produces an iterator over every [`Resource`] of the collection, see [`Api::list`]
+    ///
+    /// Each page is fetched as the iterator reaches it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::{blocking, prelude::*};
+    /// let client = blocking::Client::from_env()?;
+    ///
+    /// for request in client.list::<TaskRequest>().take(10) {
+    ///     println!("{}", request?.target_date);
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn list<T: Resource>(&self) -> PaginatedIter<'_, T> {
+        PaginatedIter::new(self, self.inner.list())
+    }
+
+    /// Collect every [`Resource`] of the collection, failing on the first error
+    pub fn list_all<T: Resource>(&self) -> Result<Vec<T>, Error> {
+        self.list().collect()
+    }
+
+    /// See [`Api::get_account_by_id`]
+    pub fn get_account_by_id(&self, id: impl Into<AccountId> + Send) -> Result<Account, Error> {
+        self.fetch(self.inner.get_account_by_id(id))
+    }
+
+    /// See [`Api::get_account_by_name`]
+    pub fn get_account_by_name(&self, name: &str) -> Result<Account, Error> {
+        self.fetch(self.inner.get_account_by_name(name))
+    }
+
+    /// Every [`Account`], see [`Api::get_accounts`]
+    pub fn get_accounts(&self) -> PaginatedIter<'_, Account> {
+        PaginatedIter::new(self, self.inner.get_accounts())
+    }
+
+    /// See [`Api::get_satellite_band_by_id`]
+    pub fn get_satellite_band_by_id(&self, id: impl Into<BandId> + Send) -> Result<Band, Error> {
+        self.fetch(self.inner.get_satellite_band_by_id(id))
+    }
+
+    /// See [`Api::get_satellite_band_by_name`]
+    pub fn get_satellite_band_by_name(&self, name: &str) -> Result<Band, Error> {
+        self.fetch(self.inner.get_satellite_band_by_name(name))
+    }
+
+    /// Every [`Band`], see [`Api::get_satellite_bands`]
+    pub fn get_satellite_bands(&self) -> PaginatedIter<'_, Band> {
+        PaginatedIter::new(self, self.inner.get_satellite_bands())
+    }
+
+    /// See [`Api::get_satellite_configuration_by_id`]
+    pub fn get_satellite_configuration_by_id(
+        &self,
+        id: impl Into<SatelliteConfigurationId> + Send,
+    ) -> Result<SatelliteConfiguration, Error> {
+        self.fetch(self.inner.get_satellite_configuration_by_id(id))
+    }
+
+    /// See [`Api::get_satellite_configuration_by_name`]
+    pub fn get_satellite_configuration_by_name(
+        &self,
+        name: &str,
+    ) -> Result<SatelliteConfiguration, Error> {
+        self.fetch(self.inner.get_satellite_configuration_by_name(name))
+    }
+
+    /// Every [`SatelliteConfiguration`], see [`Api::get_satellite_configurations`]
+    pub fn get_satellite_configurations(&self) -> PaginatedIter<'_, SatelliteConfiguration> {
+        PaginatedIter::new(self, self.inner.get_satellite_configurations())
+    }
+
+    /// See [`Api::get_satellite_by_id`]
+    pub fn get_satellite_by_id(
+        &self,
+        id: impl Into<SatelliteId> + Send,
+    ) -> Result<Satellite, Error> {
+        self.fetch(self.inner.get_satellite_by_id(id))
+    }
+
+    /// See [`Api::get_satellite_by_name`]
+    pub fn get_satellite_by_name(&self, name: &str) -> Result<Satellite, Error> {
+        self.fetch(self.inner.get_satellite_by_name(name))
+    }
+
+    /// Every [`Satellite`], see [`Api::get_satellites`]
+    pub fn get_satellites(&self) -> PaginatedIter<'_, Satellite> {
+        PaginatedIter::new(self, self.inner.get_satellites())
+    }
+
+    /// See [`Api::get_site_by_id`]
+    pub fn get_site_by_id(&self, id: impl Into<SiteId> + Send) -> Result<Site, Error> {
+        self.fetch(self.inner.get_site_by_id(id))
+    }
+
+    /// See [`Api::get_site_by_name`]
+    pub fn get_site_by_name(&self, name: &str) -> Result<Site, Error> {
+        self.fetch(self.inner.get_site_by_name(name))
+    }
+
+    /// Every [`Site`], see [`Api::get_sites`]
+    pub fn get_sites(&self) -> PaginatedIter<'_, Site> {
+        PaginatedIter::new(self, self.inner.get_sites())
+    }
+
+    /// See [`Api::get_site_configuration_by_id`]
+    pub fn get_site_configuration_by_id(
+        &self,
+        id: impl Into<SiteConfigurationId> + Send,
+    ) -> Result<SiteConfiguration, Error> {
+        self.fetch(self.inner.get_site_configuration_by_id(id))
+    }
+
+    /// See [`Api::get_request_by_id`]
+    pub fn get_request_by_id(
+        &self,
+        id: impl Into<TaskRequestId> + Send,
+    ) -> Result<TaskRequest, Error> {
+        self.fetch(self.inner.get_request_by_id(id))
+    }
+
+    /// Every [`TaskRequest`], see [`Api::get_requests`]
+    pub fn get_requests(&self) -> PaginatedIter<'_, TaskRequest> {
+        PaginatedIter::new(self, self.inner.get_requests())
+    }
+
+    /// See [`Api::get_task_by_id`]
+    pub fn get_task_by_id(&self, id: impl Into<TaskId> + Send) -> Result<Task, Error> {
+        self.fetch(self.inner.get_task_by_id(id))
+    }
+
+    /// Every [`User`], see [`Api::get_users`]
+    pub fn get_users(&self) -> PaginatedIter<'_, User> {
+        PaginatedIter::new(self, self.inner.get_users())
+    }
+
+    /// See [`Api::delete_band_details`]
+    pub fn delete_band_details(&self, id: impl Into<BandId> + Send) -> Result<(), Error> {
+        self.block_on(self.inner.delete_band_details(id))
+            .map(|_| ())
+    }
+
+    /// See [`Api::delete_satellite_configuration`]
+    pub fn delete_satellite_configuration(
+        &self,
+        id: impl Into<SatelliteConfigurationId> + Send,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.delete_satellite_configuration(id))
+            .map(|_| ())
+    }
+
+    /// See [`Api::delete_satellite`]
+    pub fn delete_satellite(&self, id: impl Into<SatelliteId> + Send) -> Result<(), Error> {
+        self.block_on(self.inner.delete_satellite(id)).map(|_| ())
+    }
+
+    /// See [`Api::delete_user`]
+    pub fn delete_user(&self, id: impl Into<UserId> + Send) -> Result<(), Error> {
+        self.block_on(self.inner.delete_user(id)).map(|_| ())
+    }
+
+    /// See [`Api::delete_task_request`]
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::{blocking, prelude::*};
+    /// let client = blocking::Client::from_env()?;
+    ///
+    /// client.delete_task_request(42)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn delete_task_request(&self, id: impl Into<TaskRequestId> + Send) -> Result<(), Error> {
+        self.block_on(self.inner.delete_task_request(id))
+            .map(|_| ())
+    }
+}
+
+/// An iterator over the items of a paginated collection, the blocking counterpart of a
+/// [`PaginatedStream`]
+pub struct PaginatedIter<'a, T> {
+    client: &'a Client,
+    stream: PaginatedStream<'a, Inner<T>>,
+}
+
+impl<'a, T> PaginatedIter<'a, T> {
+    fn new(client: &'a Client, stream: PaginatedStream<'a, Inner<T>>) -> Self {
+        Self { client, stream }
+    }
+}
+
+impl<T> Iterator for PaginatedIter<'_, T>
+where
+    T: Value,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.client.block_on(self.stream.next())?;
+
+        Some(item.map(Container::into_inner))
+    }
+}
+
+impl<T> std::fmt::Debug for PaginatedIter<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PaginatedIter").finish_non_exhaustive()
+    }
+}
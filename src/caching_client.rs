@@ -1,16 +1,33 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
+use async_stream::stream;
 use bytes::Bytes;
 use freedom_config::Config;
-use reqwest::{Response, StatusCode};
+use futures_util::StreamExt;
+use reqwest::{header::HeaderMap, Response, StatusCode};
+use time::OffsetDateTime;
+use tokio::sync::OnceCell;
 use url::Url;
 
 use crate::{
-    api::{Api, Container, Value},
+    api::{
+        info::ServerInfo, paginate, pagination::PageOptions, provenance::Provenance, Api,
+        Container, PaginatedStream, Value,
+    },
+    backoff::Backoff,
+    client::ClientBuilder,
     error::Error,
     Client,
 };
 
+use self::coalesce::Coalescer;
+
+mod coalesce;
+
 /// An asynchronous `Client` for interfacing with the ATLAS freedom API, which implements query
 /// caching.
 ///
@@ -20,10 +37,394 @@ use crate::{
 /// As a result, the items which are returned to the caller are wrapped in [`Arc`](std::sync::Arc).
 /// This makes cloning items out of the cache extremely cheap, regardless of the object's actual
 /// size.
+///
+/// # Read your writes
+///
+/// Creating or deleting an item through the client evicts the cached responses of its collection,
+/// for instance every page of `satellites` after creating a satellite. However, the server may take
+/// a moment to reflect the change, in which case a listing made immediately afterwards may be
+/// cached without it. Enabling [`read_your_writes`](CachingClientBuilder::read_your_writes) bypasses
+/// the cache for every query of a collection for a window after it is modified through the client.
 #[derive(Clone, Debug)]
 pub struct CachingClient {
     pub(crate) inner: Client,
-    pub(crate) cache: moka::future::Cache<Url, (Bytes, StatusCode)>,
+    pub(crate) cache: moka::future::Cache<Url, CacheEntry>,
+    pub(crate) coalescer: Coalescer,
+    pub(crate) negative_ttl: Option<Duration>,
+    pub(crate) bypass: bool,
+    pub(crate) recent_writes: Option<RecentWrites>,
+}
+
+/// The collections modified through the client, and when they were modified
+#[derive(Clone, Debug)]
+pub(crate) struct RecentWrites {
+    window: Duration,
+    written: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+impl RecentWrites {
+    fn new(window: Duration) -> Self {
+        Self {
+            window,
+            written: Arc::default(),
+        }
+    }
+
+    fn record(&self, collection: String) {
+        let mut written = self.written.lock().unwrap();
+        written.retain(|_, at| at.elapsed() < self.window);
+        written.insert(collection, Instant::now());
+    }
+
+    fn contains(&self, collection: &str) -> bool {
+        let written = self.written.lock().unwrap();
+        written
+            .get(collection)
+            .is_some_and(|at| at.elapsed() < self.window)
+    }
+}
+
+/// The body, status, and time at which a response was cached
+pub(crate) type CacheEntry = (Bytes, StatusCode, OffsetDateTime);
+
+impl CachingClient {
+    /// Construct a caching client wrapping the provided client, caching up to `capacity`
+    /// responses which never expire
+    ///
+    /// See [`Self::builder`] to configure expiration. To send requests with a configured HTTP
+    /// client, wrap a client constructed with [`Client::from_config_with_client`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = CachingClient::new(Client::from_config(config), 10_000);
+    /// ```
+    pub fn new(client: Client, capacity: u64) -> Self {
+        Self::builder(client).capacity(capacity).build()
+    }
+
+    /// Configure a caching client wrapping the provided client
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// # use std::time::Duration;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = CachingClient::builder(Client::from_config(config))
+    ///     .capacity(10_000)
+    ///     .ttl(Duration::from_secs(60))
+    ///     .time_to_idle(Duration::from_secs(30))
+    ///     .build();
+    /// ```
+    pub fn builder(client: Client) -> CachingClientBuilder {
+        CachingClientBuilder {
+            client,
+            capacity: DEFAULT_CAPACITY,
+            ttl: None,
+            time_to_idle: None,
+            negative_ttl: None,
+            read_your_writes: false,
+            read_your_writes_window: Duration::from_secs(30),
+            coalesce_window: None,
+        }
+    }
+
+    /// Configure a caching client wrapping the client configured by the provided builder, failing
+    /// when the client is misconfigured, see [`ClientBuilder::build`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// # use std::time::Duration;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = CachingClient::from_builder(
+    ///     Client::builder()
+    ///         .config(config)
+    ///         .request_timeout(Duration::from_secs(30)),
+    /// )
+    /// .unwrap()
+    /// .ttl(Duration::from_secs(60))
+    /// .build();
+    /// ```
+    pub fn from_builder(builder: ClientBuilder) -> Result<CachingClientBuilder, Error> {
+        Ok(Self::builder(builder.build()?))
+    }
+
+    /// A handle to the same cache whose queries always go to the server, refreshing the cached
+    /// entries with the responses
+    ///
+    /// This guarantees a fresh read of a handful of items without evicting anything else.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = CachingClient::new(Client::from_env()?, 10_000);
+    ///
+    /// let request = client.fresh().get_request_by_id(42).await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub fn fresh(&self) -> Self {
+        Self {
+            bypass: true,
+            ..self.clone()
+        }
+    }
+
+    /// A view of the client which sends every request to the provided entrypoint, see
+    /// [`Client::with_base_url`]
+    ///
+    /// The view shares the cache of the client. Responses are cached by their full URL, so those
+    /// of the two entrypoints are kept apart.
+    pub fn with_base_url(&self, entrypoint: Url) -> Result<Self, Error> {
+        Ok(Self {
+            inner: self.inner.with_base_url(entrypoint)?,
+            ..self.clone()
+        })
+    }
+
+    /// Evict the cached response for the URL
+    pub async fn invalidate(&self, url: &Url) {
+        self.cache.invalidate(url).await;
+    }
+
+    /// Evict the cached responses for every URL beneath the path, relative to the entrypoint
+    ///
+    /// For instance, `"requests"` evicts `requests/42` along with every page and search of task
+    /// requests, while leaving `sites/14` in place.
+    pub fn invalidate_prefix(&self, path: &str) {
+        // A path which cannot be joined to the entrypoint has nothing cached beneath it
+        let Ok(prefix) = self.path_to_url(path) else {
+            return;
+        };
+        let prefix = prefix.to_string();
+        let prefix = prefix.trim_end_matches('/').to_string();
+
+        // This only fails when the cache does not support invalidation closures, which it is
+        // always built with
+        let _ = self.cache.invalidate_entries_if(move |url, _| {
+            url.as_str()
+                .strip_prefix(&prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', '?']))
+        });
+    }
+
+    /// Evict every cached response
+    pub fn invalidate_all(&self) {
+        self.cache.invalidate_all();
+    }
+
+    /// Evict every cached response for the collection the URL belongs to, for instance all of
+    /// `overrides` after creating or deleting an override
+    fn invalidate_collection(&self, url: &Url) {
+        match self.collection(url) {
+            Some(collection) => self.invalidate_prefix(&collection),
+            None => self.invalidate_all(),
+        }
+    }
+
+    /// Invalidate the collection modified by a successful mutation, and remember the write when
+    /// reading your writes
+    fn record_write(&self, url: &Url) {
+        self.invalidate_collection(url);
+
+        if let (Some(recent_writes), Some(collection)) = (&self.recent_writes, self.collection(url))
+        {
+            recent_writes.record(collection);
+        }
+    }
+
+    /// Whether the URL belongs to a collection modified within the read your writes window
+    fn recently_written(&self, url: &Url) -> bool {
+        match (&self.recent_writes, self.collection(url)) {
+            (Some(recent_writes), Some(collection)) => recent_writes.contains(&collection),
+            _ => false,
+        }
+    }
+
+    /// The first segment of the URL's path relative to the entrypoint, for instance `overrides`
+    /// for `overrides/7`
+    fn collection(&self, url: &Url) -> Option<String> {
+        let entrypoint = self.config().environment().freedom_entrypoint();
+
+        entrypoint
+            .make_relative(url)
+            .and_then(|path| path.split(['/', '?']).next().map(str::to_owned))
+            .filter(|collection| !collection.is_empty() && !collection.starts_with(".."))
+    }
+
+    /// Fetch the URL from the server without consulting the cache, refreshing the cached entry
+    /// with the response
+    pub async fn get_nocache(&self, url: Url) -> Result<(Bytes, StatusCode), Error> {
+        let (body, status, _) = self.refresh(url).await?;
+
+        Ok((body, status))
+    }
+
+    async fn refresh(&self, url: Url) -> Result<(Bytes, StatusCode, Provenance), Error> {
+        let (body, status) = self.inner.get(url.clone()).await?;
+        let fetched_at = OffsetDateTime::now_utc();
+
+        if self.is_cacheable(status) {
+            self.cache
+                .insert(url.clone(), (body.clone(), status, fetched_at))
+                .await;
+        }
+
+        let provenance = Provenance {
+            url,
+            fetched_at,
+            from_cache: false,
+        };
+
+        Ok((body, status, provenance))
+    }
+
+    /// Only successful responses are memoized, so that transient failures, or entities which are
+    /// created moments later, are not served until invalidated
+    fn is_cacheable(&self, status: StatusCode) -> bool {
+        let negative = status == StatusCode::NOT_FOUND && self.negative_ttl.is_some();
+
+        status.is_success() || negative
+    }
+}
+
+const DEFAULT_CAPACITY: u64 = 10_000;
+
+/// Configures the capacity and expiration of a [`CachingClient`]
+#[derive(Debug, Clone)]
+pub struct CachingClientBuilder {
+    client: Client,
+    capacity: u64,
+    ttl: Option<Duration>,
+    time_to_idle: Option<Duration>,
+    negative_ttl: Option<Duration>,
+    read_your_writes: bool,
+    read_your_writes_window: Duration,
+    coalesce_window: Option<Duration>,
+}
+
+impl CachingClientBuilder {
+    /// The maximum number of responses held in the cache. Defaults to 10,000.
+    pub fn capacity(mut self, capacity: u64) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Expire each response once the duration has passed since it was cached
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Expire each response once the duration has passed since it was last read
+    pub fn time_to_idle(mut self, time_to_idle: Duration) -> Self {
+        self.time_to_idle = Some(time_to_idle);
+        self
+    }
+
+    /// Cache `404 Not Found` responses for the duration, rather than fetching them anew on every
+    /// query
+    ///
+    /// Other unsuccessful responses are never cached.
+    pub fn negative_ttl(mut self, negative_ttl: Duration) -> Self {
+        self.negative_ttl = Some(negative_ttl);
+        self
+    }
+
+    /// Bypass the cache for queries of any collection modified through the client, until the
+    /// [window](Self::read_your_writes_window) has passed. Disabled by default.
+    ///
+    /// See [read your writes](CachingClient#read-your-writes).
+    pub fn read_your_writes(mut self, read_your_writes: bool) -> Self {
+        self.read_your_writes = read_your_writes;
+        self
+    }
+
+    /// How long queries of a modified collection bypass the cache. Defaults to 30 seconds.
+    pub fn read_your_writes_window(mut self, window: Duration) -> Self {
+        self.read_your_writes_window = window;
+        self
+    }
+
+    /// Share a single pagination among all identical paginated queries made within the provided
+    /// window of each other.
+    ///
+    /// Each item is then fetched and deserialized once, with the same `Arc`'d items yielded to
+    /// every consumer. Consumers arriving after the pagination has started first receive the items
+    /// yielded so far. Disabled by default.
+    pub fn coalesce_window(mut self, window: Duration) -> Self {
+        self.coalesce_window = Some(window);
+        self
+    }
+
+    /// Construct the caching client
+    pub fn build(self) -> CachingClient {
+        let mut cache = moka::future::Cache::builder()
+            .max_capacity(self.capacity)
+            .support_invalidation_closures();
+        if let Some(ttl) = self.ttl {
+            cache = cache.time_to_live(ttl);
+        }
+        if let Some(time_to_idle) = self.time_to_idle {
+            cache = cache.time_to_idle(time_to_idle);
+        }
+        if let Some(negative_ttl) = self.negative_ttl {
+            cache = cache.expire_after(NotFoundExpiry(negative_ttl));
+        }
+
+        CachingClient {
+            inner: self.client,
+            cache: cache.build(),
+            coalescer: self.coalesce_window.map(Coalescer::new).unwrap_or_default(),
+            negative_ttl: self.negative_ttl,
+            bypass: false,
+            recent_writes: self
+                .read_your_writes
+                .then(|| RecentWrites::new(self.read_your_writes_window)),
+        }
+    }
+}
+
+/// Expires cached `404 Not Found` responses after the negative TTL
+struct NotFoundExpiry(Duration);
+
+impl moka::Expiry<Url, CacheEntry> for NotFoundExpiry {
+    fn expire_after_create(
+        &self,
+        _key: &Url,
+        (_, status, _): &CacheEntry,
+        _created_at: std::time::Instant,
+    ) -> Option<Duration> {
+        (*status == StatusCode::NOT_FOUND).then_some(self.0)
+    }
+}
+
+/// The outcome of a query which is not stored in the cache
+enum Uncached {
+    Failed(Error),
+    Unsuccessful(Bytes, StatusCode, OffsetDateTime),
 }
 
 impl PartialEq for CachingClient {
@@ -34,7 +435,7 @@ impl PartialEq for CachingClient {
 
 impl<T: Value> Container<T> for Arc<T> {
     fn into_inner(self) -> T {
-        std::sync::Arc::<T>::unwrap_or_clone(self)
+        Arc::try_unwrap(self).unwrap_or_else(|arc| (*arc).clone())
     }
 }
 
@@ -42,62 +443,410 @@ impl Api for CachingClient {
     type Container<T: Value> = Arc<T>;
 
     async fn delete(&self, url: Url) -> Result<Response, Error> {
-        self.inner.delete(url).await
+        let resp = self.inner.delete(url.clone()).await?;
+        if resp.status().is_success() {
+            self.record_write(&url);
+        }
+
+        Ok(resp)
     }
 
-    #[tracing::instrument]
     async fn get(&self, url: Url) -> Result<(Bytes, StatusCode), Error> {
+        let (body, status, _) = self.get_traced(url).await?;
+
+        Ok((body, status))
+    }
+
+    async fn get_response(&self, url: Url, headers: HeaderMap) -> Result<Response, Error> {
+        self.inner.get_response(url, headers).await
+    }
+
+    async fn get_traced(&self, url: Url) -> Result<(Bytes, StatusCode, Provenance), Error> {
+        if self.bypass || self.recently_written(&url) {
+            return self.refresh(url).await;
+        }
+
         // This is a rather cheap clone. Something like 50 bytes. This is necessary since we will
         // be passing this to the tokio executor which has lifetime requirements of `'static`
         let client = &self.inner;
-        let value = self
+        let result = self
             .cache
-            .try_get_with(url.clone(), async {
-                let (body, status) = client.get(url).await?;
+            .entry(url.clone())
+            .or_try_insert_with(async {
+                let (body, status) = client.get(url.clone()).await.map_err(Uncached::Failed)?;
+                let fetched_at = OffsetDateTime::now_utc();
 
-                if !status.is_success() {
-                    return Err(Error::Response(status.to_string()));
+                if !self.is_cacheable(status) {
+                    return Err(Uncached::Unsuccessful(body, status, fetched_at));
                 }
 
-                Ok((body, status))
+                Ok((body, status, fetched_at))
             })
             .await;
 
-        match value {
-            Ok(val) => Ok(val),
-            Err(e) => Err((*e).clone()),
-        }
+        let entry = match result {
+            Ok(entry) => entry,
+            Err(uncached) => {
+                return match &*uncached {
+                    Uncached::Failed(error) => Err(error.clone()),
+                    Uncached::Unsuccessful(body, status, fetched_at) => {
+                        let provenance = Provenance {
+                            url,
+                            fetched_at: *fetched_at,
+                            from_cache: false,
+                        };
+
+                        Ok((body.clone(), *status, provenance))
+                    }
+                };
+            }
+        };
+
+        let from_cache = !entry.is_fresh();
+        let (body, status, fetched_at) = entry.into_value();
+        let provenance = Provenance {
+            url,
+            fetched_at,
+            from_cache,
+        };
+
+        Ok((body, status, provenance))
     }
 
     async fn post<S>(&self, url: Url, msg: S) -> Result<Response, Error>
     where
         S: serde::Serialize + Send + Sync,
     {
-        self.inner.post(url, msg).await
+        let resp = self.inner.post(url.clone(), msg).await?;
+        if resp.status().is_success() {
+            self.record_write(&url);
+        }
+
+        Ok(resp)
+    }
+
+    async fn put<S>(&self, url: Url, msg: S) -> Result<Response, Error>
+    where
+        S: serde::Serialize + Send + Sync,
+    {
+        let resp = self.inner.put(url.clone(), msg).await?;
+        self.cache.invalidate(&url).await;
+        if resp.status().is_success() {
+            self.record_write(&url);
+        }
+
+        Ok(resp)
+    }
+
+    fn get_paginated_with_options<T>(
+        &self,
+        head_url: Url,
+        options: PageOptions,
+    ) -> PaginatedStream<'_, Self::Container<T>>
+    where
+        T: 'static + Value,
+    {
+        // Fresh reads must not be served from a pagination started by a caching handle
+        if self.bypass || self.recently_written(&head_url) {
+            return paginate(self, head_url, options);
+        }
+
+        let mut key = head_url.clone();
+        options.apply(&mut key);
+
+        // The shared pagination may outlive this borrow of the client, so it is driven by a clone
+        let client = self.clone();
+        self.coalescer.subscribe(key, move || {
+            Box::pin(stream! {
+                let mut pages = paginate::<T, _>(&client, head_url, options);
+                while let Some(item) = pages.next().await {
+                    yield item;
+                }
+            })
+        })
+    }
+
+    async fn patch<S>(&self, url: Url, msg: S) -> Result<Response, Error>
+    where
+        S: serde::Serialize + Send + Sync,
+    {
+        let resp = self.inner.patch(url.clone(), msg).await?;
+        self.cache.invalidate(&url).await;
+        if resp.status().is_success() {
+            self.record_write(&url);
+        }
+
+        Ok(resp)
     }
 
     fn config(&self) -> &Config {
         self.inner.config()
     }
 
+    fn lenient_links_enabled(&self) -> bool {
+        self.inner.lenient_links_enabled()
+    }
+
+    fn log_bodies_enabled(&self) -> bool {
+        self.inner.log_bodies_enabled()
+    }
+
+    fn backoff(&self) -> Box<dyn Backoff> {
+        self.inner.backoff()
+    }
+
+    fn retries_requests(&self) -> bool {
+        self.inner.retries_requests()
+    }
+
+    fn server_info_memo(&self) -> Option<&OnceCell<ServerInfo>> {
+        self.inner.server_info_memo()
+    }
+
     fn config_mut(&mut self) -> &mut Config {
         self.inner.config_mut()
     }
 }
 
-#[tracing::instrument]
-fn deserialize_from_value<T>(value: serde_json::Value) -> Result<T, Error>
-where
-    T: serde::de::DeserializeOwned + std::fmt::Debug,
-{
-    match serde_json::from_value::<T>(value).map_err(From::from) {
-        Ok(item) => {
-            tracing::debug!(object = ?item, "Received valid object");
-            Ok(item)
-        }
-        e => {
-            tracing::warn!(error= ?e, "Object failed deserialization");
-            e
+#[cfg(test)]
+mod tests {
+    use freedom_config::Test;
+    use httpmock::{Method::GET, MockServer};
+
+    use super::*;
+
+    fn caching_client_builder() -> CachingClientBuilder {
+        let config = Config::builder()
+            .environment(Test)
+            .key("foo")
+            .secret("bar")
+            .build()
+            .unwrap();
+
+        CachingClient::builder(Client::from_config(config)).capacity(100)
+    }
+
+    fn caching_client() -> CachingClient {
+        caching_client_builder().build()
+    }
+
+    #[test]
+    fn from_builder_rejects_invalid_client() {
+        let config = Config::builder()
+            .environment(Test)
+            .key("foo")
+            .secret("bar")
+            .build()
+            .unwrap();
+
+        let result =
+            CachingClient::from_builder(Client::builder().config(config).entrypoint("not a url"));
+        assert!(matches!(result, Err(Error::UrlConstruction(_))));
+    }
+
+    #[tokio::test]
+    async fn cache_hit_provenance() {
+        let client = caching_client();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"name": "foo"}"#);
+        });
+        let url = Url::parse(&format!("http://{}/testing", server.address())).unwrap();
+
+        let fresh = client
+            .get_json_map_traced::<serde_json::Value>(url.clone())
+            .await
+            .unwrap();
+        let cached = client
+            .get_json_map_traced::<serde_json::Value>(url.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(fresh, cached);
+        assert!(!fresh.provenance().from_cache);
+        assert!(cached.provenance().from_cache);
+        assert_eq!(fresh.provenance().url, url);
+        assert_eq!(cached.provenance().url, url);
+        assert_eq!(
+            fresh.provenance().fetched_at,
+            cached.provenance().fetched_at
+        );
+        mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn concurrent_paginations_are_coalesced() {
+        let client = caching_client_builder()
+            .coalesce_window(Duration::from_secs(60))
+            .build();
+        let server = MockServer::start();
+        let fixture = |file: &str| {
+            std::fs::read_to_string(file)
+                .unwrap()
+                .replace("localhost:8080", &server.address().to_string())
+        };
+        // Defined before the first page, which would otherwise also match its query
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/requests/search/findAll")
+                .query_param("page", "1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(fixture("resources/requests_find_all_page_2.json"));
+        });
+        let first = server.mock(|when, then| {
+            when.method(GET).path("/requests/search/findAll");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(fixture("resources/requests_find_all_page_1.json"));
+        });
+        let url = Url::parse(&server.url("/requests/search/findAll")).unwrap();
+
+        let (a, b) = tokio::join!(
+            client
+                .get_paginated::<serde_json::Value>(url.clone())
+                .collect::<Vec<_>>(),
+            client
+                .get_paginated::<serde_json::Value>(url)
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(a.len(), 3);
+        assert_eq!(a, b);
+        for (a, b) in a.iter().zip(&b) {
+            assert!(Arc::ptr_eq(a.as_ref().unwrap(), b.as_ref().unwrap()));
         }
+        first.assert_hits(1);
+        second.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn cached_until_ttl_expires() {
+        let config = Config::builder()
+            .environment(Test)
+            .key("foo")
+            .secret("bar")
+            .build()
+            .unwrap();
+        let client = CachingClient::builder(Client::from_config(config))
+            .ttl(Duration::from_millis(200))
+            .build();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.status(200).body("OK");
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+
+        client.get(url.clone()).await.unwrap();
+        client.get(url.clone()).await.unwrap();
+        mock.assert_hits(1);
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        client.get(url).await.unwrap();
+        mock.assert_hits(2);
+    }
+
+    #[tokio::test]
+    async fn unsuccessful_responses_are_not_cached() {
+        let client = caching_client();
+        let server = MockServer::start();
+        let mut unavailable = server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.status(503).body("Service Unavailable");
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+
+        let result = client.get_json_map::<serde_json::Value>(url.clone()).await;
+        assert!(
+            matches!(result.map_err(Error::into_inner), Err(Error::Api { status, .. }) if status == 503)
+        );
+        unavailable.assert_hits(1);
+        unavailable.delete();
+
+        let available = server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"name": "foo"}"#);
+        });
+        let value = client
+            .get_json_map::<serde_json::Value>(url.clone())
+            .await
+            .unwrap();
+        assert_eq!(value["name"], "foo");
+        client.get(url).await.unwrap();
+        available.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn not_found_cached_for_negative_ttl() {
+        let config = Config::builder()
+            .environment(Test)
+            .key("foo")
+            .secret("bar")
+            .build()
+            .unwrap();
+        let client = CachingClient::builder(Client::from_config(config))
+            .negative_ttl(Duration::from_millis(200))
+            .build();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.status(404);
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+
+        let (_, status) = client.get(url.clone()).await.unwrap();
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        client.get(url.clone()).await.unwrap();
+        mock.assert_hits(1);
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        client.get(url).await.unwrap();
+        mock.assert_hits(2);
+    }
+
+    #[tokio::test]
+    async fn fresh_reads_refresh_the_cache() {
+        let client = caching_client();
+        let server = MockServer::start();
+        let mut stale = server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.status(200).body(r#"{"name": "foo"}"#);
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+
+        let value = client
+            .get_json_map::<serde_json::Value>(url.clone())
+            .await
+            .unwrap();
+        assert_eq!(value["name"], "foo");
+        stale.delete();
+
+        let updated = server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.status(200).body(r#"{"name": "bar"}"#);
+        });
+        let value = client
+            .fresh()
+            .get_json_map::<serde_json::Value>(url.clone())
+            .await
+            .unwrap();
+        assert_eq!(value["name"], "bar");
+
+        let traced = client
+            .get_json_map_traced::<serde_json::Value>(url.clone())
+            .await
+            .unwrap();
+        assert_eq!(traced["name"], "bar");
+        assert!(traced.provenance().from_cache);
+
+        let (body, _) = client.get_nocache(url).await.unwrap();
+        assert_eq!(body, r#"{"name": "bar"}"#);
+        updated.assert_hits(2);
     }
 }
@@ -0,0 +1,229 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError, Weak},
+    time::{Duration, Instant},
+};
+
+use async_stream::stream;
+use futures_util::StreamExt;
+use url::Url;
+
+use crate::{api::PaginatedStream, error::Error};
+
+type Erased = Arc<dyn Any + Send + Sync>;
+
+/// The paginations started within the window, by their head URL
+///
+/// Only the subscribers keep a pagination alive, so that its buffer is released once every
+/// subscriber is dropped.
+type Started = HashMap<Url, (Instant, Weak<dyn Any + Send + Sync>)>;
+type Registry = Mutex<Started>;
+
+/// Shares a single pagination among every consumer requesting the same head URL within a window
+///
+/// Each item is fetched and deserialized once, then broadcast to all subscribers. Items are
+/// buffered, so subscribers arriving after the pagination has started receive every item from
+/// the beginning. A pagination is no longer shared once it is exhausted.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Coalescer {
+    window: Duration,
+    started: Arc<Registry>,
+}
+
+impl Coalescer {
+    pub(crate) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            started: Default::default(),
+        }
+    }
+
+    /// Subscribe to the pagination of the URL, calling `start` to begin a new pagination when
+    /// none was started within the window.
+    pub(crate) fn subscribe<T, F>(&self, url: Url, start: F) -> PaginatedStream<'static, Arc<T>>
+    where
+        T: Send + Sync + 'static,
+        F: FnOnce() -> PaginatedStream<'static, Arc<T>>,
+    {
+        if self.window.is_zero() {
+            return start();
+        }
+
+        let shared = {
+            let mut started = lock(&self.started);
+            let now = Instant::now();
+            started.retain(|_, (at, shared)| {
+                now.duration_since(*at) < self.window && shared.strong_count() > 0
+            });
+
+            let existing = started
+                .get(&url)
+                .and_then(|(_, shared)| shared.upgrade())
+                .and_then(|shared| shared.downcast::<Shared<T>>().ok());
+            match existing {
+                Some(shared) => shared,
+                None => {
+                    let shared = Arc::new(Shared::new(start()));
+                    let erased = Arc::clone(&shared) as Erased;
+                    started.insert(url.clone(), (now, Arc::downgrade(&erased)));
+                    shared
+                }
+            }
+        };
+
+        Shared::subscribe(shared, Arc::downgrade(&self.started), url)
+    }
+}
+
+fn lock(registry: &Registry) -> MutexGuard<'_, Started> {
+    registry.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+struct Shared<T> {
+    state: tokio::sync::Mutex<State<T>>,
+}
+
+struct State<T> {
+    buffer: Vec<Result<Arc<T>, Error>>,
+    /// The underlying pagination, or `None` once it is exhausted
+    source: Option<PaginatedStream<'static, Arc<T>>>,
+}
+
+impl<T> Shared<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn new(source: PaginatedStream<'static, Arc<T>>) -> Self {
+        Self {
+            state: tokio::sync::Mutex::new(State {
+                buffer: Vec::new(),
+                source: Some(source),
+            }),
+        }
+    }
+
+    fn subscribe(
+        shared: Arc<Self>,
+        registry: Weak<Registry>,
+        url: Url,
+    ) -> PaginatedStream<'static, Arc<T>> {
+        Box::pin(stream! {
+            let mut next = 0;
+            loop {
+                // Whichever subscriber first runs out of buffered items advances the source, while
+                // the others wait on the lock
+                let mut state = shared.state.lock().await;
+                if next == state.buffer.len() {
+                    let Some(source) = state.source.as_mut() else {
+                        break;
+                    };
+
+                    let item = source.next().await;
+                    match item {
+                        Some(item) => state.buffer.push(item),
+                        None => {
+                            state.source = None;
+                            shared.forget(&registry, &url);
+                            break;
+                        }
+                    }
+                }
+
+                let item = state.buffer[next].clone();
+                next += 1;
+                drop(state);
+
+                yield item;
+            }
+        })
+    }
+
+    /// Stop sharing the exhausted pagination, unless another has since replaced it
+    fn forget(self: &Arc<Self>, registry: &Weak<Registry>, url: &Url) {
+        let Some(registry) = registry.upgrade() else {
+            return;
+        };
+
+        let mut started = lock(&registry);
+        let current = started.get(url).is_some_and(|(_, shared)| {
+            std::ptr::eq(shared.as_ptr() as *const (), Arc::as_ptr(self) as *const ())
+        });
+        if current {
+            started.remove(url);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(items: Vec<i32>) -> PaginatedStream<'static, Arc<i32>> {
+        Box::pin(stream! {
+            for item in items {
+                yield Ok(Arc::new(item));
+            }
+        })
+    }
+
+    fn url() -> Url {
+        Url::parse("http://localhost:8080/api/sites").unwrap()
+    }
+
+    #[tokio::test]
+    async fn late_subscriber_receives_buffered_items() {
+        let coalescer = Coalescer::new(Duration::from_secs(60));
+
+        let mut first = coalescer.subscribe(url(), || source(vec![1, 2, 3]));
+        let head = first.next().await.unwrap().unwrap();
+
+        let second = coalescer.subscribe::<i32, _>(url(), || panic!("Pagination restarted"));
+        let rest = first.collect::<Vec<_>>().await;
+        let all = second.collect::<Vec<_>>().await;
+
+        assert_eq!(rest.len(), 2);
+        assert_eq!(all.len(), 3);
+        assert!(Arc::ptr_eq(&head, all[0].as_ref().unwrap()));
+        assert!(Arc::ptr_eq(
+            rest[1].as_ref().unwrap(),
+            all[2].as_ref().unwrap()
+        ));
+    }
+
+    #[tokio::test]
+    async fn exhausted_pagination_is_not_shared() {
+        let coalescer = Coalescer::new(Duration::from_secs(60));
+
+        let first = coalescer.subscribe(url(), || source(vec![1]));
+        assert_eq!(first.collect::<Vec<_>>().await, vec![Ok(Arc::new(1))]);
+        assert!(lock(&coalescer.started).is_empty());
+
+        let second = coalescer.subscribe(url(), || source(vec![2]));
+        assert_eq!(second.collect::<Vec<_>>().await, vec![Ok(Arc::new(2))]);
+    }
+
+    #[tokio::test]
+    async fn dropped_subscribers_release_the_pagination() {
+        let coalescer = Coalescer::new(Duration::from_secs(60));
+
+        let mut first = coalescer.subscribe(url(), || source(vec![1, 2]));
+        first.next().await.unwrap().unwrap();
+        drop(first);
+
+        let second = coalescer.subscribe(url(), || source(vec![3]));
+        assert_eq!(second.collect::<Vec<_>>().await, vec![Ok(Arc::new(3))]);
+    }
+
+    #[tokio::test]
+    async fn disabled_window_never_shares() {
+        let coalescer = Coalescer::default();
+
+        let first = coalescer.subscribe(url(), || source(vec![1]));
+        let second = coalescer.subscribe(url(), || source(vec![2]));
+
+        let (first, second) = tokio::join!(first.collect::<Vec<_>>(), second.collect::<Vec<_>>());
+        assert_eq!(first, vec![Ok(Arc::new(1))]);
+        assert_eq!(second, vec![Ok(Arc::new(2))]);
+    }
+}
@@ -1,10 +1,32 @@
+use std::{
+    borrow::Cow,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
 use bytes::Bytes;
 use freedom_config::Config;
-use reqwest::{Response, StatusCode};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Method, Response, StatusCode,
+};
 use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
+use tracing::Instrument;
 use url::Url;
 
-use crate::api::{Api, Container, Value};
+use crate::{
+    api::{info::ServerInfo, Api, Container, Value},
+    auth::AuthMode,
+    backoff::Backoff,
+    error::Error,
+    rate_limit::RateLimiter,
+    retry::RetryPolicy,
+    telemetry,
+};
+
+#[cfg(feature = "metrics")]
+use crate::metrics::MetricsHook;
 
 /// An asynchronous `Client` for interfacing with the ATLAS freedom API.
 ///
@@ -14,6 +36,69 @@ use crate::api::{Api, Container, Value};
 pub struct Client {
     pub(crate) config: Config,
     pub(crate) client: reqwest::Client,
+    /// Overrides basic authentication with the key and secret of the config, which are otherwise
+    /// read for every request so that changes made through `config_mut` take effect
+    pub(crate) auth: Option<AuthMode>,
+    /// Sent with every request, in addition to the default headers of the HTTP client
+    pub(crate) headers: HeaderMap,
+    pub(crate) lenient_links: bool,
+    /// Whether the bodies of requests and responses are logged
+    pub(crate) log_bodies: bool,
+    pub(crate) retry: Option<RetryPolicy>,
+    /// Shared by every clone of the client, so that cloning does not multiply the allowed rate
+    pub(crate) rate_limit: Option<RateLimiter>,
+    pub(crate) backoff: Box<dyn Backoff>,
+    pub(crate) http: HttpOptions,
+    pub(crate) server_info: Arc<OnceCell<ServerInfo>>,
+    #[cfg(feature = "middleware")]
+    pub(crate) middleware: Option<reqwest_middleware::ClientWithMiddleware>,
+    #[cfg(feature = "metrics")]
+    pub(crate) metrics: Option<Arc<dyn MetricsHook>>,
+}
+
+/// The HTTP version used to communicate with the server
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Protocol {
+    /// HTTP/2 when negotiated with ALPN, otherwise HTTP/1.1
+    #[default]
+    Negotiate,
+    Http1Only,
+    Http2PriorKnowledge,
+}
+
+/// Options of the underlying HTTP client
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct HttpOptions {
+    pub(crate) protocol: Protocol,
+    pub(crate) http2_keep_alive_interval: Option<Duration>,
+    pub(crate) connect_timeout: Option<Duration>,
+    /// Applied to each request rather than to the HTTP client, so that it can be overridden
+    /// without building a new HTTP client
+    pub(crate) request_timeout: Option<Duration>,
+}
+
+impl HttpOptions {
+    /// # Panics
+    ///
+    /// Panics when the TLS backend cannot be initialized, as with [`reqwest::Client::new`]
+    fn build(&self) -> reqwest::Client {
+        let mut builder = reqwest::Client::builder();
+        builder = match self.protocol {
+            Protocol::Negotiate => builder,
+            Protocol::Http1Only => builder.http1_only(),
+            Protocol::Http2PriorKnowledge => builder.http2_prior_knowledge(),
+        };
+        if let Some(interval) = self.http2_keep_alive_interval {
+            builder = builder.http2_keep_alive_interval(interval);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        builder
+            .build()
+            .expect("Failed to initialize the HTTP client")
+    }
 }
 
 impl PartialEq for Client {
@@ -23,6 +108,37 @@ impl PartialEq for Client {
 }
 
 impl Client {
+    /// Configure an API client, validating every option when it is built
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// # use std::time::Duration;
+    /// let config = Config::builder()
+    ///     .environment(Prod)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::builder()
+    ///     .config(config)
+    ///     .entrypoint("https://staging-proxy.example.com/api/")
+    ///     .header("X-Correlation-Id", "abc-123")
+    ///     .request_timeout(Duration::from_secs(30))
+    ///     .retry(RetryPolicy::new().max_retries(3))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     client.config().environment().freedom_entrypoint().as_str(),
+    ///     "https://staging-proxy.example.com/api/"
+    /// );
+    /// ```
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
     /// Construct an API client from the provided Freedom config
     ///
     /// # Example
@@ -41,176 +157,1592 @@ impl Client {
     /// ```
     pub fn from_config(config: Config) -> Self {
         Self {
+            auth: None,
             config,
             client: reqwest::Client::new(),
+            headers: HeaderMap::new(),
+            lenient_links: false,
+            log_bodies: false,
+            retry: None,
+            rate_limit: None,
+            backoff: crate::backoff::default_strategy(),
+            http: HttpOptions::default(),
+            server_info: Arc::default(),
+            #[cfg(feature = "middleware")]
+            middleware: None,
+            #[cfg(feature = "metrics")]
+            metrics: None,
+        }
+    }
+
+    /// Construct an API client from the provided Freedom config, sending every request with the
+    /// provided HTTP client
+    ///
+    /// This allows the HTTP client to be configured with a proxy, a custom root certificate,
+    /// timeouts, or pool sizing. Authentication is still applied to every request by the client,
+    /// on top of any default headers of the HTTP client.
+    ///
+    /// The HTTP options of the client, such as [`Self::http1_only`], build a new HTTP client, and
+    /// so replace the provided one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// # use std::time::Duration;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let http = reqwest::Client::builder()
+    ///     .connect_timeout(Duration::from_secs(5))
+    ///     .pool_max_idle_per_host(4)
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::from_config_with_client(config, http);
+    /// ```
+    pub fn from_config_with_client(config: Config, client: reqwest::Client) -> Self {
+        Self {
+            client,
+            ..Self::from_config(config)
+        }
+    }
+
+    /// Construct an API client from the provided Freedom config, sending every request through
+    /// the provided middleware stack
+    ///
+    /// Authentication is still applied by the client, prior to the request entering the
+    /// middleware.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let middleware = reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build();
+    /// let client = Client::from_config_with_middleware(config, middleware);
+    /// ```
+    #[cfg(feature = "middleware")]
+    pub fn from_config_with_middleware(
+        config: Config,
+        client: reqwest_middleware::ClientWithMiddleware,
+    ) -> Self {
+        Self {
+            middleware: Some(client),
+            ..Self::from_config(config)
+        }
+    }
+
+    /// Notify the hook of every request, such as to export request counts and latencies
+    ///
+    /// The hook is shared by every clone of the client.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::{metrics::MetricsFacade, prelude::*};
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::from_config(config).with_metrics(MetricsFacade);
+    /// ```
+    #[cfg(feature = "metrics")]
+    pub fn with_metrics(mut self, hook: impl MetricsHook + 'static) -> Self {
+        self.metrics = Some(Arc::new(hook));
+        self
+    }
+
+    /// Authenticate every request with the provided mode, rather than with basic authentication
+    /// using the key and secret of the config
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// # use reqwest::header::HeaderValue;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::from_config(config)
+    ///     .with_auth(AuthMode::Custom(HeaderValue::from_static("Token abc123")));
+    /// ```
+    pub fn with_auth(mut self, auth: AuthMode) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Enable or disable lenient handling of malformed links
+    ///
+    /// By default, an object containing a link which cannot be parsed as a URL fails
+    /// deserialization. When enabled, such links are logged and dropped from the object instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::from_config(config).lenient_links(true);
+    ///
+    /// assert!(client.lenient_links_enabled());
+    /// ```
+    pub fn lenient_links(mut self, lenient: bool) -> Self {
+        self.lenient_links = lenient;
+        self
+    }
+
+    /// Log the body of every request and response at the debug level
+    ///
+    /// Bodies may contain personal information and tokens, so they are not logged by default. Each
+    /// request is traced with a `freedom_api.request` span regardless, carrying its endpoint,
+    /// method, URL with the query redacted, status, and latency.
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::from_config(config).log_bodies(true);
+    ///
+    /// assert!(client.log_bodies_enabled());
+    /// ```
+    pub fn log_bodies(mut self, enabled: bool) -> Self {
+        self.log_bodies = enabled;
+        self
+    }
+
+    /// Retry requests which fail for transient reasons, according to the provided policy
+    ///
+    /// By default, requests are not retried.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::from_config(config).with_retry(RetryPolicy::new().max_retries(5));
+    /// ```
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// The strategy used when polling or resuming after a failure, such as while
+    /// [watching for task requests](crate::Api::watch_requests) or retrying a page of a
+    /// [paginated stream](crate::Api::get_paginated_with)
+    ///
+    /// Retries of individual requests are instead governed by the [`RetryPolicy`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// # use std::time::Duration;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::from_config(config)
+    ///     .with_backoff(Fibonacci::new(Duration::from_secs(1), Duration::from_secs(60)));
+    /// ```
+    pub fn with_backoff(mut self, backoff: impl Backoff + 'static) -> Self {
+        self.backoff = Box::new(backoff);
+        self
+    }
+
+    /// Only communicate with the server over HTTP/1.1
+    ///
+    /// Useful for gateways which misbehave when HTTP/2 is negotiated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::from_config(config).http1_only();
+    /// ```
+    pub fn http1_only(mut self) -> Self {
+        self.http.protocol = Protocol::Http1Only;
+        self.client = self.http.build();
+        self
+    }
+
+    /// Communicate with the server over HTTP/2 without negotiating the protocol first
+    ///
+    /// The server must support HTTP/2, otherwise every request fails.
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http.protocol = Protocol::Http2PriorKnowledge;
+        self.client = self.http.build();
+        self
+    }
+
+    /// Send HTTP/2 pings at the provided interval, to keep idle connections alive
+    ///
+    /// Has no effect on connections using HTTP/1.1.
+    pub fn http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http.http2_keep_alive_interval = Some(interval);
+        self.client = self.http.build();
+        self
+    }
+
+    /// Send at most `requests` requests per `period`, waiting before any request which would
+    /// exceed the rate
+    ///
+    /// The limit is a token bucket, so after a quiet period a burst of `requests` requests is
+    /// sent at once. The limit applies to every request of the client, including each page of a
+    /// paginated stream and each retry, and is shared with all clones and views of the client.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `requests` or `period` is zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// # use std::time::Duration;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::from_config(config).rate_limit(50, Duration::from_secs(1));
+    /// ```
+    pub fn rate_limit(mut self, requests: u32, period: Duration) -> Self {
+        self.rate_limit = Some(RateLimiter::new(requests, period));
+        self
+    }
+
+    /// Fail any request which has not connected to the server within the provided duration
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http.connect_timeout = Some(timeout);
+        self.client = self.http.build();
+        self
+    }
+
+    /// Fail any request which has not completed within the provided duration, with an
+    /// [`Error::Timeout`]
+    ///
+    /// The duration covers the entire request, from connecting to the server until the body of
+    /// the response is read, including for downloads. Each retry of a request is given the full
+    /// duration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use freedom_api::prelude::*;
+    /// # use std::time::Duration;
+    /// let config = Config::builder()
+    ///     .environment(Test)
+    ///     .key("foo")
+    ///     .secret("bar")
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::from_config(config)
+    ///     .connect_timeout(Duration::from_secs(5))
+    ///     .request_timeout(Duration::from_secs(30));
+    /// ```
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.http.request_timeout = Some(timeout);
+        self
+    }
+
+    /// A view of the client whose requests time out after the provided duration, rather than the
+    /// [request timeout](Self::request_timeout) of the client
+    ///
+    /// The view shares the HTTP connections of the client, so it is cheap to construct for a
+    /// single call.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # use std::time::Duration;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let request = client
+    ///     .with_timeout(Duration::from_secs(2))
+    ///     .get_request_by_id(42)
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        self.clone().request_timeout(timeout)
+    }
+
+    /// A view of the client which sends the provided headers with every request, such as a
+    /// correlation ID or an idempotency key
+    ///
+    /// The headers are merged with the default headers of the HTTP client, replacing any default
+    /// header of the same name. The view shares the HTTP connections of the client, so it is cheap
+    /// to construct for a single call.
+    pub fn with_headers(&self, headers: HeaderMap) -> Self {
+        let mut view = self.clone();
+        view.headers.extend(headers);
+        view
+    }
+
+    /// Like [`Self::with_headers`] for a single header, failing with an [`Error::InvalidHeader`]
+    /// when the name or value is not valid in a header
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # use time::OffsetDateTime;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let request = client
+    ///     .with_header("Idempotency-Key", "5f1c5e2a")?
+    ///     .new_task_request()
+    ///     .test_task("my_test_file.bin")
+    ///     .target_time_utc(OffsetDateTime::now_utc() + time::Duration::hours(2))
+    ///     .task_duration(120)
+    ///     .satellite_id(1016)
+    ///     .site_id(27)
+    ///     .site_configuration_id(47)
+    ///     .band_ids([2017, 2019])
+    ///     .send()
+    ///     .await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub fn with_header(
+        &self,
+        name: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> Result<Self, Error> {
+        let (name, value) = parse_header(name.as_ref(), value.as_ref())?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(name, value);
+
+        Ok(self.with_headers(headers))
+    }
+
+    /// A view of the client which sends every request to the provided entrypoint, such as a read
+    /// replica of Freedom, rather than the entrypoint of the configured environment
+    ///
+    /// The view shares the credentials, HTTP connections, retry policy, and middleware of the
+    /// client, and relative links are resolved against the provided entrypoint. Fails with
+    /// [`Error::UrlConstruction`] when the entrypoint does not end with a trailing slash.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # use futures::StreamExt;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    /// let replica = client.with_base_url("https://replica.example.com/api/".parse()?)?;
+    ///
+    /// let mut requests = replica.get_requests();
+    /// while let Some(request) = requests.next().await {
+    ///     println!("{:?}", request?.target_date);
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub fn with_base_url(&self, entrypoint: Url) -> Result<Self, Error> {
+        if entrypoint.cannot_be_a_base() || !entrypoint.path().ends_with('/') {
+            return Err(Error::UrlConstruction(format!(
+                "the entrypoint {entrypoint} must end with a trailing slash"
+            )));
+        }
+
+        let environment = Rebased {
+            fps_host: self.config.environment().fps_host().to_owned(),
+            entrypoint,
+        };
+        let config = Config::builder()
+            .environment(environment)
+            .key(self.config.key())
+            .secret(self.config.expose_secret())
+            .build()
+            .map_err(|error| Error::UrlConstruction(error.to_string()))?;
+
+        Ok(Self {
+            config,
+            // The replica may run another version of the server
+            server_info: Arc::default(),
+            ..self.clone()
+        })
+    }
+
+    /// Send the request, notifying the metrics hook of its start and end
+    #[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+    async fn send<F>(
+        &self,
+        method: Method,
+        url: &Url,
+        idempotent: bool,
+        request: F,
+    ) -> Result<Response, Error>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        // Ends the observation when dropped, even if this future is cancelled
+        #[cfg(feature = "metrics")]
+        let observation = self
+            .metrics
+            .as_deref()
+            .map(|hook| crate::metrics::Observation::start(hook, url, &method));
+
+        let result = self.send_with_retry(idempotent, request).await;
+
+        #[cfg(feature = "metrics")]
+        if let Some(observation) = observation {
+            observation.finish(result.as_ref().ok().map(Response::status));
+        }
+        result
+    }
+
+    /// The authentication of the next request
+    fn auth(&self) -> Cow<'_, AuthMode> {
+        match &self.auth {
+            Some(auth) => Cow::Borrowed(auth),
+            None => Cow::Owned(AuthMode::from_config(&self.config)),
+        }
+    }
+
+    /// Send the request produced by `request`, retrying according to the retry policy when
+    /// `idempotent` is true or the policy explicitly allows retrying non-idempotent requests.
+    async fn send_with_retry<F>(&self, idempotent: bool, request: F) -> Result<Response, Error>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        let mut policy = self
+            .retry
+            .as_ref()
+            .filter(|policy| idempotent || policy.retries_post())
+            .map(|policy| (policy, policy.strategy()));
+
+        let first_attempt = Instant::now();
+        let mut retries = 0;
+        let mut reauthenticated = false;
+        loop {
+            let request = request().headers(self.headers.clone());
+            let auth = self.auth();
+            let (mut request, token) = auth.apply(request).await?;
+            if let Some(timeout) = self.http.request_timeout {
+                request = request.timeout(timeout);
+            }
+
+            if let Some(rate_limit) = &self.rate_limit {
+                rate_limit.acquire().await;
+            }
+
+            let started = Instant::now();
+            let result = self.execute(request).await?;
+            if let Ok(response) = &result {
+                if response.status() == StatusCode::UNAUTHORIZED
+                    && !reauthenticated
+                    && auth.invalidate(token.as_deref()).await
+                {
+                    tracing::debug!(
+                        url = %telemetry::redacted(response.url()),
+                        "Refreshing the rejected token"
+                    );
+                    reauthenticated = true;
+                    continue;
+                }
+            }
+            if let Ok(response) = &result {
+                let span = tracing::Span::current();
+                span.record("status", response.status().as_u16());
+                span.record("latency_ms", first_attempt.elapsed().as_millis() as u64);
+                tracing::debug!(
+                    url = %telemetry::redacted(response.url()),
+                    status = %response.status(),
+                    version = ?response.version(),
+                    "Received response"
+                );
+            }
+            let Some((policy, backoff)) = policy.as_mut() else {
+                return result.map_err(|error| request_error(error, started));
+            };
+
+            if !policy.should_retry(retries, &result) {
+                return result.map_err(|error| request_error(error, started));
+            }
+
+            let Some(mut delay) = backoff.next_delay(retries) else {
+                return result.map_err(|error| request_error(error, started));
+            };
+            // The server knows better than the backoff when it will accept requests again, within
+            // the limit of the policy
+            if let Some(retry_after) = result
+                .as_ref()
+                .ok()
+                .and_then(|response| crate::retry::retry_after(response.headers()))
+            {
+                delay = delay.max(retry_after.min(policy.delay_limit()));
+            }
+            retries += 1;
+            match &result {
+                Ok(response) => {
+                    tracing::warn!(status = %response.status(), retry = retries, ?delay, "Retrying request")
+                }
+                Err(error) => tracing::warn!(%error, retry = retries, ?delay, "Retrying request"),
+            }
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Perform a single attempt of the request, through the middleware stack if one is configured
+    ///
+    /// The outer error is produced by a failing middleware, and is never retried.
+    async fn execute(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Result<Response>, Error> {
+        #[cfg(feature = "middleware")]
+        if let Some(middleware) = &self.middleware {
+            let request = match request.build() {
+                Ok(request) => request,
+                Err(error) => return Ok(Err(error)),
+            };
+
+            return match middleware.execute(request).await {
+                Ok(response) => Ok(Ok(response)),
+                Err(reqwest_middleware::Error::Reqwest(error)) => Ok(Err(error)),
+                Err(reqwest_middleware::Error::Middleware(error)) => {
+                    Err(Error::Response(error.to_string()))
+                }
+            };
+        }
+
+        Ok(request.send().await)
+    }
+
+    /// A convenience method for constructing an FPS client from environment variables.
+    ///
+    /// This function expects the following environment variables:
+    ///
+    /// + ATLAS_ENV: [possible values: test, prod]
+    /// + ATLAS_KEY: The ATLAS freedom key registered with an account
+    /// + ATLAS_SECRET: The ATLAS freedom secret registered with an account
+    pub fn from_env() -> Result<Self, freedom_config::Error> {
+        let config = Config::from_env()?;
+        Ok(Self::from_config(config))
+    }
+}
+
+/// Configures a [`Client`], see [`Client::builder`]
+///
+/// Every option is validated by [`build`](Self::build), so that a misconfigured client fails when
+/// it is constructed rather than on its first request.
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    config: Option<Config>,
+    entrypoint: Option<String>,
+    headers: Vec<(String, String)>,
+    connect_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    retry: Option<RetryPolicy>,
+    http_client: Option<reqwest::Client>,
+}
+
+impl ClientBuilder {
+    /// The credentials and environment of the client. Required.
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Send every request to the provided entrypoint rather than that of the environment, such as
+    /// a staging proxy, see [`Client::with_base_url`]
+    ///
+    /// The FPS host of the environment is kept, and a client whose environment is [`Prod`] is
+    /// still treated as targeting production.
+    ///
+    /// [`Prod`]: crate::config::Prod
+    pub fn entrypoint(mut self, entrypoint: impl Into<String>) -> Self {
+        self.entrypoint = Some(entrypoint.into());
+        self
+    }
+
+    /// Send the header with every request. May be called multiple times.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// See [`Client::connect_timeout`]
+    ///
+    /// The timeout is applied by building a new HTTP client, so it cannot be combined with
+    /// [`http_client`](Self::http_client).
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// See [`Client::request_timeout`]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// See [`Client::with_retry`]
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Send every request with the provided HTTP client, see [`Client::from_config_with_client`]
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Construct the client
+    ///
+    /// Fails with an [`Error::Validation`] when no config was provided, or when a connect timeout
+    /// is combined with an HTTP client, with an [`Error::UrlConstruction`] when the entrypoint is
+    /// not a valid entrypoint, and with an [`Error::InvalidHeader`] when the name or value of a
+    /// header is not valid.
+    pub fn build(self) -> Result<Client, Error> {
+        let config = self
+            .config
+            .ok_or_else(|| Error::Validation(vec![String::from("a config is required")]))?;
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let (name, value) = parse_header(name, value)?;
+            headers.insert(name, value);
+        }
+
+        let mut client = match self.http_client {
+            Some(_) if self.connect_timeout.is_some() => {
+                return Err(Error::Validation(vec![String::from(
+                    "a connect timeout cannot be applied to a provided HTTP client",
+                )]))
+            }
+            Some(http_client) => Client::from_config_with_client(config, http_client),
+            None => Client::from_config(config),
+        };
+        if let Some(entrypoint) = &self.entrypoint {
+            let entrypoint = Url::parse(entrypoint)
+                .map_err(|error| Error::UrlConstruction(format!("{entrypoint:?}: {error}")))?;
+            client = client.with_base_url(entrypoint)?;
+        }
+        client.headers = headers;
+        client.retry = self.retry;
+        if let Some(timeout) = self.connect_timeout {
+            client = client.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.request_timeout {
+            client = client.request_timeout(timeout);
+        }
+
+        Ok(client)
+    }
+}
+
+/// Validate the name and value of a header provided for a request
+fn parse_header(name: &str, value: &str) -> Result<(HeaderName, HeaderValue), Error> {
+    let name = HeaderName::from_bytes(name.as_bytes())
+        .map_err(|error| Error::InvalidHeader(format!("{name:?}: {error}")))?;
+    let value = HeaderValue::from_str(value)
+        .map_err(|error| Error::InvalidHeader(format!("value of {name}: {error}")))?;
+
+    Ok((name, value))
+}
+
+/// Convert the error of a request started at `started`, distinguishing a timeout or a failure to
+/// connect from any other failure
+fn request_error(error: reqwest::Error, started: Instant) -> Error {
+    if error.is_connect() {
+        return Error::from_reqwest(error, Error::Connect);
+    }
+    if !error.is_timeout() {
+        return Error::from(error);
+    }
+
+    Error::Timeout {
+        url: error
+            .url()
+            .map(|url| telemetry::redacted(url).to_string())
+            .unwrap_or_default(),
+        elapsed: started.elapsed(),
+    }
+}
+
+/// The environment of a client which was pointed at another entrypoint, keeping the FPS host of the
+/// original environment
+#[derive(Debug, Clone)]
+struct Rebased {
+    fps_host: String,
+    entrypoint: Url,
+}
+
+impl AsRef<str> for Rebased {
+    fn as_ref(&self) -> &str {
+        self.entrypoint.as_str()
+    }
+}
+
+impl freedom_config::Env for Rebased {
+    fn from_str(_val: &str) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    fn fps_host(&self) -> &str {
+        &self.fps_host
+    }
+
+    fn freedom_entrypoint(&self) -> Url {
+        self.entrypoint.clone()
+    }
+}
+
+/// A simple container which stores a `T`.
+///
+/// This container exists to allow us to store items on the stack, without needing to allocate with
+/// something like `Box<T>`. For all other intents and purposes, it acts as the `T` which it
+/// contains.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+#[serde(transparent)]
+pub struct Inner<T>(pub(crate) T);
+
+impl<T> std::ops::Deref for Inner<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> std::ops::DerefMut for Inner<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> Container<T> for Inner<T>
+where
+    T: Value,
+{
+    fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl Api for Client {
+    type Container<T: Value> = Inner<T>;
+
+    async fn get(&self, url: Url) -> Result<(Bytes, StatusCode), crate::error::Error> {
+        let span = telemetry::request_span(&Method::GET, &url);
+        async {
+            let started = Instant::now();
+            let resp = self
+                .send(Method::GET, &url, true, || self.client.get(url.clone()))
+                .await?;
+
+            let status = resp.status();
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                return Err(Error::RateLimited {
+                    retry_after: crate::retry::retry_after(resp.headers()),
+                });
+            }
+
+            let body = resp
+                .bytes()
+                .await
+                .map_err(|error| request_error(error, started))?;
+            if self.log_bodies {
+                tracing::debug!(body = %String::from_utf8_lossy(&body), "Received body");
+            }
+
+            Ok((body, status))
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn get_response(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+    ) -> Result<Response, crate::error::Error> {
+        self.send(Method::GET, &url, true, || {
+            self.client.get(url.clone()).headers(headers.clone())
+        })
+        .instrument(telemetry::request_span(&Method::GET, &url))
+        .await
+    }
+
+    async fn delete(&self, url: Url) -> Result<Response, crate::error::Error> {
+        self.send(Method::DELETE, &url, true, || {
+            self.client.delete(url.clone())
+        })
+        .instrument(telemetry::request_span(&Method::DELETE, &url))
+        .await
+    }
+
+    async fn post<S>(&self, url: Url, msg: S) -> Result<Response, crate::error::Error>
+    where
+        S: serde::Serialize + Sync + Send,
+    {
+        let span = telemetry::request_span(&Method::POST, &url);
+        if self.log_bodies {
+            tracing::debug!(parent: &span, body = %telemetry::json_body(&msg), "Sending body");
+        }
+
+        self.send(Method::POST, &url, false, || {
+            self.client.post(url.clone()).json(&msg)
+        })
+        .instrument(span)
+        .await
+    }
+
+    async fn put<S>(&self, url: Url, msg: S) -> Result<Response, crate::error::Error>
+    where
+        S: serde::Serialize + Sync + Send,
+    {
+        let span = telemetry::request_span(&Method::PUT, &url);
+        if self.log_bodies {
+            tracing::debug!(parent: &span, body = %telemetry::json_body(&msg), "Sending body");
+        }
+
+        self.send(Method::PUT, &url, true, || {
+            self.client.put(url.clone()).json(&msg)
+        })
+        .instrument(span)
+        .await
+    }
+
+    async fn patch<S>(&self, url: Url, msg: S) -> Result<Response, crate::error::Error>
+    where
+        S: serde::Serialize + Sync + Send,
+    {
+        let span = telemetry::request_span(&Method::PATCH, &url);
+        if self.log_bodies {
+            tracing::debug!(parent: &span, body = %telemetry::json_body(&msg), "Sending body");
+        }
+
+        self.send(Method::PATCH, &url, false, || {
+            self.client.patch(url.clone()).json(&msg)
+        })
+        .instrument(span)
+        .await
+    }
+
+    fn config(&self) -> &Config {
+        &self.config
+    }
+
+    fn lenient_links_enabled(&self) -> bool {
+        self.lenient_links
+    }
+
+    fn log_bodies_enabled(&self) -> bool {
+        self.log_bodies
+    }
+
+    fn backoff(&self) -> Box<dyn Backoff> {
+        self.backoff.clone()
+    }
+
+    fn retries_requests(&self) -> bool {
+        self.retry.is_some()
+    }
+
+    fn server_info_memo(&self) -> Option<&OnceCell<ServerInfo>> {
+        Some(&self.server_info)
+    }
+
+    fn config_mut(&mut self) -> &mut Config {
+        // The configuration may point at another server
+        self.server_info = Arc::default();
+        &mut self.config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use freedom_config::{Env, Prod, Test};
+    use httpmock::{
+        Method::{GET, PATCH, POST, PUT},
+        MockServer,
+    };
+
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    fn default_client() -> Client {
+        let config = Config::builder()
+            .environment(Test)
+            .key("foo")
+            .secret("bar")
+            .build()
+            .unwrap();
+
+        Client::from_config(config)
+    }
+
+    #[test]
+    fn clients_are_eq_based_on_config() {
+        let config = Config::builder()
+            .environment(Test)
+            .key("foo")
+            .secret("bar")
+            .build()
+            .unwrap();
+
+        let client_1 = Client::from_config(config.clone());
+        let client_2 = Client::from_config(config);
+        assert_eq!(client_1, client_2);
+    }
+
+    #[test]
+    fn wrap_and_unwrap_inner() {
+        let val = String::from("foobar");
+        let inner = Inner(val.clone());
+        assert_eq!(*inner, val);
+        let unwrapped = inner.into_inner();
+        assert_eq!(val, unwrapped);
+    }
+
+    #[test]
+    fn load_from_env() {
+        unsafe {
+            std::env::set_var("ATLAS_ENV", "TEST");
+            std::env::set_var("ATLAS_KEY", "foo");
+            std::env::set_var("ATLAS_SECRET", "bar");
+        };
+        let client = Client::from_env().unwrap();
+        assert_eq!(client.config().key(), "foo");
+        assert_eq!(client.config().expose_secret(), "bar");
+    }
+
+    #[tokio::test]
+    async fn get_ok_response() {
+        const RESPONSE: &str = "it's working";
+        let client = default_client();
+        let server = MockServer::start();
+        let addr = server.address();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.body(RESPONSE.as_bytes());
+        });
+        let url = Url::parse(&format!("http://{}/testing", addr)).unwrap();
+        let (response, status) = client.get(url).await.unwrap();
+
+        assert_eq!(response, RESPONSE.as_bytes());
+        assert_eq!(status, StatusCode::OK);
+        mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn provided_http_client_is_used() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "10.0.0.1".parse().unwrap());
+        let http = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap();
+        let client = Client::from_config_with_client(default_client().config, http);
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/testing")
+                .header("x-forwarded-for", "10.0.0.1")
+                .header_exists("authorization");
+            then.status(200);
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+        let (_, status) = client.get(url).await.unwrap();
+
+        assert_eq!(status, StatusCode::OK);
+        mock.assert_hits(1);
+    }
+
+    async fn authorization_sent(auth: AuthMode, expected: &str) {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/testing")
+                .header("authorization", expected);
+            then.status(200);
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+        let client = default_client().with_auth(auth);
+
+        let (_, status) = client.get(url).await.unwrap();
+
+        assert_eq!(status, StatusCode::OK);
+        mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn basic_auth_is_sent_by_default() {
+        let auth = AuthMode::from_config(default_client().config());
+
+        authorization_sent(auth, "Basic Zm9vOmJhcg==").await;
+    }
+
+    #[tokio::test]
+    async fn basic_auth_follows_config_changes() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/testing")
+                .header("authorization", "Basic YmF6OnF1eA==");
+            then.status(200);
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+        let mut client = default_client();
+        *client.config_mut() = Config::builder()
+            .environment(Test)
+            .key("baz")
+            .secret("qux")
+            .build()
+            .unwrap();
+
+        let (_, status) = client.get(url).await.unwrap();
+
+        assert_eq!(status, StatusCode::OK);
+        mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn bearer_auth_is_sent() {
+        authorization_sent(AuthMode::bearer("abc123"), "Bearer abc123").await;
+    }
+
+    #[tokio::test]
+    async fn custom_auth_is_sent() {
+        let auth = AuthMode::Custom(reqwest::header::HeaderValue::from_static("Token xyz"));
+
+        authorization_sent(auth, "Token xyz").await;
+    }
+
+    #[tokio::test]
+    async fn rejected_token_is_refreshed() {
+        let server = MockServer::start();
+        let rejected = server.mock(|when, then| {
+            when.method(GET)
+                .path("/testing")
+                .header("authorization", "Bearer token-1");
+            then.status(401);
+        });
+        let accepted = server.mock(|when, then| {
+            when.method(GET)
+                .path("/testing")
+                .header("authorization", "Bearer token-2");
+            then.status(200);
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&refreshes);
+        let auth = AuthMode::refreshing(move || {
+            let refresh = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            async move { Ok(format!("token-{refresh}")) }
+        });
+        let client = default_client().with_auth(auth);
+
+        let (_, status) = client.get(url.clone()).await.unwrap();
+        assert_eq!(status, StatusCode::OK);
+
+        // The refreshed token is kept for the following requests
+        let (_, status) = client.get(url).await.unwrap();
+        assert_eq!(status, StatusCode::OK);
+
+        assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+        rejected.assert_hits(1);
+        accepted.assert_hits(2);
+    }
+
+    #[tokio::test]
+    async fn stale_rejection_keeps_the_refreshed_token() {
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&refreshes);
+        let auth = AuthMode::refreshing(move || {
+            let refresh = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            async move { Ok(format!("token-{refresh}")) }
+        });
+        let sent = |auth: &AuthMode| {
+            let auth = auth.clone();
+            async move {
+                let request = reqwest::Client::new().get("http://localhost/testing");
+                auth.apply(request).await.unwrap().1.unwrap()
+            }
+        };
+
+        let first = sent(&auth).await;
+        assert_eq!(first, "token-1");
+        assert!(auth.invalidate(Some(&first)).await);
+        assert_eq!(sent(&auth).await, "token-2");
+
+        // A concurrent request rejected with the first token does not discard the second
+        assert!(auth.invalidate(Some(&first)).await);
+        assert_eq!(sent(&auth).await, "token-2");
+        assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn scoped_header_is_only_sent_by_the_view() {
+        let server = MockServer::start();
+        let scoped = server.mock(|when, then| {
+            when.method(POST)
+                .path("/testing")
+                .header("x-correlation-id", "abc-123")
+                .header_exists("authorization");
+            then.status(201);
+        });
+        let unscoped = server.mock(|when, then| {
+            when.method(POST).path("/testing").matches(|req| {
+                !req.headers
+                    .iter()
+                    .flatten()
+                    .any(|(name, _)| name.eq_ignore_ascii_case("x-correlation-id"))
+            });
+            then.status(200);
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+        let client = default_client();
+
+        let response = client
+            .with_header("X-Correlation-Id", "abc-123")
+            .unwrap()
+            .post(url.clone(), "foo")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = client.post(url, "foo").await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        scoped.assert_hits(1);
+        unscoped.assert_hits(1);
+    }
+
+    #[test]
+    fn invalid_scoped_header_is_error() {
+        let client = default_client();
+
+        assert!(matches!(
+            client.with_header("bad header", "foo"),
+            Err(Error::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            client.with_header("x-foo", "line\nbreak"),
+            Err(Error::InvalidHeader(_))
+        ));
+    }
+
+    fn config() -> Config {
+        Config::builder()
+            .environment(Prod)
+            .key("foo")
+            .secret("bar")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn built_client_applies_entrypoint_and_headers() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/testing")
+                .header("x-correlation-id", "abc-123")
+                .header_exists("authorization");
+            then.status(200);
+        });
+        let client = Client::builder()
+            .config(config())
+            .entrypoint(server.url("/api/"))
+            .header("X-Correlation-Id", "abc-123")
+            .build()
+            .unwrap();
+
+        let url = client.path_to_url("testing").unwrap();
+        let (_, status) = client.get(url).await.unwrap();
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(client.config().environment().fps_host(), Prod.fps_host());
+        mock.assert_hits(1);
+    }
+
+    #[test]
+    fn builder_rejects_invalid_header() {
+        let result = Client::builder()
+            .config(config())
+            .header("x-foo", "line\nbreak")
+            .build();
+        assert!(matches!(result, Err(Error::InvalidHeader(_))), "{result:?}");
+
+        let result = Client::builder()
+            .config(config())
+            .header("bad header", "foo")
+            .build();
+        assert!(matches!(result, Err(Error::InvalidHeader(_))), "{result:?}");
+    }
+
+    #[test]
+    fn builder_rejects_invalid_entrypoint() {
+        for entrypoint in ["not a url", "https://staging.example.com/api"] {
+            let result = Client::builder()
+                .config(config())
+                .entrypoint(entrypoint)
+                .build();
+            assert!(
+                matches!(result, Err(Error::UrlConstruction(_))),
+                "{entrypoint}: {result:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn builder_requires_config() {
+        let result = Client::builder().build();
+        assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+
+        let result = Client::builder()
+            .config(config())
+            .http_client(reqwest::Client::new())
+            .connect_timeout(Duration::from_secs(5))
+            .build();
+        assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+    }
+
+    #[tokio::test]
+    async fn requests_are_paced_across_clones() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.status(200);
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+        let client = default_client().rate_limit(5, Duration::from_millis(500));
+        let clone = client.clone();
+
+        let started = std::time::Instant::now();
+        for _ in 0..5 {
+            client.get(url.clone()).await.unwrap();
+            clone.get(url.clone()).await.unwrap();
         }
+        let elapsed = started.elapsed();
+
+        // The first five requests are a burst, the remaining five are 100ms apart
+        mock.assert_hits(10);
+        assert!(elapsed >= Duration::from_millis(450), "{elapsed:?}");
     }
 
-    /// A convenience method for constructing an FPS client from environment variables.
-    ///
-    /// This function expects the following environment variables:
-    ///
-    /// + ATLAS_ENV: [possible values: test, prod]
-    /// + ATLAS_KEY: The ATLAS freedom key registered with an account
-    /// + ATLAS_SECRET: The ATLAS freedom secret registered with an account
-    pub fn from_env() -> Result<Self, freedom_config::Error> {
-        let config = Config::from_env()?;
-        Ok(Self::from_config(config))
+    #[tokio::test]
+    async fn rate_limited_response_is_typed() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/seconds");
+            then.status(429).header("retry-after", "7");
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/missing");
+            then.status(429);
+        });
+        let client = default_client();
+
+        let seconds = client
+            .get(Url::parse(&server.url("/seconds")).unwrap())
+            .await;
+        assert_eq!(
+            seconds,
+            Err(Error::RateLimited {
+                retry_after: Some(Duration::from_secs(7))
+            })
+        );
+
+        let missing = client
+            .get(Url::parse(&server.url("/missing")).unwrap())
+            .await;
+        assert_eq!(missing, Err(Error::RateLimited { retry_after: None }));
     }
-}
 
-/// A simple container which stores a `T`.
-///
-/// This container exists to allow us to store items on the stack, without needing to allocate with
-/// something like `Box<T>`. For all other intents and purposes, it acts as the `T` which it
-/// contains.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(transparent)]
-#[serde(transparent)]
-pub struct Inner<T>(T);
+    #[tokio::test]
+    async fn retry_waits_at_least_retry_after() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.status(429).header("retry-after", "1");
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+        let client = default_client().with_retry(
+            fast_retry()
+                .max_retries(1)
+                .max_delay(Duration::from_secs(2)),
+        );
 
-impl<T> std::ops::Deref for Inner<T> {
-    type Target = T;
+        let started = std::time::Instant::now();
+        let result = client.get(url).await;
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+        assert!(matches!(result, Err(Error::RateLimited { .. })));
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        mock.assert_hits(2);
     }
-}
 
-impl<T> std::ops::DerefMut for Inner<T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+    #[tokio::test]
+    async fn retry_after_is_capped_at_max_delay() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.status(429).header("retry-after", "3600");
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+        let client = default_client().with_retry(fast_retry().max_retries(1));
+
+        let started = std::time::Instant::now();
+        let result = client.get(url).await;
+
+        assert!(matches!(result, Err(Error::RateLimited { .. })));
+        assert!(started.elapsed() < Duration::from_secs(1));
+        mock.assert_hits(2);
     }
-}
 
-impl<T> Container<T> for Inner<T>
-where
-    T: Value,
-{
-    fn into_inner(self) -> T {
-        self.0
+    fn traced_server() -> MockServer {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/api/users/1109");
+            then.status(200).body(r#"{"email":"private@example.com"}"#);
+        });
+
+        server
     }
-}
 
-impl Api for Client {
-    type Container<T: Value> = Inner<T>;
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn requests_are_traced_without_bodies() {
+        let server = traced_server();
+        let url = Url::parse(&server.url("/api/users/1109?token=hunter2")).unwrap();
 
-    async fn get(&self, url: Url) -> Result<(Bytes, StatusCode), crate::error::Error> {
-        let resp = self
-            .client
-            .get(url)
-            .basic_auth(self.config.key(), Some(&self.config.expose_secret()))
-            .send()
-            .await?;
+        default_client().get(url).await.unwrap();
 
-        let status = resp.status();
-        let body = resp.bytes().await?;
-        Ok((body, status))
+        assert!(logs_contain("freedom_api.request{"));
+        assert!(logs_contain("endpoint=users/{id}"));
+        assert!(logs_contain("method=GET"));
+        assert!(logs_contain("token=REDACTED"));
+        assert!(logs_contain("status=200"));
+        assert!(logs_contain("latency_ms="));
+        assert!(!logs_contain("hunter2"));
+        assert!(!logs_contain("private@example.com"));
     }
 
-    async fn delete(&self, url: Url) -> Result<Response, crate::error::Error> {
-        self.client
-            .delete(url)
-            .basic_auth(self.config.key(), Some(self.config.expose_secret()))
-            .send()
-            .await
-            .map_err(From::from)
-    }
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn bodies_are_logged_when_opted_in() {
+        let server = traced_server();
+        let url = Url::parse(&server.url("/api/users/1109")).unwrap();
 
-    async fn post<S>(&self, url: Url, msg: S) -> Result<Response, crate::error::Error>
-    where
-        S: serde::Serialize + Sync + Send,
-    {
-        self.client
-            .post(url)
-            .basic_auth(self.config.key(), Some(self.config.expose_secret()))
-            .json(&msg)
-            .send()
-            .await
-            .map_err(From::from)
+        default_client().log_bodies(true).get(url).await.unwrap();
+
+        assert!(logs_contain("private@example.com"));
     }
 
-    fn config(&self) -> &Config {
-        &self.config
+    #[cfg(feature = "metrics")]
+    #[derive(Debug, Default)]
+    struct RecordingHook {
+        started: std::sync::Mutex<Vec<String>>,
+        ended: std::sync::Mutex<Vec<(String, Method, Option<StatusCode>)>>,
     }
 
-    fn config_mut(&mut self) -> &mut Config {
-        &mut self.config
+    #[cfg(feature = "metrics")]
+    impl MetricsHook for Arc<RecordingHook> {
+        fn on_request_start(&self, url: &Url, _method: &Method) {
+            self.started.lock().unwrap().push(crate::metrics::path(url));
+        }
+
+        fn on_request_end(
+            &self,
+            url: &Url,
+            method: &Method,
+            status: Option<StatusCode>,
+            _duration: Duration,
+        ) {
+            self.ended
+                .lock()
+                .unwrap()
+                .push((crate::metrics::path(url), method.clone(), status));
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use freedom_config::Test;
-    use httpmock::{
-        Method::{GET, POST},
-        MockServer,
-    };
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn metrics_hook_observes_each_request() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path_contains("/api/requests/");
+            then.status(200).body("{}");
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::DELETE)
+                .path("/api/requests/3");
+            then.status(404);
+        });
+        let hook = Arc::new(RecordingHook::default());
+        let client = default_client().with_metrics(Arc::clone(&hook));
 
-    use super::*;
+        for id in [1, 2] {
+            let url = Url::parse(&server.url(format!("/api/requests/{id}"))).unwrap();
+            client.get(url).await.unwrap();
+        }
+        let url = Url::parse(&server.url("/api/requests/3")).unwrap();
+        client.delete(url).await.unwrap();
 
-    fn default_client() -> Client {
-        let config = Config::builder()
-            .environment(Test)
-            .key("foo")
-            .secret("bar")
-            .build()
-            .unwrap();
+        let started = hook.started.lock().unwrap().clone();
+        assert_eq!(started, vec!["requests/{id}"; 3]);
+        let ended = hook.ended.lock().unwrap().clone();
+        assert_eq!(
+            ended,
+            vec![
+                (
+                    String::from("requests/{id}"),
+                    Method::GET,
+                    Some(StatusCode::OK)
+                ),
+                (
+                    String::from("requests/{id}"),
+                    Method::GET,
+                    Some(StatusCode::OK)
+                ),
+                (
+                    String::from("requests/{id}"),
+                    Method::DELETE,
+                    Some(StatusCode::NOT_FOUND)
+                ),
+            ]
+        );
+    }
 
-        Client::from_config(config)
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn metrics_hook_counts_retried_request_once() {
+        let (url, hits) = sequenced_server(vec![503, 503, 200]).await;
+        let hook = Arc::new(RecordingHook::default());
+        let client = default_client()
+            .with_retry(fast_retry())
+            .with_metrics(Arc::clone(&hook));
+
+        client.get(url).await.unwrap();
+
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        assert_eq!(hook.ended.lock().unwrap().len(), 1);
     }
 
-    #[test]
-    fn clients_are_eq_based_on_config() {
-        let config = Config::builder()
-            .environment(Test)
-            .key("foo")
-            .secret("bar")
-            .build()
-            .unwrap();
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn metrics_hook_observes_cancelled_request() {
+        let server = delayed_server();
+        let url = Url::parse(&server.url("/testing")).unwrap();
+        let hook = Arc::new(RecordingHook::default());
+        let client = default_client().with_metrics(Arc::clone(&hook));
 
-        let client_1 = Client::from_config(config.clone());
-        let client_2 = Client::from_config(config);
-        assert_eq!(client_1, client_2);
+        let result = tokio::time::timeout(Duration::from_millis(50), client.get(url)).await;
+
+        assert!(result.is_err());
+        assert_eq!(hook.started.lock().unwrap().len(), 1);
+        assert_eq!(
+            hook.ended.lock().unwrap().clone(),
+            vec![(String::from("testing"), Method::GET, None)]
+        );
     }
 
-    #[test]
-    fn wrap_and_unwrap_inner() {
-        let val = String::from("foobar");
-        let inner = Inner(val.clone());
-        assert_eq!(*inner, val);
-        let unwrapped = inner.into_inner();
-        assert_eq!(val, unwrapped);
+    fn delayed_server() -> MockServer {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.status(200).delay(Duration::from_millis(500));
+        });
+
+        server
     }
 
-    #[test]
-    fn load_from_env() {
-        unsafe {
-            std::env::set_var("ATLAS_ENV", "TEST");
-            std::env::set_var("ATLAS_KEY", "foo");
-            std::env::set_var("ATLAS_SECRET", "bar");
+    #[tokio::test]
+    async fn slow_request_times_out() {
+        let server = delayed_server();
+        let url = Url::parse(&server.url("/testing?token=secret")).unwrap();
+        let client = default_client().request_timeout(Duration::from_millis(50));
+
+        let Err(Error::Timeout {
+            url: timed_out,
+            elapsed,
+        }) = client.get(url.clone()).await
+        else {
+            panic!("Expected a timeout");
         };
-        let client = Client::from_env().unwrap();
-        assert_eq!(client.config().key(), "foo");
-        assert_eq!(client.config().expose_secret(), "bar");
+
+        assert_eq!(timed_out, telemetry::redacted(&url).to_string());
+        assert!(!timed_out.contains("secret"), "{timed_out}");
+        assert!(elapsed >= Duration::from_millis(50), "{elapsed:?}");
     }
 
     #[tokio::test]
-    async fn get_ok_response() {
-        const RESPONSE: &str = "it's working";
+    async fn timeout_can_be_overridden_per_call() {
+        let server = delayed_server();
+        let url = Url::parse(&server.url("/testing")).unwrap();
         let client = default_client();
-        let server = MockServer::start();
-        let addr = server.address();
-        let mock = server.mock(|when, then| {
-            when.method(GET).path("/testing");
-            then.body(RESPONSE.as_bytes());
-        });
-        let url = Url::parse(&format!("http://{}/testing", addr)).unwrap();
-        let (response, status) = client.get(url).await.unwrap();
 
-        assert_eq!(response, RESPONSE.as_bytes());
+        let result = client
+            .with_timeout(Duration::from_millis(50))
+            .get(url.clone())
+            .await;
+        assert!(matches!(result, Err(Error::Timeout { .. })), "{result:?}");
+
+        let (_, status) = client.get(url).await.unwrap();
         assert_eq!(status, StatusCode::OK);
-        mock.assert_hits(1);
     }
 
     #[tokio::test]
@@ -231,6 +1763,143 @@ mod tests {
         mock.assert_hits(1);
     }
 
+    /// Serves the provided statuses in order, one per connection, counting the requests received
+    async fn sequenced_server(statuses: Vec<u16>) -> (Url, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!(
+            "http://{}/testing",
+            listener.local_addr().unwrap()
+        ))
+        .unwrap();
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&hits);
+
+        tokio::spawn(async move {
+            for status in statuses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = [0; 4096];
+                let _ = socket.read(&mut buf).await.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let response = format!(
+                    "HTTP/1.1 {status} STATUS\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (url, hits)
+    }
+
+    fn fast_retry() -> RetryPolicy {
+        RetryPolicy::new()
+            .max_retries(3)
+            .base_delay(Duration::from_millis(1))
+            .max_delay(Duration::from_millis(5))
+    }
+
+    #[tokio::test]
+    async fn get_retries_transient_failures() {
+        let (url, hits) = sequenced_server(vec![503, 503, 200]).await;
+        let client = default_client().with_retry(fast_retry());
+
+        let (_, status) = client.get(url).await.unwrap();
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn get_does_not_retry_client_errors() {
+        let (url, hits) = sequenced_server(vec![404, 200]).await;
+        let client = default_client().with_retry(fast_retry());
+
+        let (_, status) = client.get(url).await.unwrap();
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn get_gives_up_after_max_retries() {
+        let (url, hits) = sequenced_server(vec![503, 502, 500]).await;
+        let client = default_client().with_retry(fast_retry().max_retries(2));
+
+        let (_, status) = client.get(url).await.unwrap();
+
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn post_is_not_retried_by_default() {
+        let (url, hits) = sequenced_server(vec![503, 200]).await;
+        let client = default_client().with_retry(fast_retry());
+
+        let response = client.post(url, "foo").await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn post_retried_when_opted_in() {
+        let (url, hits) = sequenced_server(vec![429, 200]).await;
+        let client = default_client().with_retry(fast_retry().retry_post(true));
+
+        let response = client.post(url, "foo").await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn http1_only_is_applied() {
+        let client = default_client()
+            .http1_only()
+            .http2_keep_alive_interval(Duration::from_secs(10));
+        assert_eq!(client.http.protocol, Protocol::Http1Only);
+        assert_eq!(
+            client.http.http2_keep_alive_interval,
+            Some(Duration::from_secs(10))
+        );
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/testing");
+            then.status(200);
+        });
+        let url = Url::parse(&server.url("/testing")).unwrap();
+        let response = client.get_response(url, HeaderMap::new()).await.unwrap();
+
+        assert_eq!(response.version(), reqwest::Version::HTTP_11);
+        mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn http2_prior_knowledge_is_applied() {
+        const PREFACE: &[u8] = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n";
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!(
+            "http://{}/testing",
+            listener.local_addr().unwrap()
+        ))
+        .unwrap();
+        let preface = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0; PREFACE.len()];
+            socket.read_exact(&mut buf).await.unwrap();
+            buf
+        });
+
+        let client = default_client().http2_prior_knowledge();
+        assert_eq!(client.http.protocol, Protocol::Http2PriorKnowledge);
+        // The listener never responds, so the request itself fails once the preface is read
+        let _ = tokio::time::timeout(Duration::from_secs(1), client.get(url)).await;
+
+        assert_eq!(preface.await.unwrap(), PREFACE);
+    }
+
     #[tokio::test]
     async fn post_json() {
         let client = default_client();
@@ -250,4 +1919,68 @@ mod tests {
 
         mock.assert_hits(1);
     }
+
+    #[tokio::test]
+    async fn put_json() {
+        let client = default_client();
+        let server = MockServer::start();
+        let addr = server.address();
+        let json = serde_json::json!({
+            "name": "foo",
+            "data": 12
+        });
+        let json_clone = json.clone();
+        let mock = server.mock(|when, then| {
+            when.method(PUT).path("/testing").json_body(json_clone);
+            then.body(b"OK").status(200);
+        });
+        let url = Url::parse(&format!("http://{}/testing", addr)).unwrap();
+        client.put(url, &json).await.unwrap();
+
+        mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn patch_json() {
+        let client = default_client();
+        let server = MockServer::start();
+        let addr = server.address();
+        let json = serde_json::json!({
+            "name": "foo",
+            "data": 12
+        });
+        let json_clone = json.clone();
+        let mock = server.mock(|when, then| {
+            when.method(PATCH).path("/testing").json_body(json_clone);
+            then.body(b"OK").status(200);
+        });
+        let url = Url::parse(&format!("http://{}/testing", addr)).unwrap();
+        client.patch(url, &json).await.unwrap();
+
+        mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn patch_deserialize_error() {
+        let client = default_client();
+        let server = MockServer::start();
+        let addr = server.address();
+        let mock = server.mock(|when, then| {
+            when.method(PATCH).path("/testing");
+            then.body("Request is locked").status(409);
+        });
+        let url = Url::parse(&format!("http://{}/testing", addr)).unwrap();
+        let result = client
+            .patch_deserialize::<_, serde_json::Value>(url, "foo")
+            .await;
+
+        assert!(matches!(
+            result.map_err(Error::into_inner),
+            Err(crate::error::Error::Api {
+                status: StatusCode::CONFLICT,
+                ..
+            })
+        ));
+        mock.assert_hits(1);
+    }
 }
@@ -1,5 +1,19 @@
 //! Error and Result types for Freedom API
-use serde::Serialize;
+use std::time::Duration;
+
+use reqwest::{
+    header::{HeaderMap, CONTENT_TYPE},
+    Method, StatusCode,
+};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value as JsonValue;
+use url::Url;
+
+use crate::{error_codes::FreedomErrorCode, ids::BandId};
+
+pub use self::summary::{ErrorGroup, ErrorSummary};
+
+mod summary;
 
 /// Result type for the API
 pub type Result<T> = std::result::Result<T, Error>;
@@ -10,6 +24,20 @@ pub enum Error {
     #[error("Failed to get valid response from server: {0}")]
     Response(String),
 
+    /// The server rejected the request, with the details parsed from the response body
+    #[error("The server responded with {status}: {message}")]
+    Api {
+        #[serde(serialize_with = "serialize_status")]
+        status: StatusCode,
+        message: String,
+        path: Option<String>,
+        /// The numeric code of the error, when reported by the server
+        code: Option<FreedomErrorCode>,
+        /// The body of the response, truncated to [`MAX_BODY_LEN`], or a summary of its length
+        /// and content type when it is binary
+        raw_body: String,
+    },
+
     #[error("Failed to deserialize the response: {0}")]
     Deserialization(String),
 
@@ -27,20 +55,515 @@ pub enum Error {
     #[error("Failed to retrieve the HATEOAS URI: {0}")]
     MissingUri(&'static str),
 
-    #[error("Failed to parse the final segment of the path as an ID.")]
-    InvalidId,
+    /// The final segment of the path of the URL, which is included, is not an ID
+    #[error("Failed to parse the final segment of the path as an ID: {0}")]
+    InvalidId(String),
+
+    /// More task requests matched a bulk deletion than its safeguards allow, so nothing was deleted
+    #[error("Refusing to delete {matched} task requests, exceeding the maximum of {max}")]
+    BulkDeleteRefused { matched: usize, max: usize },
+
+    /// A bulk deletion targeted the production environment without explicitly allowing it, so
+    /// nothing was deleted
+    #[error("Refusing to perform bulk deletion in the production environment")]
+    BulkDeleteInProduction,
+
+    #[error("Failed to construct a URL: {0}")]
+    UrlConstruction(String),
+
+    #[error("Validation failed: {}", .0.join("; "))]
+    Validation(Vec<String>),
+
+    #[error("Failed to fetch the {hop} of the pass profile: {error}")]
+    PassProfile {
+        hop: &'static str,
+        error: Box<Error>,
+    },
+
+    #[error("Failed to export: {0}")]
+    Export(String),
+
+    #[error(
+        "Refusing to delete {entity}, which is referenced by {upcoming} upcoming task requests"
+    )]
+    InUse { entity: String, upcoming: usize },
+
+    #[error("I/O error: {0}")]
+    Io(String),
+
+    /// The caller is not permitted to view the requested resource
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
+
+    /// More than one band could be chosen, listing the IDs of the candidates
+    #[error("Multiple receive bands could be used, choose one of {0:?}")]
+    AmbiguousBands(Vec<BandId>),
+
+    /// A page of a paginated stream could not be fetched, even after retrying. The query of the
+    /// URL is redacted.
+    #[error("Failed to fetch the page {url} after {attempts} attempts: {error}")]
+    PageFetch {
+        url: String,
+        attempts: u32,
+        error: Box<Error>,
+    },
+
+    /// The server holds nothing matching the lookup, which is described by the message
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    /// The lines do not have the shape of a two-line element set, listing each violation
+    #[error("Malformed TLE: {}", .0.join("; "))]
+    MalformedTle(Vec<String>),
+
+    /// The request did not complete within the timeout of the client, with the query of its URL
+    /// redacted
+    #[error("Timed out after {elapsed:?} waiting for {url}")]
+    Timeout { url: String, elapsed: Duration },
+
+    /// The name or value of a header provided for a request is not valid
+    #[error("Invalid header: {0}")]
+    InvalidHeader(String),
+
+    /// The server responded with `429 Too Many Requests`, along with the delay it requested in
+    /// the `Retry-After` header, if any
+    #[error(
+        "The server is rate limiting requests{}",
+        .retry_after.map(|after| format!(", retry after {after:?}")).unwrap_or_default()
+    )]
+    RateLimited { retry_after: Option<Duration> },
+
+    /// The connection to the server could not be established
+    #[error("Failed to connect to the server: {0}")]
+    Connect(String),
+
+    /// The list was not collected, since it spans more than the number of pages the client follows.
+    /// The URL is that of the first page which was not followed.
+    #[error("Stopped collecting the list after {pages} pages, before {url}")]
+    TooManyPages { url: String, pages: usize },
+
+    /// The request which failed, with the query of its URL redacted, and the error it failed with
+    ///
+    /// The method is unknown when the error was produced by the HTTP client. See
+    /// [`Error::inner`] for the error without the context of its request.
+    #[error(
+        "{}{url}: {error}",
+        .method.as_ref().map(|method| format!("{method} ")).unwrap_or_default()
+    )]
+    Request {
+        #[serde(serialize_with = "serialize_method")]
+        method: Option<Method>,
+        url: Url,
+        error: Box<Error>,
+    },
 }
 
 impl Error {
+    /// The numeric code reported by the server for the rejected request, if any
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::{error_codes::FreedomErrorCode, prelude::*};
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// match client.get_satellite_band_by_id(1573).await {
+    ///     Err(error) if error.code() == Some(FreedomErrorCode::BandNotInAccount) => {
+    ///         println!("The band belongs to another account");
+    ///     }
+    ///     result => println!("{:?}", result?),
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub fn code(&self) -> Option<FreedomErrorCode> {
+        match self {
+            Self::Api { code, .. } => *code,
+            Self::PassProfile { error, .. }
+            | Self::PageFetch { error, .. }
+            | Self::Request { error, .. } => error.code(),
+            _ => None,
+        }
+    }
+
+    /// The error, without the context of the request which failed
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::{error::Error, prelude::*};
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// if let Err(error) = client.get_satellite_by_id(42).await {
+    ///     println!("{} failed", error.url().map(|url| url.as_str()).unwrap_or("A request"));
+    ///     if let Error::Deserialization(message) = error.inner() {
+    ///         println!("The satellite is malformed: {message}");
+    ///     }
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub fn inner(&self) -> &Error {
+        match self {
+            Self::Request { error, .. } => error.inner(),
+            error => error,
+        }
+    }
+
+    /// Like [`Error::inner`], taking ownership of the error
+    pub fn into_inner(self) -> Error {
+        match self {
+            Self::Request { error, .. } => error.into_inner(),
+            error => error,
+        }
+    }
+
+    /// The URL of the request which failed, with its query redacted, if known
+    pub fn url(&self) -> Option<&Url> {
+        match self {
+            Self::Request { url, .. } => Some(url),
+            Self::PassProfile { error, .. } | Self::PageFetch { error, .. } => error.url(),
+            _ => None,
+        }
+    }
+
+    /// The method of the request which failed, if known
+    pub fn method(&self) -> Option<&Method> {
+        match self {
+            Self::Request { method, .. } => method.as_ref(),
+            Self::PassProfile { error, .. } | Self::PageFetch { error, .. } => error.method(),
+            _ => None,
+        }
+    }
+
+    /// Attach the request which failed, unless the error already carries it, in which case only
+    /// its unknown method is filled in
+    pub(crate) fn in_request(self, method: Option<Method>, url: &Url) -> Self {
+        match self {
+            Self::Request {
+                method: known,
+                url,
+                error,
+            } => Self::Request {
+                method: known.or(method),
+                url,
+                error,
+            },
+            error => Self::Request {
+                method,
+                url: crate::telemetry::redacted(url),
+                error: Box::new(error),
+            },
+        }
+    }
+
+    /// Shorthand for attaching a request of a known method, see [`Self::in_request`]
+    pub(crate) fn at(self, method: Method, url: &Url) -> Self {
+        self.in_request(Some(method), url)
+    }
+
+    /// The status of the response which produced the error, if the server responded
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Api { status, .. } => Some(*status),
+            Self::PermissionDenied(_) => Some(StatusCode::FORBIDDEN),
+            Self::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            Self::PassProfile { error, .. }
+            | Self::PageFetch { error, .. }
+            | Self::Request { error, .. } => error.status(),
+            _ => None,
+        }
+    }
+
+    /// Whether the server holds nothing matching the request, having responded with
+    /// `404 Not Found` or with an empty result
+    pub fn is_not_found(&self) -> bool {
+        matches!(self.inner(), Self::NotFound(_)) || self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// Whether the request conflicts with the current state of the resource, having been
+    /// rejected with `409 Conflict`
+    pub fn is_conflict(&self) -> bool {
+        self.status() == Some(StatusCode::CONFLICT)
+    }
+
+    /// Whether the credentials were rejected, or do not permit the request, having been rejected
+    /// with `401 Unauthorized` or `403 Forbidden`
+    pub fn is_auth_error(&self) -> bool {
+        matches!(
+            self.status(),
+            Some(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+        )
+    }
+
+    /// Whether the failure is transient, such that the request may succeed when sent again: the
+    /// connection failed or timed out, the server is rate limiting requests, or the server
+    /// responded with a `5xx` error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let mut attempts = 0;
+    /// let site = loop {
+    ///     match client.get_site_by_id(14).await {
+    ///         Err(error) if error.is_retryable() && attempts < 3 => {
+    ///             attempts += 1;
+    ///             tokio::time::sleep(Duration::from_secs(1)).await;
+    ///         }
+    ///         result => break result?,
+    ///     }
+    /// };
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Connect(_) | Self::Timeout { .. } | Self::RateLimited { .. } => true,
+            Self::PassProfile { error, .. }
+            | Self::PageFetch { error, .. }
+            | Self::Request { error, .. } => error.is_retryable(),
+            error => error
+                .status()
+                .is_some_and(|status| status.is_server_error()),
+        }
+    }
+
     /// Shorthand for creating a runtime pagination error
     pub(crate) fn pag_item(s: String) -> Self {
         Self::PaginationItemDeserialization(s)
     }
+
+    /// Convert a `403 Forbidden` response into an [`Error::PermissionDenied`]
+    pub(crate) fn permission_denied(self) -> Self {
+        match self {
+            Self::Api {
+                status: StatusCode::FORBIDDEN,
+                message,
+                ..
+            } => Self::PermissionDenied(message),
+            Self::Request { method, url, error } => Self::Request {
+                method,
+                url,
+                error: Box::new(error.permission_denied()),
+            },
+            error => error,
+        }
+    }
+
+    /// Construct an [`Error::Api`] from the status and body of an unsuccessful response
+    ///
+    /// The body is expected to be the JSON error object produced by the server. When it cannot be
+    /// parsed, the message falls back to the body text, or to the status' reason when the body is
+    /// empty.
+    ///
+    /// A `429 Too Many Requests` is an [`Error::RateLimited`], without the requested delay, see
+    /// [`Self::from_response_headers`].
+    pub(crate) fn from_response(status: StatusCode, body: &[u8]) -> Self {
+        Self::from_body(status, None, body)
+    }
+
+    fn from_body(status: StatusCode, content_type: Option<&str>, body: &[u8]) -> Self {
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Self::RateLimited { retry_after: None };
+        }
+
+        let raw_body = body_text(body, content_type);
+        let parsed = serde_json::from_slice::<ErrorBody>(body).ok();
+
+        let (message, path, code) = match parsed {
+            Some(ErrorBody {
+                error,
+                message,
+                path,
+                code,
+                error_code,
+            }) => {
+                let code = code.or(error_code);
+                (message.or(error), path, code.as_ref().and_then(parse_code))
+            }
+            None => (None, None, None),
+        };
+
+        let message = message
+            .or_else(|| {
+                let text = raw_body.trim();
+                (is_textual(body, content_type) && !text.is_empty() && !is_html(text))
+                    .then(|| text.to_owned())
+            })
+            .unwrap_or_else(|| status.canonical_reason().unwrap_or_default().to_owned());
+
+        Self::Api {
+            status,
+            message,
+            path,
+            code,
+            raw_body,
+        }
+    }
+
+    /// Like [`Self::from_response`], additionally reading the delay requested by a
+    /// `429 Too Many Requests` from its `Retry-After` header
+    pub(crate) fn from_response_headers(
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited {
+                retry_after: crate::retry::retry_after(headers),
+            },
+            status => {
+                let content_type = headers
+                    .get(CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok());
+                Self::from_body(status, content_type, body)
+            }
+        }
+    }
+}
+
+/// The error object returned by the server for rejected requests
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: Option<String>,
+    message: Option<String>,
+    path: Option<String>,
+    code: Option<JsonValue>,
+    #[serde(rename = "errorCode")]
+    error_code: Option<JsonValue>,
+}
+
+/// Read the code as either a number or a string of digits
+fn parse_code(code: &JsonValue) -> Option<FreedomErrorCode> {
+    let code = match code {
+        JsonValue::Number(code) => code.as_u64()?,
+        JsonValue::String(code) => code.trim().parse().ok()?,
+        _ => return None,
+    };
+
+    u32::try_from(code).ok().map(FreedomErrorCode::from_code)
+}
+
+fn serialize_status<S>(status: &StatusCode, serializer: S) -> std::result::Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u16(status.as_u16())
+}
+
+/// The number of bytes of an unsuccessful response's body kept in an [`Error::Api`]
+pub const MAX_BODY_LEN: usize = 4 * 1024;
+
+/// The body as text, truncated to [`MAX_BODY_LEN`], or a summary of its length and content type
+/// when it is binary
+fn body_text(body: &[u8], content_type: Option<&str>) -> String {
+    let text = match std::str::from_utf8(body) {
+        Ok(text) if is_textual(body, content_type) => text,
+        _ => {
+            let content_type = content_type.unwrap_or("binary content");
+            return format!("<{} bytes of {content_type}>", body.len());
+        }
+    };
+
+    if text.len() <= MAX_BODY_LEN {
+        return text.to_owned();
+    }
+    let mut end = MAX_BODY_LEN;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    format!("{}… ({} bytes truncated)", &text[..end], text.len() - end)
+}
+
+/// Whether the text is an HTML page, such as the error page of a proxy, which does not make for a
+/// readable message
+fn is_html(text: &str) -> bool {
+    let start = text.get(..15).unwrap_or(text).to_ascii_lowercase();
+    start.starts_with("<!doctype html") || start.starts_with("<html")
+}
+
+/// Whether the body is text, judging by its content type when known
+fn is_textual(body: &[u8], content_type: Option<&str>) -> bool {
+    let textual = content_type.map_or(true, |content_type| {
+        let content_type = content_type.to_ascii_lowercase();
+        content_type.starts_with("text/")
+            || content_type.contains("json")
+            || content_type.contains("xml")
+    });
+
+    textual && std::str::from_utf8(body).is_ok()
+}
+
+fn serialize_method<S>(
+    method: &Option<Method>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match method {
+        Some(method) => serializer.serialize_some(method.as_str()),
+        None => serializer.serialize_none(),
+    }
 }
 
 impl From<reqwest::Error> for Error {
     fn from(value: reqwest::Error) -> Self {
-        Error::Response(value.to_string())
+        Self::from_reqwest(value, Error::Response)
+    }
+}
+
+impl Error {
+    /// Convert the error of the HTTP client with the constructor, attaching the URL of the failed
+    /// request when known
+    pub(crate) fn from_reqwest(error: reqwest::Error, constructor: fn(String) -> Self) -> Self {
+        let url = error.url().cloned();
+        let error = constructor(error.without_url().to_string());
+
+        match url {
+            Some(url) => error.in_request(None, &url),
+            None => error,
+        }
+    }
+}
+
+/// Adapts the result of a lookup, such that a resource which does not exist is not an error
+pub trait NotFoundExt<T> {
+    /// Produce `None` rather than an error when the resource does not exist, see
+    /// [`Error::is_not_found`]. Every other error is propagated.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// match client.get_site_by_name("LOAG").await.ok_if_found()? {
+    ///     Some(site) => println!("{:?}", site),
+    ///     None => println!("LOAG must be created"),
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn ok_if_found(self) -> Result<Option<T>>;
+}
+
+impl<T> NotFoundExt<T> for Result<T> {
+    fn ok_if_found(self) -> Result<Option<T>> {
+        match self {
+            Ok(value) => Ok(Some(value)),
+            Err(error) if error.is_not_found() => Ok(None),
+            Err(error) => Err(error),
+        }
     }
 }
 
@@ -62,8 +585,243 @@ impl From<time::error::Format> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::Io(value.to_string())
+    }
+}
+
 impl From<url::ParseError> for Error {
     fn from(value: url::ParseError) -> Self {
         Self::InvalidUri(value.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api(status: StatusCode) -> Error {
+        Error::from_response(status, b"")
+    }
+
+    #[test]
+    fn status_of_each_variant() {
+        assert_eq!(
+            api(StatusCode::CONFLICT).status(),
+            Some(StatusCode::CONFLICT)
+        );
+        assert_eq!(
+            Error::PermissionDenied(String::from("denied")).status(),
+            Some(StatusCode::FORBIDDEN)
+        );
+        assert_eq!(
+            Error::RateLimited { retry_after: None }.status(),
+            Some(StatusCode::TOO_MANY_REQUESTS)
+        );
+        let page = Error::PageFetch {
+            url: String::from("http://localhost/api/requests"),
+            attempts: 3,
+            error: Box::new(api(StatusCode::BAD_GATEWAY)),
+        };
+        assert_eq!(page.status(), Some(StatusCode::BAD_GATEWAY));
+        assert_eq!(Error::Connect(String::from("refused")).status(), None);
+        assert_eq!(Error::NotFound(String::from("satellite")).status(), None);
+    }
+
+    #[test]
+    fn classification_of_each_variant() {
+        assert!(api(StatusCode::NOT_FOUND).is_not_found());
+        assert!(Error::NotFound(String::from("satellite")).is_not_found());
+        assert!(!api(StatusCode::GONE).is_not_found());
+
+        assert!(api(StatusCode::CONFLICT).is_conflict());
+        assert!(!api(StatusCode::BAD_REQUEST).is_conflict());
+
+        assert!(api(StatusCode::UNAUTHORIZED).is_auth_error());
+        assert!(api(StatusCode::FORBIDDEN).is_auth_error());
+        assert!(Error::PermissionDenied(String::from("denied")).is_auth_error());
+        assert!(!api(StatusCode::NOT_FOUND).is_auth_error());
+    }
+
+    #[test]
+    fn retryable_failures() {
+        let retryable = [
+            Error::Connect(String::from("refused")),
+            Error::Timeout {
+                url: String::from("http://localhost/api/sites"),
+                elapsed: Duration::from_secs(30),
+            },
+            Error::RateLimited {
+                retry_after: Some(Duration::from_secs(1)),
+            },
+            api(StatusCode::SERVICE_UNAVAILABLE),
+            Error::PassProfile {
+                hop: "site",
+                error: Box::new(api(StatusCode::INTERNAL_SERVER_ERROR)),
+            },
+        ];
+        for error in retryable {
+            assert!(error.is_retryable(), "{error:?}");
+        }
+
+        let permanent = [
+            api(StatusCode::BAD_REQUEST),
+            api(StatusCode::NOT_FOUND),
+            Error::Deserialization(String::from("missing field `name`")),
+            Error::Validation(vec![String::from("name is empty")]),
+            Error::Response(String::from("builder error")),
+        ];
+        for error in permanent {
+            assert!(!error.is_retryable(), "{error:?}");
+        }
+    }
+
+    #[test]
+    fn api_error_from_json_body() {
+        let body = br#"{
+            "timestamp": "2024-10-01T12:00:00.000+00:00",
+            "status": 400,
+            "error": "Bad Request",
+            "message": "Band is not valid for the satellite",
+            "path": "/api/requests"
+        }"#;
+
+        let error = Error::from_response(StatusCode::BAD_REQUEST, body);
+
+        assert_eq!(
+            error,
+            Error::Api {
+                status: StatusCode::BAD_REQUEST,
+                message: String::from("Band is not valid for the satellite"),
+                path: Some(String::from("/api/requests")),
+                code: None,
+                raw_body: String::from_utf8_lossy(body).into_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn api_error_with_known_code() {
+        let body = br#"{
+            "status": 400,
+            "error": "Bad Request",
+            "message": "Band 1573 does not belong to the account",
+            "code": 40013
+        }"#;
+
+        let error = Error::from_response(StatusCode::BAD_REQUEST, body);
+
+        assert_eq!(error.code(), Some(FreedomErrorCode::BandNotInAccount));
+    }
+
+    #[test]
+    fn api_error_with_unknown_code() {
+        let body = br#"{"message": "Something new went wrong", "errorCode": "49999"}"#;
+
+        let error = Error::from_response(StatusCode::BAD_REQUEST, body);
+
+        assert_eq!(error.code(), Some(FreedomErrorCode::Unknown(49999)));
+        assert_eq!(
+            error.to_string(),
+            "The server responded with 400 Bad Request: Something new went wrong"
+        );
+
+        let nested = Error::PageFetch {
+            url: String::from("http://localhost:8080/api/requests?page=1"),
+            attempts: 1,
+            error: Box::new(error),
+        };
+        assert_eq!(nested.code(), Some(FreedomErrorCode::Unknown(49999)));
+    }
+
+    #[test]
+    fn api_error_from_text_body() {
+        let error = Error::from_response(StatusCode::CONFLICT, b"Overlapping pass\n");
+
+        assert_eq!(
+            error,
+            Error::Api {
+                status: StatusCode::CONFLICT,
+                message: String::from("Overlapping pass"),
+                path: None,
+                code: None,
+                raw_body: String::from("Overlapping pass\n"),
+            }
+        );
+    }
+
+    #[test]
+    fn api_error_from_empty_body() {
+        let error = Error::from_response(StatusCode::NOT_FOUND, b"");
+
+        assert_eq!(
+            error,
+            Error::Api {
+                status: StatusCode::NOT_FOUND,
+                message: String::from("Not Found"),
+                path: None,
+                code: None,
+                raw_body: String::new(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "The server responded with 404 Not Found: Not Found"
+        );
+    }
+
+    #[test]
+    fn api_error_from_html_page() {
+        let body = b"<!DOCTYPE html><html><body><h1>502 Bad Gateway</h1></body></html>";
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "text/html".parse().unwrap());
+
+        let Error::Api {
+            message, raw_body, ..
+        } = Error::from_response_headers(StatusCode::BAD_GATEWAY, &headers, body)
+        else {
+            panic!("expected an API error");
+        };
+
+        assert_eq!(message, "Bad Gateway");
+        assert_eq!(raw_body.as_bytes(), body);
+    }
+
+    #[test]
+    fn binary_body_is_summarized() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/octet-stream".parse().unwrap());
+
+        let Error::Api {
+            message, raw_body, ..
+        } = Error::from_response_headers(StatusCode::BAD_REQUEST, &headers, b"\x00\x9f\x92\x96")
+        else {
+            panic!("expected an API error");
+        };
+
+        assert_eq!(message, "Bad Request");
+        assert_eq!(raw_body, "<4 bytes of application/octet-stream>");
+
+        let Error::Api { raw_body, .. } =
+            Error::from_response(StatusCode::BAD_REQUEST, b"\xff\xfe")
+        else {
+            panic!("expected an API error");
+        };
+        assert_eq!(raw_body, "<2 bytes of binary content>");
+    }
+
+    #[test]
+    fn long_body_is_truncated() {
+        let body = "é".repeat(MAX_BODY_LEN);
+
+        let Error::Api { raw_body, .. } =
+            Error::from_response(StatusCode::INTERNAL_SERVER_ERROR, body.as_bytes())
+        else {
+            panic!("expected an API error");
+        };
+
+        assert!(raw_body.starts_with(&"é".repeat(MAX_BODY_LEN / 2)));
+        assert!(raw_body.ends_with(&format!("… ({MAX_BODY_LEN} bytes truncated)")));
+    }
+}
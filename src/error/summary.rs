@@ -0,0 +1,306 @@
+use std::fmt;
+
+use time::OffsetDateTime;
+
+use super::Error;
+
+/// The number of characters of each message kept when grouping errors
+const MESSAGE_LEN: usize = 120;
+
+/// Accumulates errors into groups of the same kind, endpoint, and message, using memory bounded
+/// by the number of groups rather than the number of errors
+///
+/// Once the maximum number of groups is reached, errors which do not belong to an existing group
+/// are only counted.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freedom_api::{error::ErrorSummary, prelude::*};
+/// # use futures::StreamExt;
+/// # tokio_test::block_on(async {
+/// let client = Client::from_env()?;
+///
+/// let (tasks, summary) = client
+///     .get_tasks_by_pass_overlapping(
+///         time::macros::datetime!(2024-01-01 00:00 UTC),
+///         time::macros::datetime!(2025-01-01 00:00 UTC),
+///     )
+///     .collect_lossy_summarized()
+///     .await;
+/// if !summary.is_empty() {
+///     eprintln!("Skipped {} tasks:\n{summary}", summary.total());
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// # });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorSummary {
+    groups: Vec<ErrorGroup>,
+    max_groups: usize,
+    ungrouped: usize,
+}
+
+/// Errors sharing the same kind, endpoint, and message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorGroup {
+    /// The variant of the [`Error`]
+    pub kind: &'static str,
+    /// The path of the request which failed, when known
+    pub endpoint: Option<String>,
+    /// The message of the first error, truncated
+    pub message: String,
+    pub count: usize,
+    pub first_seen: OffsetDateTime,
+    pub last_seen: OffsetDateTime,
+}
+
+impl Default for ErrorSummary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ErrorSummary {
+    /// A summary keeping at most 32 groups
+    pub fn new() -> Self {
+        Self::with_max_groups(32)
+    }
+
+    pub fn with_max_groups(max_groups: usize) -> Self {
+        Self {
+            groups: Vec::new(),
+            max_groups,
+            ungrouped: 0,
+        }
+    }
+
+    /// Record an occurrence of the error
+    pub fn observe(&mut self, error: &Error) {
+        self.observe_at(error, OffsetDateTime::now_utc());
+    }
+
+    fn observe_at(&mut self, error: &Error, now: OffsetDateTime) {
+        let kind = kind(error);
+        let endpoint = endpoint(error);
+        // The request is described by the endpoint, such that requests for different IDs are grouped
+        let message = truncate(error.inner().to_string());
+
+        let existing = self.groups.iter().position(|group| {
+            group.kind == kind && group.endpoint == endpoint && group.message == message
+        });
+        match existing {
+            Some(index) => {
+                let group = &mut self.groups[index];
+                group.count += 1;
+                group.last_seen = now;
+            }
+            None if self.groups.len() < self.max_groups => self.groups.push(ErrorGroup {
+                kind,
+                endpoint,
+                message,
+                count: 1,
+                first_seen: now,
+                last_seen: now,
+            }),
+            None => self.ungrouped += 1,
+        }
+    }
+
+    /// The groups of errors, in the order they were first observed
+    pub fn groups(&self) -> &[ErrorGroup] {
+        &self.groups
+    }
+
+    /// The number of errors which were counted, but did not fit into any group
+    pub fn ungrouped(&self) -> usize {
+        self.ungrouped
+    }
+
+    /// The number of errors observed
+    pub fn total(&self) -> usize {
+        self.groups.iter().map(|group| group.count).sum::<usize>() + self.ungrouped
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+}
+
+impl fmt::Display for ErrorSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>7}  {:<32}  {:<40}  {:<8}  {:<8}  message",
+            "count", "kind", "endpoint", "first", "last"
+        )?;
+        for group in &self.groups {
+            writeln!(
+                f,
+                "{:>7}  {:<32}  {:<40}  {:<8}  {:<8}  {}",
+                group.count,
+                group.kind,
+                group.endpoint.as_deref().unwrap_or("-"),
+                clock(group.first_seen),
+                clock(group.last_seen),
+                group.message
+            )?;
+        }
+        if self.ungrouped > 0 {
+            writeln!(f, "{:>7}  other errors", self.ungrouped)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn clock(time: OffsetDateTime) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        time.hour(),
+        time.minute(),
+        time.second()
+    )
+}
+
+fn truncate(mut message: String) -> String {
+    if let Some((index, _)) = message.char_indices().nth(MESSAGE_LEN) {
+        message.truncate(index);
+        message.push('…');
+    }
+
+    message
+}
+
+fn kind(error: &Error) -> &'static str {
+    match error {
+        Error::Response(_) => "Response",
+        Error::Api { .. } => "Api",
+        Error::Deserialization(_) => "Deserialization",
+        Error::PaginationItemDeserialization(_) => "PaginationItemDeserialization",
+        Error::TimeFormatError(_) => "TimeFormatError",
+        Error::InvalidUri(_) => "InvalidUri",
+        Error::MissingUri(_) => "MissingUri",
+        Error::InvalidId(_) => "InvalidId",
+        Error::BulkDeleteRefused { .. } => "BulkDeleteRefused",
+        Error::BulkDeleteInProduction => "BulkDeleteInProduction",
+        Error::UrlConstruction(_) => "UrlConstruction",
+        Error::Validation(_) => "Validation",
+        Error::PassProfile { .. } => "PassProfile",
+        Error::Export(_) => "Export",
+        Error::InUse { .. } => "InUse",
+        Error::Io(_) => "Io",
+        Error::PermissionDenied(_) => "PermissionDenied",
+        Error::AmbiguousBands(_) => "AmbiguousBands",
+        Error::PageFetch { .. } => "PageFetch",
+        Error::NotFound(_) => "NotFound",
+        Error::MalformedTle(_) => "MalformedTle",
+        Error::Timeout { .. } => "Timeout",
+        Error::InvalidHeader(_) => "InvalidHeader",
+        Error::RateLimited { .. } => "RateLimited",
+        Error::Connect(_) => "Connect",
+        Error::TooManyPages { .. } => "TooManyPages",
+        Error::Request { error, .. } => kind(error),
+    }
+}
+
+fn endpoint(error: &Error) -> Option<String> {
+    match error {
+        Error::Api { path, .. } => path.clone(),
+        Error::PassProfile { error, .. } | Error::PageFetch { error, .. } => endpoint(error),
+        Error::Request { url, error, .. } => {
+            endpoint(error).or_else(|| Some(url.path().to_owned()))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+    use time::{macros::datetime, Duration};
+
+    use super::*;
+
+    fn api_error(path: &str) -> Error {
+        Error::Api {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: String::from("Internal Server Error"),
+            path: Some(path.to_owned()),
+            code: None,
+            raw_body: String::new(),
+        }
+    }
+
+    #[test]
+    fn errors_are_grouped_by_kind_endpoint_and_message() {
+        let start = datetime!(2024-10-01 12:00 UTC);
+        let mut summary = ErrorSummary::new();
+
+        for i in 0..1000 {
+            let now = start + Duration::seconds(i);
+            summary.observe_at(&Error::pag_item(String::from("missing field `name`")), now);
+            summary.observe_at(&api_error("/api/tasks/search/findByOverlapping"), now);
+        }
+        summary.observe_at(&api_error("/api/requests"), start);
+        summary.observe_at(&Error::InvalidId(String::new()), start);
+
+        let groups = summary.groups();
+        assert_eq!(groups.len(), 4);
+        assert_eq!(summary.total(), 2002);
+        assert_eq!(groups[0].kind, "PaginationItemDeserialization");
+        assert_eq!(groups[0].count, 1000);
+        assert_eq!(groups[0].first_seen, start);
+        assert_eq!(groups[0].last_seen, start + Duration::seconds(999));
+        assert_eq!(
+            groups[1].endpoint.as_deref(),
+            Some("/api/tasks/search/findByOverlapping")
+        );
+        assert_eq!(groups[2].count, 1);
+        assert_eq!(groups[3].endpoint, None);
+    }
+
+    #[test]
+    fn groups_are_bounded() {
+        let now = datetime!(2024-10-01 12:00 UTC);
+        let mut summary = ErrorSummary::with_max_groups(3);
+
+        for i in 0..100 {
+            summary.observe_at(&Error::pag_item(format!("item {i} is invalid")), now);
+        }
+        summary.observe_at(&Error::pag_item(String::from("item 0 is invalid")), now);
+
+        assert_eq!(summary.groups().len(), 3);
+        assert_eq!(summary.groups()[0].count, 2);
+        assert_eq!(summary.ungrouped(), 97);
+        assert_eq!(summary.total(), 101);
+    }
+
+    #[test]
+    fn long_messages_are_truncated() {
+        let now = datetime!(2024-10-01 12:00 UTC);
+        let mut summary = ErrorSummary::new();
+
+        summary.observe_at(&Error::Response("ü".repeat(500)), now);
+        summary.observe_at(&Error::Response("ü".repeat(600)), now);
+
+        assert_eq!(summary.groups().len(), 1);
+        assert_eq!(summary.groups()[0].message.chars().count(), MESSAGE_LEN + 1);
+    }
+
+    #[test]
+    fn display_renders_a_row_per_group() {
+        let now = datetime!(2024-10-01 12:34:56 UTC);
+        let mut summary = ErrorSummary::with_max_groups(1);
+        summary.observe_at(&api_error("/api/requests"), now);
+        summary.observe_at(&Error::InvalidId(String::new()), now);
+
+        let table = summary.to_string();
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("count"));
+        assert!(lines[1].contains("/api/requests"));
+        assert!(lines[1].contains("12:34:56"));
+        assert!(lines[2].contains("1  other errors"));
+    }
+}
@@ -0,0 +1,113 @@
+//! # Error Codes
+//!
+//! Freedom includes a numeric code in the error objects of some rejected requests, which
+//! identifies the reason for the rejection more precisely than the status. Known codes are mapped
+//! to a [`FreedomErrorCode`] variant, so that callers can branch on them with
+//! [`Error::code`](crate::error::Error::code).
+//!
+//! To support a new code, add a line to the table passed to `error_codes!`. Variants are stable
+//! once added, while codes which are not in the table are preserved as
+//! [`FreedomErrorCode::Unknown`].
+
+use std::fmt;
+
+use serde::Serialize;
+
+macro_rules! error_codes {
+    ($($(#[$meta:meta])* $code:literal => $variant:ident,)*) => {
+        /// A numeric error code reported by Freedom
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+        #[serde(into = "u32")]
+        #[non_exhaustive]
+        pub enum FreedomErrorCode {
+            $($(#[$meta])* $variant,)*
+            /// A code which is not in the table of known codes
+            Unknown(u32),
+        }
+
+        impl FreedomErrorCode {
+            /// Map the numeric code to its variant
+            pub fn from_code(code: u32) -> Self {
+                match code {
+                    $($code => Self::$variant,)*
+                    code => Self::Unknown(code),
+                }
+            }
+
+            /// The numeric code, as reported by the server
+            pub fn code(&self) -> u32 {
+                match self {
+                    $(Self::$variant => $code,)*
+                    Self::Unknown(code) => *code,
+                }
+            }
+
+            /// The name of the variant, or `"Unknown"`
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => stringify!($variant),)*
+                    Self::Unknown(_) => "Unknown",
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    /// The band is not owned by the account making the request
+    40013 => BandNotInAccount,
+}
+
+impl FreedomErrorCode {
+    /// Whether the code is in the table of known codes
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Self::Unknown(_))
+    }
+}
+
+impl From<u32> for FreedomErrorCode {
+    fn from(value: u32) -> Self {
+        Self::from_code(value)
+    }
+}
+
+impl From<FreedomErrorCode> for u32 {
+    fn from(value: FreedomErrorCode) -> Self {
+        value.code()
+    }
+}
+
+impl fmt::Display for FreedomErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name(), self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_codes_map_to_variants() {
+        let code = FreedomErrorCode::from_code(40013);
+
+        assert_eq!(code, FreedomErrorCode::BandNotInAccount);
+        assert!(code.is_known());
+        assert_eq!(code.code(), 40013);
+        assert_eq!(code.to_string(), "BandNotInAccount (40013)");
+    }
+
+    #[test]
+    fn unknown_codes_round_trip() {
+        let code = FreedomErrorCode::from(49999);
+
+        assert_eq!(code, FreedomErrorCode::Unknown(49999));
+        assert!(!code.is_known());
+        assert_eq!(u32::from(code), 49999);
+        assert_eq!(code.to_string(), "Unknown (49999)");
+        assert_eq!(
+            serde_json::to_value(code).unwrap(),
+            serde_json::json!(49999)
+        );
+    }
+}
@@ -0,0 +1,408 @@
+//! # Parquet Export
+//!
+//! Writes the task history returned by the API into a columnar [Parquet](https://parquet.apache.org/)
+//! file, which is far more compact than the JSON responses for analytical workloads.
+//!
+//! This module is only available with the `parquet` feature enabled.
+
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use arrow::{
+    array::{
+        ArrayRef, BooleanArray, Int32Array, Int64Array, StringArray, TimestampMillisecondArray,
+    },
+    datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit},
+    record_batch::RecordBatch,
+};
+use freedom_models::task::Task;
+use futures_util::{Stream, StreamExt};
+use parquet::{arrow::AsyncArrowWriter, basic::Compression, file::properties::WriterProperties};
+use serde::Serialize;
+use time::OffsetDateTime;
+use tokio::{
+    fs::File,
+    io::{AsyncWriteExt, BufWriter},
+};
+
+use crate::{
+    api::Api,
+    error::Error,
+    extensions::{TaskExt, TaskRequestExt},
+};
+
+/// Controls how tasks are flattened and written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportOptions {
+    resolve_names: bool,
+    concurrency: usize,
+    row_group_size: usize,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            resolve_names: true,
+            concurrency: 8,
+            row_group_size: 10_000,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Whether to follow the links of each task to populate the `satellite_name` and `site_name`
+    /// columns. Enabled by default.
+    ///
+    /// Resolving the names requires three additional queries per task.
+    pub fn resolve_names(mut self, resolve_names: bool) -> Self {
+        self.resolve_names = resolve_names;
+        self
+    }
+
+    /// The maximum number of tasks whose links are followed at once. Defaults to 8.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// The number of rows buffered in memory before they are written as a row group. Defaults to
+    /// 10,000.
+    pub fn row_group_size(mut self, row_group_size: usize) -> Self {
+        self.row_group_size = row_group_size.max(1);
+        self
+    }
+}
+
+/// The outcome of an export
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportReport {
+    /// The number of rows written
+    pub rows: usize,
+    /// The number of items which failed to be fetched, and so were not written
+    pub skipped: usize,
+    /// The JSON file describing the skipped items, when any were skipped
+    pub errors_path: Option<PathBuf>,
+}
+
+/// The schema of the exported tasks
+///
+/// All timestamps are in milliseconds since the Unix epoch, and `duration_seconds` is derived from
+/// the start and end of the task.
+pub fn task_schema() -> SchemaRef {
+    let timestamp = DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()));
+
+    Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int32, true),
+        Field::new("created", timestamp.clone(), true),
+        Field::new("start", timestamp.clone(), true),
+        Field::new("end", timestamp, true),
+        Field::new("duration_seconds", DataType::Int64, true),
+        Field::new("billable", DataType::Boolean, true),
+        Field::new("found_visibility", DataType::Boolean, true),
+        Field::new("satellite_name", DataType::Utf8, true),
+        Field::new("site_name", DataType::Utf8, true),
+    ]))
+}
+
+/// Write every task yielded by the stream to a Parquet file at the provided path
+///
+/// Rows are written in the order the tasks are yielded, and are flushed to disk every
+/// [`row_group_size`](ExportOptions::row_group_size) rows so that memory usage remains bounded
+/// regardless of the size of the export. Items of the stream which are errors are skipped and
+/// appended, as they are skipped, to a sidecar JSON file next to the export, named
+/// `<path>.errors.json`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freedom_api::{export::{tasks_to_parquet, ExportOptions}, prelude::*};
+/// # use time::macros::datetime;
+/// # tokio_test::block_on(async {
+/// let client = Client::from_env()?;
+///
+/// let tasks = client.get_tasks_by_pass_overlapping(
+///     datetime!(2024-09-01 00:00 UTC),
+///     datetime!(2024-10-01 00:00 UTC),
+/// );
+/// let report = tasks_to_parquet(&client, tasks, "september.parquet", ExportOptions::default())
+///     .await?;
+///
+/// println!("Exported {} tasks, skipping {}", report.rows, report.skipped);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// # });
+/// ```
+pub async fn tasks_to_parquet<C, S>(
+    client: &C,
+    tasks: S,
+    path: impl AsRef<Path>,
+    options: ExportOptions,
+) -> Result<ExportReport, Error>
+where
+    C: Api,
+    S: Stream<Item = Result<C::Container<Task>, Error>>,
+{
+    let path = path.as_ref();
+    let schema = task_schema();
+    let properties = WriterProperties::builder()
+        .set_max_row_group_size(options.row_group_size)
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let file = File::create(path).await.map_err(export_error)?;
+    let mut writer =
+        AsyncArrowWriter::try_new(file, schema.clone(), Some(properties)).map_err(export_error)?;
+
+    let mut rows = Box::pin(
+        tasks
+            .enumerate()
+            .map(|(index, item)| async move {
+                match item {
+                    Ok(task) => Ok(TaskRow::resolve(client, &task, options.resolve_names).await),
+                    Err(error) => Err(Skipped { index, error }),
+                }
+            })
+            .buffered(options.concurrency),
+    );
+
+    let mut buffer = Vec::with_capacity(options.row_group_size);
+    let mut skipped = Sidecar::new(path);
+    let mut written = 0;
+    while let Some(row) = rows.next().await {
+        match row {
+            Ok(row) => buffer.push(row),
+            Err(item) => {
+                tracing::warn!(index = item.index, error = %item.error, "Skipping task");
+                skipped.record(&item).await?;
+            }
+        }
+
+        if buffer.len() >= options.row_group_size {
+            written += write_rows(&mut writer, &schema, &mut buffer).await?;
+        }
+    }
+    written += write_rows(&mut writer, &schema, &mut buffer).await?;
+    writer.close().await.map_err(export_error)?;
+
+    let count = skipped.count;
+    let errors_path = skipped.finish().await?;
+
+    Ok(ExportReport {
+        rows: written,
+        skipped: count,
+        errors_path,
+    })
+}
+
+/// The JSON array of skipped items written next to the export, which is only created once an item
+/// is skipped
+///
+/// Each item is written as it is skipped, so that the memory used does not grow with the number of
+/// skipped items.
+struct Sidecar {
+    path: PathBuf,
+    file: Option<BufWriter<File>>,
+    count: usize,
+}
+
+impl Sidecar {
+    fn new(export_path: &Path) -> Self {
+        let mut path = OsString::from(export_path);
+        path.push(".errors.json");
+
+        Self {
+            path: PathBuf::from(path),
+            file: None,
+            count: 0,
+        }
+    }
+
+    async fn record(&mut self, item: &Skipped) -> Result<(), Error> {
+        let (mut file, separator) = match self.file.take() {
+            Some(file) => (file, b",\n"),
+            None => {
+                let file = File::create(&self.path).await.map_err(export_error)?;
+                (BufWriter::new(file), b"[\n")
+            }
+        };
+        let json = serde_json::to_vec_pretty(item).map_err(export_error)?;
+        file.write_all(separator).await.map_err(export_error)?;
+        file.write_all(&json).await.map_err(export_error)?;
+        self.file = Some(file);
+        self.count += 1;
+
+        Ok(())
+    }
+
+    /// Close the array, returning the path of the file if any item was skipped
+    async fn finish(self) -> Result<Option<PathBuf>, Error> {
+        let Some(mut file) = self.file else {
+            return Ok(None);
+        };
+        file.write_all(b"\n]\n").await.map_err(export_error)?;
+        file.flush().await.map_err(export_error)?;
+
+        Ok(Some(self.path))
+    }
+}
+
+/// An item of the stream which could not be exported
+#[derive(Debug, Serialize)]
+struct Skipped {
+    index: usize,
+    error: Error,
+}
+
+/// A single task, flattened into the columns of [`task_schema`]
+#[derive(Debug, Default, Clone, PartialEq)]
+struct TaskRow {
+    id: Option<i32>,
+    created: Option<i64>,
+    start: Option<i64>,
+    end: Option<i64>,
+    duration_seconds: Option<i64>,
+    billable: Option<bool>,
+    found_visibility: Option<bool>,
+    satellite_name: Option<String>,
+    site_name: Option<String>,
+}
+
+impl TaskRow {
+    async fn resolve<C: Api>(client: &C, task: &Task, resolve_names: bool) -> Self {
+        let mut row = Self::from_task(task);
+        if resolve_names {
+            match names(client, task).await {
+                Ok((satellite, site)) => {
+                    row.satellite_name = Some(satellite);
+                    row.site_name = Some(site);
+                }
+                Err(error) => {
+                    tracing::warn!(id = ?row.id, %error, "Failed to resolve satellite and site");
+                }
+            }
+        }
+
+        row
+    }
+
+    fn from_task(task: &Task) -> Self {
+        Self {
+            id: task.get_id().ok().map(i32::from),
+            created: Some(millis(task.created)),
+            start: Some(millis(task.start)),
+            end: Some(millis(task.end)),
+            duration_seconds: Some((task.end - task.start).whole_seconds()),
+            billable: Some(task.billable),
+            found_visibility: Some(task.found_visibility),
+            satellite_name: None,
+            site_name: None,
+        }
+    }
+}
+
+/// Follow the links of the task to the names of its satellite and site
+async fn names<C: Api>(client: &C, task: &Task) -> Result<(String, String), Error> {
+    let request = task.get_task_request(client).await?;
+    let (satellite, site) =
+        futures_util::future::try_join(request.get_satellite(client), request.get_site(client))
+            .await?;
+
+    Ok((satellite.name, site.name))
+}
+
+/// Write the buffered rows as a single row group, returning the number of rows written
+async fn write_rows(
+    writer: &mut AsyncArrowWriter<File>,
+    schema: &SchemaRef,
+    buffer: &mut Vec<TaskRow>,
+) -> Result<usize, Error> {
+    if buffer.is_empty() {
+        return Ok(0);
+    }
+
+    let rows = std::mem::take(buffer);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(Int32Array::from_iter(rows.iter().map(|row| row.id))),
+        Arc::new(timestamps(rows.iter().map(|row| row.created))),
+        Arc::new(timestamps(rows.iter().map(|row| row.start))),
+        Arc::new(timestamps(rows.iter().map(|row| row.end))),
+        Arc::new(Int64Array::from_iter(
+            rows.iter().map(|row| row.duration_seconds),
+        )),
+        Arc::new(BooleanArray::from_iter(rows.iter().map(|row| row.billable))),
+        Arc::new(BooleanArray::from_iter(
+            rows.iter().map(|row| row.found_visibility),
+        )),
+        Arc::new(StringArray::from_iter(
+            rows.iter().map(|row| row.satellite_name.as_deref()),
+        )),
+        Arc::new(StringArray::from_iter(
+            rows.iter().map(|row| row.site_name.as_deref()),
+        )),
+    ];
+
+    let batch = RecordBatch::try_new(schema.clone(), columns).map_err(export_error)?;
+    writer.write(&batch).await.map_err(export_error)?;
+    writer.flush().await.map_err(export_error)?;
+
+    Ok(rows.len())
+}
+
+fn timestamps(values: impl Iterator<Item = Option<i64>>) -> TimestampMillisecondArray {
+    TimestampMillisecondArray::from_iter(values).with_timezone("UTC")
+}
+
+fn millis(time: OffsetDateTime) -> i64 {
+    (time.unix_timestamp_nanos() / 1_000_000) as i64
+}
+
+fn export_error(error: impl std::fmt::Display) -> Error {
+    Error::Export(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn golden_schema() {
+        let schema = task_schema();
+        let columns: Vec<_> = schema
+            .fields()
+            .iter()
+            .map(|field| (field.name().as_str(), field.data_type().to_string()))
+            .collect();
+
+        assert_eq!(
+            columns,
+            [
+                ("id", "Int32"),
+                ("created", r#"Timestamp(Millisecond, Some("UTC"))"#),
+                ("start", r#"Timestamp(Millisecond, Some("UTC"))"#),
+                ("end", r#"Timestamp(Millisecond, Some("UTC"))"#),
+                ("duration_seconds", "Int64"),
+                ("billable", "Boolean"),
+                ("found_visibility", "Boolean"),
+                ("satellite_name", "Utf8"),
+                ("site_name", "Utf8"),
+            ]
+            .map(|(name, typ)| (name, typ.to_string()))
+        );
+        assert!(schema.fields().iter().all(|field| field.is_nullable()));
+    }
+
+    #[test]
+    fn times_are_milliseconds_since_the_epoch() {
+        assert_eq!(
+            millis(datetime!(2022-05-26 04:57:26 UTC)),
+            1_653_541_046_000
+        );
+        assert_eq!(
+            millis(datetime!(2022-05-26 04:57:26.5 UTC)),
+            1_653_541_046_500
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use std::future::Future;
 
-use crate::{api::Api, error::Error};
-use freedom_models::{account::Account, satellite::Satellite, user::User};
+use super::HateoasExt;
+use crate::{api::Api, error::Error, ids::AccountId};
+use freedom_models::{account::Account, satellite::Satellite, site::Site, user::User};
 
 pub trait AccountExt {
-    fn get_id(&self) -> Result<i32, Error>;
+    fn get_id(&self) -> Result<AccountId, Error>;
 
     fn get_users<C>(
         &self,
@@ -19,18 +20,27 @@ pub trait AccountExt {
     ) -> impl Future<Output = Result<<C as Api>::Container<Vec<Satellite>>, Error>>
     where
         C: Api + Send;
+
+    /// Fetch the sites visible to the account, mapping a `403 Forbidden` response to
+    /// [`Error::PermissionDenied`]
+    fn get_sites<C>(
+        &self,
+        client: &C,
+    ) -> impl Future<Output = Result<<C as Api>::Container<Vec<Site>>, Error>>
+    where
+        C: Api + Send;
 }
 
 impl AccountExt for Account {
-    fn get_id(&self) -> Result<i32, Error> {
-        super::get_id("self", &self.links)
+    fn get_id(&self) -> Result<AccountId, Error> {
+        self.id_of("self").map(Into::into)
     }
 
     async fn get_users<C>(&self, client: &C) -> Result<<C as Api>::Container<Vec<User>>, Error>
     where
         C: Api + Send,
     {
-        super::get_item("users", &self.links, client).await
+        self.follow("users", client).await
     }
 
     async fn get_satellites<C>(
@@ -40,6 +50,15 @@ impl AccountExt for Account {
     where
         C: Api + Send,
     {
-        super::get_embedded("satellites", &self.links, client).await
+        self.follow_embedded("satellites", client).await
+    }
+
+    async fn get_sites<C>(&self, client: &C) -> Result<<C as Api>::Container<Vec<Site>>, Error>
+    where
+        C: Api + Send,
+    {
+        self.follow_embedded("sites", client)
+            .await
+            .map_err(Error::permission_denied)
     }
 }
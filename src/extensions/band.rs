@@ -1,12 +1,41 @@
-use crate::error::Error;
-use freedom_models::band::Band;
+use std::future::Future;
+
+use super::{hateoas::delete_self, HateoasExt};
+use crate::{api::Api, error::Error, ids::BandId};
+use freedom_models::{account::Account, band::Band};
+use reqwest::Response;
 
 pub trait BandExt {
-    fn get_id(&self) -> Result<i32, Error>;
+    fn get_id(&self) -> Result<BandId, Error>;
+
+    /// Fetch the account owning the band by following its `account` link
+    fn get_account<C>(&self, client: &C) -> impl Future<Output = Result<Account, Error>> + Send
+    where
+        C: Api + Send;
+
+    /// Delete the band at its `self` link, so that it is deleted from the environment it was
+    /// fetched from
+    fn delete<C>(&self, client: &C) -> impl Future<Output = Result<Response, Error>> + Send
+    where
+        C: Api + Send;
 }
 
 impl BandExt for Band {
-    fn get_id(&self) -> Result<i32, Error> {
-        super::get_id("self", &self.links)
+    fn get_id(&self) -> Result<BandId, Error> {
+        self.id_of("self").map(Into::into)
+    }
+
+    async fn get_account<C>(&self, client: &C) -> Result<Account, Error>
+    where
+        C: Api + Send,
+    {
+        self.follow_content("account", client).await
+    }
+
+    async fn delete<C>(&self, client: &C) -> Result<Response, Error>
+    where
+        C: Api + Send,
+    {
+        delete_self(self, client).await
     }
 }
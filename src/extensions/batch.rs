@@ -0,0 +1,70 @@
+//! # Batch Resolution
+//!
+//! Following a link of every model in a list one at a time is slow, since each hop waits on the
+//! previous one. [`resolve_all`] drives the hops concurrently, while bounding the number of
+//! requests in flight so the server is not flooded.
+//!
+//! The resolver is free to use any [`Api`](crate::Api) client. With a
+//! [`CachingClient`](crate::prelude::CachingClient), models which link to the same resource only
+//! fetch it once, since the remaining hops are served from the cache.
+
+use std::future::Future;
+
+use futures_util::{stream, StreamExt};
+
+use crate::error::Error;
+
+/// The number of resolvers run at once by [`resolve_all`]
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Resolve every item with the resolver, running at most [`DEFAULT_CONCURRENCY`] resolvers at
+/// once, see [`resolve_all_with`]
+///
+/// # Example
+///
+/// ```no_run
+/// # use freedom_api::{extensions::batch::resolve_all, prelude::*};
+/// # tokio_test::block_on(async {
+/// let client = Client::from_env()?;
+///
+/// let requests = client.get_requests().take_inner(500).await?;
+/// let satellites = resolve_all(requests.iter(), |request| request.get_satellite(&client)).await;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// # });
+/// ```
+pub async fn resolve_all<I, F, Fut, T>(items: I, resolve: F) -> Vec<Result<T, Error>>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    resolve_all_with(items, DEFAULT_CONCURRENCY, resolve).await
+}
+
+/// Resolve every item with the resolver, running at most `concurrency` resolvers at once
+///
+/// The results are produced in the order of the items, regardless of the order in which the
+/// resolvers complete. A failed resolver does not stop the others, its error is produced in place
+/// of its result.
+pub async fn resolve_all_with<I, F, Fut, T>(
+    items: I,
+    concurrency: usize,
+    mut resolve: F,
+) -> Vec<Result<T, Error>>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let mut resolved: Vec<_> = stream::iter(items.into_iter().enumerate())
+        .map(|(index, item)| {
+            let resolving = resolve(item);
+            async move { (index, resolving.await) }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+
+    resolved.sort_by_key(|(index, _)| *index);
+    resolved.into_iter().map(|(_, result)| result).collect()
+}
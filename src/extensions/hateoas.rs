@@ -0,0 +1,156 @@
+use std::future::Future;
+
+use freedom_models::{
+    utils::{Content, Embedded},
+    Hateoas,
+};
+use reqwest::Response;
+use url::Url;
+
+use crate::{
+    api::{delete_checked, Api, Value},
+    error::Error,
+};
+
+/// Follow the links of any Freedom model, including link relations without a dedicated method on
+/// the extension traits
+///
+/// Freedom serves the target of a link in one of three shapes, each of which has a method:
+///
+/// + [`follow`](Self::follow) deserializes the body as it is, as for a single entity carrying its
+///   own `_links`, or for a plain value such as an AzEl.
+/// + [`follow_embedded`](Self::follow_embedded) unwraps a collection, which is served beneath
+///   `_embedded` as `{ "_embedded": { "<rel>": [..] }, "_links": {..} }`. Each item of the
+///   collection carries its own `_links`, while the outer links describe the collection, and are
+///   dropped.
+/// + [`follow_content`](Self::follow_content) unwraps a single entity served beneath `content` as
+///   `{ "content": {..}, "_links": {..} }`. The entity does not carry its links, so the outer
+///   links are moved onto it.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freedom_api::prelude::*;
+/// # tokio_test::block_on(async {
+/// let client = Client::from_env()?;
+///
+/// let satellite = client.get_satellite_by_id(42).await?;
+/// let account: Account = satellite.follow_content("account", &client).await?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// # });
+/// ```
+pub trait HateoasExt: Hateoas {
+    /// The URL of the link relation, if the model has one
+    fn link(&self, rel: &str) -> Option<&Url> {
+        self.get_links().get(rel)
+    }
+
+    /// Parse the ID of the resource at the link relation from the final segment of its path
+    ///
+    /// A trailing slash is ignored, as are the query and fragment of the link. See
+    /// [`id64_of`](Self::id64_of) for IDs beyond the range of an `i32`.
+    fn id_of(&self, rel: &'static str) -> Result<i32, Error> {
+        let url = self.link(rel).ok_or(Error::MissingUri(rel))?;
+
+        crate::utils::id_from_url(url)
+    }
+
+    /// Like [`id_of`](Self::id_of), parsing the ID as an `i64`
+    fn id64_of(&self, rel: &'static str) -> Result<i64, Error> {
+        let url = self.link(rel).ok_or(Error::MissingUri(rel))?;
+
+        crate::utils::id_from_url(url)
+    }
+
+    /// Fetch the target of the link relation, deserializing the body as it is
+    fn follow<T, C>(
+        &self,
+        rel: &'static str,
+        client: &C,
+    ) -> impl Future<Output = Result<T, Error>> + Send
+    where
+        T: Value,
+        C: Api,
+    {
+        let url = self.link(rel).cloned().ok_or(Error::MissingUri(rel));
+
+        async move { client.get_json_map(url?).await }
+    }
+
+    /// Fetch the collection at the link relation, unwrapping it from `_embedded`
+    fn follow_embedded<T, C>(
+        &self,
+        rel: &'static str,
+        client: &C,
+    ) -> impl Future<Output = Result<<C as Api>::Container<T>, Error>> + Send
+    where
+        T: Value,
+        C: Api,
+    {
+        let wrapped = self.follow::<Embedded<<C as Api>::Container<T>>, C>(rel, client);
+
+        async move { Ok(wrapped.await?.items) }
+    }
+
+    /// Fetch the entity at the link relation, unwrapping it from `content`
+    fn follow_content<T, C>(
+        &self,
+        rel: &'static str,
+        client: &C,
+    ) -> impl Future<Output = Result<T, Error>> + Send
+    where
+        T: Value + Hateoas,
+        C: Api,
+    {
+        let wrapped = self.follow::<Content<T>, C>(rel, client);
+
+        async move { Ok(wrapped.await?.inner) }
+    }
+
+    /// Fetch the model again by following its `self` link, such as to observe a change of status
+    ///
+    /// A caching client serves the model from its cache, until the entry expires. Refreshing
+    /// through the view returned by `CachingClient::fresh` fetches the model from the server, and
+    /// updates the cache with it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let request = client.get_request_by_id(42).await?;
+    /// let request = request.refresh(&client).await?;
+    /// println!("Last modified at {:?}", request.modified);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn refresh<C>(
+        &self,
+        client: &C,
+    ) -> impl Future<Output = Result<<C as Api>::Container<Self>, Error>> + Send
+    where
+        Self: Value,
+        C: Api,
+    {
+        self.follow("self", client)
+    }
+}
+
+impl<H: Hateoas> HateoasExt for H {}
+
+/// Delete the model at its `self` link, rather than at a path built from its ID, so that it is
+/// deleted from the server it was fetched from
+pub(crate) async fn delete_self<H, C>(model: &H, client: &C) -> Result<Response, Error>
+where
+    H: Hateoas,
+    C: Api,
+{
+    let url = model
+        .link("self")
+        .cloned()
+        .ok_or(Error::MissingUri("self"))?;
+
+    delete_checked(client, url).await
+}
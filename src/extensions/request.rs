@@ -1,16 +1,97 @@
 use std::future::Future;
 
-use crate::{api::Api, error::Error};
+use super::{hateoas::delete_self, HateoasExt, TaskExt};
+use crate::{
+    api::{patch_status_at, Api, Container},
+    error::Error,
+    ids::TaskRequestId,
+};
 use freedom_models::{
+    azel::{AzEl, Direction},
     band::Band,
     satellite::Satellite,
     site::{Site, SiteConfiguration},
-    task::{Task, TaskRequest},
+    task::{Task, TaskRequest, TaskStatusType},
     user::User,
 };
+use reqwest::Response;
+use time::OffsetDateTime;
+
+/// The task produced for a task request, along with its AzEl and the details of the pass derived
+/// from it
+#[derive(Debug, Clone)]
+pub struct PassProfile {
+    pub task: Task,
+    pub azel: AzEl,
+    /// Acquisition of signal, the first sample at or above the horizon
+    pub aos: Option<OffsetDateTime>,
+    /// Loss of signal, the last sample at or above the horizon
+    pub los: Option<OffsetDateTime>,
+    /// The highest elevation reached during the pass, in degrees
+    pub max_elevation: Option<f64>,
+    /// The time at which the highest elevation is reached
+    pub max_elevation_time: Option<OffsetDateTime>,
+}
+
+impl PassProfile {
+    fn new(task: Task, azel: AzEl) -> Self {
+        let summary = PassSummary::from_directions(&azel.directions);
+
+        Self {
+            task,
+            azel,
+            aos: summary.aos,
+            los: summary.los,
+            max_elevation: summary.max_elevation,
+            max_elevation_time: summary.max_elevation_time,
+        }
+    }
+}
+
+/// The details of a pass derived from the directions of its AzEl
+#[derive(Debug, Default, PartialEq)]
+struct PassSummary {
+    aos: Option<OffsetDateTime>,
+    los: Option<OffsetDateTime>,
+    max_elevation: Option<f64>,
+    max_elevation_time: Option<OffsetDateTime>,
+}
+
+impl PassSummary {
+    fn from_directions(directions: &[Direction]) -> Self {
+        let mut visible = directions.iter().filter(|direction| direction.el >= 0.0);
+        let aos = visible.next().map(|direction| direction.timestamp);
+        let los = visible
+            .next_back()
+            .map(|direction| direction.timestamp)
+            .or(aos);
+
+        // The earliest direction wins when several share the highest elevation
+        let peak = directions
+            .iter()
+            .fold(None, |peak: Option<&Direction>, direction| match peak {
+                Some(peak) if peak.el >= direction.el => Some(peak),
+                _ => Some(direction),
+            });
+
+        Self {
+            aos,
+            los,
+            max_elevation: peak.map(|direction| direction.el),
+            max_elevation_time: peak.map(|direction| direction.timestamp),
+        }
+    }
+}
+
+fn hop_failed(hop: &'static str) -> impl FnOnce(Error) -> Error {
+    move |error| Error::PassProfile {
+        hop,
+        error: Box::new(error),
+    }
+}
 
 pub trait TaskRequestExt {
-    fn get_id(&self) -> Result<i32, Error>;
+    fn get_id(&self) -> Result<TaskRequestId, Error>;
 
     fn get_task<C>(
         &self,
@@ -44,25 +125,65 @@ pub trait TaskRequestExt {
     fn get_user<C>(&self, client: &C) -> impl Future<Output = Result<User, Error>> + Send
     where
         C: Api + Send;
+
+    /// Fetch the task produced for the request, and the AzEl of that task, deriving the AOS, LOS,
+    /// and maximum elevation of the pass.
+    ///
+    /// The AzEl is located through the task, so the two are necessarily fetched in sequence. A
+    /// failure is reported as [`Error::PassProfile`], stating which of the two could not be
+    /// fetched.
+    fn get_pass_profile<C>(
+        &self,
+        client: &C,
+    ) -> impl Future<Output = Result<PassProfile, Error>> + Send
+    where
+        C: Api + Send;
+
+    /// Delete the task request at its `self` link
+    ///
+    /// The link is followed rather than a path built from the ID of the request, so the request
+    /// is deleted from the environment it was fetched from.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let request = client.get_request_by_id(42).await?;
+    /// request.delete(&client).await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn delete<C>(&self, client: &C) -> impl Future<Output = Result<Response, Error>> + Send
+    where
+        C: Api + Send;
+
+    /// Cancel the task request at its `self` link, as for
+    /// [`Api::cancel_task_request`](crate::Api::cancel_task_request)
+    fn cancel<C>(&self, client: &C) -> impl Future<Output = Result<Response, Error>> + Send
+    where
+        C: Api + Send;
 }
 
 impl TaskRequestExt for TaskRequest {
-    fn get_id(&self) -> Result<i32, Error> {
-        super::get_id("self", &self.links)
+    fn get_id(&self) -> Result<TaskRequestId, Error> {
+        self.id_of("self").map(Into::into)
     }
 
     async fn get_task<C>(&self, client: &C) -> Result<<C as Api>::Container<Task>, Error>
     where
         C: Api + Send,
     {
-        super::get_item("task", &self.links, client).await
+        self.follow("task", client).await
     }
 
     async fn get_site<C>(&self, client: &C) -> Result<Site, Error>
     where
         C: Api + Send,
     {
-        super::get_content("site", &self.links, client).await
+        self.follow_content("site", client).await
     }
 
     async fn get_target_bands<C>(
@@ -72,7 +193,7 @@ impl TaskRequestExt for TaskRequest {
     where
         C: Api + Send,
     {
-        super::get_embedded("targetBands", &self.links, client).await
+        self.follow_embedded("targetBands", client).await
     }
 
     async fn get_config<C>(&self, client: &C) -> Result<SiteConfiguration, Error>
@@ -80,20 +201,102 @@ impl TaskRequestExt for TaskRequest {
         C: Api + Send,
     {
         tracing::debug!(links = ?self.links, "Getting configuration");
-        super::get_content("configuration", &self.links, client).await
+        self.follow_content("configuration", client).await
     }
 
     async fn get_satellite<C>(&self, client: &C) -> Result<Satellite, Error>
     where
         C: Api + Send,
     {
-        super::get_content("satellite", &self.links, client).await
+        self.follow_content("satellite", client).await
     }
 
     async fn get_user<C>(&self, client: &C) -> Result<User, Error>
     where
         C: Api + Send,
     {
-        super::get_content("user", &self.links, client).await
+        self.follow_content("user", client).await
+    }
+
+    async fn get_pass_profile<C>(&self, client: &C) -> Result<PassProfile, Error>
+    where
+        C: Api + Send,
+    {
+        let task = self.get_task(client).await.map_err(hop_failed("task"))?;
+        let azel = task.get_azel(client).await.map_err(hop_failed("azel"))?;
+
+        Ok(PassProfile::new(task.into_inner(), azel.into_inner()))
+    }
+
+    async fn delete<C>(&self, client: &C) -> Result<Response, Error>
+    where
+        C: Api + Send,
+    {
+        delete_self(self, client).await
+    }
+
+    async fn cancel<C>(&self, client: &C) -> Result<Response, Error>
+    where
+        C: Api + Send,
+    {
+        let url = self
+            .link("self")
+            .cloned()
+            .ok_or(Error::MissingUri("self"))?;
+
+        patch_status_at(client, url, TaskStatusType::Cancelled).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn summarize_azel_fixture() {
+        let azel = std::fs::read_to_string("resources/tasks_74344_azel.json").unwrap();
+        let azel: AzEl = serde_json::from_str(&azel).unwrap();
+        assert_eq!(azel.directions.len(), 26);
+
+        assert_eq!(
+            PassSummary::from_directions(&azel.directions),
+            PassSummary {
+                aos: Some(datetime!(2022-05-26 04:57:26 UTC)),
+                los: Some(datetime!(2022-05-26 05:09:56 UTC)),
+                max_elevation: Some(47.21),
+                max_elevation_time: Some(datetime!(2022-05-26 05:03:26 UTC)),
+            }
+        );
+    }
+
+    #[test]
+    fn summarize_without_directions() {
+        assert_eq!(PassSummary::from_directions(&[]), PassSummary::default());
+    }
+
+    #[test]
+    fn directions_below_the_horizon_are_not_visible() {
+        let direction = |timestamp, el| Direction {
+            timestamp,
+            az: 180.0,
+            el,
+        };
+        let directions = [
+            direction(datetime!(2022-05-26 04:57:26 UTC), -1.5),
+            direction(datetime!(2022-05-26 04:57:56 UTC), 3.25),
+            direction(datetime!(2022-05-26 04:58:26 UTC), -0.5),
+        ];
+
+        assert_eq!(
+            PassSummary::from_directions(&directions),
+            PassSummary {
+                aos: Some(datetime!(2022-05-26 04:57:56 UTC)),
+                los: Some(datetime!(2022-05-26 04:57:56 UTC)),
+                max_elevation: Some(3.25),
+                max_elevation_time: Some(datetime!(2022-05-26 04:57:56 UTC)),
+            }
+        );
     }
 }
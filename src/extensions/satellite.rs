@@ -1,13 +1,97 @@
-use freedom_models::satellite::Satellite;
+use std::future::Future;
 
-use crate::error::Error;
+use freedom_models::{
+    account::Account, satellite::Satellite, satellite_configuration::SatelliteConfiguration,
+};
+use reqwest::Response;
+
+use super::{hateoas::delete_self, HateoasExt};
+use crate::{
+    api::{orbit::OrbitInfo, visibility::Visibility, Api},
+    error::Error,
+    ids::SatelliteId,
+};
 
 pub trait SatelliteExt {
-    fn get_id(&self) -> Result<i32, Error>;
+    fn get_id(&self) -> Result<SatelliteId, Error>;
+
+    /// Fetch the orbit of the satellite by following its `orbitInfo` link
+    fn get_orbit_info<C>(
+        &self,
+        client: &C,
+    ) -> impl Future<Output = Result<<C as Api>::Container<OrbitInfo>, Error>> + Send
+    where
+        C: Api + Send;
+
+    /// Fetch the upcoming visibility windows of the satellite by following its
+    /// `upcomingVisibilities` link
+    fn get_upcoming_visibilities<C>(
+        &self,
+        client: &C,
+    ) -> impl Future<Output = Result<<C as Api>::Container<Vec<Visibility>>, Error>> + Send
+    where
+        C: Api + Send;
+
+    /// Fetch the configuration of the satellite by following its `configuration` link
+    fn get_configuration<C>(
+        &self,
+        client: &C,
+    ) -> impl Future<Output = Result<SatelliteConfiguration, Error>> + Send
+    where
+        C: Api + Send;
+
+    /// Fetch the account owning the satellite by following its `account` link
+    fn get_account<C>(&self, client: &C) -> impl Future<Output = Result<Account, Error>> + Send
+    where
+        C: Api + Send;
+
+    /// Delete the satellite at its `self` link, so that it is deleted from the environment it was
+    /// fetched from
+    fn delete<C>(&self, client: &C) -> impl Future<Output = Result<Response, Error>> + Send
+    where
+        C: Api + Send;
 }
 
 impl SatelliteExt for Satellite {
-    fn get_id(&self) -> Result<i32, Error> {
-        super::get_id("self", &self.links)
+    fn get_id(&self) -> Result<SatelliteId, Error> {
+        self.id_of("self").map(Into::into)
+    }
+
+    async fn get_orbit_info<C>(&self, client: &C) -> Result<<C as Api>::Container<OrbitInfo>, Error>
+    where
+        C: Api + Send,
+    {
+        self.follow("orbitInfo", client).await
+    }
+
+    async fn get_upcoming_visibilities<C>(
+        &self,
+        client: &C,
+    ) -> Result<<C as Api>::Container<Vec<Visibility>>, Error>
+    where
+        C: Api + Send,
+    {
+        self.follow("upcomingVisibilities", client).await
+    }
+
+    async fn get_configuration<C>(&self, client: &C) -> Result<SatelliteConfiguration, Error>
+    where
+        C: Api + Send,
+    {
+        self.follow_content("configuration", client).await
+    }
+
+    async fn get_account<C>(&self, client: &C) -> Result<Account, Error>
+    where
+        C: Api + Send,
+    {
+        self.follow_content("account", client).await
+    }
+
+    async fn delete<C>(&self, client: &C) -> Result<Response, Error>
+    where
+        C: Api + Send,
+    {
+        delete_self(self, client).await
     }
 }
@@ -0,0 +1,61 @@
+use std::future::Future;
+
+use super::{hateoas::delete_self, HateoasExt};
+use crate::{api::Api, error::Error, ids::SatelliteConfigurationId};
+use freedom_models::{
+    account::Account, band::Band, satellite_configuration::SatelliteConfiguration,
+};
+use reqwest::Response;
+
+pub trait SatelliteConfigurationExt {
+    fn get_id(&self) -> Result<SatelliteConfigurationId, Error>;
+
+    /// Fetch the bands of the configuration by following its `bandDetails` link
+    fn get_band_details<C>(
+        &self,
+        client: &C,
+    ) -> impl Future<Output = Result<<C as Api>::Container<Vec<Band>>, Error>> + Send
+    where
+        C: Api + Send;
+
+    /// Fetch the account owning the configuration by following its `account` link
+    fn get_account<C>(&self, client: &C) -> impl Future<Output = Result<Account, Error>> + Send
+    where
+        C: Api + Send;
+
+    /// Delete the configuration at its `self` link, so that it is deleted from the environment it was
+    /// fetched from
+    fn delete<C>(&self, client: &C) -> impl Future<Output = Result<Response, Error>> + Send
+    where
+        C: Api + Send;
+}
+
+impl SatelliteConfigurationExt for SatelliteConfiguration {
+    fn get_id(&self) -> Result<SatelliteConfigurationId, Error> {
+        self.id_of("self").map(Into::into)
+    }
+
+    async fn get_band_details<C>(
+        &self,
+        client: &C,
+    ) -> Result<<C as Api>::Container<Vec<Band>>, Error>
+    where
+        C: Api + Send,
+    {
+        self.follow_embedded("bandDetails", client).await
+    }
+
+    async fn get_account<C>(&self, client: &C) -> Result<Account, Error>
+    where
+        C: Api + Send,
+    {
+        self.follow_content("account", client).await
+    }
+
+    async fn delete<C>(&self, client: &C) -> Result<Response, Error>
+    where
+        C: Api + Send,
+    {
+        delete_self(self, client).await
+    }
+}
@@ -1,23 +1,48 @@
-use crate::error;
+use std::future::Future;
+
+use super::HateoasExt;
+use crate::{
+    api::Api,
+    error::Error,
+    ids::{SiteConfigurationId, SiteId},
+};
 
 use freedom_models::site::{Site, SiteConfiguration};
 
 pub trait SiteConfigurationExt {
-    fn get_id(&self) -> Result<i32, error::Error>;
+    fn get_id(&self) -> Result<SiteConfigurationId, Error>;
 }
 
 impl SiteConfigurationExt for SiteConfiguration {
-    fn get_id(&self) -> Result<i32, error::Error> {
-        super::get_id("self", &self.links)
+    fn get_id(&self) -> Result<SiteConfigurationId, Error> {
+        self.id_of("self").map(Into::into)
     }
 }
 
 pub trait SiteExt {
-    fn get_id(&self) -> Result<i32, error::Error>;
+    fn get_id(&self) -> Result<SiteId, Error>;
+
+    /// Fetch the configurations of the site by following its `configurations` link
+    fn get_configurations<C>(
+        &self,
+        client: &C,
+    ) -> impl Future<Output = Result<<C as Api>::Container<Vec<SiteConfiguration>>, Error>> + Send
+    where
+        C: Api + Send;
 }
 
 impl SiteExt for Site {
-    fn get_id(&self) -> Result<i32, error::Error> {
-        super::get_id("self", &self.links)
+    fn get_id(&self) -> Result<SiteId, Error> {
+        self.id_of("self").map(Into::into)
+    }
+
+    async fn get_configurations<C>(
+        &self,
+        client: &C,
+    ) -> Result<<C as Api>::Container<Vec<SiteConfiguration>>, Error>
+    where
+        C: Api + Send,
+    {
+        self.follow_embedded("configurations", client).await
     }
 }
@@ -0,0 +1,377 @@
+use std::{
+    future::{poll_fn, Future},
+    ops::Deref,
+};
+
+use async_stream::stream;
+
+use crate::{
+    api::{Container, PaginatedStream},
+    error::{Error, ErrorSummary},
+};
+
+/// Client-side combinators for the paginated streams produced by the [`Api`](crate::Api)
+///
+/// Many queries have no dedicated search endpoint on the server. These combinators allow a
+/// paginated stream to be filtered and reshaped locally, while leaving any errors in the stream
+/// untouched so the caller can still observe them.
+///
+/// The predicates operate on the wrapped value through [`Deref`], so items are never unwrapped
+/// from their container.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freedom_api::prelude::*;
+/// # use futures::StreamExt;
+/// # tokio_test::block_on(async {
+/// let client = Client::from_env()?;
+///
+/// let names = client
+///     .get_satellites()
+///     .filter_model(|sat: &Satellite| sat.account_name == "ATLAS")
+///     .map_inner(|sat: &Satellite| sat.name.clone())
+///     .take_items(10)
+///     .collect::<Vec<_>>()
+///     .await;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// # });
+/// ```
+pub trait PaginatedStreamExt<'a, C>
+where
+    C: Deref,
+{
+    /// Retain only the items whose wrapped value satisfies the predicate
+    ///
+    /// Errors are always passed through.
+    fn filter_model<F>(self, predicate: F) -> PaginatedStream<'a, C>
+    where
+        F: FnMut(&C::Target) -> bool + Send + 'a;
+
+    /// Map the wrapped value of each successful item into a new value
+    ///
+    /// Errors are always passed through.
+    fn map_inner<U, F>(self, f: F) -> PaginatedStream<'a, U>
+    where
+        F: FnMut(&C::Target) -> U + Send + 'a,
+        U: Send + 'a;
+
+    /// Limit the stream to the first `n` successful items
+    ///
+    /// Errors encountered before the limit is reached are passed through, but do not count
+    /// towards `n`.
+    fn take_items(self, n: usize) -> PaginatedStream<'a, C>;
+
+    /// Collect every successful item, summarizing the errors rather than keeping each of them
+    ///
+    /// Memory used by the errors is bounded regardless of the length of the stream, see
+    /// [`ErrorSummary`].
+    fn collect_lossy_summarized(self) -> impl Future<Output = (Vec<C>, ErrorSummary)> + Send + 'a;
+
+    /// Collect the wrapped value of every item, failing on the first error
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let satellites: Vec<Satellite> = client.get_satellites().try_collect_inner().await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn try_collect_inner<T>(self) -> impl Future<Output = Result<Vec<T>, Error>> + Send + 'a
+    where
+        C: Container<T>,
+        T: Send + 'a;
+
+    /// Collect the wrapped value of every successful item, along with every error
+    ///
+    /// Unlike [`collect_lossy_summarized`](Self::collect_lossy_summarized), every error is kept,
+    /// so prefer it for streams which may produce a great many errors.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let (accounts, errors) = client.get_accounts().collect_inner_lossy().await;
+    /// for error in errors {
+    ///     eprintln!("Skipped an account: {error}");
+    /// }
+    /// println!("{accounts:#?}");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn collect_inner_lossy<T>(self) -> impl Future<Output = (Vec<T>, Vec<Error>)> + Send + 'a
+    where
+        C: Container<T>,
+        T: Send + 'a;
+
+    /// Collect the wrapped values of the first `n` items, failing on the first error
+    ///
+    /// No further pages are fetched once `n` items have been collected.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use freedom_api::prelude::*;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::from_env()?;
+    ///
+    /// let requests: Vec<TaskRequest> = client.get_requests().take_inner(10).await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    fn take_inner<T>(self, n: usize) -> impl Future<Output = Result<Vec<T>, Error>> + Send + 'a
+    where
+        C: Container<T>,
+        T: Send + 'a;
+}
+
+impl<'a, C> PaginatedStreamExt<'a, C> for PaginatedStream<'a, C>
+where
+    C: Deref + Send + 'a,
+{
+    fn filter_model<F>(mut self, mut predicate: F) -> PaginatedStream<'a, C>
+    where
+        F: FnMut(&C::Target) -> bool + Send + 'a,
+    {
+        Box::pin(stream! {
+            while let Some(item) = poll_fn(|cx| self.as_mut().poll_next(cx)).await {
+                if let Ok(container) = &item {
+                    if !predicate(container) {
+                        continue;
+                    }
+                }
+
+                yield item;
+            }
+        })
+    }
+
+    fn map_inner<U, F>(mut self, mut f: F) -> PaginatedStream<'a, U>
+    where
+        F: FnMut(&C::Target) -> U + Send + 'a,
+        U: Send + 'a,
+    {
+        Box::pin(stream! {
+            while let Some(item) = poll_fn(|cx| self.as_mut().poll_next(cx)).await {
+                yield item.map(|container| f(&container));
+            }
+        })
+    }
+
+    fn take_items(mut self, n: usize) -> PaginatedStream<'a, C> {
+        Box::pin(stream! {
+            let mut taken = 0;
+            while taken < n {
+                let Some(item) = poll_fn(|cx| self.as_mut().poll_next(cx)).await else {
+                    break;
+                };
+
+                if item.is_ok() {
+                    taken += 1;
+                }
+
+                yield item;
+            }
+        })
+    }
+
+    async fn collect_lossy_summarized(mut self) -> (Vec<C>, ErrorSummary) {
+        let mut items = Vec::new();
+        let mut summary = ErrorSummary::new();
+        while let Some(item) = poll_fn(|cx| self.as_mut().poll_next(cx)).await {
+            match item {
+                Ok(item) => items.push(item),
+                Err(error) => summary.observe(&error),
+            }
+        }
+
+        (items, summary)
+    }
+
+    fn try_collect_inner<T>(self) -> impl Future<Output = Result<Vec<T>, Error>> + Send + 'a
+    where
+        C: Container<T>,
+        T: Send + 'a,
+    {
+        self.take_inner(usize::MAX)
+    }
+
+    async fn collect_inner_lossy<T>(mut self) -> (Vec<T>, Vec<Error>)
+    where
+        C: Container<T>,
+        T: Send + 'a,
+    {
+        let mut items = Vec::new();
+        let mut errors = Vec::new();
+        while let Some(item) = poll_fn(|cx| self.as_mut().poll_next(cx)).await {
+            match item {
+                Ok(item) => items.push(item.into_inner()),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        (items, errors)
+    }
+
+    async fn take_inner<T>(mut self, n: usize) -> Result<Vec<T>, Error>
+    where
+        C: Container<T>,
+        T: Send + 'a,
+    {
+        let mut items = Vec::new();
+        while items.len() < n {
+            match poll_fn(|cx| self.as_mut().poll_next(cx)).await {
+                Some(item) => items.push(item?.into_inner()),
+                None => break,
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::client::Inner;
+
+    fn synthetic<'a, C: Send + 'a>(items: Vec<Result<C, Error>>) -> PaginatedStream<'a, C> {
+        Box::pin(stream! {
+            for item in items {
+                yield item;
+            }
+        })
+    }
+
+    fn items() -> Vec<Result<i32, Error>> {
+        vec![
+            Ok(1),
+            Err(Error::pag_item(String::from("bad item"))),
+            Ok(2),
+            Ok(3),
+            Ok(4),
+        ]
+    }
+
+    #[tokio::test]
+    async fn filter_inner_containers() {
+        let stream = synthetic(items().into_iter().map(|r| r.map(Inner)).collect());
+
+        let filtered = stream
+            .filter_model(|val: &i32| val % 2 == 0)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(filtered.len(), 3);
+        assert!(filtered[0].is_err());
+        assert_eq!(**filtered[1].as_ref().unwrap(), 2);
+        assert_eq!(**filtered[2].as_ref().unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn filter_arc_containers() {
+        let stream = synthetic(items().into_iter().map(|r| r.map(Arc::new)).collect());
+
+        let filtered = stream
+            .filter_model(|val: &i32| *val > 2)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(filtered.len(), 3);
+        assert!(filtered[0].is_err());
+        assert_eq!(**filtered[1].as_ref().unwrap(), 3);
+        assert_eq!(**filtered[2].as_ref().unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn map_inner_preserves_errors() {
+        let stream = synthetic(items().into_iter().map(|r| r.map(Inner)).collect());
+
+        let mapped = stream
+            .map_inner(|val: &i32| val.to_string())
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(mapped.len(), 5);
+        assert_eq!(mapped[0], Ok(String::from("1")));
+        assert!(mapped[1].is_err());
+        assert_eq!(mapped[4], Ok(String::from("4")));
+    }
+
+    #[tokio::test]
+    async fn take_items_only_counts_ok() {
+        let stream = synthetic(items().into_iter().map(|r| r.map(Arc::new)).collect());
+
+        let taken = stream.take_items(2).collect::<Vec<_>>().await;
+
+        assert_eq!(taken.len(), 3);
+        assert_eq!(taken.iter().filter(|item| item.is_ok()).count(), 2);
+        assert!(taken[1].is_err());
+    }
+
+    #[tokio::test]
+    async fn collect_lossy_summarized_groups_errors() {
+        let mut items = items();
+        items.push(Err(Error::pag_item(String::from("bad item"))));
+        let stream = synthetic(items.into_iter().map(|r| r.map(Inner)).collect());
+
+        let (items, summary) = stream.collect_lossy_summarized().await;
+
+        assert_eq!(items.len(), 4);
+        assert_eq!(summary.total(), 2);
+        assert_eq!(summary.groups().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn try_collect_inner_fails_fast() {
+        let stream = synthetic(items().into_iter().map(|r| r.map(Inner)).collect());
+        assert!(stream.try_collect_inner().await.is_err());
+
+        let stream = synthetic(
+            items()
+                .into_iter()
+                .filter_map(|r| r.ok().map(|val| Ok(Inner(val))))
+                .collect(),
+        );
+        assert_eq!(stream.try_collect_inner().await, Ok(vec![1, 2, 3, 4]));
+    }
+
+    #[tokio::test]
+    async fn collect_inner_lossy_partitions() {
+        let stream = synthetic(items().into_iter().map(|r| r.map(Inner)).collect());
+
+        let (items, errors) = stream.collect_inner_lossy().await;
+
+        assert_eq!(items, vec![1, 2, 3, 4]);
+        assert_eq!(errors, vec![Error::pag_item(String::from("bad item"))]);
+    }
+
+    #[tokio::test]
+    async fn take_inner_stops_after_n() {
+        let stream = synthetic(items().into_iter().skip(2).map(|r| r.map(Inner)).collect());
+        assert_eq!(stream.take_inner(2).await, Ok(vec![2, 3]));
+
+        let stream = synthetic(items().into_iter().map(|r| r.map(Inner)).collect());
+        assert!(stream.take_inner(2).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn take_zero_items() {
+        let stream = synthetic(items().into_iter().map(|r| r.map(Inner)).collect());
+
+        let taken = stream.take_items(0).collect::<Vec<_>>().await;
+
+        assert!(taken.is_empty());
+    }
+}
@@ -1,6 +1,7 @@
 use std::future::Future;
 
-use crate::{api::Api, error::Error};
+use super::HateoasExt;
+use crate::{api::Api, error::Error, ids::TaskId};
 use freedom_models::{
     azel::AzEl,
     site::SiteConfiguration,
@@ -8,8 +9,14 @@ use freedom_models::{
 };
 
 pub trait TaskExt {
-    fn get_id(&self) -> Result<i32, Error>;
+    fn get_id(&self) -> Result<TaskId, Error>;
 
+    /// Like [`get_id`](Self::get_id), for deployments whose task IDs exceed the range of an `i32`
+    fn get_id64(&self) -> Result<i64, Error>;
+
+    /// Fetch the request from which the task was created, by following its `taskRequest` link
+    ///
+    /// Tasks without the link fall back to [`Api::get_request_by_task_id`].
     fn get_task_request<C>(
         &self,
         client: &C,
@@ -33,8 +40,12 @@ pub trait TaskExt {
 }
 
 impl TaskExt for Task {
-    fn get_id(&self) -> Result<i32, Error> {
-        super::get_id("self", &self.links)
+    fn get_id(&self) -> Result<TaskId, Error> {
+        self.id_of("self").map(Into::into)
+    }
+
+    fn get_id64(&self) -> Result<i64, Error> {
+        self.id64_of("self")
     }
 
     async fn get_task_request<C>(
@@ -44,7 +55,11 @@ impl TaskExt for Task {
     where
         C: Api,
     {
-        super::get_item("taskRequest", &self.links, client).await
+        if !self.links.contains_key("taskRequest") {
+            return client.get_request_by_task_id(self.get_id()?).await;
+        }
+
+        self.follow("taskRequest", client).await
     }
 
     async fn get_config<C>(
@@ -54,13 +69,13 @@ impl TaskExt for Task {
     where
         C: Api + Send + Sync,
     {
-        super::get_item("config", &self.links, client).await
+        self.follow("config", client).await
     }
 
     async fn get_azel<C>(&self, client: &C) -> Result<<C as Api>::Container<AzEl>, Error>
     where
         C: Api + Send + Sync,
     {
-        super::get_item("azel", &self.links, client).await
+        self.follow("azel", client).await
     }
 }
@@ -1,25 +1,51 @@
 use std::future::Future;
 
-use crate::{api::Api, error::Error};
+use super::HateoasExt;
+use crate::{api::Api, error::Error, ids::UserId};
 use freedom_models::{account::Account, user::User};
+use serde::Deserialize;
 
 pub trait UserExt {
-    fn get_id(&self) -> Result<i32, Error>;
+    fn get_id(&self) -> Result<UserId, Error>;
 
     fn get_account<C>(&self, client: &C) -> impl Future<Output = Result<Account, Error>> + Send
     where
         C: Api + Send;
+
+    /// Fetch the names of the roles assigned to the user
+    ///
+    /// The roles are served alongside the user, but are not part of the [`User`] model, so the
+    /// user is fetched again by following its `self` link.
+    fn get_roles<C>(&self, client: &C) -> impl Future<Output = Result<Vec<String>, Error>> + Send
+    where
+        C: Api + Send;
+}
+
+/// The roles of a user, ignoring the remaining fields
+#[derive(Debug, Clone, Deserialize)]
+struct Roles {
+    #[serde(default)]
+    roles: Vec<String>,
 }
 
 impl UserExt for User {
-    fn get_id(&self) -> Result<i32, Error> {
-        super::get_id("self", &self.links)
+    fn get_id(&self) -> Result<UserId, Error> {
+        self.id_of("self").map(Into::into)
     }
 
     async fn get_account<C>(&self, client: &C) -> Result<Account, Error>
     where
         C: Api + Send,
     {
-        super::get_content("account", &self.links, client).await
+        self.follow_content("account", client).await
+    }
+
+    async fn get_roles<C>(&self, client: &C) -> Result<Vec<String>, Error>
+    where
+        C: Api + Send,
+    {
+        let user: Roles = self.follow("self", client).await?;
+
+        Ok(user.roles)
     }
 }
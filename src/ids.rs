@@ -0,0 +1,137 @@
+//! # Typed IDs
+//!
+//! Freedom identifies every resource with an integer, which makes it easy to pass the ID of one
+//! kind of resource where another is expected, such as a site configuration ID in place of a site
+//! ID. Each kind of resource has its own ID type, which the [`Api`](crate::Api) methods and the
+//! builders accept in place of a bare integer, and which the `get_id` extension methods return.
+//!
+//! Integers convert into any of the ID types, so existing call sites keep working, while a typed
+//! ID is only accepted where its own kind of resource is expected:
+//!
+//! ```compile_fail
+//! # use freedom_api::prelude::*;
+//! # tokio_test::block_on(async {
+//! let client = Client::from_env()?;
+//!
+//! let configuration = SiteConfigurationId::from(47);
+//! let site = client.get_site_by_id(configuration).await?;
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! # });
+//! ```
+//!
+//! ```compile_fail
+//! # use freedom_api::prelude::*;
+//! # use time::OffsetDateTime;
+//! let client = Client::from_env()?;
+//!
+//! let satellite = SatelliteId::from(1016);
+//! let request = client
+//!     .new_task_request()
+//!     .test_task("my_test_file.bin")
+//!     .target_time_utc(OffsetDateTime::now_utc())
+//!     .task_duration(120)
+//!     .satellite_id(satellite)
+//!     .site_id(satellite);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+macro_rules! ids {
+    ($($(#[$meta:meta])* $name:ident,)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(
+                Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+            )]
+            #[serde(transparent)]
+            pub struct $name(i32);
+
+            impl $name {
+                /// The ID as an integer
+                pub const fn get(self) -> i32 {
+                    self.0
+                }
+            }
+
+            impl From<i32> for $name {
+                fn from(id: i32) -> Self {
+                    Self(id)
+                }
+            }
+
+            impl From<$name> for i32 {
+                fn from(id: $name) -> Self {
+                    id.0
+                }
+            }
+
+            impl PartialEq<i32> for $name {
+                fn eq(&self, other: &i32) -> bool {
+                    self.0 == *other
+                }
+            }
+
+            impl PartialOrd<i32> for $name {
+                fn partial_cmp(&self, other: &i32) -> Option<std::cmp::Ordering> {
+                    self.0.partial_cmp(other)
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.0.fmt(f)
+                }
+            }
+
+            impl FromStr for $name {
+                type Err = ParseIntError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    s.parse().map(Self)
+                }
+            }
+        )*
+    };
+}
+
+ids! {
+    /// The ID of an [`Account`](freedom_models::account::Account)
+    AccountId,
+    /// The ID of a [`Band`](freedom_models::band::Band)
+    BandId,
+    /// The ID of a [`Satellite`](freedom_models::satellite::Satellite)
+    SatelliteId,
+    /// The ID of a
+    /// [`SatelliteConfiguration`](freedom_models::satellite_configuration::SatelliteConfiguration)
+    SatelliteConfigurationId,
+    /// The ID of a [`Site`](freedom_models::site::Site)
+    SiteId,
+    /// The ID of a [`SiteConfiguration`](freedom_models::site::SiteConfiguration)
+    SiteConfigurationId,
+    /// The ID of a [`Task`](freedom_models::task::Task)
+    TaskId,
+    /// The ID of a [`TaskRequest`](freedom_models::task::TaskRequest)
+    TaskRequestId,
+    /// The ID of a [`User`](freedom_models::user::User)
+    UserId,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_transparent() {
+        let id = SiteId::from(14);
+
+        assert_eq!(serde_json::to_string(&id).unwrap(), "14");
+        assert_eq!(serde_json::from_str::<SiteId>("14").unwrap(), id);
+        assert_eq!(id.to_string(), "14");
+        assert_eq!("14".parse::<SiteId>().unwrap(), id);
+        assert_eq!(i32::from(id), 14);
+        assert_eq!(id, 14);
+    }
+}
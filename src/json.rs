@@ -0,0 +1,314 @@
+//! JSON parsing backend
+//!
+//! By default, responses are parsed with `serde_json`. When the `fast-json` feature is enabled,
+//! responses are first parsed with `simd-json`, falling back to `serde_json` for any type which
+//! `simd-json` cannot handle. Either way, failures are reported as
+//! [`Error::Deserialization`](crate::error::Error::Deserialization), naming the target type, the
+//! path to the offending value, and a snippet of the JSON surrounding it.
+use serde::de::DeserializeOwned;
+use serde_json::Value as JsonValue;
+use serde_path_to_error::{Path, Segment};
+
+use crate::error::Error;
+
+/// The number of bytes of the offending JSON included in the description of a failure
+const SNIPPET_LENGTH: usize = 160;
+
+#[cfg(not(feature = "fast-json"))]
+pub(crate) fn from_slice<T>(json: &[u8]) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    from_slice_with_context(json)
+}
+
+#[cfg(feature = "fast-json")]
+pub(crate) fn from_slice<T>(json: &[u8]) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    // simd-json parses in place, so it requires its own mutable copy of the input
+    let mut bytes = json.to_vec();
+    match simd_json::serde::from_slice(&mut bytes) {
+        Ok(value) => Ok(value),
+        Err(error) => {
+            tracing::trace!(%error, "simd-json failed to parse, falling back to serde_json");
+            from_slice_with_context(json)
+        }
+    }
+}
+
+/// Parse with `serde_json`, describing a failure with the target type, the path to the offending
+/// value, and a snippet of the JSON surrounding it
+fn from_slice_with_context<T>(json: &[u8]) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    let mut deserializer = serde_json::Deserializer::from_slice(json);
+    let value = serde_path_to_error::deserialize(&mut deserializer).map_err(|error| {
+        let snippet = snippet(json, offset(json, error.inner()));
+        Error::Deserialization(describe::<T>(
+            &error.path().to_string(),
+            error.inner(),
+            &snippet,
+        ))
+    })?;
+    deserializer.end().map_err(|error| {
+        let snippet = snippet(json, offset(json, &error));
+        Error::Deserialization(describe::<T>(".", &error, &snippet))
+    })?;
+
+    Ok(value)
+}
+
+/// Deserialize the value, describing a failure as with [`from_slice`]
+pub(crate) fn from_value<T>(value: &JsonValue) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    serde_path_to_error::deserialize(value).map_err(|error| {
+        Error::Deserialization(describe::<T>(
+            &error.path().to_string(),
+            error.inner(),
+            &snippet_at(value, error.path()),
+        ))
+    })
+}
+
+/// Deserialize an item of a paginated stream, describing a failure as with [`from_value`]
+pub(crate) fn item_from_value<T>(item: &JsonValue) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    serde_path_to_error::deserialize(item).map_err(|error| {
+        Error::pag_item(describe::<T>(
+            &error.path().to_string(),
+            error.inner(),
+            &snippet_at(item, error.path()),
+        ))
+    })
+}
+
+fn describe<T>(path: &str, error: &serde_json::Error, json: &str) -> String {
+    format!(
+        "{} at `{path}`: {error}, in `{json}`",
+        std::any::type_name::<T>()
+    )
+}
+
+/// The byte offset of the position reported by the error
+fn offset(json: &[u8], error: &serde_json::Error) -> usize {
+    let line_start: usize = json
+        .split(|byte| *byte == b'\n')
+        .take(error.line().saturating_sub(1))
+        .map(|line| line.len() + 1)
+        .sum();
+
+    (line_start + error.column()).min(json.len())
+}
+
+/// The JSON surrounding the offset, with its whitespace collapsed
+fn snippet(json: &[u8], offset: usize) -> String {
+    let start = offset.saturating_sub(SNIPPET_LENGTH / 2);
+    let end = (start + SNIPPET_LENGTH).min(json.len());
+    let snippet = String::from_utf8_lossy(&json[start..end])
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let before = if start > 0 { "..." } else { "" };
+    let after = if end < json.len() { "..." } else { "" };
+    format!("{before}{snippet}{after}")
+}
+
+/// The JSON surrounding the value at the path, within its parent so that its key is included
+///
+/// Where the path leads to a value which does not exist, such as a missing field, the deepest
+/// value along the path is used instead.
+fn snippet_at(value: &JsonValue, path: &Path) -> String {
+    let (mut parent, mut located) = (value, value);
+    for segment in path.iter() {
+        let child = match (segment, located) {
+            (Segment::Seq { index }, JsonValue::Array(items)) => items.get(*index),
+            (Segment::Map { key }, JsonValue::Object(fields)) => fields.get(key),
+            _ => None,
+        };
+        let Some(child) = child else {
+            break;
+        };
+        (parent, located) = (located, child);
+    }
+
+    let json = parent.to_string();
+    let offset = json.find(&located.to_string()).unwrap_or_default();
+
+    snippet(json.as_bytes(), offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use freedom_models::satellite::Satellite;
+    #[cfg(feature = "fast-json")]
+    use freedom_models::{
+        account::Account,
+        band::Band,
+        satellite_configuration::SatelliteConfiguration,
+        site::Site,
+        task::{Task, TaskRequest},
+        user::User,
+    };
+
+    use super::*;
+
+    #[cfg(feature = "fast-json")]
+    #[test]
+    fn backends_agree_on_fixtures() {
+        let fixtures = std::fs::read_dir("resources")
+            .unwrap()
+            .chain(std::fs::read_dir("resources/tasks_1").unwrap())
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"));
+
+        for path in fixtures {
+            let json = std::fs::read_to_string(&path).unwrap();
+            let mut bytes = json.clone().into_bytes();
+
+            let simd: JsonValue = simd_json::serde::from_slice(&mut bytes).unwrap();
+            let serde: JsonValue = serde_json::from_str(&json).unwrap();
+            assert_eq!(simd, serde, "{}", path.display());
+
+            let name = path.strip_prefix("resources").unwrap().to_string_lossy();
+            if name.starts_with("tasks_1") {
+                models_agree::<Task>(&serde, &path);
+            } else if name.starts_with("satellite_bands") {
+                models_agree::<Band>(&serde, &path);
+            } else if name.starts_with("satellite_configurations") {
+                models_agree::<SatelliteConfiguration>(&serde, &path);
+            } else if name.starts_with("satellite_find") {
+                models_agree::<Satellite>(&serde, &path);
+            } else if name.starts_with("sites") {
+                models_agree::<Site>(&serde, &path);
+            } else if name.starts_with("requests") {
+                models_agree::<TaskRequest>(&serde, &path);
+            } else if name.starts_with("accounts") {
+                models_agree::<Account>(&serde, &path);
+            } else if name.starts_with("users") {
+                models_agree::<User>(&serde, &path);
+            }
+        }
+    }
+
+    /// Deserialize the fixture, or each item embedded in it, as the model with both backends, and
+    /// check they produce the same model, or both fail
+    #[cfg(feature = "fast-json")]
+    fn models_agree<T>(fixture: &JsonValue, path: &std::path::Path)
+    where
+        T: DeserializeOwned + std::fmt::Debug,
+    {
+        let items = match fixture.pointer("/_embedded").and_then(JsonValue::as_object) {
+            Some(embedded) => embedded
+                .values()
+                .filter_map(JsonValue::as_array)
+                .flatten()
+                .collect(),
+            None => vec![fixture],
+        };
+
+        for item in items {
+            let json = item.to_string();
+            let mut bytes = json.clone().into_bytes();
+
+            let simd = simd_json::serde::from_slice::<T>(&mut bytes).ok();
+            let serde = serde_json::from_str::<T>(&json).ok();
+            assert_eq!(
+                format!("{simd:?}"),
+                format!("{serde:?}"),
+                "{}",
+                path.display()
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_json_is_deserialization_error() {
+        let result = from_slice::<JsonValue>(b"{ \"name\": ");
+
+        assert!(matches!(result, Err(Error::Deserialization(_))));
+    }
+
+    #[test]
+    fn failure_names_type_path_and_snippet() {
+        let json = br#"{ "name": "FooBar", "noradCatId": "one hundred" }"#;
+
+        let Err(Error::Deserialization(message)) = from_slice_with_context::<Satellite>(json)
+        else {
+            panic!("expected a deserialization error");
+        };
+
+        assert!(message.contains("Satellite"), "{message}");
+        assert!(message.contains("noradCatId"), "{message}");
+        assert!(
+            message.contains(r#""noradCatId": "one hundred""#),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn invalid_utf8_is_not_replaced() {
+        let result = from_slice_with_context::<String>(b"\"\xff\"");
+
+        assert!(matches!(result, Err(Error::Deserialization(_))));
+    }
+
+    #[test]
+    fn long_input_is_truncated() {
+        let json = format!("[{}\"oops\"]", "1, ".repeat(1000));
+
+        let Err(Error::Deserialization(message)) =
+            from_slice_with_context::<Vec<u32>>(json.as_bytes())
+        else {
+            panic!("expected a deserialization error");
+        };
+
+        assert!(message.contains("oops"), "{message}");
+        assert!(message.len() < 2 * SNIPPET_LENGTH + 100, "{message}");
+    }
+
+    #[test]
+    fn long_values_are_truncated_around_the_failure() {
+        let mut numbers = vec![serde_json::json!(1); 1000];
+        numbers.push("oops".into());
+        let value = serde_json::json!({ "numbers": numbers });
+
+        let Err(Error::Deserialization(message)) = from_value::<HashMap<String, Vec<u32>>>(&value)
+        else {
+            panic!("expected a deserialization error");
+        };
+        assert!(message.contains("oops"), "{message}");
+        assert!(message.len() < 2 * SNIPPET_LENGTH + 100, "{message}");
+
+        let Err(Error::PaginationItemDeserialization(message)) =
+            item_from_value::<HashMap<String, Vec<u32>>>(&value)
+        else {
+            panic!("expected a deserialization error");
+        };
+        assert!(message.contains("oops"), "{message}");
+        assert!(message.len() < 2 * SNIPPET_LENGTH + 100, "{message}");
+    }
+
+    #[test]
+    fn value_failure_includes_its_key() {
+        let value = serde_json::json!({ "name": "FooBar", "noradCatId": "one hundred" });
+
+        let Err(Error::Deserialization(message)) = from_value::<Satellite>(&value) else {
+            panic!("expected a deserialization error");
+        };
+        assert!(message.contains("noradCatId"), "{message}");
+        assert!(
+            message.contains(r#""noradCatId":"one hundred""#),
+            "{message}"
+        );
+    }
+}
@@ -1,34 +1,75 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "caching")]
+mod any_client;
 mod api;
+mod auth;
+mod backoff;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 #[cfg(feature = "caching")]
 mod caching_client;
 mod client;
 pub mod error;
+pub mod error_codes;
+#[cfg(feature = "parquet")]
+pub mod export;
 pub mod extensions;
+pub mod ids;
+mod json;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+mod rate_limit;
+mod retry;
+mod telemetry;
+#[cfg(feature = "test-util")]
+pub mod testing;
 mod utils;
 
 pub use self::{
-    api::{Api, Container, Value},
+    api::{bulk, fps, orbit, Api, Container, Value},
     client::Client,
 };
 
 /// Contains the client, data models, error types, and traits necessary for queries
 pub mod prelude {
     #[cfg(feature = "caching")]
-    pub use crate::caching_client::CachingClient;
+    pub use crate::{
+        any_client::AnyClient,
+        caching_client::{CachingClient, CachingClientBuilder},
+    };
     pub use crate::{
         api::{
+            delete::{DeleteReport, DeleteSafeguards, RequestFilter},
+            info::ServerInfo,
+            orbit::OrbitInfo,
+            pagination::{PageMeta, PageOptions, PaginationBehavior, SortOrder},
+            pairing::BandPair,
             post::{
-                BandDetailsBuilder, OverrideBuilder, SatelliteBuilder,
-                SatelliteConfigurationBuilder, UserBuilder,
+                AccountBuilder, BandDetailsBuilder, BandRule, CreatedTaskRequest, ExistingOverride,
+                OverrideBuilder, SatelliteBuilder, SatelliteConfigurationBuilder, SiteBuilder,
+                SiteConfigurationBuilder, UserBuilder, ValidationRules,
             },
+            provenance::{Provenance, Traced},
+            put::{BandDetailsUpdateBuilder, SatelliteUpdateBuilder},
+            resource::{NamedResource, Resource},
+            time_range::TimeRange,
+            token::{FpsTarget, FpsToken, TokenProvider},
+            usage::{UpcomingRequest, UsageReport},
+            visibility::Visibility,
             Api, Container, Value,
         },
-        client::Client,
+        auth::{AuthMode, RefreshingToken},
+        backoff::{
+            Backoff, BackoffExt, Constant, Exponential, Fibonacci, Jitter, Jittered, Limited,
+        },
+        client::{Client, ClientBuilder},
         config::*,
+        error::NotFoundExt,
         extensions::*,
+        ids::*,
         models::*,
+        retry::RetryPolicy,
     };
 }
 
@@ -0,0 +1,141 @@
+//! Hooks observing the requests sent by the [`Client`](crate::Client), such as to export metrics
+use std::{
+    fmt::Debug,
+    time::{Duration, Instant},
+};
+
+use reqwest::{Method, StatusCode};
+use url::Url;
+
+/// Observes every request sent by a [`Client`](crate::Client), see
+/// [`Client::with_metrics`](crate::Client::with_metrics)
+///
+/// A request is observed once, regardless of the number of attempts made by its retries.
+pub trait MetricsHook: Debug + Send + Sync {
+    /// Called before the first attempt of the request
+    fn on_request_start(&self, _url: &Url, _method: &Method) {}
+
+    /// Called once the request has completed, with the status of its response, or `None` when no
+    /// response was received, including when the request was cancelled
+    fn on_request_end(
+        &self,
+        url: &Url,
+        method: &Method,
+        status: Option<StatusCode>,
+        duration: Duration,
+    );
+}
+
+/// An observed request, which notifies the hook of its end when dropped
+///
+/// A request whose future is dropped before completing, such as by a timeout or `select!`, is
+/// observed as having received no response.
+pub(crate) struct Observation<'a> {
+    hook: &'a dyn MetricsHook,
+    url: &'a Url,
+    method: Method,
+    started: Instant,
+    status: Option<StatusCode>,
+}
+
+impl<'a> Observation<'a> {
+    /// Notify the hook of the start of the request
+    pub(crate) fn start(hook: &'a dyn MetricsHook, url: &'a Url, method: &Method) -> Self {
+        hook.on_request_start(url, method);
+
+        Self {
+            hook,
+            url,
+            method: method.clone(),
+            started: Instant::now(),
+            status: None,
+        }
+    }
+
+    /// Notify the hook of the end of the request, with the status of its response if any
+    pub(crate) fn finish(mut self, status: Option<StatusCode>) {
+        self.status = status;
+    }
+}
+
+impl Drop for Observation<'_> {
+    fn drop(&mut self) {
+        self.hook
+            .on_request_end(self.url, &self.method, self.status, self.started.elapsed());
+    }
+}
+
+/// A [`MetricsHook`] emitting to the recorder installed for the [`metrics`](::metrics) crate
+///
+/// Each metric is labelled with the `method` and the normalized `path` of the request, in which IDs
+/// are replaced by placeholders such as `requests/{id}`:
+///
+/// + `freedom_api_requests_in_flight`: a gauge of the requests awaiting a response
+/// + `freedom_api_requests_total`: a counter of the completed requests, additionally labelled with
+///   the `status` of the response, or `error` when none was received
+/// + `freedom_api_request_duration_seconds`: a histogram of the latency of the requests
+///
+/// # Example
+///
+/// ```
+/// # use freedom_api::{metrics::MetricsFacade, prelude::*};
+/// let config = Config::builder()
+///     .environment(Test)
+///     .key("foo")
+///     .secret("bar")
+///     .build()
+///     .unwrap();
+/// let client = Client::from_config(config).with_metrics(MetricsFacade);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsFacade;
+
+impl MetricsHook for MetricsFacade {
+    fn on_request_start(&self, url: &Url, method: &Method) {
+        ::metrics::gauge!(
+            "freedom_api_requests_in_flight",
+            "method" => method.to_string(),
+            "path" => path(url),
+        )
+        .increment(1.0);
+    }
+
+    fn on_request_end(
+        &self,
+        url: &Url,
+        method: &Method,
+        status: Option<StatusCode>,
+        duration: Duration,
+    ) {
+        let method = method.to_string();
+        let path = path(url);
+        let status = status
+            .map(|status| status.as_u16().to_string())
+            .unwrap_or_else(|| String::from("error"));
+
+        ::metrics::gauge!(
+            "freedom_api_requests_in_flight",
+            "method" => method.clone(),
+            "path" => path.clone(),
+        )
+        .decrement(1.0);
+        ::metrics::counter!(
+            "freedom_api_requests_total",
+            "method" => method.clone(),
+            "path" => path.clone(),
+            "status" => status,
+        )
+        .increment(1);
+        ::metrics::histogram!(
+            "freedom_api_request_duration_seconds",
+            "method" => method,
+            "path" => path,
+        )
+        .record(duration.as_secs_f64());
+    }
+}
+
+/// The path of the request beneath the API, without the IDs which would make it unique
+pub fn path(url: &Url) -> String {
+    crate::telemetry::endpoint(url)
+}
@@ -0,0 +1,128 @@
+//! Client-side rate limiting of requests
+use std::{sync::Arc, time::Duration};
+
+use tokio::{sync::Mutex, time::Instant};
+
+/// A token bucket pacing the requests of a [`Client`](crate::Client) and all of its clones
+///
+/// The bucket holds up to `requests` tokens, and is refilled at a rate of `requests` per `period`.
+/// Each attempt of a request takes a token, waiting for one to be refilled when the bucket is
+/// empty. A full bucket allows a burst of `requests` requests.
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    tokens: f64,
+    /// The time taken to refill a single token
+    interval: Duration,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// # Panics
+    ///
+    /// Panics when `requests` is zero or `period` is zero
+    pub(crate) fn new(requests: u32, period: Duration) -> Self {
+        assert!(
+            requests > 0,
+            "the rate limit must allow at least one request"
+        );
+        assert!(
+            !period.is_zero(),
+            "the period of the rate limit must not be zero"
+        );
+
+        let bucket = Bucket {
+            capacity: f64::from(requests),
+            tokens: f64::from(requests),
+            interval: period / requests,
+            refilled_at: Instant::now(),
+        };
+
+        Self {
+            bucket: Arc::new(Mutex::new(bucket)),
+        }
+    }
+
+    /// Wait until a token is available, then take it
+    pub(crate) async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                match bucket.take(Instant::now()) {
+                    Ok(()) => return,
+                    Err(wait) => wait,
+                }
+            };
+
+            tracing::trace!(?wait, "Waiting for the rate limit");
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+impl Bucket {
+    /// Take a token, or produce the time until one is available
+    fn take(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        let refilled = elapsed.as_secs_f64() / self.interval.as_secs_f64();
+        self.tokens = (self.tokens + refilled).min(self.capacity);
+        self.refilled_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return Ok(());
+        }
+
+        Err(self.interval.mul_f64(1.0 - self.tokens))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bucket(requests: u32, period: Duration, now: Instant) -> Bucket {
+        Bucket {
+            capacity: f64::from(requests),
+            tokens: f64::from(requests),
+            interval: period / requests,
+            refilled_at: now,
+        }
+    }
+
+    #[test]
+    fn burst_is_allowed_then_paced() {
+        let start = Instant::now();
+        let mut bucket = bucket(5, Duration::from_secs(1), start);
+
+        for _ in 0..5 {
+            assert_eq!(bucket.take(start), Ok(()));
+        }
+        let waits = [start, start + Duration::from_millis(100)].map(|now| {
+            let Err(wait) = bucket.take(now) else {
+                panic!("expected to wait");
+            };
+            wait.as_secs_f64()
+        });
+        assert!((waits[0] - 0.2).abs() < 1e-6, "{waits:?}");
+        assert!((waits[1] - 0.1).abs() < 1e-6, "{waits:?}");
+
+        assert_eq!(bucket.take(start + Duration::from_millis(200)), Ok(()));
+    }
+
+    #[test]
+    fn idle_bucket_does_not_exceed_capacity() {
+        let start = Instant::now();
+        let mut bucket = bucket(2, Duration::from_secs(1), start);
+
+        let later = start + Duration::from_secs(60);
+        assert_eq!(bucket.take(later), Ok(()));
+        assert_eq!(bucket.take(later), Ok(()));
+        assert!(bucket.take(later).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+//! Retry policies for transient failures
+use std::time::Duration;
+
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    Response, StatusCode,
+};
+use time::{macros::format_description, OffsetDateTime, PrimitiveDateTime};
+
+use crate::backoff::{Backoff, BackoffExt, Exponential, Jitter};
+
+/// Controls how the [`Client`](crate::Client) retries requests which failed for transient reasons
+///
+/// Requests are retried when the connection fails, the request times out, or the server responds
+/// with `429 Too Many Requests` or any `5xx` status. All other responses are returned immediately.
+///
+/// By default, the delay between attempts grows exponentially from the base delay, up to the
+/// maximum delay, with random jitter applied to avoid many clients retrying in lockstep. Any other
+/// [`Backoff`] strategy may be used instead.
+///
+/// # Example
+///
+/// ```
+/// # use freedom_api::prelude::*;
+/// # use std::time::Duration;
+/// let policy = RetryPolicy::new()
+///     .max_retries(5)
+///     .base_delay(Duration::from_millis(100))
+///     .max_delay(Duration::from_secs(10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    backoff: Option<Box<dyn Backoff>>,
+    retry_post: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            backoff: None,
+            retry_post: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The maximum number of retries after the initial attempt. Defaults to 3.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// The delay before the first retry. Defaults to 200 milliseconds.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// The upper bound on the delay between attempts, including the delay requested by the server
+    /// in a `Retry-After` header. Defaults to 5 seconds.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Use the provided strategy to determine the delay between attempts, in place of the base
+    /// and maximum delays
+    pub fn backoff(mut self, backoff: impl Backoff + 'static) -> Self {
+        self.backoff = Some(Box::new(backoff));
+        self
+    }
+
+    /// Whether POST requests should also be retried. Defaults to `false`, since a POST which
+    /// reached the server before failing may already have created the resource.
+    pub fn retry_post(mut self, retry_post: bool) -> Self {
+        self.retry_post = retry_post;
+        self
+    }
+
+    pub(crate) fn retries_post(&self) -> bool {
+        self.retry_post
+    }
+
+    /// The longest delay honoured from a `Retry-After` header, so that a misbehaving server cannot
+    /// stall a request indefinitely
+    pub(crate) fn delay_limit(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// Whether another attempt should be made, given the number of retries already performed
+    pub(crate) fn should_retry(
+        &self,
+        retries: u32,
+        result: &Result<Response, reqwest::Error>,
+    ) -> bool {
+        retries < self.max_retries && is_transient(result)
+    }
+
+    /// A fresh instance of the strategy determining the delay between the attempts of a request
+    pub(crate) fn strategy(&self) -> Box<dyn Backoff> {
+        match &self.backoff {
+            Some(backoff) => backoff.clone(),
+            None => Box::new(
+                Exponential::new(self.base_delay, self.max_delay).with_jitter(Jitter::Equal),
+            ),
+        }
+    }
+}
+
+fn is_transient(result: &Result<Response, reqwest::Error>) -> bool {
+    match result {
+        Ok(response) => {
+            let status = response.status();
+            status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+        }
+        Err(error) => error.is_connect() || error.is_timeout(),
+    }
+}
+
+/// The delay requested by the server in the `Retry-After` header, either as a number of seconds
+/// or as an HTTP date
+///
+/// A date in the past requests no delay.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let format = format_description!(
+        "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT"
+    );
+    let date = PrimitiveDateTime::parse(value, format).ok()?.assume_utc();
+    let delay = date - OffsetDateTime::now_utc();
+
+    Some(delay.try_into().unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    fn headers(retry_after: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_str(retry_after).unwrap());
+        headers
+    }
+
+    #[test]
+    fn retry_after_in_seconds() {
+        assert_eq!(retry_after(&headers("120")), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn retry_after_as_date() {
+        let format = format_description!(
+            "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT"
+        );
+        let date = OffsetDateTime::now_utc() + time::Duration::seconds(90);
+        let delay = retry_after(&headers(&date.format(format).unwrap())).unwrap();
+
+        assert!(delay > Duration::from_secs(85), "{delay:?}");
+        assert!(delay <= Duration::from_secs(90), "{delay:?}");
+
+        let past = retry_after(&headers("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert_eq!(past, Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn retry_after_missing_or_malformed() {
+        assert_eq!(retry_after(&HeaderMap::new()), None);
+        assert_eq!(retry_after(&headers("soon")), None);
+    }
+
+    #[test]
+    fn delay_grows_exponentially_within_bounds() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(1000));
+
+        for (retries, expected) in [
+            (0, 100),
+            (1, 200),
+            (2, 400),
+            (3, 800),
+            (4, 1000),
+            (30, 1000),
+        ] {
+            let expected = Duration::from_millis(expected);
+            let delay = policy.strategy().next_delay(retries).unwrap();
+            assert!(delay >= expected / 2, "{delay:?} < {expected:?} / 2");
+            assert!(delay <= expected, "{delay:?} > {expected:?}");
+        }
+    }
+}
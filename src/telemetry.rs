@@ -0,0 +1,139 @@
+//! Tracing spans and fields describing the requests sent to Freedom
+use reqwest::Method;
+use serde::Serialize;
+use tracing::Span;
+use url::Url;
+
+/// The span of a single request, including its retries
+///
+/// The `status` and `latency_ms` fields are recorded once a response is received.
+pub(crate) fn request_span(method: &Method, url: &Url) -> Span {
+    tracing::info_span!(
+        "freedom_api.request",
+        endpoint = %endpoint(url),
+        method = %method,
+        url = %redacted(url),
+        status = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+    )
+}
+
+/// The span of a paginated stream, under which the request of every page is made
+pub(crate) fn pagination_span(url: &Url) -> Span {
+    tracing::info_span!(
+        "freedom_api.paginate",
+        endpoint = %endpoint(url),
+        url = %redacted(url),
+    )
+}
+
+/// The path of the URL beneath the API, with each numeric ID replaced by `{id}` and the name of
+/// a downloaded file replaced by `{file}`, such that the requests of an endpoint can be grouped
+/// together
+pub(crate) fn endpoint(url: &Url) -> String {
+    let path = url.path();
+    let path = match path.find("/api/") {
+        Some(index) => &path[index + "/api/".len()..],
+        None => path.trim_start_matches('/'),
+    };
+
+    let mut segments: Vec<_> = path
+        .split('/')
+        .map(|segment| {
+            match !segment.is_empty() && segment.bytes().all(|byte| byte.is_ascii_digit()) {
+                true => "{id}",
+                false => segment,
+            }
+        })
+        .collect();
+    // Files are downloaded from `downloads/{task_id}/{file_name}`
+    if segments.first() == Some(&"downloads") && segments.len() > 2 {
+        segments.truncate(2);
+        segments.push("{file}");
+    }
+
+    segments.join("/")
+}
+
+/// The URL with the value of every query parameter redacted, since they may identify users
+pub(crate) fn redacted(url: &Url) -> Url {
+    let mut url = url.clone();
+    if url.query().is_none() {
+        return url;
+    }
+
+    let keys: Vec<String> = url.query_pairs().map(|(key, _)| key.into_owned()).collect();
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(keys.iter().map(|key| (key, "REDACTED")));
+
+    url
+}
+
+/// The JSON of a request body, for logging when opted into
+pub(crate) fn json_body<S: Serialize>(body: &S) -> String {
+    serde_json::to_string(body).unwrap_or_else(|error| format!("<unserializable: {error}>"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoints_of_the_api_are_normalized() {
+        let cases = [
+            ("accounts/12/newuser", "accounts/{id}/newuser"),
+            ("downloads/7/pass.iq", "downloads/{id}/{file}"),
+            (
+                "requests/search/findByTask?task=x",
+                "requests/search/findByTask",
+            ),
+            ("satellite_bands/3", "satellite_bands/{id}"),
+            (
+                "satellite_configurations/5",
+                "satellite_configurations/{id}",
+            ),
+            ("satellites/710/orbitInfo", "satellites/{id}/orbitInfo"),
+            (
+                "satellites/710/visibilities",
+                "satellites/{id}/visibilities",
+            ),
+            ("sites/4", "sites/{id}"),
+            ("tasks/9/azel", "tasks/{id}/azel"),
+            ("overrides/2", "overrides/{id}"),
+            (
+                "users/search/findOneByEmail?email=x",
+                "users/search/findOneByEmail",
+            ),
+            ("fps", "fps"),
+        ];
+        let base = Url::parse("https://test-api.atlasground.com/api/").unwrap();
+
+        for (path, expected) in cases {
+            assert_eq!(endpoint(&base.join(path).unwrap()), expected, "{path}");
+        }
+    }
+
+    #[test]
+    fn endpoint_replaces_ids() {
+        let url =
+            Url::parse("https://test-api.atlasground.com/api/requests/42/taskRequest?x=1").unwrap();
+
+        assert_eq!(endpoint(&url), "requests/{id}/taskRequest");
+    }
+
+    #[test]
+    fn query_values_are_redacted() {
+        let url = Url::parse(
+            "https://test-api.atlasground.com/api/users/search/findOneByEmail?email=a%40b.com",
+        )
+        .unwrap();
+
+        assert_eq!(
+            redacted(&url).as_str(),
+            "https://test-api.atlasground.com/api/users/search/findOneByEmail?email=REDACTED"
+        );
+        let plain = Url::parse("https://test-api.atlasground.com/api/sites").unwrap();
+        assert_eq!(redacted(&plain), plain);
+    }
+}
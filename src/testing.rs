@@ -0,0 +1,15 @@
+//! # Test Utilities
+//!
+//! Test doubles for code which is generic over the [`Api`](crate::Api) trait, allowing it to be
+//! tested without a Freedom server.
+//!
+//! [`FakeApi`] keeps entities in memory, which suits tests of application logic, while
+//! [`TestingEnv`] runs a mock HTTP server for tests which exercise a real [`Client`](crate::Client)
+//! against fixtures of Freedom responses.
+//!
+//! This module is only available with the `test-util` feature enabled.
+
+mod env;
+mod fake;
+
+pub use self::{env::TestingEnv, fake::FakeApi};
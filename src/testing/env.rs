@@ -0,0 +1,350 @@
+use std::{collections::HashMap, path::Path, sync::Arc};
+
+use freedom_config::Config;
+use freedom_models::Hateoas;
+use httpmock::{prelude::*, Mock, Then, When};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use url::Url;
+
+use crate::Client;
+
+/// The name of the query parameter used to request a page of a collection
+const PAGE_PARAM: &str = "page";
+
+/// A handle to a mock Freedom server, for use as the environment of a [`Client`]
+///
+/// Links in fixtures which point at `localhost:8080`, as served by a local Freedom instance, are
+/// rewritten to point at the mock server.
+///
+/// Cloning the environment shares the underlying server, which allows mocks to be inspected after
+/// a client has been constructed from a clone.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freedom_api::{prelude::*, testing::TestingEnv};
+/// # tokio_test::block_on(async {
+/// let env = TestingEnv::new();
+/// let delete = env.delete("/satellites/12", 204);
+/// let client = Client::from(env.clone());
+///
+/// client.delete_satellite(12).await?;
+/// delete.assert();
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct TestingEnv {
+    server: Arc<MockServer>,
+}
+
+impl std::fmt::Debug for TestingEnv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TestingEnv").finish()
+    }
+}
+
+impl AsRef<str> for TestingEnv {
+    fn as_ref(&self) -> &str {
+        "TestingEnv"
+    }
+}
+
+impl TestingEnv {
+    /// Start a new mock server
+    pub fn new() -> Self {
+        let server = Arc::new(MockServer::start());
+        Self { server }
+    }
+
+    /// Define a mock on the server, as with [`MockServer::mock`]
+    pub fn mock<F>(&self, config: F) -> Mock<'_>
+    where
+        F: FnOnce(When, Then),
+    {
+        self.server.mock(config)
+    }
+
+    /// The URL of the path on the mock server
+    pub fn url(&self, path: impl Into<String>) -> String {
+        self.server.url(path)
+    }
+
+    /// The port on which the mock server listens
+    pub fn port(&self) -> u16 {
+        self.server.port()
+    }
+
+    /// Parse the links, pointing them at the mock server
+    pub fn map_to_links(&self, map: HashMap<&str, &str>) -> HashMap<String, Url> {
+        map.into_iter()
+            .map(|(key, val)| (key.to_string(), Url::parse(&self.rewrite(val)).unwrap()))
+            .collect()
+    }
+
+    /// Read the JSON fixture, pointing any links at the mock server
+    pub fn json_from_file(&self, file: impl AsRef<Path>) -> String {
+        let file = std::fs::read(file).unwrap();
+        let file = String::from_utf8(file).unwrap();
+        self.rewrite(&file)
+    }
+
+    /// Serve the JSON fixture for GET requests to the path
+    pub fn get_json_from_file(
+        &self,
+        path: &str,
+        query: Vec<(&str, &str)>,
+        file: impl AsRef<Path>,
+    ) -> Mock<'_> {
+        let file = self.json_from_file(file);
+        self.mock(|mut when, then| {
+            when = when.method(GET).path(path);
+            for (name, value) in query {
+                when = when.query_param(name, value);
+            }
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(file);
+        })
+    }
+
+    /// Serialize the model into the HAL representation served by Freedom, pointing any links at
+    /// the mock server
+    pub fn fixture_for<T: Serialize + Hateoas>(&self, value: &T) -> Value {
+        let mut value = serde_json::to_value(value).unwrap();
+        let object = value.as_object_mut().unwrap();
+        let links = object
+            .remove("_links")
+            .or_else(|| object.remove("links"))
+            .unwrap_or_else(|| json!({}));
+        object.insert(String::from("_links"), self.hal_links(links));
+
+        value
+    }
+
+    /// Wrap the models in a page of results, as served by the collection and search endpoints
+    ///
+    /// The `key` is the name of the collection within `_embedded`, while `next` is the path of the
+    /// following page on the mock server, if any.
+    pub fn paged_fixture<T: Serialize + Hateoas>(
+        &self,
+        key: &str,
+        items: &[T],
+        next: Option<&str>,
+    ) -> Value {
+        let total_pages = if next.is_some() { 2 } else { 1 };
+        self.page(key, items, 0, total_pages, next)
+    }
+
+    /// Serve the generated fixture for GET requests to the path
+    pub fn get_json(&self, path: &str, query: Vec<(&str, &str)>, body: Value) -> Mock<'_> {
+        self.mock(|mut when, then| {
+            when = when.method(GET).path(path);
+            for (name, value) in query {
+                when = when.query_param(name, value);
+            }
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(body);
+        })
+    }
+
+    /// Serve each of the pages of models in turn for GET requests to the collection at the path
+    ///
+    /// The first page is served when no page is requested, and every page links to the next, so
+    /// that the client walks the entire collection. One mock is returned for each page.
+    pub fn get_pages<T: Serialize + Hateoas>(
+        &self,
+        path: &str,
+        key: &str,
+        pages: &[&[T]],
+    ) -> Vec<Mock<'_>> {
+        // An empty collection is still served as a single, empty page
+        let empty: &[T] = &[];
+        let pages = match pages {
+            [] => std::slice::from_ref(&empty),
+            pages => pages,
+        };
+        let total_pages = pages.len();
+
+        let bodies = pages
+            .iter()
+            .enumerate()
+            .map(|(number, items)| {
+                let next = (number + 1 < total_pages)
+                    .then(|| format!("{path}?{PAGE_PARAM}={}", number + 1));
+                self.page(key, items, number, total_pages, next.as_deref())
+            })
+            .collect();
+
+        self.get_json_pages(path, Vec::new(), bodies)
+    }
+
+    /// Serve each of the JSON pages in turn for GET requests to the path with the query, as for
+    /// [`get_pages`](Self::get_pages)
+    ///
+    /// The pages are served as provided, so each must link to the next itself, using the `page`
+    /// query parameter. One mock is returned for each page.
+    pub fn get_json_pages(
+        &self,
+        path: &str,
+        query: Vec<(&str, &str)>,
+        pages: Vec<Value>,
+    ) -> Vec<Mock<'_>> {
+        pages
+            .into_iter()
+            .enumerate()
+            .map(|(number, body)| {
+                self.mock(|mut when, then| {
+                    when = when.method(GET).path(path);
+                    for (name, value) in &query {
+                        when = when.query_param(*name, *value);
+                    }
+                    match number {
+                        0 => when.matches(|req| {
+                            !req.query_params
+                                .iter()
+                                .flatten()
+                                .any(|(key, _)| key == PAGE_PARAM)
+                        }),
+                        number => when.query_param(PAGE_PARAM, number.to_string()),
+                    };
+
+                    then.status(200)
+                        .header("content-type", "application/json")
+                        .json_body(body);
+                })
+            })
+            .collect()
+    }
+
+    /// Expect POST requests to the path, responding with the status and JSON body
+    pub fn post_json(&self, path: &str, status: u16, body: Value) -> Mock<'_> {
+        self.mock(|when, then| {
+            when.method(POST).path(path);
+            then.status(status)
+                .header("content-type", "application/json")
+                .json_body(body);
+        })
+    }
+
+    /// Expect POST requests to the path with the JSON body, responding with the status and the
+    /// fixture for the created model
+    pub fn post_json_matching<T: Serialize + Hateoas>(
+        &self,
+        path: &str,
+        request: Value,
+        status: u16,
+        created: &T,
+    ) -> Mock<'_> {
+        let created = self.fixture_for(created);
+        self.mock(|when, then| {
+            when.method(POST).path(path).json_body(request);
+            then.status(status)
+                .header("content-type", "application/json")
+                .json_body(created);
+        })
+    }
+
+    /// Expect DELETE requests to the path, responding with the status
+    pub fn delete(&self, path: &str, status: u16) -> Mock<'_> {
+        self.mock(|when, then| {
+            when.method(DELETE).path(path);
+            then.status(status);
+        })
+    }
+
+    fn page<T: Serialize + Hateoas>(
+        &self,
+        key: &str,
+        items: &[T],
+        number: usize,
+        total_pages: usize,
+        next: Option<&str>,
+    ) -> Value {
+        let items: Vec<_> = items.iter().map(|item| self.fixture_for(item)).collect();
+        let total = items.len();
+        let mut embedded = Map::new();
+        embedded.insert(key.to_string(), Value::Array(items));
+        let mut links = Map::new();
+        if let Some(next) = next {
+            links.insert(String::from("next"), json!({ "href": self.url(next) }));
+        }
+
+        json!({
+            "_embedded": embedded,
+            "_links": links,
+            "page": {
+                "size": 20,
+                "totalElements": total,
+                "totalPages": total_pages,
+                "number": number
+            }
+        })
+    }
+
+    /// Convert links of either the `{"rel": "href"}` or `{"rel": {"href": "href"}}` form into the
+    /// latter
+    fn hal_links(&self, links: Value) -> Value {
+        let Value::Object(links) = links else {
+            return json!({});
+        };
+
+        links
+            .into_iter()
+            .map(|(rel, link)| {
+                let href = match link {
+                    Value::Object(mut link) => link.remove("href").unwrap_or(Value::Null),
+                    href => href,
+                };
+                let href = self.rewrite(href.as_str().unwrap_or_default());
+
+                (rel, json!({ "href": href }))
+            })
+            .collect()
+    }
+
+    fn rewrite(&self, text: &str) -> String {
+        text.replace("localhost:8080", &format!("localhost:{}", self.port()))
+    }
+}
+
+impl From<TestingEnv> for Client {
+    fn from(value: TestingEnv) -> Self {
+        let config = Config::builder()
+            .environment(value)
+            .key("")
+            .secret("")
+            .build()
+            .unwrap();
+
+        Client::from_config(config)
+    }
+}
+
+impl Default for TestingEnv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl freedom_config::Env for TestingEnv {
+    fn from_str(_val: &str) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Some(Self::new())
+    }
+
+    fn fps_host(&self) -> &str {
+        "localhost"
+    }
+
+    fn freedom_entrypoint(&self) -> Url {
+        let url = self.server.base_url();
+        Url::parse(&url).unwrap()
+    }
+}
@@ -0,0 +1,829 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use bytes::Bytes;
+use freedom_config::{Config, Test};
+use freedom_models::{band::Band, satellite::Satellite, site::Site, task::TaskRequest};
+use reqwest::{
+    header::{HeaderMap, CONTENT_TYPE, LOCATION},
+    Response, StatusCode,
+};
+use serde::Serialize;
+use serde_json::{json, Map, Value as JsonValue};
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::{
+    api::{Api, Value},
+    client::Inner,
+    error::Error,
+    utils::parse_time,
+};
+
+/// The page size used when a request does not specify one
+const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// An [`Api`] which serves entities from memory rather than from a Freedom server
+///
+/// Entities are seeded with [`insert`](Self::insert) and are served by the getters, paginated
+/// streams, and searches of the [`Api`], while the builders insert new entities with the next
+/// available ID. Clones share the same entities.
+///
+/// Every entity links to itself with `self`. Links to other entities, such as the `configuration`
+/// of a task request, may either point directly at the other entity, or take the form
+/// `<collection>/<id>/<rel>` used by the Freedom API when the association was created by a builder
+/// or with [`associate`](Self::associate) and [`associate_many`](Self::associate_many).
+///
+/// Searches filter the entities of the collection by each query parameter: fields such as `name`,
+/// `status`, and `type` by equality, links such as `account` or `configuration` by URL, parameters
+/// such as `satelliteName` by the name of the linked entity, and `start` and `end` by the target
+/// date of task requests or the pass of tasks. Searches which the fake cannot evaluate, such as
+/// those relative to the current day, fail with a `400 Bad Request`.
+///
+/// # Example
+///
+/// ```
+/// # use freedom_api::{prelude::*, testing::FakeApi};
+/// # use futures::StreamExt;
+/// # tokio_test::block_on(async {
+/// async fn satellite_names<C: Api>(client: &C) -> Vec<String> {
+///     client
+///         .get_satellites()
+///         .filter_map(|satellite| async move { satellite.ok().map(|s| s.name.clone()) })
+///         .collect()
+///         .await
+/// }
+///
+/// let satellite: Satellite = serde_json::from_str(
+///     &std::fs::read_to_string("resources/satellite_find_one_710.json")?,
+/// )?;
+/// let fake = FakeApi::new();
+/// let id = fake.insert_satellite(&satellite);
+///
+/// assert_eq!(id, 710);
+/// assert_eq!(satellite_names(&fake).await, ["FooBar 6"]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// # });
+/// ```
+#[derive(Debug, Clone)]
+pub struct FakeApi {
+    config: Config,
+    page_size: usize,
+    state: Arc<Mutex<State>>,
+}
+
+impl Default for FakeApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeApi {
+    /// A fake without any entities, for the test environment
+    pub fn new() -> Self {
+        let config = Config::builder()
+            .environment(Test)
+            .key("fake")
+            .secret("fake")
+            .build()
+            .expect("The test environment is always valid");
+
+        Self::from_config(config)
+    }
+
+    /// A fake without any entities, serving URLs beneath the entrypoint of the configuration
+    pub fn from_config(config: Config) -> Self {
+        Self {
+            config,
+            page_size: DEFAULT_PAGE_SIZE,
+            state: Arc::default(),
+        }
+    }
+
+    /// The number of entities in each page, unless the request specifies a size. Defaults to 20.
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// The URL of the path, beneath the entrypoint of the fake
+    ///
+    /// # Panics
+    ///
+    /// When the path is not beneath the entrypoint, see [`Api::path_to_url`].
+    pub fn url(&self, path: impl AsRef<str>) -> Url {
+        self.path_to_url(path)
+            .expect("The path should be relative to the entrypoint")
+    }
+
+    /// Add the entity to the collection, returning its ID
+    ///
+    /// The ID is read from the `self` link of the entity when present, otherwise the next
+    /// available ID is assigned. Links using the `localhost:8080` host of the fixtures are
+    /// rewritten to the entrypoint of the fake.
+    ///
+    /// # Panics
+    ///
+    /// When the entity does not serialize to a JSON object.
+    pub fn insert<T: Serialize>(&self, collection: &str, entity: &T) -> i32 {
+        let value = serde_json::to_value(entity).expect("The entity should serialize");
+        assert!(value.is_object(), "The entity should be an object");
+
+        let base = self.base();
+        let mut state = self.lock();
+        state.insert(&base, collection, value, HashMap::new())
+    }
+
+    pub fn insert_satellite(&self, satellite: &Satellite) -> i32 {
+        self.insert("satellites", satellite)
+    }
+
+    pub fn insert_band(&self, band: &Band) -> i32 {
+        self.insert("satellite_bands", band)
+    }
+
+    pub fn insert_site(&self, site: &Site) -> i32 {
+        self.insert("sites", site)
+    }
+
+    pub fn insert_request(&self, request: &TaskRequest) -> i32 {
+        self.insert("requests", request)
+    }
+
+    /// Link the entity to the target with the relation, such as the `configuration` of a task
+    /// request, replacing any existing link
+    pub fn associate(&self, collection: &str, id: i32, rel: &str, target: Url) {
+        self.set_association(collection, id, rel, Association::One(target));
+    }
+
+    /// Link the entity to a list of targets with the relation, such as the `targetBands` of a task
+    /// request, replacing any existing link
+    pub fn associate_many(&self, collection: &str, id: i32, rel: &str, targets: Vec<Url>) {
+        self.set_association(collection, id, rel, Association::Many(targets));
+    }
+
+    fn set_association(&self, collection: &str, id: i32, rel: &str, association: Association) {
+        let base = self.base();
+        let mut state = self.lock();
+        if let Some(entity) = state.entity_mut(collection, id) {
+            entity.associate(&base, collection, rel, association);
+        }
+    }
+
+    /// The entity of the collection matching the ID, as it is served
+    pub fn entity(&self, collection: &str, id: i32) -> Option<JsonValue> {
+        self.lock()
+            .collections
+            .get(collection)?
+            .get(&id)
+            .map(|entity| entity.value.clone())
+    }
+
+    /// The number of entities in the collection
+    pub fn len(&self, collection: &str) -> usize {
+        self.lock()
+            .collections
+            .get(collection)
+            .map_or(0, BTreeMap::len)
+    }
+
+    fn base(&self) -> Url {
+        self.config.environment().freedom_entrypoint()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn handle_get(&self, url: &Url) -> (StatusCode, JsonValue) {
+        let base = self.base();
+        let state = self.lock();
+        let Some(segments) = segments(&base, url) else {
+            return not_found(url);
+        };
+
+        let result = match segments.as_slice() {
+            [collection] => Ok(page(url, collection, state.all(collection), self.page_size)),
+            [collection, "search", name] => {
+                state.search(&base, url, collection, name, self.page_size)
+            }
+            // Freedom serves some searches beside the collection, such as `satellites/findOneByName`
+            [collection, name] if name.starts_with("find") => {
+                state.search(&base, url, collection, name, self.page_size)
+            }
+            [collection, id] => state
+                .entity(collection, id)
+                .map(|entity| entity.value.clone())
+                .ok_or_else(|| not_found(url)),
+            [collection, id, rel] => state.follow(&base, url, collection, id, rel),
+            _ => Err(not_found(url)),
+        };
+
+        match result {
+            Ok(body) => (StatusCode::OK, body),
+            Err(error) => error,
+        }
+    }
+
+    fn handle_write(&self, url: &Url, body: Option<JsonValue>, write: Write) -> Response {
+        let base = self.base();
+        let mut state = self.lock();
+        let Some(segments) = segments(&base, url) else {
+            let (status, body) = not_found(url);
+            return response(status, Some(&body), None);
+        };
+
+        match (write, segments.as_slice(), body) {
+            (Write::Create, [collection], Some(JsonValue::Object(fields))) => {
+                let (fields, associations) = split_associations(&base, fields);
+                let id = state.insert(&base, collection, fields, associations);
+                let location = base
+                    .join(&format!("{collection}/{id}"))
+                    .expect("The location should be beneath the entrypoint");
+                let entity = state.entity(collection, &id.to_string()).map(|e| &e.value);
+
+                response(StatusCode::CREATED, entity, Some(&location))
+            }
+            (Write::Replace | Write::Merge, [collection, id], Some(JsonValue::Object(fields))) => {
+                let Some(entity) = state.entity_mut(collection, id.parse().unwrap_or_default())
+                else {
+                    let (status, body) = not_found(url);
+                    return response(status, Some(&body), None);
+                };
+                let (fields, associations) = split_associations(&base, fields);
+                entity.update(fields, write == Write::Replace);
+                for (rel, association) in associations {
+                    entity.associate(&base, collection, &rel, association);
+                }
+
+                response(StatusCode::OK, Some(&entity.value), None)
+            }
+            (Write::Delete, [collection, id], _) => {
+                let removed = state
+                    .collections
+                    .get_mut(*collection)
+                    .and_then(|entities| entities.remove(&id.parse::<i32>().unwrap_or_default()));
+                match removed {
+                    Some(_) => response(StatusCode::NO_CONTENT, None, None),
+                    None => {
+                        let (status, body) = not_found(url);
+                        response(status, Some(&body), None)
+                    }
+                }
+            }
+            _ => {
+                let (status, body) = error_body(
+                    StatusCode::BAD_REQUEST,
+                    url,
+                    "The fake does not support this request",
+                );
+                response(status, Some(&body), None)
+            }
+        }
+    }
+}
+
+impl Api for FakeApi {
+    type Container<T: Value> = Inner<T>;
+
+    async fn get(&self, url: Url) -> Result<(Bytes, StatusCode), Error> {
+        let (status, body) = self.handle_get(&url);
+
+        Ok((Bytes::from(serde_json::to_vec(&body)?), status))
+    }
+
+    async fn get_response(&self, url: Url, _headers: HeaderMap) -> Result<Response, Error> {
+        let (status, body) = self.handle_get(&url);
+
+        Ok(response(status, Some(&body), None))
+    }
+
+    async fn delete(&self, url: Url) -> Result<Response, Error> {
+        Ok(self.handle_write(&url, None, Write::Delete))
+    }
+
+    async fn post<S>(&self, url: Url, msg: S) -> Result<Response, Error>
+    where
+        S: Serialize + Sync + Send,
+    {
+        let body = serde_json::to_value(&msg)?;
+
+        Ok(self.handle_write(&url, Some(body), Write::Create))
+    }
+
+    async fn put<S>(&self, url: Url, msg: S) -> Result<Response, Error>
+    where
+        S: Serialize + Sync + Send,
+    {
+        let body = serde_json::to_value(&msg)?;
+
+        Ok(self.handle_write(&url, Some(body), Write::Replace))
+    }
+
+    async fn patch<S>(&self, url: Url, msg: S) -> Result<Response, Error>
+    where
+        S: Serialize + Sync + Send,
+    {
+        let body = serde_json::to_value(&msg)?;
+
+        Ok(self.handle_write(&url, Some(body), Write::Merge))
+    }
+
+    fn config(&self) -> &Config {
+        &self.config
+    }
+
+    fn config_mut(&mut self) -> &mut Config {
+        &mut self.config
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Write {
+    Create,
+    Replace,
+    Merge,
+    Delete,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    collections: HashMap<String, BTreeMap<i32, Entity>>,
+    next_id: i32,
+}
+
+#[derive(Debug, Clone)]
+struct Entity {
+    id: i32,
+    /// The entity as it is served, including its links
+    value: JsonValue,
+    /// The targets of the links of the form `<collection>/<id>/<rel>`
+    associations: HashMap<String, Association>,
+}
+
+#[derive(Debug, Clone)]
+enum Association {
+    One(Url),
+    Many(Vec<Url>),
+}
+
+impl Association {
+    fn targets(&self) -> &[Url] {
+        match self {
+            Association::One(target) => std::slice::from_ref(target),
+            Association::Many(targets) => targets,
+        }
+    }
+}
+
+impl Entity {
+    fn associate(&mut self, base: &Url, collection: &str, rel: &str, association: Association) {
+        let href = base
+            .join(&format!("{collection}/{}/{rel}", self.id))
+            .expect("The association should be beneath the entrypoint");
+        self.value["_links"][rel] = json!({ "href": href });
+        self.associations.insert(rel.to_owned(), association);
+    }
+
+    fn update(&mut self, fields: JsonValue, replace: bool) {
+        let JsonValue::Object(fields) = fields else {
+            return;
+        };
+        let Some(value) = self.value.as_object_mut() else {
+            return;
+        };
+
+        if replace {
+            value.retain(|key, _| key == "_links");
+        }
+        value.extend(fields);
+    }
+
+    fn field(&self, key: &str) -> Option<&str> {
+        self.value.get(key).and_then(JsonValue::as_str)
+    }
+
+    fn time(&self, key: &str) -> Option<OffsetDateTime> {
+        self.field(key).and_then(parse_time)
+    }
+
+    /// The URLs of the entities linked with the relation
+    fn related(&self, rel: &str) -> Vec<Url> {
+        if let Some(association) = self.associations.get(rel) {
+            return association.targets().to_vec();
+        }
+
+        self.value
+            .pointer(&format!("/_links/{rel}/href"))
+            .and_then(JsonValue::as_str)
+            .and_then(|href| Url::parse(href).ok())
+            .into_iter()
+            .collect()
+    }
+}
+
+impl State {
+    fn insert(
+        &mut self,
+        base: &Url,
+        collection: &str,
+        mut value: JsonValue,
+        associations: HashMap<String, Association>,
+    ) -> i32 {
+        let mut links = take_links(base, &mut value);
+        let id = links
+            .get("self")
+            .and_then(JsonValue::as_str)
+            .and_then(|href| href.rsplit('/').next()?.parse().ok())
+            .unwrap_or(self.next_id + 1);
+        self.next_id = self.next_id.max(id);
+
+        let href = base
+            .join(&format!("{collection}/{id}"))
+            .expect("The entity should be beneath the entrypoint");
+        links.insert(String::from("self"), JsonValue::String(href.into()));
+        let links: Map<_, _> = links
+            .into_iter()
+            .map(|(rel, href)| (rel, json!({ "href": href })))
+            .collect();
+        value["_links"] = JsonValue::Object(links);
+
+        let mut entity = Entity {
+            id,
+            value,
+            associations: HashMap::new(),
+        };
+        for (rel, association) in associations {
+            entity.associate(base, collection, &rel, association);
+        }
+        self.collections
+            .entry(collection.to_owned())
+            .or_default()
+            .insert(id, entity);
+
+        id
+    }
+
+    fn entity(&self, collection: &str, id: &str) -> Option<&Entity> {
+        self.collections.get(collection)?.get(&id.parse().ok()?)
+    }
+
+    fn entity_mut(&mut self, collection: &str, id: i32) -> Option<&mut Entity> {
+        self.collections.get_mut(collection)?.get_mut(&id)
+    }
+
+    fn all(&self, collection: &str) -> Vec<JsonValue> {
+        self.collections
+            .get(collection)
+            .into_iter()
+            .flat_map(BTreeMap::values)
+            .map(|entity| entity.value.clone())
+            .collect()
+    }
+
+    /// The entity served at the URL
+    fn lookup(&self, base: &Url, url: &Url) -> Option<&Entity> {
+        match segments(base, url)?.as_slice() {
+            [collection, id] => self.entity(collection, id),
+            _ => None,
+        }
+    }
+
+    fn follow(
+        &self,
+        base: &Url,
+        url: &Url,
+        collection: &str,
+        id: &str,
+        rel: &str,
+    ) -> Result<JsonValue, (StatusCode, JsonValue)> {
+        let association = self
+            .entity(collection, id)
+            .and_then(|entity| entity.associations.get(rel))
+            .ok_or_else(|| not_found(url))?;
+        let mut entities = association
+            .targets()
+            .iter()
+            .filter_map(|target| self.lookup(base, target))
+            .map(|entity| entity.value.clone());
+
+        match association {
+            Association::One(_) => entities.next().ok_or_else(|| not_found(url)),
+            Association::Many(_) => Ok(json!({
+                "_embedded": { rel: entities.collect::<Vec<_>>() },
+                "_links": { "self": { "href": url } },
+            })),
+        }
+    }
+
+    fn search(
+        &self,
+        base: &Url,
+        url: &Url,
+        collection: &str,
+        name: &str,
+        page_size: usize,
+    ) -> Result<JsonValue, (StatusCode, JsonValue)> {
+        let unsupported = |reason: String| error_body(StatusCode::BAD_REQUEST, url, &reason);
+        if name.contains("Today") || name.contains("Public") {
+            return Err(unsupported(format!("The fake does not support {name}")));
+        }
+
+        let filters: Vec<_> = url
+            .query_pairs()
+            .filter(|(key, _)| !matches!(&**key, "page" | "size" | "sort"))
+            .collect();
+        let mut matched = Vec::new();
+        for entity in self
+            .collections
+            .get(collection)
+            .into_iter()
+            .flat_map(BTreeMap::values)
+        {
+            let mut all = true;
+            for (key, value) in &filters {
+                let Some(matches) = self.matches(base, collection, name, entity, key, value) else {
+                    return Err(unsupported(format!(
+                        "The fake does not support the {key} parameter"
+                    )));
+                };
+                all &= matches;
+            }
+            if all {
+                matched.push(entity.value.clone());
+            }
+        }
+
+        match name.starts_with("findOne") {
+            true => matched.into_iter().next().ok_or_else(|| not_found(url)),
+            false => Ok(page(url, collection, matched, page_size)),
+        }
+    }
+
+    /// Whether the entity matches the search parameter, or `None` when it cannot be evaluated
+    fn matches(
+        &self,
+        base: &Url,
+        collection: &str,
+        search: &str,
+        entity: &Entity,
+        key: &str,
+        value: &str,
+    ) -> Option<bool> {
+        let names = |rel: &str| -> Vec<String> {
+            entity
+                .related(rel)
+                .iter()
+                .filter_map(|url| self.lookup(base, url)?.field("name").map(str::to_owned))
+                .collect()
+        };
+        // Tasks are searched by their pass, which is either overlapping or starting within the
+        // range, while task requests are searched by their target date
+        let (from, until) = match (collection, search.contains("StartBetween")) {
+            ("tasks", true) => ("start", "start"),
+            ("tasks", false) => ("end", "start"),
+            _ => ("targetDate", "targetDate"),
+        };
+
+        let matches = match key {
+            "name" | "status" | "type" => entity.field(key) == Some(value),
+            "ids" => value
+                .split(',')
+                .any(|id| id.trim().parse() == Ok(entity.id)),
+            "start" => entity.time(from) >= Some(parse_time(value)?),
+            "end" => {
+                let end = parse_time(value)?;
+                entity.time(until).is_some_and(|time| time <= end)
+            }
+            _ => {
+                if let Some(rel) = key.strip_suffix("Names") {
+                    let names = names(rel);
+                    value.split(',').any(|name| names.iter().any(|n| n == name))
+                } else if let Some(rel) = key.strip_suffix("Name") {
+                    names(rel).iter().any(|name| name == value)
+                } else {
+                    let value = Url::parse(value).ok()?;
+                    entity.related(key).contains(&value)
+                }
+            }
+        };
+
+        Some(matches)
+    }
+}
+
+/// The segments of the URL beneath the entrypoint, or `None` for URLs outside of it
+fn segments<'a>(base: &Url, url: &'a Url) -> Option<Vec<&'a str>> {
+    if url.origin() != base.origin() {
+        return None;
+    }
+
+    let path = url.path().strip_prefix(base.path())?;
+    Some(
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect(),
+    )
+}
+
+/// Remove the links of the entity, in either the `{"rel": "href"}` or `{"rel": {"href": "href"}}`
+/// form, rewriting the fixture host to the entrypoint
+fn take_links(base: &Url, value: &mut JsonValue) -> Map<String, JsonValue> {
+    let Some(object) = value.as_object_mut() else {
+        return Map::new();
+    };
+    let links = object.remove("_links").or_else(|| object.remove("links"));
+    let Some(JsonValue::Object(links)) = links else {
+        return Map::new();
+    };
+
+    links
+        .into_iter()
+        .filter_map(|(rel, link)| {
+            let href = match &link {
+                JsonValue::String(href) => href.as_str(),
+                link => link.get("href")?.as_str()?,
+            };
+            let href = match href.strip_prefix("http://localhost:8080/api/") {
+                Some(path) => base.join(path).ok()?.to_string(),
+                None => href.to_owned(),
+            };
+
+            Some((rel, JsonValue::String(href)))
+        })
+        .collect()
+}
+
+/// Separate the fields referencing other entities by URL, as submitted by the builders
+fn split_associations(
+    base: &Url,
+    fields: Map<String, JsonValue>,
+) -> (JsonValue, HashMap<String, Association>) {
+    let as_url = |value: &JsonValue| {
+        let url = Url::parse(value.as_str()?).ok()?;
+        segments(base, &url).is_some().then_some(url)
+    };
+
+    let mut associations = HashMap::new();
+    let mut remaining = Map::new();
+    for (key, value) in fields {
+        let association = match &value {
+            JsonValue::Array(items) if !items.is_empty() => items
+                .iter()
+                .map(as_url)
+                .collect::<Option<Vec<_>>>()
+                .map(Association::Many),
+            value => as_url(value).map(Association::One),
+        };
+
+        match association {
+            Some(association) => {
+                associations.insert(key, association);
+            }
+            None => {
+                remaining.insert(key, value);
+            }
+        }
+    }
+
+    (JsonValue::Object(remaining), associations)
+}
+
+/// A page of the items, as served by the collection and search endpoints
+fn page(url: &Url, collection: &str, items: Vec<JsonValue>, default_size: usize) -> JsonValue {
+    let query = |key: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == key)
+            .and_then(|(_, value)| value.parse::<usize>().ok())
+    };
+    let size = query("size")
+        .filter(|size| *size > 0)
+        .unwrap_or(default_size);
+    let number = query("page").unwrap_or(0);
+    let total = items.len();
+    let total_pages = total.div_ceil(size);
+
+    let mut links = json!({ "self": { "href": url } });
+    if number + 1 < total_pages {
+        let mut next = url.clone();
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(key, _)| key != "page")
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        next.query_pairs_mut()
+            .clear()
+            .extend_pairs(pairs)
+            .append_pair("page", &(number + 1).to_string());
+        links["next"] = json!({ "href": next });
+    }
+    let items: Vec<_> = items.into_iter().skip(number * size).take(size).collect();
+
+    json!({
+        "_embedded": { collection: items },
+        "_links": links,
+        "page": {
+            "size": size,
+            "totalElements": total,
+            "totalPages": total_pages,
+            "number": number,
+        },
+    })
+}
+
+fn not_found(url: &Url) -> (StatusCode, JsonValue) {
+    error_body(
+        StatusCode::NOT_FOUND,
+        url,
+        "No entity is served at this URL",
+    )
+}
+
+/// The error object of the Freedom API
+fn error_body(status: StatusCode, url: &Url, message: &str) -> (StatusCode, JsonValue) {
+    let body = json!({
+        "status": status.as_u16(),
+        "error": status.canonical_reason(),
+        "message": message,
+        "path": url.path(),
+    });
+
+    (status, body)
+}
+
+fn response(status: StatusCode, body: Option<&JsonValue>, location: Option<&Url>) -> Response {
+    let mut response = http::Response::builder().status(status);
+    if let Some(location) = location {
+        response = response.header(LOCATION, location.as_str());
+    }
+    let body = match body {
+        Some(body) => {
+            response = response.header(CONTENT_TYPE, "application/json");
+            serde_json::to_vec(body).unwrap_or_default()
+        }
+        None => Vec::new(),
+    };
+
+    Response::from(response.body(body).expect("The response should be valid"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded() -> FakeApi {
+        let fake = FakeApi::new().page_size(2);
+        for name in ["Hubble", "Landsat", "Aqua", "Terra", "Aura"] {
+            fake.insert("satellites", &json!({ "name": name }));
+        }
+
+        fake
+    }
+
+    #[test]
+    fn ids_are_read_from_self_links() {
+        let fake = FakeApi::new();
+
+        let id = fake.insert(
+            "requests",
+            &json!({ "_links": { "self": { "href": "http://localhost:8080/api/requests/104" } } }),
+        );
+        assert_eq!(id, 104);
+        assert_eq!(fake.insert("requests", &json!({})), 105);
+
+        let entity = fake.entity("requests", 104).unwrap();
+        assert_eq!(
+            entity["_links"]["self"]["href"],
+            fake.url("requests/104").as_str()
+        );
+    }
+
+    #[test]
+    fn collections_are_paged() {
+        let fake = seeded();
+
+        let (status, page) = fake.handle_get(&fake.url("satellites?page=1"));
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(page["page"]["totalPages"], 3);
+        assert_eq!(page["_embedded"]["satellites"][0]["name"], "Aqua");
+        let next = page["_links"]["next"]["href"].as_str().unwrap();
+        assert!(next.ends_with("satellites?page=2"), "{next}");
+
+        let (_, last) = fake.handle_get(&Url::parse(next).unwrap());
+        assert!(last["_links"].get("next").is_none());
+    }
+
+    #[test]
+    fn unsupported_searches_are_rejected() {
+        let fake = seeded();
+
+        let (status, _) = fake.handle_get(&fake.url("requests/search/findAllUpcomingToday"));
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = fake.handle_get(&fake.url("satellites/search/findByColor?color=red"));
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
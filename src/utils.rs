@@ -1,3 +1,14 @@
+use std::str::FromStr;
+
+use serde_json::{Map, Value as JsonValue};
+use time::{
+    format_description::well_known::{Iso8601, Rfc3339},
+    OffsetDateTime,
+};
+use url::Url;
+
+use crate::error::Error;
+
 pub(crate) fn list_to_string<I, S>(list: I) -> String
 where
     I: IntoIterator<Item = S>,
@@ -8,3 +19,217 @@ where
         .collect::<Vec<String>>()
         .join(",")
 }
+
+/// Parse a timestamp in either the RFC 3339 form sent by the server, or the ISO 8601 form
+/// produced when serializing the models
+pub(crate) fn parse_time(value: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(value, &Rfc3339)
+        .or_else(|_| OffsetDateTime::parse(value, &Iso8601::DEFAULT))
+        .ok()
+}
+
+/// Fail with [`Error::Validation`] naming every one of the provided values which is NaN or
+/// infinite
+///
+/// Non-finite numbers cannot be represented in JSON, and would otherwise be sent as `null`.
+pub(crate) fn check_finite<'a, I>(values: I) -> Result<(), Error>
+where
+    I: IntoIterator<Item = (&'a str, Option<f64>)>,
+{
+    let violations: Vec<_> = values
+        .into_iter()
+        .filter_map(|(name, value)| match value {
+            Some(value) if !value.is_finite() => Some(format!("{name} of {value} is not finite")),
+            _ => None,
+        })
+        .collect();
+
+    match violations.is_empty() {
+        true => Ok(()),
+        false => Err(Error::Validation(violations)),
+    }
+}
+
+/// Strictly parse a finite number, with a period as the decimal separator and no grouping
+///
+/// Locale-specific forms such as `"1.000,5"` are rejected rather than misinterpreted.
+pub(crate) fn parse_finite(name: &str, value: &str) -> Result<f64, Error> {
+    match value.parse::<f64>() {
+        Ok(number) if number.is_finite() => Ok(number),
+        _ => Err(Error::Validation(vec![format!(
+            "{name} of {value:?} is not a finite number"
+        )])),
+    }
+}
+
+/// Parse the ID from the final non-empty segment of the path of the URL
+///
+/// A trailing slash is ignored, as are the query and fragment, such as the projection of a link.
+pub(crate) fn id_from_url<I: FromStr>(url: &Url) -> Result<I, Error> {
+    url.path_segments()
+        .and_then(|mut segments| segments.rfind(|segment| !segment.is_empty()))
+        .and_then(|id| id.parse().ok())
+        .ok_or_else(|| Error::InvalidId(url.to_string()))
+}
+
+/// Walks the provided JSON value, dropping any HATEOAS link whose href cannot be parsed as a URL.
+///
+/// The models parse every link into a [`Url`], so without this pass a single malformed href causes
+/// the entire object to fail deserialization.
+pub(crate) fn sanitize_links(value: &mut JsonValue) {
+    match value {
+        JsonValue::Object(map) => {
+            for (key, inner) in map.iter_mut() {
+                if key == "_links" || key == "links" {
+                    if let JsonValue::Object(links) = inner {
+                        drop_invalid_links(links);
+                    }
+                }
+
+                sanitize_links(inner);
+            }
+        }
+        JsonValue::Array(items) => items.iter_mut().for_each(sanitize_links),
+        _ => {}
+    }
+}
+
+fn drop_invalid_links(links: &mut Map<String, JsonValue>) {
+    let self_path = links
+        .get("self")
+        .and_then(link_href)
+        .unwrap_or("<unknown>")
+        .to_string();
+
+    links.retain(|name, link| match link_href(link) {
+        Some(href) if Url::parse(href).is_err() => {
+            tracing::warn!(entity = %self_path, link = %name, href, "Dropping unparseable link");
+            false
+        }
+        _ => true,
+    });
+}
+
+fn link_href(link: &JsonValue) -> Option<&str> {
+    match link {
+        JsonValue::String(href) => Some(href.as_str()),
+        JsonValue::Object(_) => link.get("href").and_then(JsonValue::as_str),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn non_finite_values_are_named() {
+        assert!(check_finite([("frequency", Some(8096.0)), ("gain", None)]).is_ok());
+
+        let Err(Error::Validation(violations)) = check_finite([
+            ("frequency", Some(f64::NAN)),
+            ("bandwidth", Some(1.5)),
+            ("gain", Some(f64::NEG_INFINITY)),
+        ]) else {
+            panic!("Expected a validation error");
+        };
+        assert_eq!(
+            violations,
+            [
+                "frequency of NaN is not finite",
+                "gain of -inf is not finite"
+            ]
+        );
+    }
+
+    #[test]
+    fn numbers_parse_strictly() {
+        assert_eq!(parse_finite("frequency", "8096.5").unwrap(), 8096.5);
+
+        for value in ["1.000,5", "1,5", " 8096", "NaN", "inf", "-infinity", ""] {
+            let result = parse_finite("frequency", value);
+            assert!(matches!(result, Err(Error::Validation(_))), "{value:?}");
+        }
+    }
+
+    #[test]
+    fn ids_parse_from_final_segment() {
+        let id = |url: &str| id_from_url::<i32>(&Url::parse(url).unwrap());
+
+        assert_eq!(id("http://localhost:8080/api/satellites/710").unwrap(), 710);
+        assert_eq!(
+            id("http://localhost:8080/api/satellites/710/").unwrap(),
+            710
+        );
+        assert_eq!(
+            id("http://localhost:8080/api/satellites/710//").unwrap(),
+            710
+        );
+        assert_eq!(
+            id("http://localhost:8080/api/satellites/710?projection=full#top").unwrap(),
+            710
+        );
+    }
+
+    #[test]
+    fn invalid_ids_name_the_url() {
+        for url in [
+            "http://localhost:8080/api/satellites/search",
+            "http://localhost:8080/api/satellites/710/orbitInfo/",
+            "http://localhost:8080/",
+            "http://localhost:8080/api/tasks/4294967296",
+        ] {
+            let result = id_from_url::<i32>(&Url::parse(url).unwrap());
+            assert_eq!(result, Err(Error::InvalidId(url.to_owned())));
+        }
+    }
+
+    #[test]
+    fn large_ids_parse_as_i64() {
+        let url = Url::parse("http://localhost:8080/api/tasks/4294967296/").unwrap();
+
+        assert_eq!(id_from_url::<i64>(&url).unwrap(), 4_294_967_296);
+        assert!(id_from_url::<i32>(&url).is_err());
+    }
+
+    #[test]
+    fn drops_only_unparseable_links() {
+        let mut value = json!({
+            "name": "LOAG",
+            "_links": {
+                "self": { "href": "http://localhost:8080/api/sites/14" },
+                "configurations": { "href": "http://local host:8080/api/sites/14/configurations" },
+            }
+        });
+
+        sanitize_links(&mut value);
+
+        let links = value["_links"].as_object().unwrap();
+        assert_eq!(links.len(), 1);
+        assert!(links.contains_key("self"));
+    }
+
+    #[test]
+    fn sanitizes_nested_links() {
+        let mut value = json!({
+            "_embedded": {
+                "sites": [
+                    {
+                        "links": {
+                            "self": "http://localhost:8080/api/sites/1",
+                            "bad": "http://local host:8080/api/sites/1/bad",
+                        }
+                    }
+                ]
+            }
+        });
+
+        sanitize_links(&mut value);
+
+        let links = value["_embedded"]["sites"][0]["links"].as_object().unwrap();
+        assert_eq!(links.len(), 1);
+        assert!(links.contains_key("self"));
+    }
+}
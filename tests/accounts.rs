@@ -1,8 +1,10 @@
 mod common;
 
 use common::{TestResult, TestingEnv};
-use freedom_api::prelude::*;
+use freedom_api::{error::Error, prelude::*};
 use futures::StreamExt;
+use httpmock::Method::POST;
+use serde_json::json;
 use time::{Date, OffsetDateTime, Time};
 
 #[tokio::test]
@@ -30,3 +32,84 @@ async fn fetch_account() -> TestResult {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn create_account() -> TestResult {
+    let env = TestingEnv::new();
+    let location = env.url("/api/accounts/105");
+    let create = env.mock(|when, then| {
+        when.method(POST).path("/accounts").json_body(json!({
+            "name": "ABC Space",
+            "storageKey": "ABCSpace",
+            "accessRealtimeCidr": [{ "name": "ABC Space 1", "cidr": "1.2.3.4/32" }],
+            "accessApiCidr": [],
+            "postProcessDoneByAccount": true
+        }));
+        then.status(201).header("location", location.as_str());
+    });
+    let client = Client::from(env.clone());
+
+    let id = client
+        .new_account()
+        .name("ABC Space")
+        .storage_key("ABCSpace")
+        .add_realtime_cidr("ABC Space 1", "1.2.3.4/32")
+        .post_process_done_by_account(true)
+        .send()
+        .await?;
+
+    assert_eq!(id, 105);
+    create.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_account_from_body() -> TestResult {
+    let env = TestingEnv::new();
+    let self_link = env.url("/api/accounts/106");
+    let create = env.mock(|when, then| {
+        when.method(POST).path("/accounts").json_body(json!({
+            "name": "XYZ Space",
+            "accessRealtimeCidr": [],
+            "accessApiCidr": [],
+            "postProcessDoneByAccount": false
+        }));
+        then.status(201)
+            .header("content-type", "application/json")
+            .json_body(json!({
+                "name": "XYZ Space",
+                "_links": { "self": { "href": self_link } }
+            }));
+    });
+    let client = Client::from(env.clone());
+
+    let id = client.new_account().name("XYZ Space").send().await?;
+
+    assert_eq!(id, 106);
+    create.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn malformed_cidr_is_rejected() -> TestResult {
+    let env = TestingEnv::new();
+    let create = env.post_json("/accounts", 201, json!({}));
+    let client = Client::from(env.clone());
+
+    let result = client
+        .new_account()
+        .name("ABC Space")
+        .add_api_cidr("Office", "10.0.0.0")
+        .send()
+        .await;
+
+    let Err(Error::Validation(violations)) = result else {
+        panic!("Expected a validation error, got {result:?}");
+    };
+    assert_eq!(violations, [r#""10.0.0.0" of "Office" is not a CIDR"#]);
+    create.assert_hits(0);
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "caching")]
+
+mod common;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::prelude::*;
+use futures::StreamExt;
+
+fn any_clients(env: &TestingEnv) -> [AnyClient; 2] {
+    let client = Client::from(env.clone());
+    [
+        AnyClient::from(client.clone()),
+        AnyClient::from(CachingClient::new(client, 100)),
+    ]
+}
+
+#[tokio::test]
+async fn find_satellites_through_either_client() -> TestResult {
+    let env = TestingEnv::new();
+
+    let by_id = env.get_json_from_file(
+        "/satellites/710",
+        Vec::new(),
+        "resources/satellite_find_one_710.json",
+    );
+    env.get_json_from_file(
+        "/satellites",
+        Vec::new(),
+        "resources/satellite_find_all.json",
+    );
+
+    for client in any_clients(&env) {
+        let satellite = client.get_satellite_by_id(710).await?;
+        assert_eq!(satellite.get_id()?, 710);
+
+        let satellites = client
+            .get_satellites()
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(satellites.len(), 14);
+    }
+    by_id.assert_hits(2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn caching_variant_serves_from_cache() -> TestResult {
+    let env = TestingEnv::new();
+
+    let by_id = env.get_json_from_file(
+        "/satellites/710",
+        Vec::new(),
+        "resources/satellite_find_one_710.json",
+    );
+    let [plain, caching] = any_clients(&env);
+
+    plain.get_satellite_by_id(710).await?;
+    plain.get_satellite_by_id(710).await?;
+    by_id.assert_hits(2);
+
+    let first = caching.get_satellite_by_id(710).await?;
+    let second = caching.get_satellite_by_id(710).await?;
+    assert_eq!(first, second);
+    by_id.assert_hits(3);
+
+    Ok(())
+}
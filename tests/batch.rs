@@ -0,0 +1,91 @@
+mod common;
+
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+use common::{TestResult, TestingEnv};
+use freedom_api::{
+    error::Error,
+    extensions::batch::{resolve_all, resolve_all_with},
+    prelude::*,
+};
+use httpmock::Method::GET;
+
+const LATENCY: Duration = Duration::from_millis(200);
+
+#[tokio::test]
+async fn links_are_resolved_concurrently_in_order() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.get_json_from_file(
+        "/satellites",
+        Vec::new(),
+        "resources/satellite_find_all.json",
+    );
+    let orbits = env.mock(|when, then| {
+        when.method(GET).path_contains("/orbitInfo");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(env.json_from_file("resources/satellite_orbit_info_710.json"))
+            .delay(LATENCY);
+    });
+    let client = Client::from(env.clone());
+
+    let satellites = client.get_satellites().try_collect_inner().await?;
+    let expected: Vec<_> = satellites.iter().map(|sat| sat.get_id()).collect();
+
+    let in_flight = AtomicUsize::new(0);
+    let most_in_flight = AtomicUsize::new(0);
+    let started = Instant::now();
+
+    let resolved = resolve_all_with(&satellites, 4, |sat| {
+        let (client, in_flight, most_in_flight) = (&client, &in_flight, &most_in_flight);
+        async move {
+            let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            most_in_flight.fetch_max(now, Ordering::SeqCst);
+
+            let orbit = sat.get_orbit_info(client).await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            orbit.and_then(|_| sat.get_id())
+        }
+    })
+    .await;
+    let elapsed = started.elapsed();
+
+    assert_eq!(resolved, expected);
+    assert_eq!(most_in_flight.load(Ordering::SeqCst), 4);
+    orbits.assert_hits(satellites.len());
+
+    // Fetched one at a time, the satellites would take at least the latency of every hop
+    assert!(
+        elapsed < LATENCY * satellites.len() as u32 / 2,
+        "resolving took {elapsed:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn failures_are_produced_in_place() -> TestResult {
+    let resolved = resolve_all(1..=3, |id| async move {
+        match id {
+            2 => Err(Error::InvalidId(String::from("/api/satellites/two"))),
+            id => Ok(id * 10),
+        }
+    })
+    .await;
+
+    assert_eq!(
+        resolved,
+        [
+            Ok(10),
+            Err(Error::InvalidId(String::from("/api/satellites/two"))),
+            Ok(30)
+        ]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::{blocking, error::Error, prelude::*};
+use httpmock::Method::DELETE;
+
+fn blocking_client(env: &TestingEnv) -> blocking::Client {
+    blocking::Client::from(Client::from(env.clone()))
+}
+
+#[test]
+fn find_satellites_blocking() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.get_json_from_file(
+        "/satellites/710",
+        Vec::new(),
+        "resources/satellite_find_one_710.json",
+    );
+    env.get_json_from_file(
+        "/satellites/findOneByName",
+        vec![("name", "FooBar 6")],
+        "resources/satellite_find_one_710.json",
+    );
+    env.get_json_from_file(
+        "/satellites",
+        Vec::new(),
+        "resources/satellite_find_all.json",
+    );
+    let client = blocking_client(&env);
+
+    let by_id = client.get_satellite_by_id(710)?;
+    let by_name = client.get_satellite_by_name("FooBar 6")?;
+    assert_eq!(by_id, by_name);
+    assert_eq!(by_id.get_id()?, 710);
+
+    let satellites = client.get_satellites().collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(satellites.len(), 14);
+    assert_eq!(client.list_all::<Satellite>()?, satellites);
+
+    Ok(())
+}
+
+#[test]
+fn requests_iterate_every_page() -> TestResult {
+    let env = TestingEnv::new();
+
+    let pages = env.get_json_pages(
+        "/requests/search/findAll",
+        vec![],
+        vec![
+            serde_json::from_str(&env.json_from_file("resources/requests_find_all_page_1.json"))?,
+            serde_json::from_str(&env.json_from_file("resources/requests_find_all_page_2.json"))?,
+        ],
+    );
+    let client = blocking_client(&env);
+
+    let ids = client
+        .get_requests()
+        .map(|request| request?.get_id())
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(ids, [101, 102, 103]);
+    pages[1].assert_hits(1);
+
+    Ok(())
+}
+
+#[test]
+fn delete_request_blocking() -> TestResult {
+    let env = TestingEnv::new();
+
+    let deleted = env.delete("/requests/101", 204);
+    let rejected = env.mock(|when, then| {
+        when.method(DELETE).path("/requests/102");
+        then.status(409)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "status": 409,
+                "error": "Conflict",
+                "message": "Task request is already scheduled",
+                "path": "/api/requests/102"
+            }));
+    });
+    let client = blocking_client(&env);
+
+    client.delete_task_request(101)?;
+    let result = client.delete_task_request(102).map_err(Error::into_inner);
+    assert!(matches!(result, Err(Error::Api { .. })), "{result:?}");
+    deleted.assert_hits(1);
+    rejected.assert_hits(1);
+
+    Ok(())
+}
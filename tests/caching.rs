@@ -0,0 +1,147 @@
+#![cfg(feature = "caching")]
+
+mod common;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::prelude::*;
+use futures::StreamExt;
+use httpmock::Method::{DELETE, GET, POST};
+
+fn caching_client(env: &TestingEnv) -> CachingClient {
+    CachingClient::new(Client::from(env.clone()), 100)
+}
+
+#[tokio::test]
+async fn delete_invalidates_only_the_collection() -> TestResult {
+    let env = TestingEnv::new();
+
+    let get_override = env.mock(|when, then| {
+        when.method(GET).path("/overrides/7");
+        then.status(200).body("{}");
+    });
+    let get_site =
+        env.get_json_from_file("/sites/14", Vec::new(), "resources/sites_find_one_14.json");
+    env.mock(|when, then| {
+        when.method(DELETE).path("/overrides/7");
+        then.status(204);
+    });
+    let client = caching_client(&env);
+    let override_url = client.path_to_url("overrides/7")?;
+
+    client.get(override_url.clone()).await?;
+    client.get_site_by_id(14).await?;
+    client.delete_override(7).await?;
+
+    client.get(override_url).await?;
+    let site = client.get_site_by_id(14).await?;
+    assert_eq!(site.name, "LOAG");
+    get_override.assert_hits(2);
+    get_site.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalidate_by_url_and_prefix() -> TestResult {
+    let env = TestingEnv::new();
+
+    let first = env.mock(|when, then| {
+        when.method(GET).path("/requests/1");
+        then.status(200).body("{}");
+    });
+    let second = env.mock(|when, then| {
+        when.method(GET).path("/requests/2");
+        then.status(200).body("{}");
+    });
+    let site = env.mock(|when, then| {
+        when.method(GET).path("/sites/14");
+        then.status(200).body("{}");
+    });
+    let client = caching_client(&env);
+    let urls =
+        ["requests/1", "requests/2", "sites/14"].map(|path| client.path_to_url(path).unwrap());
+    let fetch_all = || async {
+        for url in &urls {
+            client.get(url.clone()).await.unwrap();
+        }
+    };
+
+    fetch_all().await;
+    client.invalidate(&urls[0]).await;
+    fetch_all().await;
+    first.assert_hits(2);
+    second.assert_hits(1);
+    site.assert_hits(1);
+
+    client.invalidate_prefix("requests");
+    fetch_all().await;
+    first.assert_hits(3);
+    second.assert_hits(2);
+    site.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_your_writes_bypasses_warm_cache() -> TestResult {
+    let env = TestingEnv::new();
+
+    let list = env.get_json_from_file(
+        "/satellites",
+        Vec::new(),
+        "resources/satellite_find_all.json",
+    );
+    env.mock(|when, then| {
+        when.method(POST).path("/satellites");
+        then.status(201);
+    });
+    let client = CachingClient::builder(Client::from(env.clone()))
+        .read_your_writes(true)
+        .build();
+    let count = || async { client.get_satellites().count().await };
+
+    let before = count().await;
+    count().await;
+    list.assert_hits(1);
+
+    client
+        .post(client.path_to_url("satellites")?, serde_json::json!({}))
+        .await?;
+    assert_eq!(count().await, before);
+    list.assert_hits(2);
+
+    count().await;
+    list.assert_hits(3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn refresh_through_fresh_view() -> TestResult {
+    let env = TestingEnv::new();
+    let requests: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/requests_find_all.json"))?;
+    let mut request = requests["_embedded"]["requests"][0].clone();
+    env.get_json("/requests/101", vec![], request.clone());
+    let mut original = env.get_json("/api/requests/101", vec![], request.clone());
+    let client = caching_client(&env);
+
+    let fetched = client.get_request_by_id(101).await?;
+    fetched.refresh(&client).await?;
+    original.delete();
+    request["modified"] = "2024-10-01T13:00:00Z".into();
+    let updated = env.get_json("/api/requests/101", vec![], request);
+
+    // The cached task request is served until the fresh view fetches it again
+    let cached = fetched.refresh(&client).await?;
+    assert_eq!(cached.modified, fetched.modified);
+    updated.assert_hits(0);
+
+    let refreshed = fetched.refresh(&client.fresh()).await?;
+    assert_ne!(refreshed.modified, fetched.modified);
+    let cached = fetched.refresh(&client).await?;
+    assert_eq!(cached.modified, refreshed.modified);
+    updated.assert_hits(1);
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+//! Wire compatibility of the models with payloads recorded from Freedom
+//!
+//! Every JSON file beneath `resources/recorded` is a response body captured from the API, with any
+//! identifying details scrubbed. The model each payload must deserialize into is worked out from
+//! the directory of the collection it was recorded from, and whether it is a page or a list, so
+//! that every payload on disk is checked without keeping a list of them by hand. Each failure is
+//! reported with the path of the offending field, so that a breaking change to the models is
+//! caught before it reaches production.
+//!
+//! To record a new payload, save the scrubbed body beneath the directory named for its collection.
+
+use std::path::Path;
+
+use freedom_api::prelude::{OrbitInfo, Visibility};
+use freedom_models::{
+    band::Band,
+    pagination::Paginated,
+    satellite::Satellite,
+    site::Site,
+    task::{Task, TaskRequest},
+};
+use serde::de::DeserializeOwned;
+
+const RECORDED: &str = "resources/recorded";
+
+/// The model of single payloads, by the directory of the collection they were recorded from
+const COLLECTIONS: &[(&str, &str)] = &[
+    ("requests", "TaskRequest"),
+    ("satellite_bands", "Band"),
+    ("satellite_orbit_info", "OrbitInfo"),
+    ("satellite_visibilities", "Visibility"),
+    ("satellites", "Satellite"),
+    ("sites", "Site"),
+    ("tasks", "Task"),
+];
+
+/// Every JSON payload beneath the directory, relative to [`RECORDED`]
+fn recorded_files(dir: &Path, files: &mut Vec<String>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            recorded_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            let relative = path.strip_prefix(RECORDED).unwrap();
+            files.push(relative.to_string_lossy().replace('\\', "/"));
+        }
+    }
+}
+
+fn deserialize<T: DeserializeOwned>(body: &str) -> Result<(), String> {
+    let deserializer = &mut serde_json::Deserializer::from_str(body);
+
+    serde_path_to_error::deserialize::<_, T>(deserializer)
+        .map(|_| ())
+        .map_err(|error| format!("at `{}`: {}", error.path(), error.inner()))
+}
+
+fn check(model: &str, body: &str) -> Result<(), String> {
+    match model {
+        "Band" => deserialize::<Band>(body),
+        "OrbitInfo" => deserialize::<OrbitInfo>(body),
+        "Satellite" => deserialize::<Satellite>(body),
+        "Site" => deserialize::<Site>(body),
+        "Task" => deserialize::<Task>(body),
+        "TaskRequest" => deserialize::<TaskRequest>(body),
+        "Paginated<Band>" => deserialize::<Paginated<Band>>(body),
+        "Paginated<Satellite>" => deserialize::<Paginated<Satellite>>(body),
+        "Paginated<Site>" => deserialize::<Paginated<Site>>(body),
+        "Paginated<Task>" => deserialize::<Paginated<Task>>(body),
+        "Paginated<TaskRequest>" => deserialize::<Paginated<TaskRequest>>(body),
+        "Vec<Visibility>" => deserialize::<Vec<Visibility>>(body),
+        model => Err(format!("unknown model {model}")),
+    }
+}
+
+/// The model the payload must deserialize into, inferred from the directory of the collection it
+/// was recorded from, and whether it is a page or a list of results
+fn model_of(file: &str, body: &str) -> Result<String, String> {
+    let collection = file.split('/').next().unwrap_or_default();
+    let Some((_, model)) = COLLECTIONS.iter().find(|(name, _)| *name == collection) else {
+        return Err("not recorded beneath a known collection".to_string());
+    };
+    let body: serde_json::Value =
+        serde_json::from_str(body).map_err(|error| format!("is not JSON: {error}"))?;
+
+    Ok(if body.get("_embedded").is_some() {
+        format!("Paginated<{model}>")
+    } else if body.is_array() {
+        format!("Vec<{model}>")
+    } else {
+        model.to_string()
+    })
+}
+
+#[test]
+fn recorded_payloads_deserialize() {
+    let mut files = Vec::new();
+    recorded_files(Path::new(RECORDED), &mut files);
+    files.sort();
+    assert!(
+        !files.is_empty(),
+        "no payloads are recorded beneath {RECORDED}"
+    );
+
+    let mut failures = Vec::new();
+    for file in &files {
+        let body = std::fs::read_to_string(Path::new(RECORDED).join(file)).unwrap();
+        let result = model_of(file, &body)
+            .and_then(|model| check(&model, &body).map_err(|error| format!("as {model} {error}")));
+        if let Err(error) = result {
+            failures.push(format!("{file} {error}"));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} recorded payloads are incompatible:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "parquet")]
+
+mod common;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::{
+    error::Error,
+    export::{task_schema, tasks_to_parquet, ExportOptions},
+    prelude::*,
+};
+use futures::StreamExt;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+#[tokio::test]
+async fn export_tasks_across_pages() -> TestResult {
+    let env = TestingEnv::new();
+    let first = env.get_json_from_file(
+        "/tasks/search/findByOverlapping",
+        vec![],
+        "resources/tasks_1/page_1.json",
+    );
+    let second = env.get_json_from_file(
+        "/api/tasks/search/findByOverlapping",
+        vec![("page", "1")],
+        "resources/tasks_1/page_2.json",
+    );
+    let client = Client::from(env.clone());
+    let tasks = client
+        .get_paginated::<Task>(env.url("/tasks/search/findByOverlapping").parse()?)
+        .chain(futures::stream::once(async {
+            Err(Error::Response(String::from("dropped connection")))
+        }));
+
+    let path = std::env::temp_dir().join(format!("freedom-export-{}.parquet", env.port()));
+    let options = ExportOptions::default()
+        .resolve_names(false)
+        .row_group_size(8);
+    let report = tasks_to_parquet(&client, tasks, &path, options).await?;
+    first.assert_hits(1);
+    second.assert_hits(1);
+
+    assert_eq!(report.rows, 34);
+    assert_eq!(report.skipped, 1);
+    let errors_path = report.errors_path.unwrap();
+    let errors: serde_json::Value = serde_json::from_slice(&std::fs::read(&errors_path)?)?;
+    assert_eq!(errors[0]["index"], 34);
+
+    let reader = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(&path)?)?;
+    assert_eq!(reader.schema(), &task_schema());
+    assert_eq!(reader.metadata().num_row_groups(), 5);
+    let rows: usize = reader
+        .build()?
+        .map(|batch| batch.map(|batch| batch.num_rows()))
+        .sum::<Result<_, _>>()?;
+    assert_eq!(rows, 34);
+
+    std::fs::remove_file(path)?;
+    std::fs::remove_file(errors_path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn every_skipped_item_is_written_to_the_sidecar() -> TestResult {
+    let env = TestingEnv::new();
+    let client = Client::from(env.clone());
+    let tasks = futures::stream::iter((0..3).map(|attempt| {
+        Err::<<Client as Api>::Container<Task>, _>(Error::Response(format!("attempt {attempt}")))
+    }));
+
+    let path = std::env::temp_dir().join(format!("freedom-export-{}.parquet", env.port()));
+    let report = tasks_to_parquet(&client, tasks, &path, ExportOptions::default()).await?;
+
+    assert_eq!(report.rows, 0);
+    assert_eq!(report.skipped, 3);
+    let errors_path = report.errors_path.unwrap();
+    let errors: Vec<serde_json::Value> = serde_json::from_slice(&std::fs::read(&errors_path)?)?;
+    let indices: Vec<_> = errors.iter().map(|error| error["index"].clone()).collect();
+    assert_eq!(indices, [0, 1, 2]);
+
+    std::fs::remove_file(path)?;
+    std::fs::remove_file(errors_path)?;
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "test-util")]
+
+use freedom_api::{error::Error, prelude::*, testing::FakeApi};
+use futures::StreamExt;
+use serde_json::{json, Value as JsonValue};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn fixture_items(file: &str) -> Vec<JsonValue> {
+    let page: JsonValue = serde_json::from_str(&std::fs::read_to_string(file).unwrap()).unwrap();
+
+    page["_embedded"]
+        .as_object()
+        .and_then(|embedded| embedded.values().next())
+        .and_then(JsonValue::as_array)
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn seeded_entities_are_paged() -> TestResult {
+    let fake = FakeApi::new().page_size(5);
+    let satellites = fixture_items("resources/satellite_find_all.json");
+    for satellite in &satellites {
+        fake.insert("satellites", satellite);
+    }
+
+    let fetched: Vec<_> = fake.get_satellites().collect().await;
+    assert_eq!(fetched.len(), satellites.len());
+    assert!(fetched.iter().all(Result::is_ok));
+
+    let first = fetched[0].as_ref().unwrap();
+    let by_id = fake.get_satellite_by_id(first.get_id()?).await?;
+    let by_name = fake.get_satellite_by_name(&first.name).await?;
+    assert_eq!(by_id.name, first.name);
+    assert_eq!(by_name.get_id(), first.get_id());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn searches_filter_seeded_entities() -> TestResult {
+    let fake = FakeApi::new();
+    for request in fixture_items("resources/requests_find_all.json") {
+        fake.insert("requests", &request);
+    }
+
+    let requests = fake.get_requests_by_ids(["101", "103"]).await?;
+    let ids: Vec<_> = requests.iter().map(|request| request.get_id()).collect();
+    assert_eq!(ids, [Ok(101.into()), Ok(103.into())]);
+
+    let missing = fake.get_satellite_by_name("Missing").await;
+    assert!(
+        matches!(
+            missing.as_ref().map_err(Error::inner),
+            Err(Error::Api { .. })
+        ),
+        "{missing:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn associations_are_followed() -> TestResult {
+    let fake = FakeApi::new();
+    let band: JsonValue = serde_json::from_str(&std::fs::read_to_string(
+        "resources/satellite_bands_find_one_1573.json",
+    )?)?;
+    let band_id = fake.insert("satellite_bands", &band);
+    let request: JsonValue = serde_json::from_str(&std::fs::read_to_string(
+        "resources/requests_created_104.json",
+    )?)?;
+    let request_id = fake.insert("requests", &request);
+    fake.associate_many(
+        "requests",
+        request_id,
+        "targetBands",
+        vec![fake.url(format!("satellite_bands/{band_id}"))],
+    );
+
+    let request = fake.get_request_by_id(request_id).await?;
+    let bands = request.get_target_bands(&fake).await?;
+
+    assert_eq!(bands.len(), 1);
+    assert_eq!(bands[0].name, "FooBarBand1");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn builders_insert_entities() -> TestResult {
+    let fake = FakeApi::new();
+    fake.insert(
+        "satellites",
+        &json!({ "name": "FooBar 6", "_links": { "self": fake.url("satellites/710") } }),
+    );
+    fake.insert(
+        "satellite_configurations",
+        &json!({ "name": "FooBarConfig1", "_links": { "self": fake.url("satellite_configurations/812") } }),
+    );
+
+    fake.new_override()
+        .name("bitrate override")
+        .satellite_id(710)
+        .satellite_configuration_id(812)
+        .add_property("site.hardware.modem.ttc.rx.demodulator.bitrate", 8096)
+        .send()
+        .await?;
+    assert_eq!(fake.len("overrides"), 1);
+
+    let created = fake.entity("overrides", 813).unwrap();
+    assert_eq!(created["name"], "bitrate override");
+    assert_eq!(
+        created["_links"]["satellite"]["href"],
+        fake.url("overrides/813/satellite").as_str()
+    );
+
+    OverrideBuilder::from_existing(&fake, 813)
+        .await?
+        .name("bitrate override, attempt 2")
+        .send()
+        .await?;
+    assert_eq!(fake.len("overrides"), 2);
+
+    fake.delete_override(813).await?;
+    assert_eq!(fake.len("overrides"), 1);
+
+    Ok(())
+}
@@ -0,0 +1,359 @@
+mod common;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::{error::Error, prelude::*};
+use futures::StreamExt;
+use httpmock::Method::GET;
+
+/// Large enough to be received over several chunks
+fn recording() -> Vec<u8> {
+    (0..4 * 1024 * 1024).map(|i| (i % 251) as u8).collect()
+}
+
+#[tokio::test]
+async fn stream_file_chunks() -> TestResult {
+    let env = TestingEnv::new();
+    let body = recording();
+    env.mock(|when, then| {
+        when.method(GET).path("/downloads/42/data.bin");
+        then.status(200).body(&body);
+    });
+    let client = Client::from(env);
+
+    let mut chunks = client.get_file_streamed(42, "data.bin").await?;
+    let mut received = Vec::new();
+    let mut count = 0;
+    while let Some(chunk) = chunks.next().await {
+        received.extend_from_slice(&chunk?);
+        count += 1;
+    }
+
+    assert!(count > 1);
+    assert_eq!(received, body);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn download_file_to_disk() -> TestResult {
+    let env = TestingEnv::new();
+    let body = recording();
+    env.mock(|when, then| {
+        when.method(GET).path("/downloads/42/data.bin");
+        then.status(200).body(&body);
+    });
+    let client = Client::from(env.clone());
+
+    let path = std::env::temp_dir().join(format!("freedom-download-{}.bin", env.port()));
+    let written = client.download_file_to(42, "data.bin", &path).await?;
+
+    assert_eq!(written, body.len() as u64);
+    assert_eq!(std::fs::read(&path)?, body);
+    std::fs::remove_file(path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unsuccessful_download_fails_before_streaming() -> TestResult {
+    let env = TestingEnv::new();
+    env.mock(|when, then| {
+        when.method(GET).path("/downloads/42/missing.bin");
+        then.status(404)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "status": 404,
+                "error": "Not Found",
+                "message": "No such file",
+                "path": "/api/downloads/42/missing.bin"
+            }));
+    });
+    let client = Client::from(env.clone());
+
+    let result = client.get_file_streamed(42, "missing.bin").await;
+    assert!(
+        matches!(result.as_ref().map_err(Error::inner), Err(Error::Api { status, .. }) if status.as_u16() == 404),
+        "{:?}",
+        result.err()
+    );
+
+    let path = std::env::temp_dir().join(format!("freedom-download-{}.bin", env.port()));
+    let result = client.download_file_to(42, "missing.bin", &path).await;
+    assert!(
+        matches!(
+            result.as_ref().map_err(Error::inner),
+            Err(Error::Api { .. })
+        ),
+        "{result:?}"
+    );
+    assert!(!path.exists());
+
+    Ok(())
+}
+
+fn partial_path(env: &TestingEnv, name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("freedom-{name}-{}.bin", env.port()))
+}
+
+#[tokio::test]
+async fn get_file_range_requests_only_the_range() -> TestResult {
+    let env = TestingEnv::new();
+    let body = recording();
+    let mock = env.mock(|when, then| {
+        when.method(GET)
+            .path("/downloads/42/data.bin")
+            .header("range", "bytes=100-199");
+        then.status(206).body(&body[100..200]);
+    });
+    let client = Client::from(env.clone());
+
+    let chunks: Vec<_> = client
+        .get_file_range(42, "data.bin", 100..200)
+        .await?
+        .collect()
+        .await;
+    let received = chunks.into_iter().collect::<Result<Vec<_>, _>>()?.concat();
+
+    mock.assert_hits(1);
+    assert_eq!(received, &body[100..200]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_file_range_slices_ignored_range() -> TestResult {
+    let env = TestingEnv::new();
+    let body = recording();
+    env.mock(|when, then| {
+        when.method(GET).path("/downloads/42/data.bin");
+        then.status(200).body(&body);
+    });
+    let client = Client::from(env);
+
+    let chunks: Vec<_> = client
+        .get_file_range(42, "data.bin", 70_000..1_000_000)
+        .await?
+        .collect()
+        .await;
+    let received = chunks.into_iter().collect::<Result<Vec<_>, _>>()?.concat();
+
+    assert_eq!(received, &body[70_000..1_000_000]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resume_partial_download() -> TestResult {
+    let env = TestingEnv::new();
+    let body = recording();
+    let resumed = env.mock(|when, then| {
+        when.method(GET)
+            .path("/downloads/42/data.bin")
+            .header("range", "bytes=1000-");
+        then.status(206)
+            .header(
+                "content-range",
+                format!("bytes 1000-{}/{}", body.len() - 1, body.len()),
+            )
+            .body(&body[1000..]);
+    });
+    let client = Client::from(env.clone());
+
+    let path = partial_path(&env, "resume");
+    std::fs::write(&path, &body[..1000])?;
+    let size = client
+        .download_file_resumable(42, "data.bin", &path)
+        .await?;
+
+    resumed.assert_hits(1);
+    assert_eq!(size, body.len() as u64);
+    assert_eq!(std::fs::read(&path)?, body);
+    std::fs::remove_file(path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resume_other_range_restarts_download() -> TestResult {
+    let env = TestingEnv::new();
+    let body = recording();
+    let mismatched = env.mock(|when, then| {
+        when.method(GET)
+            .path("/downloads/42/data.bin")
+            .header("range", "bytes=1000-");
+        then.status(206)
+            .header(
+                "content-range",
+                format!("bytes 0-{}/{}", body.len() - 1, body.len()),
+            )
+            .body(&body);
+    });
+    let restarted = env.mock(|when, then| {
+        when.method(GET)
+            .path("/downloads/42/data.bin")
+            .matches(|req| {
+                !req.headers
+                    .iter()
+                    .flatten()
+                    .any(|(name, _)| name.eq_ignore_ascii_case("range"))
+            });
+        then.status(200).body(&body);
+    });
+    let client = Client::from(env.clone());
+
+    let path = partial_path(&env, "mismatched");
+    std::fs::write(&path, &body[..1000])?;
+    let size = client
+        .download_file_resumable(42, "data.bin", &path)
+        .await?;
+
+    mismatched.assert_hits(1);
+    restarted.assert_hits(1);
+    assert_eq!(size, body.len() as u64);
+    assert_eq!(std::fs::read(&path)?, body);
+    std::fs::remove_file(path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn file_name_is_a_single_encoded_segment() -> TestResult {
+    let env = TestingEnv::new();
+    let file = env.mock(|when, then| {
+        when.method(GET).path("/downloads/42/pass%201%23%2F2.bin");
+        then.status(200).body("data");
+    });
+    let client = Client::from(env);
+
+    let data = client
+        .get_file_by_task_id_and_name(42, "pass 1#/2.bin")
+        .await?;
+    assert_eq!(data, "data");
+    file.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resume_ignored_range_restarts_download() -> TestResult {
+    let env = TestingEnv::new();
+    let body = recording();
+    env.mock(|when, then| {
+        when.method(GET).path("/downloads/42/data.bin");
+        then.status(200).body(&body);
+    });
+    let client = Client::from(env.clone());
+
+    let path = partial_path(&env, "restart");
+    std::fs::write(&path, b"corrupted partial file")?;
+    let size = client
+        .download_file_resumable(42, "data.bin", &path)
+        .await?;
+
+    assert_eq!(size, body.len() as u64);
+    assert_eq!(std::fs::read(&path)?, body);
+    std::fs::remove_file(path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resume_complete_download() -> TestResult {
+    let env = TestingEnv::new();
+    let body = recording();
+    let unsatisfiable = env.mock(|when, then| {
+        when.method(GET)
+            .path("/downloads/42/data.bin")
+            .header("range", format!("bytes={}-", body.len()));
+        then.status(416)
+            .header("content-range", format!("bytes */{}", body.len()));
+    });
+    let client = Client::from(env.clone());
+
+    let path = partial_path(&env, "complete");
+    std::fs::write(&path, &body)?;
+    let size = client
+        .download_file_resumable(42, "data.bin", &path)
+        .await?;
+
+    unsatisfiable.assert_hits(1);
+    assert_eq!(size, body.len() as u64);
+    assert_eq!(std::fs::read(&path)?, body);
+    std::fs::remove_file(path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_file_keeps_the_explanation() -> TestResult {
+    let env = TestingEnv::new();
+    env.mock(|when, then| {
+        when.method(GET).path("/downloads/42/bad.bin");
+        then.status(400)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "error": "Bad Request",
+                "message": "The file name is not valid",
+            }));
+    });
+    env.mock(|when, then| {
+        when.method(GET).path("/downloads/42/broken.bin");
+        then.status(500)
+            .header("content-type", "text/html")
+            .body("<html><body>Internal Server Error</body></html>");
+    });
+    env.mock(|when, then| {
+        when.method(GET).path("/downloads/42/missing.bin");
+        then.status(404);
+    });
+    let client = Client::from(env);
+
+    let explained = client
+        .get_file_by_task_id_and_name(42, "bad.bin")
+        .await
+        .unwrap_err();
+    let Error::Api {
+        status, message, ..
+    } = explained.inner()
+    else {
+        panic!("expected an API error, got {explained:?}");
+    };
+    assert_eq!(status.as_u16(), 400);
+    assert_eq!(message, "The file name is not valid");
+
+    let html = client
+        .get_file_by_task_id_and_name(42, "broken.bin")
+        .await
+        .unwrap_err();
+    let Error::Api {
+        status,
+        message,
+        raw_body,
+        ..
+    } = html.inner()
+    else {
+        panic!("expected an API error, got {html:?}");
+    };
+    assert_eq!(status.as_u16(), 500);
+    assert_eq!(message, "Internal Server Error");
+    assert_eq!(raw_body, "<html><body>Internal Server Error</body></html>");
+
+    let empty = client
+        .get_file_by_task_id_and_name(42, "missing.bin")
+        .await
+        .unwrap_err();
+    let Error::Api {
+        status,
+        message,
+        raw_body,
+        ..
+    } = empty.inner()
+    else {
+        panic!("expected an API error, got {empty:?}");
+    };
+    assert_eq!(status.as_u16(), 404);
+    assert_eq!(message, "Not Found");
+    assert!(raw_body.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use common::{get_account_content, TestResult, TestingEnv};
+use freedom_api::{error::Error, prelude::*};
+
+/// Serve satellite 710, and fetch it so that its links point at the mock server
+async fn satellite(env: &TestingEnv, client: &Client) -> Result<Satellite, Error> {
+    env.get_json_from_file(
+        "/satellites/710",
+        vec![],
+        "resources/satellite_find_one_710.json",
+    );
+
+    Ok(client.get_satellite_by_id(710).await?.into_inner())
+}
+
+#[tokio::test]
+async fn links_and_ids() -> TestResult {
+    let env = TestingEnv::new();
+    let client = Client::from(env.clone());
+    let sat = satellite(&env, &client).await?;
+
+    assert!(sat
+        .link("account")
+        .is_some_and(|url| url.path() == "/api/satellites/710/account"));
+    assert!(sat.link("unknown").is_none());
+    assert_eq!(sat.id_of("self")?, 710);
+    assert!(matches!(
+        sat.id_of("unknown"),
+        Err(Error::MissingUri("unknown"))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn follow_plain_body() -> TestResult {
+    let env = TestingEnv::new();
+    env.get_json_from_file(
+        "/api/satellites/710/orbitInfo",
+        vec![],
+        "resources/satellite_orbit_info_710.json",
+    );
+    let client = Client::from(env.clone());
+    let sat = satellite(&env, &client).await?;
+
+    let orbit: serde_json::Value = sat.follow("orbitInfo", &client).await?;
+    assert!(orbit["tle"]["line1"]
+        .as_str()
+        .is_some_and(|line| line.starts_with("1 25544U")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn follow_content_moves_outer_links() -> TestResult {
+    let env = TestingEnv::new();
+    get_account_content(&env, "/api/satellites/710/account");
+    let client = Client::from(env.clone());
+    let sat = satellite(&env, &client).await?;
+
+    let account: Account = sat.follow_content("account", &client).await?;
+    assert_eq!(account.name, "ABC Space");
+    assert_eq!(account.id_of("self")?, 34);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn follow_embedded_unwraps_collection() -> TestResult {
+    let env = TestingEnv::new();
+    get_account_content(&env, "/api/satellites/710/account");
+    env.get_json_from_file(
+        "/api/accounts/34/satellites",
+        vec![],
+        "resources/satellite_find_all.json",
+    );
+    let client = Client::from(env.clone());
+    let sat = satellite(&env, &client).await?;
+
+    let account: Account = sat.follow_content("account", &client).await?;
+    let satellites = account
+        .follow_embedded::<Vec<Satellite>, _>("satellites", &client)
+        .await?;
+    assert_eq!(satellites.len(), 14);
+    assert_eq!(satellites[0].id_of("self")?, 710);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn follow_missing_link() -> TestResult {
+    let env = TestingEnv::new();
+    let client = Client::from(env.clone());
+    let sat = satellite(&env, &client).await?;
+
+    let result = sat.follow::<serde_json::Value, _>("unknown", &client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("unknown"))),
+        "{result:?}"
+    );
+    let result = sat.follow_embedded::<Band, _>("unknown", &client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("unknown"))),
+        "{result:?}"
+    );
+    let result = sat.follow_content::<Account, _>("unknown", &client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("unknown"))),
+        "{result:?}"
+    );
+
+    Ok(())
+}
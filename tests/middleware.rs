@@ -0,0 +1,68 @@
+#![cfg(feature = "middleware")]
+
+mod common;
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use common::{TestResult, TestingEnv};
+use freedom_api::prelude::*;
+use futures::StreamExt;
+use reqwest_middleware::{ClientBuilder, Middleware, Next};
+
+/// Counts every request passing through the middleware stack
+#[derive(Debug, Clone, Default)]
+struct Counter(Arc<AtomicUsize>);
+
+#[async_trait::async_trait]
+impl Middleware for Counter {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut http::Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        next.run(req, extensions).await
+    }
+}
+
+#[tokio::test]
+async fn middleware_sees_every_page() -> TestResult {
+    let env = TestingEnv::new();
+
+    let pages = env.get_json_pages(
+        "/requests/search/findAll",
+        vec![],
+        vec![
+            serde_json::from_str(&env.json_from_file("resources/requests_find_all_page_1.json"))?,
+            serde_json::from_str(&env.json_from_file("resources/requests_find_all_page_2.json"))?,
+        ],
+    );
+
+    let counter = Counter::default();
+    let middleware = ClientBuilder::new(reqwest::Client::new())
+        .with(counter.clone())
+        .build();
+    let config = Client::from(env.clone()).config().clone();
+    let client = Client::from_config_with_middleware(config, middleware);
+
+    // Failing items are propagated, so that the error is reported rather than only its presence
+    let ids = client
+        .get_requests()
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .map(|request| request?.get_id())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(ids, [101, 102, 103]);
+    assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+    for page in pages {
+        page.assert_hits(1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::error::Error;
+use freedom_api::prelude::*;
+use httpmock::Method::POST;
+use serde_json::json;
+
+#[tokio::test]
+async fn create_override() -> TestResult {
+    let env = TestingEnv::new();
+
+    let create = env.mock(|when, then| {
+        when.method(POST)
+            .path("/overrides")
+            .json_body(serde_json::json!({
+                "name": "downconverter.gain override for sat 1 on config 2",
+                "satellite": env.url("/satellites/1"),
+                "configuration": env.url("/satellite_configurations/2"),
+                "properties": {
+                    "site.hardware.modem.ttc.rx.demodulator.bitrate": "8096"
+                }
+            }));
+        then.status(201);
+    });
+    let client = Client::from(env.clone());
+
+    client
+        .new_override()
+        .name("downconverter.gain override for sat 1 on config 2")
+        .satellite_id(1)
+        .satellite_configuration_id(2)
+        .add_property("site.hardware.modem.ttc.rx.demodulator.bitrate", 8096_u32)
+        .send()
+        .await?;
+    create.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn clone_override_with_modified_properties() -> TestResult {
+    let env = TestingEnv::new();
+
+    let existing = env.get_json(
+        "/overrides/5",
+        vec![],
+        json!({
+            "name": "downconverter.gain override",
+            "properties": {
+                "site.hardware.modem.ttc.rx.demodulator.bitrate": "8096",
+                "site.hardware.downconverter.gain": 12
+            },
+            "_links": {
+                "self": { "href": env.url("/api/overrides/5") },
+                "satellite": { "href": env.url("/api/overrides/5/satellite") },
+                "configuration": { "href": env.url("/api/overrides/5/configuration") }
+            }
+        }),
+    );
+    let satellite = env.get_json(
+        "/api/overrides/5/satellite",
+        vec![],
+        json!({ "_links": { "self": { "href": env.url("/api/satellites/1") } } }),
+    );
+    let configuration = env.get_json(
+        "/api/overrides/5/configuration",
+        vec![],
+        json!({ "_links": { "self": { "href": env.url("/api/satellite_configurations/2") } } }),
+    );
+    let create = env.mock(|when, then| {
+        when.method(POST).path("/overrides").json_body(json!({
+            "name": "downconverter.gain override, attempt 2",
+            "satellite": env.url("/api/satellites/1"),
+            "configuration": env.url("/api/satellite_configurations/2"),
+            "properties": {
+                "site.hardware.modem.ttc.rx.demodulator.bitrate": "4096",
+                "site.hardware.modem.ttc.rx.demodulator.mode": "bpsk"
+            }
+        }));
+        then.status(201);
+    });
+    let client = Client::from(env.clone());
+
+    OverrideBuilder::from_existing(&client, 5)
+        .await?
+        .name("downconverter.gain override, attempt 2")
+        .set_property("site.hardware.modem.ttc.rx.demodulator.bitrate", 4096)
+        .set_property("site.hardware.modem.ttc.rx.demodulator.mode", "bpsk")
+        .remove_property("site.hardware.downconverter.gain")
+        .remove_property("site.hardware.not.present")
+        .send()
+        .await?;
+
+    existing.assert_hits(1);
+    satellite.assert_hits(1);
+    configuration.assert_hits(1);
+    create.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn override_without_properties_is_rejected() -> TestResult {
+    let env = TestingEnv::new();
+    let create = env.mock(|when, then| {
+        when.method(POST).path("/overrides");
+        then.status(201);
+    });
+    let client = Client::from(env.clone());
+
+    let result = client
+        .new_override()
+        .name("empty")
+        .satellite_id(1)
+        .satellite_configuration_id(2)
+        .send()
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+    create.assert_hits(0);
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::{error::Error, prelude::*};
+use freedom_models::band::Band;
+use futures::TryStreamExt;
+use httpmock::Method::{GET, POST};
+use serde_json::json;
+use time::OffsetDateTime;
+
+fn bands(env: &TestingEnv) -> Vec<Band> {
+    let all: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/satellite_bands_find_all.json"))
+            .unwrap();
+
+    serde_json::from_value(all["_embedded"]["satellite_bands"].clone()).unwrap()
+}
+
+#[tokio::test]
+async fn reads_go_to_replica_and_writes_to_primary() -> TestResult {
+    let primary = TestingEnv::new();
+    let replica = TestingEnv::new();
+    let bands = bands(&replica);
+    let pages: Vec<_> = bands.chunks(4).collect();
+    let replica_pages = replica.get_pages("/satellite_bands", "satellite_bands", &pages);
+    let primary_reads = primary.mock(|when, then| {
+        when.method(GET);
+        then.status(500);
+    });
+    let create = primary.mock(|when, then| {
+        when.method(POST).path("/requests");
+        then.status(201)
+            .header("content-type", "application/json")
+            .body(primary.json_from_file("resources/requests_created_104.json"));
+    });
+    let replica_writes = replica.mock(|when, then| {
+        when.method(POST);
+        then.status(500);
+    });
+    let client = Client::from(primary.clone());
+    let view = client.with_base_url(replica.url("/").parse()?)?;
+
+    let fetched: Vec<_> = view
+        .get_satellite_bands()
+        .map_ok(|band| band.into_inner())
+        .try_collect()
+        .await?;
+    let created = client
+        .new_task_request()
+        .test_task("test.bin")
+        .target_time_utc(OffsetDateTime::now_utc() + Duration::from_secs(15 * 60))
+        .task_duration(120)
+        .satellite_id(1)
+        .site_id(2)
+        .site_configuration_id(3)
+        .band_ids([4, 5])
+        .send()
+        .await?;
+
+    assert_eq!(fetched, bands);
+    assert_eq!(created.id, 104);
+    for page in replica_pages {
+        page.assert_hits(1);
+    }
+    create.assert_hits(1);
+    primary_reads.assert_hits(0);
+    replica_writes.assert_hits(0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn relative_links_resolve_against_replica() -> TestResult {
+    let primary = TestingEnv::new();
+    let replica = TestingEnv::new();
+    let all: serde_json::Value =
+        serde_json::from_str(&replica.json_from_file("resources/satellite_bands_find_all.json"))?;
+    let band = |index: usize| all["_embedded"]["satellite_bands"][index].clone();
+    replica.get_json_pages(
+        "/satellite_configurations/812/bandDetails",
+        vec![],
+        vec![
+            json!({
+                "_embedded": { "satellite_bands": [band(0)] },
+                "_links": { "next": { "href": "satellite_configurations/812/bandDetails?page=1" } }
+            }),
+            json!({ "_embedded": { "satellite_bands": [band(5)] } }),
+        ],
+    );
+    let client = Client::from(primary.clone());
+    let view = client.with_base_url(replica.url("/").parse()?)?;
+
+    let pair = view.suggest_band_pair(812).await?;
+
+    assert_eq!(pair.rx.get_id()?, 1573);
+    assert_eq!(
+        pair.tx.as_ref().map(|tx| tx.get_id()).transpose()?,
+        Some(1581.into())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn entrypoint_without_trailing_slash_is_rejected() -> TestResult {
+    let env = TestingEnv::new();
+    let client = Client::from(env);
+
+    let result = client.with_base_url("http://localhost:8080/api".parse()?);
+
+    assert!(
+        matches!(result, Err(Error::UrlConstruction(_))),
+        "{result:?}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,921 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::{error::Error, prelude::*};
+use futures::StreamExt;
+use httpmock::Method::{DELETE, GET, PATCH, POST};
+use time::{format_description::well_known::Iso8601, macros::datetime, OffsetDateTime, UtcOffset};
+
+#[tokio::test]
+async fn bulk_delete_refuses_prod() -> TestResult {
+    let config = Config::builder()
+        .environment(Prod)
+        .key("foo")
+        .secret("bar")
+        .build()?;
+    let client = Client::from_config(config);
+
+    let result = client
+        .delete_requests_matching(RequestFilter::new(|_| true), DeleteSafeguards::new(10))
+        .await;
+    assert!(
+        matches!(result, Err(Error::BulkDeleteInProduction)),
+        "{result:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn bulk_delete_refuses_prod_through_entrypoint() -> TestResult {
+    let env = TestingEnv::new();
+    let config = Config::builder()
+        .environment(Prod)
+        .key("foo")
+        .secret("bar")
+        .build()?;
+    let client = Client::builder()
+        .config(config)
+        .entrypoint(env.url("/"))
+        .build()?;
+
+    let result = client
+        .delete_requests_matching(RequestFilter::new(|_| true), DeleteSafeguards::new(10))
+        .await;
+    assert!(
+        matches!(result, Err(Error::BulkDeleteInProduction)),
+        "{result:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn bulk_delete_refuses_exceeding_max_count() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.get_json_from_file(
+        "/requests/search/findAll",
+        Vec::new(),
+        "resources/requests_find_all.json",
+    );
+    let delete = env.mock(|when, then| {
+        when.method(DELETE).path_contains("/requests/");
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    let result = client
+        .delete_requests_matching(RequestFilter::new(|_| true), DeleteSafeguards::new(2))
+        .await;
+    assert!(
+        matches!(result, Err(Error::BulkDeleteRefused { matched: 3, max: 2 })),
+        "{result:?}"
+    );
+    delete.assert_hits(0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn bulk_delete_matching() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.get_json_from_file(
+        "/requests/search/findAll",
+        Vec::new(),
+        "resources/requests_find_all.json",
+    );
+    let deletes = [102, 103].map(|id| {
+        env.mock(|when, then| {
+            when.method(DELETE).path(format!("/requests/{id}"));
+            then.status(204);
+        })
+    });
+    let kept = env.mock(|when, then| {
+        when.method(DELETE).path("/requests/101");
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    let filter = RequestFilter::new(|request| request.get_id().is_ok_and(|id| id > 101));
+    let safeguards = DeleteSafeguards::new(2).concurrency(2);
+    let report = client.delete_requests_matching(filter, safeguards).await?;
+
+    assert_eq!(report.matched, vec![102, 103]);
+    assert_eq!(report.deleted().collect::<Vec<_>>(), vec![102, 103]);
+    assert_eq!(report.failed().count(), 0);
+    for delete in deletes {
+        delete.assert_hits(1);
+    }
+    kept.assert_hits(0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn bulk_delete_dry_run_only_reports_matches() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.get_json_from_file(
+        "/requests/search/findAll",
+        Vec::new(),
+        "resources/requests_find_all.json",
+    );
+    let delete = env.mock(|when, then| {
+        when.method(DELETE).path_contains("/requests/");
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    let filter = RequestFilter::new(|request| request.get_id().is_ok_and(|id| id > 101));
+    let safeguards = DeleteSafeguards::new(2).dry_run(true);
+    let report = client.delete_requests_matching(filter, safeguards).await?;
+
+    assert_eq!(report.matched, vec![102, 103]);
+    assert!(report.outcomes.is_empty());
+    delete.assert_hits(0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_all_requests_with_page_options() -> TestResult {
+    let env = TestingEnv::new();
+
+    let pages = env.get_json_pages(
+        "/requests/search/findAll",
+        vec![("size", "500"), ("sort", "created,desc")],
+        vec![
+            serde_json::from_str(&env.json_from_file("resources/requests_find_all_page_1.json"))?,
+            serde_json::from_str(&env.json_from_file("resources/requests_find_all_page_2.json"))?,
+        ],
+    );
+    let client = Client::from(env.clone());
+
+    let options = PageOptions::new()
+        .size(500)
+        .sort("created", SortOrder::Descending);
+    let ids = client
+        .get_requests_with_options(options)
+        .map(|result| result.unwrap().get_id().unwrap())
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(ids, vec![101, 102, 103]);
+    for page in pages {
+        page.assert_hits(1);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_all_requests_following_relative_next_link() -> TestResult {
+    let env = TestingEnv::new();
+
+    let mut page: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/requests_find_all_page_1.json"))?;
+    page["_links"]["next"]["href"] = "/requests/search/findAll?page=1".into();
+    let pages = env.get_json_pages(
+        "/requests/search/findAll",
+        vec![],
+        vec![
+            page,
+            serde_json::from_str(&env.json_from_file("resources/requests_find_all_page_2.json"))?,
+        ],
+    );
+    let client = Client::from(env.clone());
+
+    let ids = client
+        .get_requests()
+        .map(|result| result.unwrap().get_id().unwrap())
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(ids, vec![101, 102, 103]);
+    for page in pages {
+        page.assert_hits(1);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn count_requests_fetches_single_item() -> TestResult {
+    let env = TestingEnv::new();
+    let count = env.get_json_from_file(
+        "/requests/search/findAll",
+        vec![("page", "0"), ("size", "1")],
+        "resources/requests_find_all.json",
+    );
+    let client = Client::from(env.clone());
+
+    assert_eq!(client.count_requests().await?, 3);
+    count.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn page_meta_of_paginated_collection() -> TestResult {
+    let env = TestingEnv::new();
+    env.get_json_from_file(
+        "/requests/search/findAll",
+        vec![("size", "500")],
+        "resources/requests_find_all_page_1.json",
+    );
+    let client = Client::from(env);
+
+    let mut url = client.path_to_url("requests/search/findAll")?;
+    url.query_pairs_mut().append_pair("size", "500");
+    let meta = client.get_page_meta(url).await?;
+
+    assert_eq!(meta.size, 500);
+    assert_eq!(meta.total_elements, 3);
+    assert_eq!(meta.total_pages, 2);
+    assert_eq!(meta.number, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn watch_requests_yields_only_new_requests() -> TestResult {
+    let env = TestingEnv::new();
+
+    let mut first = env.get_json_from_file(
+        "/requests/search/findAll",
+        vec![("sort", "created,desc")],
+        "resources/requests_watch_1.json",
+    );
+    let client = Client::from(env.clone());
+
+    let mut requests = client.watch_requests(Duration::from_millis(100));
+    let add_request = async {
+        while first.hits() == 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        first.delete();
+        env.get_json_from_file(
+            "/requests/search/findAll",
+            vec![("sort", "created,desc")],
+            "resources/requests_watch_2.json",
+        )
+    };
+
+    let (next, second) = tokio::time::timeout(Duration::from_secs(5), async {
+        tokio::join!(requests.next(), add_request)
+    })
+    .await?;
+
+    assert_eq!(next.unwrap()?.get_id()?, 103);
+    assert!(second.hits() >= 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_request_rejection_is_parsed() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.mock(|when, then| {
+        when.method(DELETE).path("/requests/101");
+        then.status(409)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "status": 409,
+                "error": "Conflict",
+                "message": "Task request is already scheduled",
+                "path": "/api/requests/101"
+            }));
+    });
+    let client = Client::from(env.clone());
+
+    let Err(Error::Api {
+        status,
+        message,
+        path,
+        ..
+    }) = client
+        .delete_task_request(101)
+        .await
+        .map_err(Error::into_inner)
+    else {
+        panic!("Expected an API error");
+    };
+    assert_eq!(status.as_u16(), 409);
+    assert_eq!(message, "Task request is already scheduled");
+    assert_eq!(path.as_deref(), Some("/api/requests/101"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cancel_request_patches_status() -> TestResult {
+    let env = TestingEnv::new();
+
+    let cancel = env.mock(|when, then| {
+        when.method(PATCH)
+            .path("/requests/101")
+            .json_body(serde_json::json!({ "status": "CANCELLED" }));
+        then.status(200);
+    });
+    let client = Client::from(env.clone());
+
+    client.cancel_task_request(101).await?;
+    cancel.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn refused_status_transition_is_parsed() -> TestResult {
+    let env = TestingEnv::new();
+
+    let transition = env.mock(|when, then| {
+        when.method(PATCH)
+            .path("/requests/101")
+            .json_body(serde_json::json!({ "status": "QUEUED_PASS" }));
+        then.status(400)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "status": 400,
+                "error": "Bad Request",
+                "message": "Cannot transition from COMPLETED to QUEUED_PASS",
+                "path": "/api/requests/101"
+            }));
+    });
+    let client = Client::from(env.clone());
+
+    let status = TaskStatusType::QueuedPass;
+    let Err(Error::Api {
+        status, message, ..
+    }) = client
+        .set_task_request_status(101, status)
+        .await
+        .map_err(Error::into_inner)
+    else {
+        panic!("Expected an API error");
+    };
+    assert_eq!(status.as_u16(), 400);
+    assert_eq!(message, "Cannot transition from COMPLETED to QUEUED_PASS");
+    transition.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn long_id_lists_are_chunked() -> TestResult {
+    let env = TestingEnv::new();
+
+    let page: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/requests_find_all.json"))?;
+    let requests = page["_embedded"]["requests"]
+        .as_array()
+        .ok_or("the page has no requests")?;
+
+    // Each chunk of IDs is answered with a single request, so the merged order is observable
+    let ids: Vec<_> = (1..=250).map(|id| id.to_string()).collect();
+    let chunks: Vec<_> = ids
+        .chunks(100)
+        .zip(requests)
+        .map(|(chunk, request)| {
+            env.mock(|when, then| {
+                when.method(GET)
+                    .path("/requests/search/findAllByIds")
+                    .query_param("ids", chunk.join(","));
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .json_body(serde_json::json!({
+                        "_embedded": { "requests": [request] },
+                        "_links": {},
+                    }));
+            })
+        })
+        .collect();
+    let client = Client::from(env.clone());
+
+    // Duplicated IDs are only queried once
+    let found = client
+        .get_requests_by_ids(ids.iter().chain(&ids[..10]))
+        .await?;
+
+    let found: Vec<_> = found.iter().map(|request| request.get_id()).collect();
+    assert_eq!(found, [Ok(101.into()), Ok(102.into()), Ok(103.into())]);
+    for chunk in chunks {
+        chunk.assert_hits(1);
+    }
+
+    Ok(())
+}
+
+async fn create_test_request(client: &Client) -> Result<CreatedTaskRequest, Error> {
+    client
+        .new_task_request()
+        .test_task("test.bin")
+        .target_time_utc(OffsetDateTime::now_utc() + Duration::from_secs(15 * 60))
+        .task_duration(120)
+        .satellite_id(1)
+        .site_id(2)
+        .site_configuration_id(3)
+        .band_ids([4, 5])
+        .send()
+        .await
+}
+
+#[tokio::test]
+async fn create_request_from_location_header() -> TestResult {
+    let env = TestingEnv::new();
+
+    let location = format!("http://localhost:{}/api/requests/104", env.port());
+    let create = env.mock(|when, then| {
+        when.method(POST).path("/requests");
+        then.status(201).header("location", location.as_str());
+    });
+    let client = Client::from(env.clone());
+
+    let created = create_test_request(&client).await?;
+
+    assert_eq!(created.id, 104);
+    assert_eq!(created.location.as_str(), location);
+    assert!(created.body.is_none());
+    create.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_request_from_typed_ids() -> TestResult {
+    let env = TestingEnv::new();
+    let client = Client::from(env.clone());
+    let url = |path: &str| client.path_to_url(path).map(String::from);
+    let body = serde_json::json!({
+        "satellite": url("satellites/1")?,
+        "site": url("sites/2")?,
+        "configuration": url("configurations/3")?,
+        "targetBands": [url("satellite_bands/4")?, url("satellite_bands/5")?],
+    });
+
+    let location = format!("http://localhost:{}/api/requests/104", env.port());
+    let create = env.mock(|when, then| {
+        when.method(POST)
+            .path("/requests")
+            .json_body_partial(body.to_string());
+        then.status(201).header("location", location.as_str());
+    });
+
+    let created = client
+        .new_task_request()
+        .test_task("test.bin")
+        .target_time_utc(OffsetDateTime::now_utc() + Duration::from_secs(15 * 60))
+        .task_duration(120)
+        .satellite_id(SatelliteId::from(1))
+        .site_id(SiteId::from(2))
+        .site_configuration_id(SiteConfigurationId::from(3))
+        .band_ids([BandId::from(4), BandId::from(5)])
+        .send()
+        .await?;
+
+    assert_eq!(created.id, TaskRequestId::from(104));
+    create.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_request_from_body() -> TestResult {
+    let env = TestingEnv::new();
+
+    let create = env.mock(|when, then| {
+        when.method(POST).path("/requests");
+        then.status(201)
+            .header("content-type", "application/json")
+            .body(env.json_from_file("resources/requests_created_104.json"));
+    });
+    let client = Client::from(env.clone());
+
+    let created = create_test_request(&client).await?;
+
+    assert_eq!(created.id, 104);
+    assert_eq!(created.location.path(), "/api/requests/104");
+    assert_eq!(
+        created.body.map(|request| request.get_id()),
+        Some(Ok(104.into()))
+    );
+    create.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_requests_by_status_and_account_and_target_date_between() -> TestResult {
+    let env = TestingEnv::new();
+    let start = datetime!(2024-10-01 00:00 UTC);
+    let end = datetime!(2024-10-02 00:00 UTC);
+    let account = env.url("/accounts/34");
+
+    let search = env.mock(|when, then| {
+        when.method(GET)
+            .path("/requests/search/findAllByStatusAndAccountAndTargetDateBetween")
+            .query_param("status", "QUEUED")
+            .query_param("account", &account)
+            .query_param("start", start.format(&Iso8601::DEFAULT).unwrap())
+            .query_param("end", end.format(&Iso8601::DEFAULT).unwrap())
+            .matches(|req| req.query_params.as_ref().map_or(0, Vec::len) == 4);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "_embedded": { "requests": [] },
+                "_links": {},
+                "page": { "size": 20, "totalElements": 0, "totalPages": 0, "number": 0 }
+            }));
+    });
+    let client = Client::from(env.clone());
+
+    let requests = client
+        .get_requests_by_status_and_account_and_target_date_between("QUEUED", &account, start, end)
+        .collect::<Vec<_>>()
+        .await;
+    assert!(requests.is_empty());
+    search.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_requests_by_time_range() -> TestResult {
+    let env = TestingEnv::new();
+    let start = datetime!(2024-10-01 00:00 UTC);
+    let end = datetime!(2024-10-02 00:00 UTC);
+
+    let search = env.mock(|when, then| {
+        when.method(GET)
+            .path("/requests/search/findAllByOverlappingPublic")
+            .query_param("start", "2024-10-01T00:00:00.000000000Z")
+            .query_param("end", "2024-10-02T00:00:00.000000000Z");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "_embedded": { "requests": [] },
+                "_links": {},
+                "page": { "size": 20, "totalElements": 0, "totalPages": 0, "number": 0 }
+            }));
+    });
+    let client = Client::from(env.clone());
+
+    let range = TimeRange::new(start, end)?;
+    for items in [
+        client
+            .get_requests_by_overlapping_public_in(range)
+            .collect::<Vec<_>>()
+            .await,
+        client
+            .get_requests_by_overlapping_public(start, end)
+            .collect::<Vec<_>>()
+            .await,
+    ] {
+        assert!(items.is_empty());
+    }
+    search.assert_hits(2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reversed_ranges_are_not_sent() -> TestResult {
+    let env = TestingEnv::new();
+    let start = datetime!(2024-10-01 00:00 UTC);
+    let end = datetime!(2024-10-02 00:00 UTC);
+
+    let search = env.mock(|when, then| {
+        when.method(GET)
+            .path("/requests/search/findAllByTargetDateBetween");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "_embedded": { "requests": [] } }));
+    });
+    let client = Client::from(env.clone());
+
+    let result = client.get_requests_by_target_date_between(end, start).await;
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+    search.assert_hits(0);
+
+    Ok(())
+}
+
+/// ISO 8601 cannot represent offsets with a seconds component, so formatting this time fails
+fn unformattable_time() -> OffsetDateTime {
+    datetime!(2024-10-01 00:00 UTC).to_offset(UtcOffset::from_hms(0, 0, 30).unwrap())
+}
+
+#[tokio::test]
+async fn unformattable_times_are_errors_rather_than_panics() -> TestResult {
+    let env = TestingEnv::new();
+    let client = Client::from(env);
+    let time = unformattable_time();
+    let valid = datetime!(2024-10-02 00:00 UTC);
+
+    let results = [
+        client
+            .get_requests_by_account_and_target_date_between("account", time, valid)
+            .collect::<Vec<_>>()
+            .await,
+        client
+            .get_requests_by_overlapping_public(valid, time)
+            .collect::<Vec<_>>()
+            .await,
+        client
+            .get_requests_by_status_and_account_and_target_date_between(
+                "QUEUED", "account", time, valid,
+            )
+            .collect::<Vec<_>>()
+            .await,
+    ];
+    for items in results {
+        assert_eq!(items.len(), 1);
+        assert!(
+            matches!(items[0], Err(Error::TimeFormatError(_))),
+            "{:?}",
+            items[0]
+        );
+    }
+
+    let result = client
+        .get_requests_by_target_date_between(time, valid)
+        .await;
+    assert!(
+        matches!(result, Err(Error::TimeFormatError(_))),
+        "{result:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn embedded_lists_follow_next_links() -> TestResult {
+    let env = TestingEnv::new();
+    let path = "/requests/search/findAllPassedToday";
+
+    let mut first: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/requests_find_all.json"))?;
+    first["_links"]["next"] = serde_json::json!({ "href": env.url(format!("{path}?page=1")) });
+    let mut second: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/requests_find_all.json"))?;
+    second["_embedded"]["requests"]
+        .as_array_mut()
+        .unwrap()
+        .truncate(1);
+
+    let pages = env.get_json_pages(path, vec![], vec![first, second]);
+    let client = Client::from(env.clone());
+
+    let requests = client.get_requests_passed_today().await?;
+    for page in pages {
+        page.assert_hits(1);
+    }
+    assert_eq!(requests.len(), 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn embedded_lists_are_not_truncated() -> TestResult {
+    let env = TestingEnv::new();
+    let path = "/requests/search/findAllPassedToday";
+
+    // Every page links to itself, so the list never ends
+    let mut page: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/requests_find_all.json"))?;
+    page["_links"]["next"] = serde_json::json!({ "href": env.url(path) });
+    let pages = env.get_json(path, Vec::new(), page);
+    let client = Client::from(env.clone());
+
+    let result = client.get_requests_passed_today().await;
+    assert!(
+        matches!(result, Err(Error::TooManyPages { pages: 100, .. })),
+        "{result:?}"
+    );
+    pages.assert_hits(100);
+
+    Ok(())
+}
+
+/// The first task request of the fixture, task request 101, as served by the mock server
+fn request_fixture(env: &TestingEnv) -> serde_json::Value {
+    let requests: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/requests_find_all.json")).unwrap();
+
+    requests["_embedded"]["requests"][0].clone()
+}
+
+#[tokio::test]
+async fn refresh_observes_changes() -> TestResult {
+    let env = TestingEnv::new();
+    let mut request = request_fixture(&env);
+    env.get_json("/requests/101", vec![], request.clone());
+    request["modified"] = "2024-10-01T13:00:00Z".into();
+    env.get_json("/api/requests/101", vec![], request);
+    let client = Client::from(env.clone());
+
+    let fetched = client.get_request_by_id(101).await?;
+    let refreshed = fetched.refresh(&client).await?;
+    assert_eq!(fetched.modified, Some(datetime!(2024-10-01 12:00 UTC)));
+    assert_eq!(refreshed.modified, Some(datetime!(2024-10-01 13:00 UTC)));
+
+    Ok(())
+}
+
+/// Serve the first task of the fixture as the task of request 101
+fn task_of_request(env: &TestingEnv) -> Result<(), Error> {
+    let page: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/tasks_1/page_1.json"))?;
+    env.get_json(
+        "/api/requests/101/task",
+        Vec::new(),
+        page["_embedded"]["tasks"][0].clone(),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pass_profile_follows_task_to_azel() -> TestResult {
+    let env = TestingEnv::new();
+    let request: TaskRequest = serde_json::from_value(request_fixture(&env))?;
+    task_of_request(&env)?;
+    env.get_json_from_file(
+        "/api/tasks/74344/azel",
+        Vec::new(),
+        "resources/tasks_74344_azel.json",
+    );
+    let client = Client::from(env.clone());
+
+    let profile = request.get_pass_profile(&client).await?;
+    assert_eq!(profile.task.get_id()?, 74344);
+    assert_eq!(profile.azel.directions.len(), 26);
+    assert_eq!(profile.aos, Some(datetime!(2022-05-26 04:57:26 UTC)));
+    assert_eq!(profile.los, Some(datetime!(2022-05-26 05:09:56 UTC)));
+    assert_eq!(profile.max_elevation, Some(47.21));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pass_profile_reports_the_failed_hop() -> TestResult {
+    let env = TestingEnv::new();
+    let request: TaskRequest = serde_json::from_value(request_fixture(&env))?;
+    let client = Client::from(env.clone());
+
+    let mut missing_task = env.mock(|when, then| {
+        when.method(GET).path("/api/requests/101/task");
+        then.status(404);
+    });
+    let result = request.get_pass_profile(&client).await;
+    let Err(Error::PassProfile { hop, error }) = result else {
+        panic!("Expected the task to fail, got {result:?}");
+    };
+    assert_eq!(hop, "task");
+    assert!(error.is_not_found(), "{error:?}");
+
+    missing_task.delete();
+    task_of_request(&env)?;
+    env.mock(|when, then| {
+        when.method(GET).path("/api/tasks/74344/azel");
+        then.status(500);
+    });
+    let result = request.get_pass_profile(&client).await;
+    let Err(Error::PassProfile { hop, error }) = result else {
+        panic!("Expected the AzEl to fail, got {result:?}");
+    };
+    assert_eq!(hop, "azel");
+    assert_eq!(
+        error.status(),
+        Some(reqwest::StatusCode::INTERNAL_SERVER_ERROR)
+    );
+
+    Ok(())
+}
+
+/// Task request 101, whose `self` link points at another server than the entrypoint of the client
+fn request_elsewhere(env: &TestingEnv, elsewhere: &TestingEnv) -> Result<TaskRequest, Error> {
+    let mut request = request_fixture(env);
+    request["_links"]["self"]["href"] = elsewhere.url("/api/requests/101").into();
+
+    Ok(serde_json::from_value(request)?)
+}
+
+#[tokio::test]
+async fn delete_follows_self_link() -> TestResult {
+    let env = TestingEnv::new();
+    let elsewhere = TestingEnv::new();
+    let request = request_elsewhere(&env, &elsewhere)?;
+
+    let by_id = env.mock(|when, then| {
+        when.method(DELETE);
+        then.status(204);
+    });
+    let linked = elsewhere.mock(|when, then| {
+        when.method(DELETE).path("/api/requests/101");
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    request.delete(&client).await?;
+    linked.assert_hits(1);
+    by_id.assert_hits(0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cancel_follows_self_link() -> TestResult {
+    let env = TestingEnv::new();
+    let elsewhere = TestingEnv::new();
+    let request = request_elsewhere(&env, &elsewhere)?;
+
+    let cancel = elsewhere.mock(|when, then| {
+        when.method(PATCH)
+            .path("/api/requests/101")
+            .json_body(serde_json::json!({ "status": "CANCELLED" }));
+        then.status(200);
+    });
+    let client = Client::from(env.clone());
+
+    request.cancel(&client).await?;
+    cancel.assert_hits(1);
+
+    let mut request = request;
+    request.links.clear();
+    let result = request.delete(&client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("self"))),
+        "{result:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_requests_by_site_id() -> TestResult {
+    let env = TestingEnv::new();
+    let site = env.url("/sites/14");
+    let search = env.get_json(
+        "/requests/search/findAllBySiteAndTargetDateBetween",
+        vec![
+            ("site", &site),
+            ("start", "2024-10-01T00:00:00.000000000Z"),
+            ("end", "2024-10-02T00:00:00.000000000Z"),
+        ],
+        serde_json::json!({ "_embedded": { "requests": [request_fixture(&env)] } }),
+    );
+    let client = Client::from(env.clone());
+
+    let requests = client
+        .get_requests_by_site_id(
+            14,
+            datetime!(2024-10-01 00:00 UTC),
+            datetime!(2024-10-02 00:00 UTC),
+        )
+        .try_collect_inner()
+        .await?;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].get_id()?, 101);
+    search.assert_hits(1);
+
+    let result = client
+        .get_requests_by_site_id(
+            14,
+            datetime!(2024-10-02 00:00 UTC),
+            datetime!(2024-10-01 00:00 UTC),
+        )
+        .try_collect_inner()
+        .await;
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+    search.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_requests_by_band_id() -> TestResult {
+    let env = TestingEnv::new();
+    let band = env.url("/satellite_bands/1573");
+    let search = env.get_json(
+        "/requests/search/findAllByTargetBand",
+        vec![("band", &band)],
+        serde_json::json!({ "_embedded": { "requests": [request_fixture(&env)] } }),
+    );
+    let client = Client::from(env.clone());
+
+    let requests = client
+        .get_requests_by_band_id(1573)
+        .try_collect_inner()
+        .await?;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].get_id()?, 101);
+    search.assert_hits(1);
+
+    Ok(())
+}
@@ -1,11 +1,12 @@
 mod common;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
-use common::{TestResult, TestingEnv};
-use freedom_api::prelude::*;
+use common::{get_account_content, TestResult, TestingEnv};
+use freedom_api::{error::Error, prelude::*};
 use freedom_models::band::{Band, BandType, IoConfiguration, IoHardware};
 use futures::StreamExt;
+use httpmock::Method::{DELETE, GET, PATCH, POST};
 use time::macros::datetime;
 
 fn band(env: &TestingEnv) -> Band {
@@ -61,6 +62,30 @@ async fn find_all_bands() -> TestResult {
     Ok(())
 }
 
+#[tokio::test]
+async fn find_all_bands_across_pages() -> TestResult {
+    let env = TestingEnv::new();
+    let band = band(&env);
+    let mut second = band.clone();
+    second.name = String::from("FooBarBand2");
+
+    env.get_pages(
+        "/satellite_bands",
+        "satellite_bands",
+        &[std::slice::from_ref(&band), std::slice::from_ref(&second)],
+    );
+    let client = Client::from(env);
+
+    let bands = client
+        .get_satellite_bands()
+        .map(|result| result.unwrap().into_inner())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(bands, vec![band, second]);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn find_one_band_by_id() -> TestResult {
     let env = TestingEnv::new();
@@ -79,6 +104,20 @@ async fn find_one_band_by_id() -> TestResult {
     Ok(())
 }
 
+#[tokio::test]
+async fn find_one_band_by_id_from_fixture() -> TestResult {
+    let env = TestingEnv::new();
+    let band = band(&env);
+
+    env.get_json("/satellite_bands/1573", Vec::new(), env.fixture_for(&band));
+    let client = Client::from(env);
+
+    let band_recv = client.get_satellite_band_by_id(1573).await?.into_inner();
+    assert_eq!(band_recv, band);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn find_one_band_by_name() -> TestResult {
     let env = TestingEnv::new();
@@ -99,3 +138,451 @@ async fn find_one_band_by_name() -> TestResult {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn find_one_band_by_name_with_reserved_characters() -> TestResult {
+    let env = TestingEnv::new();
+    let band = band(&env);
+
+    env.get_json_from_file(
+        "/satellite_bands/search/findOneByName",
+        vec![("name", "S+X Band = Downlink & Uplink")],
+        "resources/satellite_bands_find_one_1573.json",
+    );
+    let client = Client::from(env);
+
+    let band_recv = client
+        .get_satellite_band_by_name("S+X Band = Downlink & Uplink")
+        .await?
+        .into_inner();
+    assert_eq!(band_recv, band);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn update_existing_band_only_changes_set_fields() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.get_json_from_file(
+        "/satellite_bands/1573",
+        Vec::new(),
+        "resources/satellite_bands_find_one_1573.json",
+    );
+    let update = env.mock(|when, then| {
+        when.method(PATCH)
+            .path("/satellite_bands/1573")
+            .json_body(serde_json::json!({
+                "name": "FooBarBand1",
+                "type": "RECEIVE",
+                "frequencyMghz": 2000.0,
+                "defaultBandWidthMghz": 1000.0,
+                "ioConfiguration": {
+                    "startHexPattern": "",
+                    "endHexPattern": "",
+                    "stripPattern": false,
+                    "ioHardware": "MODEM"
+                },
+                "polarization": "RIGHT",
+                "manualTransmitControl": false
+            }));
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    let band = client.get_satellite_band_by_id(1573).await?;
+    BandDetailsUpdateBuilder::from_existing(&client, &band)?
+        .frequency(2000.0)
+        .send()
+        .await?;
+    update.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn update_band_by_id_only_sends_set_fields() -> TestResult {
+    let env = TestingEnv::new();
+
+    let update = env.mock(|when, then| {
+        when.method(PATCH)
+            .path("/satellite_bands/1573")
+            .json_body(serde_json::json!({ "gain": 4.5 }));
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    client.update_band_details(1573).gain(4.5).send().await?;
+    update.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_band_validation_and_bypass() -> TestResult {
+    let env = TestingEnv::new();
+
+    let create = env.mock(|when, then| {
+        when.method(POST).path("/satellite_bands");
+        then.status(201);
+    });
+    let client = Client::from(env.clone());
+    let new_band = || {
+        client
+            .new_band_details()
+            .name("FooBarBand2")
+            .band_type(BandType::Receive)
+            .frequency(60_000.0)
+            .default_band_width(1.45)
+            .io_hardware(IoHardware::Modem)
+            .validate(ValidationRules::default())
+    };
+
+    let result = new_band().send().await;
+    assert!(matches!(result, Err(Error::Validation(violations)) if violations.len() == 1));
+    create.assert_hits(0);
+
+    new_band().send_unchecked().await?;
+    create.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_finite_numbers_are_never_sent() -> TestResult {
+    let env = TestingEnv::new();
+
+    let create = env.mock(|when, then| {
+        when.method(POST).path("/satellite_bands");
+        then.status(201);
+    });
+    let update = env.mock(|when, then| {
+        when.method(PATCH).path("/satellite_bands/1573");
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+    let new_band = |frequency: f64| {
+        client
+            .new_band_details()
+            .name("FooBarBand2")
+            .band_type(BandType::Receive)
+            .frequency(frequency)
+            .default_band_width(1.45)
+            .io_hardware(IoHardware::Modem)
+    };
+
+    let result = new_band(f64::NAN).send_unchecked().await;
+    assert!(matches!(result, Err(Error::Validation(violations)) if violations.len() == 1));
+    let result = new_band(8096.0).gain(f64::INFINITY).send_raw().await;
+    assert!(matches!(result, Err(Error::Validation(violations)) if violations.len() == 1));
+    let result = client
+        .update_band_details(1573)
+        .effective_isotropic_radiative_power(f64::NEG_INFINITY)
+        .send()
+        .await;
+    assert!(matches!(result, Err(Error::Validation(violations)) if violations.len() == 1));
+    create.assert_hits(0);
+    update.assert_hits(0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn frequency_strings_parse_strictly() -> TestResult {
+    let env = TestingEnv::new();
+
+    let create = env.mock(|when, then| {
+        when.method(POST)
+            .path("/satellite_bands")
+            .json_body_partial(r#"{ "frequencyMghz": 8096.5 }"#);
+        then.status(201);
+    });
+    let client = Client::from(env.clone());
+    let named = || {
+        client
+            .new_band_details()
+            .name("FooBarBand2")
+            .band_type(BandType::Receive)
+    };
+
+    for frequency in ["8.096,5", "NaN", "inf", "8096.5 MHz"] {
+        let result = named().try_frequency_str(frequency);
+        assert!(matches!(result, Err(Error::Validation(_))), "{frequency:?}");
+        let result = client
+            .update_band_details(1573)
+            .try_frequency_str(frequency);
+        assert!(matches!(result, Err(Error::Validation(_))), "{frequency:?}");
+    }
+
+    named()
+        .try_frequency_str("8096.5")?
+        .default_band_width(1.45)
+        .io_hardware(IoHardware::Modem)
+        .send()
+        .await?;
+    create.assert_hits(1);
+
+    Ok(())
+}
+
+/// Serve the task requests targeting each band, where only request 102 targets band 1573, and is
+/// yet to occur when `upcoming` is set
+fn requests_targeting_band(env: &TestingEnv, upcoming: bool) {
+    let requests: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/requests_find_all.json")).unwrap();
+    let mut request = requests["_embedded"]["requests"][1].clone();
+    if upcoming {
+        request["targetDate"] = "2099-01-01T00:00:00Z".into();
+    }
+
+    for (band, requests) in [(1573, vec![request]), (1590, Vec::new())] {
+        let band = env.url(&format!("/satellite_bands/{band}"));
+        env.get_json(
+            "/requests/search/findAllByTargetBand",
+            vec![("band", &band)],
+            serde_json::json!({ "_embedded": { "requests": requests } }),
+        );
+    }
+}
+
+#[tokio::test]
+async fn band_usage_only_counts_targeting_requests() -> TestResult {
+    let env = TestingEnv::new();
+    requests_targeting_band(&env, false);
+    let client = Client::from(env);
+
+    let usage = client.get_band_usage(1573).await?;
+    assert_eq!(usage.past, 1);
+    assert!(!usage.has_upcoming());
+    assert!(usage.next_upcoming.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_used_band_is_refused() -> TestResult {
+    let env = TestingEnv::new();
+    requests_targeting_band(&env, true);
+    let delete = env.mock(|when, then| {
+        when.method(DELETE).path("/satellite_bands/1573");
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    let result = client.delete_band_details_if_unused(1573).await;
+    assert!(
+        matches!(result, Err(Error::InUse { upcoming: 1, .. })),
+        "{result:?}"
+    );
+    delete.assert_hits(0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_unused_band() -> TestResult {
+    let env = TestingEnv::new();
+    requests_targeting_band(&env, true);
+    let delete = env.mock(|when, then| {
+        when.method(DELETE).path("/satellite_bands/1590");
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    client.delete_band_details_if_unused(1590).await?;
+    delete.assert_hits(1);
+
+    Ok(())
+}
+
+fn fixture_bands(env: &TestingEnv) -> Vec<Band> {
+    let all: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/satellite_bands_find_all.json"))
+            .unwrap();
+
+    serde_json::from_value(all["_embedded"]["satellite_bands"].clone()).unwrap()
+}
+
+#[tokio::test]
+async fn retry_failed_page() -> TestResult {
+    let env = TestingEnv::new();
+    let bands = fixture_bands(&env);
+    // Defined before the pages, so that it takes precedence over the second page
+    let mut failure = env.mock(|when, then| {
+        when.method(GET)
+            .path("/satellite_bands")
+            .query_param("page", "1");
+        then.status(503);
+    });
+    let pages: Vec<_> = bands.chunks(2).collect();
+    let mocks = env.get_pages("/satellite_bands", "satellite_bands", &pages);
+    let client = Client::from(env.clone()).with_backoff(Constant::new(Duration::from_millis(500)));
+
+    let url = client.path_to_url("satellite_bands")?;
+    let scan = tokio::spawn(async move {
+        client
+            .get_paginated_with::<Band>(url, PaginationBehavior::RetryPage { attempts: 3 })
+            .map(|band| band.map(|band| band.into_inner()))
+            .collect::<Vec<_>>()
+            .await
+    });
+    while failure.hits_async().await == 0 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    failure.delete();
+
+    let fetched = scan.await?.into_iter().collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(fetched, bands);
+    for mock in mocks {
+        mock.assert_hits(1);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn page_fetch_error_after_exhausting_attempts() -> TestResult {
+    let env = TestingEnv::new();
+    let bands = fixture_bands(&env);
+    let failure = env.mock(|when, then| {
+        when.method(GET)
+            .path("/satellite_bands")
+            .query_param("page", "1");
+        then.status(503);
+    });
+    let pages: Vec<_> = bands.chunks(2).collect();
+    env.get_pages("/satellite_bands", "satellite_bands", &pages);
+    let client = Client::from(env.clone()).with_backoff(Constant::new(Duration::ZERO));
+
+    let url = client.path_to_url("satellite_bands")?;
+    let results = client
+        .get_paginated_with::<Band>(url, PaginationBehavior::RetryPage { attempts: 2 })
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(results.len(), 3);
+    assert!(results[..2].iter().all(Result::is_ok));
+    let Err(Error::PageFetch {
+        attempts, error, ..
+    }) = &results[2]
+    else {
+        panic!("Expected a page fetch error, got {:?}", results[2]);
+    };
+    assert_eq!(*attempts, 2);
+    assert!(matches!(error.inner(), Error::Api { .. }), "{error:?}");
+    failure.assert_hits(2);
+
+    Ok(())
+}
+
+/// Serve the bands in pages of two, where the second page fails with the status
+fn failing_second_page(env: &TestingEnv, status: u16) -> httpmock::Mock<'_> {
+    let failure = env.mock(|when, then| {
+        when.method(GET)
+            .path("/satellite_bands")
+            .query_param("page", "1");
+        then.status(status);
+    });
+    let bands = fixture_bands(env);
+    let pages: Vec<_> = bands.chunks(2).collect();
+    env.get_pages("/satellite_bands", "satellite_bands", &pages);
+
+    failure
+}
+
+#[tokio::test]
+async fn page_fetch_does_not_retry_permanent_failures() -> TestResult {
+    let env = TestingEnv::new();
+    let failure = failing_second_page(&env, 404);
+    let client = Client::from(env.clone()).with_backoff(Constant::new(Duration::ZERO));
+
+    let url = client.path_to_url("satellite_bands")?;
+    let results = client
+        .get_paginated_with::<Band>(url, PaginationBehavior::RetryPage { attempts: 3 })
+        .collect::<Vec<_>>()
+        .await;
+
+    assert!(
+        matches!(results[2], Err(Error::PageFetch { attempts: 1, .. })),
+        "{:?}",
+        results[2]
+    );
+    failure.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn page_fetch_leaves_retries_to_the_client() -> TestResult {
+    let env = TestingEnv::new();
+    let failure = failing_second_page(&env, 503);
+    let client = Client::from(env.clone())
+        .with_backoff(Constant::new(Duration::ZERO))
+        .with_retry(
+            RetryPolicy::new()
+                .max_retries(1)
+                .backoff(Constant::new(Duration::ZERO)),
+        );
+
+    let url = client.path_to_url("satellite_bands")?;
+    let results = client
+        .get_paginated_with::<Band>(url, PaginationBehavior::RetryPage { attempts: 3 })
+        .collect::<Vec<_>>()
+        .await;
+
+    assert!(
+        matches!(results[2], Err(Error::PageFetch { attempts: 1, .. })),
+        "{:?}",
+        results[2]
+    );
+    failure.assert_hits(2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn account_of_band() -> TestResult {
+    let env = TestingEnv::new();
+    let band = band(&env);
+    get_account_content(&env, "/api/satellite_bands/1573/account");
+    let client = Client::from(env);
+
+    let account = band.get_account(&client).await?;
+    assert_eq!(account.name, "ABC Space");
+    assert_eq!(account.get_id()?, 34);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn band_without_account_link_is_missing_uri() -> TestResult {
+    let env = TestingEnv::new();
+    let mut band = band(&env);
+    band.links.remove("account");
+    let client = Client::from(env);
+
+    let result = band.get_account(&client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("account"))),
+        "{result:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_band_at_self_link() -> TestResult {
+    let env = TestingEnv::new();
+    let band = band(&env);
+
+    let delete = env.mock(|when, then| {
+        when.method(DELETE).path("/api/satellite_bands/1573");
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    band.delete(&client).await?;
+    delete.assert_hits(1);
+
+    Ok(())
+}
@@ -2,9 +2,10 @@ mod common;
 
 use std::collections::HashMap;
 
-use common::{TestResult, TestingEnv};
-use freedom_api::prelude::*;
+use common::{get_account_content, TestResult, TestingEnv};
+use freedom_api::{error::Error, prelude::*};
 use futures::StreamExt;
+use httpmock::Method::DELETE;
 use time::macros::datetime;
 
 fn config(env: &TestingEnv) -> SatelliteConfiguration {
@@ -66,7 +67,7 @@ async fn find_all_satellite_configurations() -> TestResult {
 }
 
 #[tokio::test]
-async fn find_one_band_by_id() -> TestResult {
+async fn find_one_satellite_configuration_by_id() -> TestResult {
     let env = TestingEnv::new();
     let config = config(&env);
 
@@ -87,7 +88,7 @@ async fn find_one_band_by_id() -> TestResult {
 }
 
 #[tokio::test]
-async fn find_one_band_by_name() -> TestResult {
+async fn find_one_satellite_configuration_by_name() -> TestResult {
     let env = TestingEnv::new();
     let config = config(&env);
 
@@ -106,3 +107,212 @@ async fn find_one_band_by_name() -> TestResult {
 
     Ok(())
 }
+
+/// Serve the task requests referencing configuration 810, the second of which is yet to occur
+/// when `upcoming` is set
+fn requests_by_configuration(env: &TestingEnv, upcoming: bool) {
+    let mut requests: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/requests_find_all.json")).unwrap();
+    if upcoming {
+        requests["_embedded"]["requests"][1]["targetDate"] = "2099-01-01T00:00:00Z".into();
+    }
+    let configuration = env.url("/satellite_configurations/810");
+
+    env.get_json(
+        "/requests/search/findAllByConfigurationOrderByCreatedAsc",
+        vec![("configuration", configuration.as_str())],
+        requests,
+    );
+}
+
+#[tokio::test]
+async fn configuration_usage_lists_upcoming_requests() -> TestResult {
+    let env = TestingEnv::new();
+    requests_by_configuration(&env, true);
+    let client = Client::from(env);
+
+    let usage = client.get_configuration_usage(810).await?;
+    assert_eq!(usage.past, 2);
+    assert_eq!(usage.upcoming, 1);
+    assert_eq!(usage.next_upcoming.len(), 1);
+    assert_eq!(usage.next_upcoming[0].id, 102);
+    assert_eq!(
+        usage.next_upcoming[0].target_date,
+        datetime!(2099-01-01 00:00 UTC)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_used_configuration_is_refused() -> TestResult {
+    let env = TestingEnv::new();
+    requests_by_configuration(&env, true);
+    let delete = env.mock(|when, then| {
+        when.method(DELETE).path("/satellite_configurations/810");
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    let result = client.delete_satellite_configuration_if_unused(810).await;
+    assert!(
+        matches!(result, Err(Error::InUse { upcoming: 1, .. })),
+        "{result:?}"
+    );
+    delete.assert_hits(0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_unused_configuration() -> TestResult {
+    let env = TestingEnv::new();
+    requests_by_configuration(&env, false);
+    let delete = env.mock(|when, then| {
+        when.method(DELETE).path("/satellite_configurations/810");
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    client.delete_satellite_configuration_if_unused(810).await?;
+    delete.assert_hits(1);
+
+    Ok(())
+}
+
+/// Serve the bands of configuration 812, picked by index from the bands fixture, both by ID and at
+/// the `bandDetails` link of the configuration
+fn band_details(env: &TestingEnv, indices: &[usize]) {
+    let all: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/satellite_bands_find_all.json"))
+            .unwrap();
+    let bands: Vec<_> = indices
+        .iter()
+        .map(|&index| all["_embedded"]["satellite_bands"][index].clone())
+        .collect();
+
+    let body = serde_json::json!({ "_embedded": { "satellite_bands": bands } });
+
+    for path in [
+        "/satellite_configurations/812/bandDetails",
+        "/api/satellite_configurations/812/bandDetails",
+    ] {
+        env.get_json(path, vec![], body.clone());
+    }
+}
+
+#[tokio::test]
+async fn suggest_single_band_pair() -> TestResult {
+    let env = TestingEnv::new();
+    band_details(&env, &[5, 1]);
+    let client = Client::from(env);
+
+    let pair = client.suggest_band_pair(812).await?;
+    assert_eq!(pair.rx.get_id()?, 1574);
+    assert_eq!(
+        pair.tx.as_ref().map(|tx| tx.get_id()).transpose()?,
+        Some(1581.into())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn suggest_band_pair_lists_ambiguous_candidates() -> TestResult {
+    let env = TestingEnv::new();
+    band_details(&env, &[1, 0, 5]);
+    let client = Client::from(env);
+
+    let result = client.suggest_band_pair(812).await;
+    let Err(Error::AmbiguousBands(candidates)) = result else {
+        panic!("Expected ambiguous bands, got {result:?}");
+    };
+    assert_eq!(candidates, [1573, 1574]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn suggest_receive_only_band_pair() -> TestResult {
+    let env = TestingEnv::new();
+    band_details(&env, &[0]);
+    let client = Client::from(env);
+
+    let pair = client.suggest_band_pair(812).await?;
+    assert_eq!(pair.rx.get_id()?, 1573);
+    assert!(pair.tx.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn band_details_of_configuration() -> TestResult {
+    let env = TestingEnv::new();
+    let config = config(&env);
+    band_details(&env, &[0, 5]);
+    let client = Client::from(env);
+
+    assert_eq!(config.get_id()?, 812);
+    let bands = config.get_band_details(&client).await?;
+    let ids = bands
+        .iter()
+        .map(|band| band.get_id())
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(ids, [1573, 1581]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn account_of_configuration() -> TestResult {
+    let env = TestingEnv::new();
+    let config = config(&env);
+
+    get_account_content(&env, "/api/satellite_configurations/812/account");
+    let client = Client::from(env);
+
+    let account = config.get_account(&client).await?;
+    assert_eq!(account.name, "ABC Space");
+    assert_eq!(account.get_id()?, 34);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn configuration_without_links_is_missing_uri() -> TestResult {
+    let env = TestingEnv::new();
+    let mut config = config(&env);
+    config.links.clear();
+    let client = Client::from(env);
+
+    let result = config.get_band_details(&client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("bandDetails"))),
+        "{result:?}"
+    );
+    let result = config.get_account(&client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("account"))),
+        "{result:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_configuration_at_self_link() -> TestResult {
+    let env = TestingEnv::new();
+    let config = config(&env);
+
+    let delete = env.mock(|when, then| {
+        when.method(DELETE)
+            .path("/api/satellite_configurations/812");
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    config.delete(&client).await?;
+    delete.assert_hits(1);
+
+    Ok(())
+}
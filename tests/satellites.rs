@@ -2,10 +2,11 @@ mod common;
 
 use std::collections::HashMap;
 
-use common::{TestResult, TestingEnv};
-use freedom_api::prelude::*;
+use common::{get_account_content, get_content, TestResult, TestingEnv};
+use freedom_api::{error::Error, prelude::*};
 use futures::StreamExt;
-use time::macros::datetime;
+use httpmock::Method::{DELETE, GET, PATCH, POST};
+use time::{format_description::well_known::Iso8601, macros::datetime};
 
 fn sat(env: &TestingEnv) -> Satellite {
     let mut links = HashMap::new();
@@ -105,3 +106,465 @@ async fn find_one_satellite_by_name() -> TestResult {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn satellites_as_resource() -> TestResult {
+    let env = TestingEnv::new();
+    let sat = sat(&env);
+
+    env.get_json_from_file(
+        "/satellites",
+        Vec::new(),
+        "resources/satellite_find_all.json",
+    );
+    env.get_json_from_file(
+        "/satellites/710",
+        Vec::new(),
+        "resources/satellite_find_one_710.json",
+    );
+    env.get_json_from_file(
+        "/satellites/findOneByName",
+        vec![("name", "FooBar 6")],
+        "resources/satellite_find_one_710.json",
+    );
+    let client = Client::from(env);
+
+    let satellites = client
+        .list::<Satellite>()
+        .map(|result| result.unwrap().into_inner())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(satellites.len(), 14);
+    assert_eq!(&satellites[0], &sat);
+
+    let satellite = client.get_by_id::<Satellite>(710).await?.into_inner();
+    assert_eq!(satellite, sat);
+
+    let satellite = client
+        .find_by_name::<Satellite>("FooBar 6")
+        .await?
+        .into_inner();
+    assert_eq!(satellite, sat);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_one_satellite_by_norad_id() -> TestResult {
+    let env = TestingEnv::new();
+    let sat = sat(&env);
+
+    env.get_json_from_file(
+        "/satellites/search/findOneByNoradCatId",
+        vec![("noradCatId", "100")],
+        "resources/satellite_find_one_710.json",
+    );
+    let client = Client::from(env);
+
+    let satellite = client.get_satellite_by_norad_id(100).await?.into_inner();
+    assert_eq!(satellite, sat);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unknown_norad_id_is_not_found() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.mock(|when, then| {
+        when.method(GET)
+            .path("/satellites/search/findOneByNoradCatId")
+            .query_param("noradCatId", "404");
+        then.status(404);
+    });
+    env.mock(|when, then| {
+        when.method(GET)
+            .path("/satellites/search/findOneByNoradCatId")
+            .query_param("noradCatId", "204");
+        then.status(200);
+    });
+    let client = Client::from(env);
+
+    for norad_id in [404, 204] {
+        let error = client
+            .get_satellite_by_norad_id(norad_id)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error,
+            Error::NotFound(format!("no satellite has the NORAD catalog ID {norad_id}"))
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_satellites_by_account_name() -> TestResult {
+    let env = TestingEnv::new();
+    let sat = sat(&env);
+
+    env.get_json_from_file(
+        "/satellites/search/findAllByAccountName",
+        vec![("accountName", "ABC Space")],
+        "resources/satellite_find_all.json",
+    );
+    let client = Client::from(env);
+
+    let satellites = client
+        .get_satellites_by_account_name("ABC Space")
+        .try_collect_inner()
+        .await?;
+    assert_eq!(satellites.len(), 14);
+    assert_eq!(satellites[0], sat);
+
+    Ok(())
+}
+
+const LINE1: &str = "1 25544U 98067A   24290.51782528  .00016717  00000-0  10270-3 0  9005";
+const LINE2: &str = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.50377579 77070";
+
+#[tokio::test]
+async fn find_satellite_orbit_info() -> TestResult {
+    let env = TestingEnv::new();
+    let sat = sat(&env);
+
+    // Served both by ID, and at the `orbitInfo` link of the satellite
+    for path in ["/satellites/710/orbitInfo", "/api/satellites/710/orbitInfo"] {
+        env.get_json_from_file(path, Vec::new(), "resources/satellite_orbit_info_710.json");
+    }
+    let client = Client::from(env);
+
+    let expected = Some(TwoLineElement {
+        line1: String::from(LINE1),
+        line2: String::from(LINE2),
+    });
+
+    let orbit = client.get_satellite_orbit_info(710).await?.into_inner();
+    assert_eq!(orbit.tle, expected);
+
+    let orbit = sat.get_orbit_info(&client).await?.into_inner();
+    assert_eq!(orbit.tle, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn set_satellite_tle() -> TestResult {
+    let env = TestingEnv::new();
+
+    let update = env.mock(|when, then| {
+        when.method(PATCH)
+            .path("/satellites/710")
+            .json_body(serde_json::json!({
+                "tle": { "line1": LINE1, "line2": LINE2 },
+            }));
+        then.status(200);
+    });
+    let client = Client::from(env.clone());
+
+    client.set_satellite_tle(710, LINE1, LINE2).await?;
+    update.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn malformed_tle_is_not_sent() -> TestResult {
+    let env = TestingEnv::new();
+
+    let update = env.mock(|when, then| {
+        when.method(PATCH).path("/satellites/710");
+        then.status(200);
+    });
+    let client = Client::from(env.clone());
+
+    let result = client.set_satellite_tle(710, LINE1, "2 25544").await;
+
+    assert!(matches!(result, Err(Error::MalformedTle(_))));
+    update.assert_hits(0);
+
+    Ok(())
+}
+
+fn assert_visibilities(visibilities: &[Visibility]) {
+    assert_eq!(visibilities.len(), 3);
+    assert!(visibilities
+        .windows(2)
+        .all(|pair| pair[0].start <= pair[1].start));
+
+    let first = &visibilities[0];
+    assert_eq!(first.site, "Svalbard");
+    assert_eq!(first.start, datetime!(2024-10-18 02:14:05 UTC));
+    assert_eq!(first.end, datetime!(2024-10-18 02:25:41 UTC));
+    assert_eq!(first.max_elevation, 42.7);
+    assert_eq!(visibilities[1].site, "Fairbanks");
+}
+
+#[tokio::test]
+async fn find_visibilities_by_satellite_id() -> TestResult {
+    let env = TestingEnv::new();
+
+    let start = datetime!(2024-10-18 00:00 UTC);
+    let end = datetime!(2024-10-19 00:00 UTC);
+    let (start_param, end_param) = (
+        start.format(&Iso8601::DEFAULT)?,
+        end.format(&Iso8601::DEFAULT)?,
+    );
+    env.get_json_from_file(
+        "/satellites/710/visibilities",
+        vec![("start", &start_param), ("end", &end_param)],
+        "resources/satellite_visibilities_710.json",
+    );
+    let client = Client::from(env);
+
+    let visibilities = client
+        .get_visibilities_by_satellite_id(710, start, end)
+        .await?;
+    assert_visibilities(&visibilities);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_upcoming_visibilities_of_satellite() -> TestResult {
+    let env = TestingEnv::new();
+    let sat = sat(&env);
+
+    env.get_json_from_file(
+        "/api/satellites/710/upcomingVisibilities",
+        Vec::new(),
+        "resources/satellite_visibilities_710.json",
+    );
+    let client = Client::from(env);
+
+    let visibilities = sat.get_upcoming_visibilities(&client).await?;
+    assert_visibilities(&visibilities);
+
+    Ok(())
+}
+
+const CONFLICT_BODY: &str = r#"{"status":409,"error":"Conflict","message":"A satellite named FooBar 6 already exists","path":"/api/satellites"}"#;
+
+fn mock_conflict(env: &TestingEnv) -> httpmock::Mock<'_> {
+    env.mock(|when, then| {
+        when.method(POST).path("/satellites");
+        then.status(409)
+            .header("content-type", "application/json")
+            .body(CONFLICT_BODY);
+    })
+}
+
+#[tokio::test]
+async fn create_satellite_conflict_is_error() -> TestResult {
+    let env = TestingEnv::new();
+    let conflict = mock_conflict(&env);
+    let client = Client::from(env.clone());
+
+    let result = client
+        .new_satellite()
+        .name("FooBar 6")
+        .satellite_configuration_id(42)
+        .norad_id(100)
+        .send()
+        .await;
+
+    let Err(Error::Api {
+        status,
+        message,
+        raw_body,
+        ..
+    }) = result.as_ref().map_err(Error::inner)
+    else {
+        panic!("Expected an API error, got {result:?}");
+    };
+    assert_eq!(status.as_u16(), 409);
+    assert_eq!(message, "A satellite named FooBar 6 already exists");
+    assert_eq!(raw_body, CONFLICT_BODY);
+    conflict.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_satellite_conflict_raw() -> TestResult {
+    let env = TestingEnv::new();
+    let conflict = mock_conflict(&env);
+    let client = Client::from(env.clone());
+
+    let response = client
+        .new_satellite()
+        .name("FooBar 6")
+        .satellite_configuration_id(42)
+        .norad_id(100)
+        .send_raw()
+        .await?;
+
+    assert_eq!(response.status().as_u16(), 409);
+    assert_eq!(response.text().await?, CONFLICT_BODY);
+    conflict.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn update_satellite() -> TestResult {
+    let env = TestingEnv::new();
+
+    let configuration = env.url("/satellite_configurations/42");
+    let update = env.mock(|when, then| {
+        when.method(PATCH)
+            .path("/satellites/710")
+            .json_body(serde_json::json!({
+                "name": "FooBar 7",
+                "configuration": configuration,
+            }));
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    client
+        .update_satellite(710)
+        .name("FooBar 7")
+        .satellite_configuration_id(42)
+        .send()
+        .await?;
+    update.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mismatched_satellite_reports_type_and_snippet() -> TestResult {
+    let env = TestingEnv::new();
+    env.get_json_from_file(
+        "/satellites/710",
+        Vec::new(),
+        "resources/satellite_find_one_mismatched.json",
+    );
+    let client = Client::from(env);
+
+    let error = client.get_satellite_by_id(710).await.unwrap_err();
+    let Error::Deserialization(message) = error.inner() else {
+        panic!("expected a deserialization error, got {error:?}");
+    };
+
+    assert!(message.contains("Satellite"), "{message}");
+    assert!(message.contains("noradCatId"), "{message}");
+    assert!(
+        message.contains(r#""noradCatId": "one hundred""#),
+        "{message}"
+    );
+    assert!(error.to_string().contains("/satellites/710"), "{error}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mismatched_page_item_reports_its_json() -> TestResult {
+    let env = TestingEnv::new();
+    env.get_json_from_file(
+        "/satellites",
+        Vec::new(),
+        "resources/satellite_find_all_mismatched.json",
+    );
+    let client = Client::from(env);
+
+    let satellites = client.get_satellites().collect::<Vec<_>>().await;
+
+    assert_eq!(satellites.len(), 2);
+    assert!(satellites[0].is_ok());
+    let Err(error) = &satellites[1] else {
+        panic!("expected the second satellite to fail");
+    };
+    let Error::PaginationItemDeserialization(message) = error.inner() else {
+        panic!("expected an item deserialization error, got {error:?}");
+    };
+    assert!(message.contains("Satellite"), "{message}");
+    assert!(message.contains("noradCatId"), "{message}");
+    assert!(message.contains("/api/satellites/711"), "{message}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn configuration_and_account_of_satellite() -> TestResult {
+    let env = TestingEnv::new();
+    let sat = sat(&env);
+
+    let configuration = serde_json::from_str(
+        &env.json_from_file("resources/satellite_configurations_find_one_810.json"),
+    )?;
+    get_content(&env, "/api/satellites/710/configuration", configuration);
+    get_account_content(&env, "/api/satellites/710/account");
+    let client = Client::from(env);
+
+    let configuration = sat.get_configuration(&client).await?;
+    assert_eq!(configuration.name, "FooBarConfig1");
+    assert_eq!(configuration.get_id()?, 812);
+
+    let account = sat.get_account(&client).await?;
+    assert_eq!(account.name, "ABC Space");
+    assert_eq!(account.get_id()?, 34);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn satellite_without_links_is_missing_uri() -> TestResult {
+    let env = TestingEnv::new();
+    let mut sat = sat(&env);
+    sat.links.remove("configuration");
+    sat.links.remove("account");
+    let client = Client::from(env);
+
+    let result = sat.get_configuration(&client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("configuration"))),
+        "{result:?}"
+    );
+    let result = sat.get_account(&client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("account"))),
+        "{result:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn malformed_configuration_link_is_missing_uri() -> TestResult {
+    let env = TestingEnv::new();
+
+    let mut satellite: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/satellite_find_one_710.json"))?;
+    satellite["_links"]["configuration"]["href"] = "not a url".into();
+    env.get_json("/satellites/710", vec![], satellite);
+    let client = Client::from(env).lenient_links(true);
+
+    let sat = client.get_satellite_by_id(710).await?.into_inner();
+    let result = sat.get_configuration(&client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("configuration"))),
+        "{result:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_satellite_at_self_link() -> TestResult {
+    let env = TestingEnv::new();
+    let sat = sat(&env);
+
+    let delete = env.mock(|when, then| {
+        when.method(DELETE).path("/api/satellites/710");
+        then.status(204);
+    });
+    let client = Client::from(env.clone());
+
+    sat.delete(&client).await?;
+    delete.assert_hits(1);
+
+    Ok(())
+}
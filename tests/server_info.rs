@@ -0,0 +1,97 @@
+mod common;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::{error::Error, prelude::*};
+use httpmock::Method::GET;
+use serde_json::json;
+use time::macros::datetime;
+
+#[tokio::test]
+async fn full_info_payload() -> TestResult {
+    let env = TestingEnv::new();
+    let info = env.get_json(
+        "/actuator/info",
+        vec![],
+        json!({
+            "build": {
+                "artifact": "freedom",
+                "version": "3.12.1",
+                "time": "2024-10-01T12:00:00.000Z"
+            },
+            "modules": ["bundles", "visibilities"]
+        }),
+    );
+    let client = Client::from(env.clone());
+
+    let server = client.get_server_info().await?;
+
+    assert!(server.is_known());
+    assert_eq!(server.version.as_deref(), Some("3.12.1"));
+    assert_eq!(server.build_time, Some(datetime!(2024-10-01 12:00 UTC)));
+    assert_eq!(server.has_module("bundles"), Some(true));
+    assert_eq!(server.has_module("azel"), Some(false));
+    assert_eq!(server.version_at_least((3, 12, 0)), Some(true));
+
+    // Repeated calls are served from the memo of the client
+    client.get_server_info().await?;
+    info.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn minimal_info_payload() -> TestResult {
+    let env = TestingEnv::new();
+    env.get_json("/actuator/info", vec![], json!({}));
+    let client = Client::from(env.clone());
+
+    let server = client.get_server_info().await?;
+
+    assert!(server.is_known());
+    assert_eq!(server.version, None);
+    assert_eq!(server.build_time, None);
+    assert_eq!(server.has_module("bundles"), None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unavailable_info_is_unknown() -> TestResult {
+    let env = TestingEnv::new();
+    let info = env.mock(|when, then| {
+        when.method(GET).path("/actuator/info");
+        then.status(404);
+    });
+    let client = Client::from(env.clone());
+
+    let server = client.get_server_info().await?;
+
+    assert_eq!(server, ServerInfo::unknown());
+    assert!(!server.is_known());
+    client.get_server_info().await?;
+    info.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn failing_info_is_an_error() -> TestResult {
+    let env = TestingEnv::new();
+    env.mock(|when, then| {
+        when.method(GET).path("/actuator/info");
+        then.status(500);
+    });
+    let client = Client::from(env.clone());
+
+    let result = client.get_server_info().await;
+
+    assert!(
+        matches!(
+            result.as_ref().map_err(Error::inner),
+            Err(Error::Api { .. })
+        ),
+        "{result:?}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::{error::Error, prelude::*};
+use futures::StreamExt;
+use serde_json::json;
+use time::macros::datetime;
+
+fn config(env: &TestingEnv, id: i32) -> SiteConfiguration {
+    let this = format!("http://localhost:8080/api/configurations/{id}");
+    let site = format!("http://localhost:8080/api/configurations/{id}/site");
+    let mut links = HashMap::new();
+    links.insert("self", this.as_str());
+    links.insert("siteConfiguration", this.as_str());
+    links.insert("site", site.as_str());
+    let links = env.map_to_links(links);
+
+    SiteConfiguration {
+        created: datetime!(2019-04-22 23:31:07 UTC),
+        modified: Some(datetime!(2023-01-26 16:27:15 UTC)),
+        internal_meta_data: None,
+        name: format!("LOAG-{id}"),
+        description: Some(String::from("Los Angeles S-band")),
+        configuration_seconds: 300,
+        virtual_cfg: Some(false),
+        properties: Some(HashMap::new()),
+        hardware: None,
+        links,
+    }
+}
+
+fn site(env: &TestingEnv) -> Site {
+    let mut site: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/sites_find_one_14.json")).unwrap();
+    site["_links"] = json!({
+        "self": { "href": env.url("/api/sites/14") },
+        "configurations": { "href": env.url("/api/sites/14/configurations") }
+    });
+
+    serde_json::from_value(site).unwrap()
+}
+
+#[tokio::test]
+async fn find_all_site_configurations() -> TestResult {
+    let env = TestingEnv::new();
+    let configs = [config(&env, 47), config(&env, 48)];
+
+    env.get_json(
+        "/configurations",
+        Vec::new(),
+        env.paged_fixture("siteConfigurations", &configs, None),
+    );
+    let client = Client::from(env);
+
+    let found = client
+        .get_site_configurations()
+        .map(|result| result.unwrap().into_inner())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(found, configs);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_one_site_configuration_by_id() -> TestResult {
+    let env = TestingEnv::new();
+    let config = config(&env, 47);
+
+    env.get_json("/configurations/47", Vec::new(), env.fixture_for(&config));
+    let client = Client::from(env);
+
+    let found = client.get_site_configuration_by_id(47).await?.into_inner();
+    assert_eq!(found, config);
+    assert_eq!(found.get_id()?, 47);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn site_configurations_as_resource() -> TestResult {
+    let env = TestingEnv::new();
+    let config = config(&env, 47);
+
+    env.get_json(
+        "/configurations",
+        Vec::new(),
+        env.paged_fixture("siteConfigurations", std::slice::from_ref(&config), None),
+    );
+    env.get_json("/configurations/47", Vec::new(), env.fixture_for(&config));
+    let client = Client::from(env);
+
+    let found = client
+        .list::<SiteConfiguration>()
+        .map(|result| result.unwrap().into_inner())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(found, vec![config.clone()]);
+
+    let found = client
+        .get_by_id::<SiteConfiguration>(47)
+        .await?
+        .into_inner();
+    assert_eq!(found, config);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn configurations_of_site() -> TestResult {
+    let env = TestingEnv::new();
+    let configs = [config(&env, 47), config(&env, 48)];
+    let items: Vec<_> = configs
+        .iter()
+        .map(|config| env.fixture_for(config))
+        .collect();
+    let embedded = json!({ "_embedded": { "siteConfigurations": items } });
+
+    env.get_json("/sites/14/configurations", Vec::new(), embedded.clone());
+    env.get_json("/api/sites/14/configurations", Vec::new(), embedded);
+    let client = Client::from(env.clone());
+
+    let by_id = client.get_site_configurations_by_site_id(14).await?;
+    assert_eq!(by_id.into_inner(), configs);
+
+    let linked = site(&env).get_configurations(&client).await?;
+    assert_eq!(linked.into_inner(), configs);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn site_without_configurations_link_is_missing_uri() -> TestResult {
+    let env = TestingEnv::new();
+    let mut site = site(&env);
+    site.links.remove("configurations");
+    let client = Client::from(env);
+
+    let result = site.get_configurations(&client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("configurations"))),
+        "{result:?}"
+    );
+
+    Ok(())
+}
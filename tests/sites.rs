@@ -3,9 +3,14 @@ mod common;
 use std::collections::HashMap;
 
 use common::{TestResult, TestingEnv};
-use freedom_api::prelude::*;
+use freedom_api::{error::Error, prelude::*};
 use freedom_models::azel::Location;
 use futures::StreamExt;
+use httpmock::{
+    HttpMockRequest,
+    Method::{GET, POST},
+};
+use serde_json::json;
 use time::macros::datetime;
 
 fn site(env: &TestingEnv) -> Site {
@@ -55,6 +60,30 @@ async fn find_all_sites() -> TestResult {
     Ok(())
 }
 
+#[tokio::test]
+async fn find_all_sites_from_fixture() -> TestResult {
+    let env = TestingEnv::new();
+    let site = site(&env);
+
+    env.get_json(
+        "/sites",
+        Vec::new(),
+        env.paged_fixture("sites", std::slice::from_ref(&site), None),
+    );
+    let client = Client::from(env);
+
+    let sites = client
+        .get_sites()
+        .map(|result| result.unwrap().into_inner())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(sites.len(), 1);
+    let first = &sites[0];
+    assert_eq!(first, &site);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn find_one_site_by_id() -> TestResult {
     let env = TestingEnv::new();
@@ -69,6 +98,20 @@ async fn find_one_site_by_id() -> TestResult {
     Ok(())
 }
 
+#[tokio::test]
+async fn find_one_site_by_id_from_fixture() -> TestResult {
+    let env = TestingEnv::new();
+    let site = site(&env);
+
+    env.get_json("/sites/14", Vec::new(), env.fixture_for(&site));
+    let client = Client::from(env);
+
+    let configuration = client.get_site_by_id(14).await?.into_inner();
+    assert_eq!(configuration, site);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn find_one_site_by_name() -> TestResult {
     let env = TestingEnv::new();
@@ -86,3 +129,399 @@ async fn find_one_site_by_name() -> TestResult {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn sites_as_resource() -> TestResult {
+    let env = TestingEnv::new();
+    let site = site(&env);
+
+    env.get_json(
+        "/sites",
+        Vec::new(),
+        env.paged_fixture("sites", std::slice::from_ref(&site), None),
+    );
+    env.get_json("/sites/14", Vec::new(), env.fixture_for(&site));
+    env.get_json_from_file(
+        "/sites/search/findOneByName",
+        vec![("name", "LOAG")],
+        "resources/sites_find_one_14.json",
+    );
+    let client = Client::from(env);
+
+    let sites = client
+        .list::<Site>()
+        .map(|result| result.unwrap().into_inner())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(sites, vec![site.clone()]);
+
+    let found = client.get_by_id::<Site>(14).await?.into_inner();
+    assert_eq!(found, site);
+
+    let found = client.find_by_name::<Site>("LOAG").await?.into_inner();
+    assert_eq!(found, site);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_one_site_by_name_with_reserved_characters() -> TestResult {
+    let env = TestingEnv::new();
+    let site = site(&env);
+
+    // The whole name arrives as the only query parameter, rather than being split on the `&`
+    let find = env.mock(|when, then| {
+        when.method(GET)
+            .path("/sites/search/findOneByName")
+            .matches(|req| only_query_param(req, "name", "ABC Space & Co"));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(env.json_from_file("resources/sites_find_one_14.json"));
+    });
+    let client = Client::from(env.clone());
+
+    let configuration = client
+        .get_site_by_name("ABC Space & Co")
+        .await?
+        .into_inner();
+    assert_eq!(configuration, site);
+    find.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn site_names_are_encoded_as_a_single_parameter() -> TestResult {
+    let env = TestingEnv::new();
+    let find = env.mock(|when, then| {
+        when.method(GET)
+            .path("/sites/search/findOneByName")
+            .matches(|req| only_query_param(req, "name", "a+b=c & d"));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(env.json_from_file("resources/sites_find_one_14.json"));
+    });
+    let client = Client::from(env.clone());
+
+    client.get_site_by_name("a+b=c & d").await?;
+    find.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_one_site_with_bad_link() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.get_json_from_file(
+        "/sites/14",
+        Vec::new(),
+        "resources/sites_find_one_14_bad_link.json",
+    );
+    let client = Client::from(env);
+
+    assert!(client.get_site_by_id(14).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_one_site_with_bad_link_lenient() -> TestResult {
+    let env = TestingEnv::new();
+    let mut site = site(&env);
+    site.links.remove("configurations");
+
+    env.get_json_from_file(
+        "/sites/14",
+        Vec::new(),
+        "resources/sites_find_one_14_bad_link.json",
+    );
+    let client = Client::from(env).lenient_links(true);
+
+    let configuration = client.get_site_by_id(14).await?.into_inner();
+    assert_eq!(configuration, site);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_sites_by_account_name() -> TestResult {
+    let env = TestingEnv::new();
+    let site = site(&env);
+
+    env.get_json_from_file(
+        "/sites/search/findAllByAccountName",
+        vec![("accountName", "ABC Space")],
+        "resources/sites_find_all_by_account_name.json",
+    );
+    let client = Client::from(env);
+
+    let sites = client
+        .get_sites_by_account_name("ABC Space")
+        .map(|result| result.unwrap().into_inner())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(sites.len(), 2);
+    assert_eq!(sites[0], site);
+    assert_eq!(sites[1].name, "SVAL");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_sites_by_account_name_with_reserved_characters() -> TestResult {
+    let env = TestingEnv::new();
+    let site = site(&env);
+
+    let find = env.mock(|when, then| {
+        when.method(GET)
+            .path("/sites/search/findAllByAccountName")
+            .matches(|req| only_query_param(req, "accountName", "ABC Space & Co"));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(env.json_from_file("resources/sites_find_all_by_account_name.json"));
+    });
+    let client = Client::from(env.clone());
+
+    let sites = client
+        .get_sites_by_account_name("ABC Space & Co")
+        .map(|result| result.unwrap().into_inner())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(sites.len(), 2);
+    assert_eq!(sites[0], site);
+    find.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_sites_by_hidden_account_name() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path("/sites/search/findAllByAccountName");
+        then.status(403)
+            .header("content-type", "application/json")
+            .body(r#"{"error": "Forbidden", "message": "Access is denied"}"#);
+    });
+    let client = Client::from(env);
+
+    let sites = client
+        .get_sites_by_account_name("Partner")
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(sites.len(), 1);
+    assert!(
+        matches!(sites[0].as_ref().map_err(Error::inner), Err(Error::PermissionDenied(message)) if message == "Access is denied")
+    );
+
+    Ok(())
+}
+
+/// Whether the single query parameter of the request is the decoded name and value
+fn only_query_param(req: &HttpMockRequest, name: &str, value: &str) -> bool {
+    match req.query_params.as_deref() {
+        Some([(key, found)]) => key == name && found == value,
+        _ => false,
+    }
+}
+
+fn site_configurations(env: &TestingEnv, ids: &[i32]) {
+    let configurations: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            serde_json::json!({
+                "_links": { "self": { "href": env.url(format!("/api/site_configurations/{id}")) } }
+            })
+        })
+        .collect();
+
+    env.get_json(
+        "/api/sites/14/configurations",
+        vec![],
+        serde_json::json!({ "_embedded": { "site_configurations": configurations } }),
+    );
+}
+
+#[tokio::test]
+async fn fps_endpoint_by_ids() -> TestResult {
+    let env = TestingEnv::new();
+    env.get_json_from_file("/sites/14", vec![], "resources/sites_find_one_14.json");
+    site_configurations(&env, &[47, 48, 52]);
+    let client = Client::from(env);
+
+    let (host, port) = client.fps_endpoint_by_ids(14, 52).await?;
+
+    assert_eq!(host, "localhost");
+    assert_eq!(port, 20102);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fps_endpoint_of_foreign_configuration() -> TestResult {
+    let env = TestingEnv::new();
+    env.get_json_from_file("/sites/14", vec![], "resources/sites_find_one_14.json");
+    site_configurations(&env, &[47, 48]);
+    let client = Client::from(env);
+
+    let result = client.fps_endpoint_by_ids(14, 52).await;
+
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_site() -> TestResult {
+    let env = TestingEnv::new();
+    let create = env.mock(|when, then| {
+        when.method(POST).path("/sites").json_body(json!({
+            "name": "LOAG",
+            "description": "Los Angeles",
+            "location": {
+                "longitude": -2.15,
+                "latitude": 50.5,
+                "elevation": 32.652
+            },
+            "baseFpsPort": 20100,
+            "properties": { "operator": "ATLAS" }
+        }));
+        then.status(201);
+    });
+    let client = Client::from(env.clone());
+
+    client
+        .new_site()
+        .name("LOAG")
+        .location(Location {
+            longitude: -2.15,
+            latitude: 50.5,
+            elevation: 32.652,
+        })
+        .base_fps_port(20100)
+        .description("Los Angeles")
+        .property("operator", "ATLAS")
+        .send()
+        .await?;
+    create.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn site_off_the_globe_is_rejected() -> TestResult {
+    let env = TestingEnv::new();
+    let create = env.post_json("/sites", 201, json!({}));
+    let client = Client::from(env.clone());
+
+    let result = client
+        .new_site()
+        .name("LOAG")
+        .location(Location {
+            longitude: 200.0,
+            latitude: 95.0,
+            elevation: 32.652,
+        })
+        .base_fps_port(20100)
+        .send()
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+    create.assert_hits(0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_site_configuration() -> TestResult {
+    let env = TestingEnv::new();
+    let site = env.url("/sites/14");
+    let create = env.mock(|when, then| {
+        when.method(POST).path("/configurations").json_body(json!({
+            "site": site,
+            "name": "S-Band",
+            "properties": { "antenna": "3.7m", "modem": "qpsk" }
+        }));
+        then.status(201);
+    });
+    let client = Client::from(env.clone());
+
+    client
+        .new_site_configuration()
+        .site_id(14)
+        .name("S-Band")
+        .properties([("antenna", "3.7m"), ("modem", "qpsk")])
+        .send()
+        .await?;
+    create.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn optional_site_by_name_is_found() -> TestResult {
+    let env = TestingEnv::new();
+    let site = site(&env);
+
+    env.get_json_from_file(
+        "/sites/search/findOneByName",
+        vec![("name", "LOAG")],
+        "resources/sites_find_one_14.json",
+    );
+    let client = Client::from(env);
+
+    let found = client.get_site_by_name("LOAG").await.ok_if_found()?;
+    assert_eq!(found.map(Container::into_inner), Some(site));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn optional_site_by_name_is_none_when_missing() -> TestResult {
+    let env = TestingEnv::new();
+    env.mock(|when, then| {
+        when.method(GET)
+            .path("/sites/search/findOneByName")
+            .query_param("name", "Nowhere");
+        then.status(404);
+    });
+    let client = Client::from(env);
+
+    let found = client.get_site_by_name("Nowhere").await.ok_if_found()?;
+    assert!(found.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn optional_site_by_name_propagates_server_errors() -> TestResult {
+    let env = TestingEnv::new();
+    env.mock(|when, then| {
+        when.method(GET).path("/sites/search/findOneByName");
+        then.status(500);
+    });
+    let client = Client::from(env);
+
+    let error = client
+        .get_site_by_name("LOAG")
+        .await
+        .ok_if_found()
+        .unwrap_err();
+    assert_eq!(
+        error.status(),
+        Some(reqwest::StatusCode::INTERNAL_SERVER_ERROR),
+        "{error:?}"
+    );
+    assert_eq!(error.method(), Some(&reqwest::Method::GET));
+    let rendered = error.to_string();
+    assert!(
+        rendered.starts_with("GET ")
+            && rendered.contains("/sites/search/findOneByName?name=REDACTED"),
+        "{rendered}"
+    );
+
+    Ok(())
+}
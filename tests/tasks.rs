@@ -0,0 +1,163 @@
+mod common;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::prelude::*;
+use time::macros::datetime;
+
+#[tokio::test]
+async fn find_azel_by_task_id() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.get_json_from_file(
+        "/tasks/74344/azel",
+        Vec::new(),
+        "resources/tasks_74344_azel.json",
+    );
+    let client = Client::from(env);
+
+    let azel = client.get_azel_by_task_id(74344).await?.into_inner();
+
+    assert_eq!(azel.start, datetime!(2022-05-26 04:57:26 UTC));
+    assert_eq!(azel.end, datetime!(2022-05-26 05:09:56 UTC));
+    assert_eq!(
+        (azel.location.latitude, azel.location.longitude),
+        (50.5, -2.15)
+    );
+
+    let (azimuth, elevation): (Vec<_>, Vec<_>) = azel
+        .directions
+        .iter()
+        .map(|direction| (direction.az, direction.el))
+        .unzip();
+
+    assert_eq!(azel.directions.len(), 26);
+    assert_eq!(azimuth[..3], [201.4, 197.0, 192.6]);
+    assert_eq!(elevation[..3], [0.0, 5.93, 11.76]);
+    assert_eq!(azimuth.last(), Some(&91.4));
+    assert_eq!(elevation.last(), Some(&0.0));
+
+    let peak = elevation.iter().copied().fold(f64::MIN, f64::max);
+    assert_eq!(peak, 47.21);
+
+    Ok(())
+}
+
+fn task_without_request_link(
+    env: &TestingEnv,
+) -> Result<Task, Box<dyn std::error::Error + Send + Sync>> {
+    let page: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/tasks_1/page_1.json"))?;
+    let mut task = page["_embedded"]["tasks"][0].clone();
+    task["_links"]
+        .as_object_mut()
+        .ok_or("the task has no links")?
+        .remove("taskRequest");
+
+    Ok(serde_json::from_value(task)?)
+}
+
+#[tokio::test]
+async fn find_request_by_task_id() -> TestResult {
+    let env = TestingEnv::new();
+
+    let task_url = env.url("/tasks/74344");
+    let find = env.get_json_from_file(
+        "/requests/search/findByTask",
+        vec![("task", &task_url)],
+        "resources/recorded/requests/find_one_104.json",
+    );
+    let client = Client::from(env.clone());
+
+    let request = client.get_request_by_task_id(74344).await?;
+    assert_eq!(request.get_id()?, 104);
+
+    // Without a `taskRequest` link, the task falls back to the lookup by its ID
+    let task = task_without_request_link(&env)?;
+    let request = task.get_task_request(&client).await?;
+    assert_eq!(request.get_id()?, 104);
+    find.assert_hits(2);
+
+    Ok(())
+}
+
+/// The first tasks of the fixture, as served by the mock server
+fn tasks(
+    env: &TestingEnv,
+    count: usize,
+) -> Result<Vec<Task>, Box<dyn std::error::Error + Send + Sync>> {
+    let page: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/tasks_1/page_1.json"))?;
+    let tasks: Vec<Task> = serde_json::from_value(page["_embedded"]["tasks"].clone())?;
+
+    Ok(tasks.into_iter().take(count).collect())
+}
+
+#[tokio::test]
+async fn tasks_by_site_follow_next_links() -> TestResult {
+    let env = TestingEnv::new();
+    let tasks = tasks(&env, 5)?;
+    let pages = env.get_pages(
+        "/tasks/search/findBySite",
+        "tasks",
+        &[&tasks[..3], &tasks[3..]],
+    );
+    let client = Client::from(env.clone());
+
+    let found = client.get_tasks_by_site_id(14).try_collect_inner().await?;
+    assert_eq!(found, tasks);
+    for page in pages {
+        page.assert_hits(1);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tasks_by_site_query_the_site() -> TestResult {
+    let env = TestingEnv::new();
+    let tasks = tasks(&env, 2)?;
+    let site = env.url("/sites/14");
+    let search = env.get_json(
+        "/tasks/search/findBySite",
+        vec![("site", &site)],
+        env.paged_fixture("tasks", &tasks, None),
+    );
+    let client = Client::from(env.clone());
+
+    let found = client.get_tasks_by_site_id(14).try_collect_inner().await?;
+    assert_eq!(found, tasks);
+    search.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tasks_by_satellite_name_follow_next_links() -> TestResult {
+    let env = TestingEnv::new();
+    let tasks = tasks(&env, 5)?;
+    let first = env.get_json(
+        "/tasks/search/findBySatelliteName",
+        vec![("satelliteName", "ISS (ZARYA) & Co")],
+        env.paged_fixture(
+            "tasks",
+            &tasks[..3],
+            Some("/tasks/search/findBySatelliteName?page=1"),
+        ),
+    );
+    let second = env.get_json(
+        "/tasks/search/findBySatelliteName",
+        vec![("page", "1")],
+        env.paged_fixture("tasks", &tasks[3..], None),
+    );
+    let client = Client::from(env.clone());
+
+    let found = client
+        .get_tasks_by_satellite_name("ISS (ZARYA) & Co")
+        .try_collect_inner()
+        .await?;
+    assert_eq!(found, tasks);
+    first.assert_hits(1);
+    second.assert_hits(1);
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::prelude::*;
+use freedom_models::band::Band;
+use futures::TryStreamExt;
+
+fn bands(env: &TestingEnv) -> Vec<Band> {
+    let all: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/satellite_bands_find_all.json"))
+            .unwrap();
+
+    serde_json::from_value(all["_embedded"]["satellite_bands"].clone()).unwrap()
+}
+
+#[tokio::test]
+async fn serves_every_page_of_a_collection() -> TestResult {
+    let env = TestingEnv::new();
+    let bands = bands(&env);
+    let pages: Vec<_> = bands.chunks(2).collect();
+    let mocks = env.get_pages("/satellite_bands", "satellite_bands", &pages);
+    let client = Client::from(env.clone());
+
+    let fetched: Vec<_> = client
+        .get_satellite_bands()
+        .map_ok(|band| band.into_inner())
+        .try_collect()
+        .await?;
+    assert_eq!(fetched, bands);
+    assert_eq!(mocks.len(), 3);
+    for mock in mocks {
+        mock.assert_hits(1);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serves_empty_collection() -> TestResult {
+    let env = TestingEnv::new();
+    let mocks = env.get_pages::<Band>("/satellite_bands", "satellite_bands", &[]);
+    let client = Client::from(env.clone());
+
+    let fetched: Vec<_> = client.get_satellite_bands().try_collect().await?;
+    assert!(fetched.is_empty());
+    mocks[0].assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expects_delete() -> TestResult {
+    let env = TestingEnv::new();
+    let delete = env.delete("/satellites/12", 204);
+    let client = Client::from(env.clone());
+
+    client.delete_satellite(12).await?;
+    delete.assert_hits(1);
+
+    Ok(())
+}
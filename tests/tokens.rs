@@ -0,0 +1,169 @@
+mod common;
+
+use common::{TestResult, TestingEnv};
+use freedom_api::{error::Error, prelude::*};
+use httpmock::Method::POST;
+use serde_json::json;
+use time::{Duration, OffsetDateTime};
+
+#[tokio::test]
+async fn new_token_by_satellite_id() -> TestResult {
+    let env = TestingEnv::new();
+    let fps = env.mock(|when, then| {
+        when.method(POST).path("/fps").json_body(json!({
+            "band": "/api/satellite_bands/42",
+            "satellite": "/api/satellites/101",
+        }));
+        then.status(200).json_body(json!({ "token": "abc123" }));
+    });
+    let client = Client::from(env.clone());
+
+    let token = client.new_token_by_satellite_id(42, 101).await?;
+
+    assert_eq!(token, "abc123");
+    fps.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rejected_token_request_reports_server_error() -> TestResult {
+    let env = TestingEnv::new();
+    env.mock(|when, then| {
+        when.method(POST).path("/fps");
+        then.status(403).json_body(json!({
+            "status": 403,
+            "error": "Forbidden",
+            "message": "Band 42 is not available on configuration 7",
+            "path": "/api/fps",
+        }));
+    });
+    let client = Client::from(env.clone());
+
+    let Err(Error::Api {
+        status,
+        message,
+        path,
+        ..
+    }) = client
+        .new_token_by_site_configuration_id(42, 7)
+        .await
+        .map_err(Error::into_inner)
+    else {
+        panic!("Expected an API error");
+    };
+    assert_eq!(status.as_u16(), 403);
+    assert_eq!(message, "Band 42 is not available on configuration 7");
+    assert_eq!(path.as_deref(), Some("/api/fps"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn successful_response_without_token() -> TestResult {
+    let env = TestingEnv::new();
+    env.mock(|when, then| {
+        when.method(POST).path("/fps");
+        then.status(200).json_body(json!({ "expires": 300 }));
+    });
+    let client = Client::from(env.clone());
+
+    let error = client.new_token_by_satellite_id(42, 101).await.unwrap_err();
+
+    assert_eq!(error, Error::Response(String::from("Missing token field")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn typed_token_reports_expiry_and_host() -> TestResult {
+    let env = TestingEnv::new();
+    env.mock(|when, then| {
+        when.method(POST).path("/fps");
+        then.status(200).json_body(json!({
+            "token": "abc123",
+            "expiration": "2024-10-01T12:00:00Z",
+            "port": 20100,
+        }));
+    });
+    let client = Client::from(env.clone());
+
+    let token = client
+        .request_fps_token(42, FpsTarget::SiteConfiguration(7.into()))
+        .await?;
+
+    assert_eq!(token.band_id, 42);
+    assert_eq!(token.target, FpsTarget::SiteConfiguration(7.into()));
+    assert_eq!(token.host, "localhost");
+    assert_eq!(token.port, Some(20100));
+    assert_eq!(
+        token.expires_at,
+        Some(time::macros::datetime!(2024-10-01 12:00 UTC))
+    );
+    assert!(token.is_expired());
+
+    Ok(())
+}
+
+fn expiring_in(env: &TestingEnv, band_id: u32, expires_in: Duration) -> httpmock::Mock<'_> {
+    let expiration = (OffsetDateTime::now_utc() + expires_in).unix_timestamp();
+    env.mock(|when, then| {
+        when.method(POST).path("/fps").json_body_partial(
+            json!({ "band": format!("/api/satellite_bands/{band_id}") }).to_string(),
+        );
+        then.status(200)
+            .json_body(json!({ "token": "abc123", "expiration": expiration }));
+    })
+}
+
+#[tokio::test]
+async fn provider_reuses_unexpired_tokens() -> TestResult {
+    let env = TestingEnv::new();
+    let fps = expiring_in(&env, 42, Duration::hours(1));
+    let provider = TokenProvider::new(Client::from(env.clone()));
+
+    for _ in 0..3 {
+        provider.token(42, FpsTarget::Satellite(101.into())).await?;
+    }
+
+    fps.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn provider_refreshes_expiring_tokens() -> TestResult {
+    let env = TestingEnv::new();
+    let fps = expiring_in(&env, 42, Duration::seconds(10));
+    let provider = TokenProvider::new(Client::from(env.clone()));
+
+    // The token expires within the default margin, so is requested every time
+    provider.token(42, FpsTarget::Satellite(101.into())).await?;
+    provider.token(42, FpsTarget::Satellite(101.into())).await?;
+    fps.assert_hits(2);
+
+    let provider = provider.refresh_margin(std::time::Duration::ZERO);
+    provider.token(42, FpsTarget::Satellite(101.into())).await?;
+    provider.token(42, FpsTarget::Satellite(101.into())).await?;
+    fps.assert_hits(2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn provider_caches_per_band_and_target() -> TestResult {
+    let env = TestingEnv::new();
+    let first = expiring_in(&env, 42, Duration::hours(1));
+    let second = expiring_in(&env, 43, Duration::hours(1));
+    let provider = TokenProvider::new(Client::from(env.clone()));
+
+    provider.token(42, FpsTarget::Satellite(101.into())).await?;
+    provider.token(42, FpsTarget::Satellite(102.into())).await?;
+    provider.token(43, FpsTarget::Satellite(101.into())).await?;
+    provider.token(42, FpsTarget::Satellite(101.into())).await?;
+
+    first.assert_hits(2);
+    second.assert_hits(1);
+
+    Ok(())
+}
@@ -0,0 +1,241 @@
+mod common;
+
+use common::{get_account_content, TestResult, TestingEnv};
+use freedom_api::{error::Error, prelude::*};
+use httpmock::Method::{GET, POST};
+
+#[tokio::test]
+async fn send_validated_rejects_unknown_roles() -> TestResult {
+    let env = TestingEnv::new();
+
+    let roles = env.mock(|when, then| {
+        when.method(GET).path("/users/roles");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"["ROLE_USER", "ROLE_SCHEDULER"]"#);
+    });
+    let create = env.mock(|when, then| {
+        when.method(POST).path("/accounts/1/newuser");
+        then.status(201);
+    });
+    let client = Client::from(env.clone());
+    let new_user = |role: &str| {
+        client
+            .new_user()
+            .account_id(1)
+            .first_name("Han")
+            .last_name("Solo")
+            .email("flyingsolo@gmail.com")
+            .add_role(role)
+    };
+
+    let result = new_user("ROLE_SCHEDULR").send_validated().await;
+    assert_eq!(
+        result.unwrap_err(),
+        Error::Validation(vec![String::from(
+            r#"unknown role "ROLE_SCHEDULR", did you mean "ROLE_SCHEDULER"?"#
+        )])
+    );
+    create.assert_hits(0);
+
+    new_user("ROLE_SCHEDULER").send_validated().await?;
+    roles.assert_hits(2);
+    create.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn available_roles_fall_back_to_known_roles() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.mock(|when, then| {
+        when.method(GET).path("/users/roles");
+        then.status(404);
+    });
+    let client = Client::from(env);
+
+    let roles = client.get_available_roles().await?;
+    assert!(roles.contains(&String::from("ROLE_USER")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn available_roles_fall_back_when_listing_is_refused() -> TestResult {
+    for status in [403, 405] {
+        let env = TestingEnv::new();
+
+        env.mock(|when, then| {
+            when.method(GET).path("/users/roles");
+            then.status(status);
+        });
+        let client = Client::from(env);
+
+        let roles = client.get_available_roles().await?;
+        assert!(roles.contains(&String::from("ROLE_USER")), "{status}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn available_roles_propagate_server_errors() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.mock(|when, then| {
+        when.method(GET).path("/users/roles");
+        then.status(500);
+    });
+    let client = Client::from(env);
+
+    assert!(client.get_available_roles().await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_user_by_email() -> TestResult {
+    let env = TestingEnv::new();
+
+    // A `+` which is not encoded would be decoded into a space by the server
+    let find = env.mock(|when, then| {
+        when.method(GET)
+            .path("/users/search/findOneByEmail")
+            .query_param("email", "han+ops@abcspace.com");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(env.json_from_file("resources/users_find_one_by_email.json"));
+    });
+    let client = Client::from(env.clone());
+
+    let user = client.get_user_by_email("han+ops@abcspace.com").await?;
+
+    assert_eq!(user.get_id()?, 1109);
+    find.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_users_by_account_name() -> TestResult {
+    let env = TestingEnv::new();
+
+    env.get_json_from_file(
+        "/users/search/findAllByAccountName",
+        vec![("accountName", "ABC Space")],
+        "resources/users_find_all_by_account_name.json",
+    );
+    let client = Client::from(env);
+
+    let ids = client
+        .get_users_by_account_name("ABC Space")
+        .try_collect_inner()
+        .await?
+        .iter()
+        .map(UserExt::get_id)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(ids, [1109, 1110]);
+
+    Ok(())
+}
+
+/// Serve the user found by email, and the user at its `self` link
+fn user_fixture(env: &TestingEnv) {
+    env.get_json_from_file(
+        "/users/search/findOneByEmail",
+        vec![("email", "han+ops@abcspace.com")],
+        "resources/users_find_one_by_email.json",
+    );
+    env.get_json_from_file(
+        "/api/users/1109",
+        vec![],
+        "resources/users_find_one_by_email.json",
+    );
+}
+
+#[tokio::test]
+async fn roles_and_account_of_user() -> TestResult {
+    let env = TestingEnv::new();
+    user_fixture(&env);
+    get_account_content(&env, "/api/users/1109/account");
+    let client = Client::from(env);
+
+    let user = client.get_user_by_email("han+ops@abcspace.com").await?;
+    assert_eq!(
+        user.get_roles(&client).await?,
+        ["ROLE_USER", "ROLE_OPERATOR"]
+    );
+
+    let account = user.get_account(&client).await?;
+    assert_eq!(account.name, "ABC Space");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_without_links_is_missing_uri() -> TestResult {
+    let env = TestingEnv::new();
+    user_fixture(&env);
+    let client = Client::from(env);
+
+    let mut user = client
+        .get_user_by_email("han+ops@abcspace.com")
+        .await?
+        .into_inner();
+    user.links.clear();
+
+    let result = user.get_account(&client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("account"))),
+        "{result:?}"
+    );
+    let result = user.get_roles(&client).await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("self"))),
+        "{result:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn current_user_and_account() -> TestResult {
+    let env = TestingEnv::new();
+    env.get_json_from_file("/users/current", vec![], "resources/users_current.json");
+    get_account_content(&env, "/api/users/1110/account");
+    let client = Client::from(env.clone());
+
+    let user = client.get_current_user().await?;
+    assert_eq!(user.get_id()?, 1110);
+
+    let account = client.get_current_account().await?;
+    assert_eq!(account.name, "ABC Space");
+
+    let account_uri = client.account_uri().await?;
+    assert_eq!(
+        account_uri,
+        format!("http://localhost:{}/api/accounts/34", env.port())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn current_account_without_link_is_missing_uri() -> TestResult {
+    let env = TestingEnv::new();
+    let mut user: serde_json::Value =
+        serde_json::from_str(&env.json_from_file("resources/users_current.json"))?;
+    user["_links"].as_object_mut().unwrap().remove("account");
+    env.get_json("/users/current", vec![], user);
+    let client = Client::from(env);
+
+    let result = client.account_uri().await;
+    assert!(
+        matches!(result, Err(Error::MissingUri("account"))),
+        "{result:?}"
+    );
+
+    Ok(())
+}